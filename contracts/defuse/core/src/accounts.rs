@@ -1,9 +1,16 @@
+use core::num::NonZeroU16;
 use std::{borrow::Cow, collections::BTreeSet};
 
-use near_sdk::{AccountIdRef, near};
-use serde_with::base64::Base64;
+use defuse_borsh_utils::As;
+use defuse_time::borsh::TimestampNanoSeconds;
+use near_sdk::{
+    AccountIdRef, CryptoHash,
+    borsh::{BorshDeserialize, BorshSerialize},
+    near,
+};
+use serde_with::{base58::Base58, base64::Base64};
 
-use crate::{Nonce, Salt, public_key::PublicKey};
+use crate::{Nonce, Salt, Timestamp, public_key::PublicKey};
 
 #[must_use = "make sure to `.emit()` this event"]
 #[near(serializers = [json])]
@@ -41,6 +48,29 @@ pub struct PublicKeyEvent<'a> {
     pub public_key: Cow<'a, PublicKey>,
 }
 
+/// Time after which a public key can no longer be used by the account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[borsh(crate = "::near_sdk::borsh")]
+pub struct PublicKeyExpiration {
+    #[borsh(
+        serialize_with = "As::<TimestampNanoSeconds<i64>>::serialize",
+        deserialize_with = "As::<TimestampNanoSeconds<i64>>::deserialize"
+    )]
+    pub expires_at: Timestamp,
+}
+
+impl PublicKeyExpiration {
+    #[inline]
+    pub const fn new(expires_at: Timestamp) -> Self {
+        Self { expires_at }
+    }
+
+    #[inline]
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.expires_at <= now
+    }
+}
+
 #[near(serializers = [json])]
 #[derive(Debug, Clone)]
 pub struct NonceEvent {
@@ -55,6 +85,31 @@ impl NonceEvent {
     }
 }
 
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct IntentCancelledEvent {
+    #[serde_as(as = "Base58")]
+    pub intent_hash: CryptoHash,
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct WebAuthnAllowedOriginsEvent<'a> {
+    /// `None` means that assertions created on any origin are accepted
+    pub allowed_origins: Option<Cow<'a, [String]>>,
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct MultisigThresholdEvent {
+    /// Minimum number of distinct public keys required to sign
+    /// intents on behalf of the account
+    pub threshold: NonZeroU16,
+}
+
 #[must_use = "make sure to `.emit()` this event"]
 #[near(serializers = [json])]
 #[derive(Debug, Clone)]
@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+use defuse_borsh_utils::As;
+use defuse_time::borsh::TimestampNanoSeconds;
+use near_sdk::{
+    AccountIdRef,
+    borsh::{BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    near,
+};
+
+use crate::{Timestamp, token_id::TokenId};
+
+/// Amount of owner's tokens which a spender is allowed to transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[borsh(crate = "::near_sdk::borsh")]
+pub struct Allowance {
+    pub amount: u128,
+
+    /// `None` if the allowance never expires
+    #[borsh(
+        serialize_with = "As::<Option<TimestampNanoSeconds<i64>>>::serialize",
+        deserialize_with = "As::<Option<TimestampNanoSeconds<i64>>>::deserialize"
+    )]
+    pub expires_at: Option<Timestamp>,
+}
+
+impl Allowance {
+    /// Returns amount which can still be spent at given time
+    #[inline]
+    pub fn available_at(&self, now: Timestamp) -> u128 {
+        if self.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            0
+        } else {
+            self.amount
+        }
+    }
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct AllowanceSetEvent<'a> {
+    pub spender_id: Cow<'a, AccountIdRef>,
+    pub token_id: Cow<'a, TokenId>,
+    /// Zero if the allowance was revoked
+    pub amount: U128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
@@ -0,0 +1,317 @@
+//! Checkpoints of the verifier state for light verification.
+//!
+//! A [`StateCheckpoint`] commits to all account balances and nonces at
+//! given block height as a root of [`MerkleTree`] over [`CheckpointLeaf`]s.
+//! The root is computed off-chain from the state at `block_height` and
+//! published on-chain by a permissioned account, so that third parties can
+//! verify a single balance or nonce with [`CheckpointProof`] instead of
+//! replaying all events since genesis.
+
+use defuse_digest::{Digest, sha2::Sha256};
+use near_sdk::{AccountId, CryptoHash, borsh, near};
+use serde_with::{DisplayFromStr, base58::Base58, base64::Base64};
+
+use crate::{Nonce, token_id::TokenId};
+
+/// Domain separation tags to prevent second preimage attacks
+const LEAF_TAG: u8 = 0x00;
+const NODE_TAG: u8 = 0x01;
+
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateCheckpoint {
+    /// Sequential number of the checkpoint, starting from zero
+    pub epoch: u64,
+    /// Height of the block which the state was committed at
+    pub block_height: u64,
+    /// Total number of leaves in the tree
+    pub leaves: u64,
+    #[serde_as(as = "Base58")]
+    pub root: CryptoHash,
+}
+
+/// Single entry of the verifier state
+#[near(serializers = [borsh, json])]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckpointLeaf {
+    Balance {
+        account_id: AccountId,
+        token_id: TokenId,
+        #[serde_as(as = "DisplayFromStr")]
+        amount: u128,
+    },
+    Nonce {
+        account_id: AccountId,
+        #[serde_as(as = "Base64")]
+        nonce: Nonce,
+    },
+}
+
+impl CheckpointLeaf {
+    /// `sha256(0x00 || borsh(leaf))`
+    #[must_use]
+    pub fn hash(&self) -> CryptoHash {
+        Sha256::new()
+            .chain_update([LEAF_TAG])
+            .chain_update(borsh::to_vec(self).unwrap_or_else(|_| unreachable!()))
+            .finalize()
+            .into()
+    }
+}
+
+/// `sha256(0x01 || left || right)`
+#[inline]
+fn hash_node(left: &CryptoHash, right: &CryptoHash) -> CryptoHash {
+    Sha256::new()
+        .chain_update([NODE_TAG])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Binary Merkle tree over leaf hashes sorted in ascending order.
+/// The last node on a level with odd number of nodes is promoted
+/// to the next level as-is.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    levels: Vec<Vec<CryptoHash>>,
+}
+
+impl MerkleTree {
+    pub fn new<'a>(leaves: impl IntoIterator<Item = &'a CheckpointLeaf>) -> Self {
+        let mut level: Vec<_> = leaves.into_iter().map(CheckpointLeaf::hash).collect();
+        level.sort_unstable();
+        level.dedup();
+
+        let mut levels = vec![level];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Self { levels }
+    }
+
+    #[must_use]
+    pub fn leaves(&self) -> u64 {
+        self.levels[0]
+            .len()
+            .try_into()
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Returns `None` for empty tree
+    #[must_use]
+    pub fn root(&self) -> Option<CryptoHash> {
+        self.levels.last()?.first().copied()
+    }
+
+    /// Returns proof of inclusion for given leaf, if present
+    #[must_use]
+    pub fn proof(&self, leaf: &CheckpointLeaf) -> Option<MerkleProof> {
+        let mut index = self.levels[0].binary_search(&leaf.hash()).ok()?;
+        let proof_index = index.try_into().ok()?;
+
+        let mut siblings = Vec::with_capacity(self.levels.len() - 1);
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                siblings.push(*sibling);
+            }
+            index /= 2;
+        }
+
+        Some(MerkleProof {
+            index: proof_index,
+            siblings,
+        })
+    }
+}
+
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Index of the leaf among sorted leaf hashes
+    pub index: u64,
+    /// Sibling hashes from the bottom to the top, excluding promoted nodes
+    #[serde_as(as = "Vec<Base58>")]
+    pub siblings: Vec<CryptoHash>,
+}
+
+impl MerkleProof {
+    /// Computes root of a tree with given total number of `leaves`
+    #[must_use]
+    pub fn compute_root(&self, leaf: CryptoHash, leaves: u64) -> Option<CryptoHash> {
+        if self.index >= leaves {
+            return None;
+        }
+
+        let mut siblings = self.siblings.iter();
+        let (mut index, mut width, mut hash) = (self.index, leaves, leaf);
+        while width > 1 {
+            if index % 2 == 1 {
+                hash = hash_node(siblings.next()?, &hash);
+            } else if index + 1 < width {
+                hash = hash_node(&hash, siblings.next()?);
+            }
+            index /= 2;
+            width = width.div_ceil(2);
+        }
+
+        // all siblings must be consumed
+        siblings.next().is_none().then_some(hash)
+    }
+}
+
+/// Off-chain verifiable proof that the leaf was a part of the
+/// verifier state at given checkpoint.
+///
+/// NOTE: the checkpoint itself MUST be checked against the one
+/// published on-chain.
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointProof {
+    pub checkpoint: StateCheckpoint,
+    pub leaf: CheckpointLeaf,
+    pub proof: MerkleProof,
+}
+
+impl CheckpointProof {
+    #[must_use]
+    pub fn verify(&self) -> bool {
+        self.proof
+            .compute_root(self.leaf.hash(), self.checkpoint.leaves)
+            .is_some_and(|root| root == self.checkpoint.root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::serde_json;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn leaves(n: u8) -> Vec<CheckpointLeaf> {
+        (0..n)
+            .map(|i| {
+                if i % 2 == 0 {
+                    CheckpointLeaf::Balance {
+                        account_id: format!("user{i}.near").parse().unwrap(),
+                        token_id: TokenId::Nep141("token.near".parse().unwrap()),
+                        amount: i.into(),
+                    }
+                } else {
+                    CheckpointLeaf::Nonce {
+                        account_id: format!("user{i}.near").parse().unwrap(),
+                        nonce: [i; 32],
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn checkpoint(tree: &MerkleTree) -> StateCheckpoint {
+        StateCheckpoint {
+            epoch: 0,
+            block_height: 100,
+            leaves: tree.leaves(),
+            root: tree.root().unwrap(),
+        }
+    }
+
+    #[rstest]
+    fn prove_all(#[values(1, 2, 3, 4, 5, 7, 8, 13)] n: u8) {
+        let leaves = leaves(n);
+        let tree = MerkleTree::new(&leaves);
+        let checkpoint = checkpoint(&tree);
+
+        for leaf in leaves {
+            let proof = CheckpointProof {
+                checkpoint,
+                proof: tree.proof(&leaf).unwrap(),
+                leaf,
+            };
+            assert!(proof.verify());
+        }
+    }
+
+    #[test]
+    fn order_independent() {
+        let mut leaves = leaves(5);
+        let root = MerkleTree::new(&leaves).root();
+        leaves.reverse();
+        assert_eq!(MerkleTree::new(&leaves).root(), root);
+    }
+
+    #[test]
+    fn empty() {
+        let tree = MerkleTree::new([]);
+        assert_eq!(tree.root(), None);
+        assert_eq!(tree.proof(&leaves(1)[0]), None);
+    }
+
+    #[test]
+    fn invalid_proofs() {
+        let leaves = leaves(5);
+        let tree = MerkleTree::new(&leaves);
+        let valid = CheckpointProof {
+            checkpoint: checkpoint(&tree),
+            proof: tree.proof(&leaves[2]).unwrap(),
+            leaf: leaves[2].clone(),
+        };
+        assert!(valid.verify());
+
+        let mut proof = valid.clone();
+        proof.leaf = CheckpointLeaf::Balance {
+            account_id: "user2.near".parse().unwrap(),
+            token_id: TokenId::Nep141("token.near".parse().unwrap()),
+            amount: 3,
+        };
+        assert!(!proof.verify());
+
+        let mut proof = valid.clone();
+        proof.proof.index = (proof.proof.index + 1) % 5;
+        assert!(!proof.verify());
+
+        let mut proof = valid.clone();
+        proof.proof.siblings.push([0; 32]);
+        assert!(!proof.verify());
+
+        let mut proof = valid.clone();
+        proof.checkpoint.leaves = proof.proof.index;
+        assert!(!proof.verify());
+
+        let mut proof = valid;
+        proof.proof.siblings.pop();
+        assert!(!proof.verify());
+    }
+
+    #[test]
+    fn json() {
+        let leaf = CheckpointLeaf::Nonce {
+            account_id: "alice.near".parse().unwrap(),
+            nonce: [0; 32],
+        };
+        let json = serde_json::to_value(&leaf).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "kind": "nonce",
+                "account_id": "alice.near",
+                "nonce": "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<CheckpointLeaf>(json).unwrap(),
+            leaf
+        );
+    }
+}
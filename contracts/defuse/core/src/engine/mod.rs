@@ -3,12 +3,22 @@ mod state;
 
 pub use self::{inspector::*, state::*};
 
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, HashMap, HashSet},
+};
+
 use defuse_crypto::{Payload, SignedPayload};
+use near_sdk::{AccountId, AccountIdRef, CryptoHash};
 
 use crate::{
-    DefuseError, ExpirableNonce, Nonce, Result, SaltedNonce, Timestamp, VersionedNonce,
-    intents::{DefuseIntents, ExecutableIntent},
-    payload::{DefusePayload, ExtractDefusePayload, multi::MultiPayload},
+    DefuseError, ExpirableNonce, Nonce, PublicKey, Result, SaltedNonce, Timestamp, VersionedNonce,
+    events::DefuseEvent,
+    intents::{DefuseIntents, ExecutableIntent, IntentGroup},
+    payload::{
+        DefusePayload, ExtractDefusePayload,
+        multi::{MultiPayload, UnsupportedPayloadEvent},
+    },
 };
 
 use self::deltas::{Deltas, Transfers};
@@ -16,6 +26,23 @@ use self::deltas::{Deltas, Transfers};
 pub struct Engine<S, I> {
     pub state: Deltas<S>,
     pub inspector: I,
+    /// ERC-1271 payloads verified by EVM signature oracle, keyed by
+    /// `(chain_id, contract, hash)`
+    pub erc1271_verified: HashSet<(u64, [u8; 20], CryptoHash)>,
+    /// Account which submitted the intents and receives relayer fees
+    pub relayer_id: Option<AccountId>,
+    /// Signers of intent groups along with ones whose payloads
+    /// were executed so far
+    groups: HashMap<[u8; 32], (BTreeSet<AccountId>, BTreeSet<AccountId>)>,
+}
+
+/// Who authorized the signed payload
+enum Signers {
+    /// Public keys registered for the signer, at least multisig
+    /// threshold of the signer of them must be distinct
+    PublicKeys(Vec<PublicKey>),
+    /// ERC-1271 smart account, which can only sign for itself
+    Erc1271(AccountId),
 }
 
 impl<S, I> Engine<S, I>
@@ -28,26 +55,84 @@ where
         Self {
             state: Deltas::new(state),
             inspector,
+            erc1271_verified: HashSet::new(),
+            relayer_id: None,
+            groups: HashMap::new(),
         }
     }
 
+    /// Marks ERC-1271 payloads with given `(chain_id, contract, hash)` as
+    /// verified by EVM signature oracle, since they can't be verified
+    /// synchronously
+    #[must_use]
+    #[inline]
+    pub fn with_erc1271_verified(
+        mut self,
+        verified: impl IntoIterator<Item = (u64, [u8; 20], CryptoHash)>,
+    ) -> Self {
+        self.erc1271_verified.extend(verified);
+        self
+    }
+
+    /// Sets the account which receives relayer fees
+    #[must_use]
+    #[inline]
+    pub fn with_relayer_id(mut self, relayer_id: impl Into<Option<AccountId>>) -> Self {
+        self.relayer_id = relayer_id.into();
+        self
+    }
+
     pub fn execute_signed_intents(
         mut self,
         signed: impl IntoIterator<Item = MultiPayload>,
     ) -> Result<Transfers> {
-        for signed in signed {
+        for (index, signed) in signed.into_iter().enumerate() {
+            if let MultiPayload::Unsupported(payload) = signed {
+                // only this payload is rejected, since older verifiers
+                // can't know standards added after their deployment
+                self.inspector
+                    .on_event(DefuseEvent::UnsupportedPayloadRejected(
+                        UnsupportedPayloadEvent {
+                            index,
+                            standard: Cow::Owned(payload.standard),
+                        },
+                    ));
+                continue;
+            }
             self.execute_signed_intent(signed)?;
         }
+        self.verify_groups()?;
         self.finalize()
     }
 
     fn execute_signed_intent(&mut self, signed: MultiPayload) -> Result<()> {
-        // verify signed payload and get public key
-        let public_key = signed.verify().ok_or(DefuseError::InvalidSignature)?;
-
         // calculate intent hash
         let hash = signed.hash();
 
+        // verify signed payload and get public key(s)
+        let signers = match &signed {
+            MultiPayload::Multisig(multisig) => multisig.verify().map(Signers::PublicKeys),
+            MultiPayload::Bls12381Aggregated(payload) => payload.verify().map(|public_keys| {
+                Signers::PublicKeys(public_keys.into_iter().map(PublicKey::Bls12381).collect())
+            }),
+            MultiPayload::Erc1271(payload) => {
+                if !self.state.is_erc1271_chain_allowed(payload.chain_id) {
+                    return Err(DefuseError::Erc1271ChainNotAllowed(payload.chain_id));
+                }
+                self.erc1271_verified
+                    .contains(&payload.oracle_query())
+                    .then(|| Signers::Erc1271(payload.signer_id()))
+            }
+            signed => signed
+                .verify()
+                .map(|public_key| Signers::PublicKeys(vec![public_key])),
+        }
+        .ok_or(DefuseError::InvalidSignature)?;
+
+        // origins and hashes should be collected before the payload is consumed
+        let webauthn_origins = signed.webauthn_origins();
+        let hashes = signed.hashes();
+
         // extract NEP-413 payload
         let DefusePayload::<DefuseIntents> {
             signer_id,
@@ -69,21 +154,141 @@ where
             return Err(DefuseError::DeadlineExpired);
         }
 
-        // make sure the account has this public key
-        if !self.state.has_public_key(&signer_id, &public_key) {
-            return Err(DefuseError::PublicKeyNotExist(signer_id, public_key));
+        // make sure message is already valid
+        if let Some(not_before) = intents.not_before
+            && Timestamp::now() < not_before
+        {
+            return Err(DefuseError::NotBefore(not_before));
+        }
+
+        match signers {
+            // make sure the account has these public keys
+            Signers::PublicKeys(public_keys) => {
+                self.verify_signers(&signer_id, public_keys)?;
+            }
+            Signers::Erc1271(contract_id) => {
+                if signer_id != contract_id {
+                    return Err(DefuseError::Erc1271SignerMismatch(contract_id));
+                }
+            }
+        }
+        self.verify_webauthn_origins(&signer_id, webauthn_origins)?;
+
+        if self.state.is_account_frozen(&signer_id) {
+            return Err(DefuseError::AccountFrozen(signer_id));
+        }
+
+        if hashes
+            .into_iter()
+            .any(|hash| self.state.is_intent_cancelled(&signer_id, hash))
+        {
+            return Err(DefuseError::IntentCancelled);
         }
 
         // commit nonce
         self.verify_intent_nonce(nonce, deadline)?;
         self.state.commit_nonce(signer_id.clone(), nonce)?;
 
+        if let Some(group) = &intents.group {
+            self.add_to_group(&signer_id, group)?;
+        }
+
         intents.execute_intent(&signer_id, self, hash)?;
         self.inspector.on_intent_executed(&signer_id, hash, nonce);
 
         Ok(())
     }
 
+    /// Ensures that all public keys are registered for the account
+    /// and at least multisig threshold of the account of them are distinct
+    fn verify_signers(
+        &self,
+        signer_id: &AccountIdRef,
+        mut public_keys: Vec<PublicKey>,
+    ) -> Result<()> {
+        if let Some(public_key) = public_keys
+            .iter()
+            .find(|public_key| !self.state.has_public_key(signer_id, public_key))
+        {
+            return Err(DefuseError::PublicKeyNotExist(
+                signer_id.to_owned(),
+                Box::new(*public_key),
+            ));
+        }
+
+        let now = Timestamp::now();
+        if let Some(public_key) = public_keys.iter().find(|public_key| {
+            self.state
+                .public_key_expires_at(signer_id, public_key)
+                .is_some_and(|expires_at| expires_at <= now)
+        }) {
+            return Err(DefuseError::PublicKeyExpired(
+                signer_id.to_owned(),
+                Box::new(*public_key),
+            ));
+        }
+
+        let threshold = self.state.multisig_threshold(signer_id);
+        public_keys.sort_unstable();
+        public_keys.dedup();
+        if public_keys.len() < usize::from(threshold.get()) {
+            return Err(DefuseError::MultisigThresholdNotReached(threshold));
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that all `WebAuthn` assertions were created on origins
+    /// allowed for the account
+    fn verify_webauthn_origins(
+        &self,
+        signer_id: &AccountIdRef,
+        origins: Vec<String>,
+    ) -> Result<()> {
+        if let Some(origin) = origins
+            .into_iter()
+            .find(|origin| !self.state.is_webauthn_origin_allowed(signer_id, origin))
+        {
+            return Err(DefuseError::WebAuthnOriginNotAllowed(
+                signer_id.to_owned(),
+                origin,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Counts one more payload executed within the group
+    fn add_to_group(
+        &mut self,
+        signer_id: &AccountIdRef,
+        IntentGroup { id, signers }: &IntentGroup,
+    ) -> Result<()> {
+        let (group_signers, executed) = self
+            .groups
+            .entry(*id)
+            .or_insert_with(|| (signers.clone(), BTreeSet::new()));
+        if group_signers != signers
+            || !group_signers.contains(signer_id)
+            || !executed.insert(signer_id.to_owned())
+        {
+            return Err(DefuseError::IntentGroupSignersMismatch);
+        }
+        Ok(())
+    }
+
+    /// Ensures that all payloads were executed for each group
+    fn verify_groups(&self) -> Result<()> {
+        if self
+            .groups
+            .values()
+            .any(|(signers, executed)| executed != signers)
+        {
+            return Err(DefuseError::IntentGroupIncomplete);
+        }
+        Ok(())
+    }
+
     #[inline]
     fn verify_intent_nonce(&self, nonce: Nonce, intent_deadline: Timestamp) -> Result<()> {
         let Some(nonce) = VersionedNonce::maybe_from(nonce) else {
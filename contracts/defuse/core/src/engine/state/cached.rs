@@ -1,5 +1,5 @@
 use crate::{
-    DefuseError, Nonce, NoncePrefix, Nonces, Result, Salt,
+    DefuseError, Nonce, NoncePrefix, Nonces, Result, Salt, Timestamp,
     amounts::Amounts,
     fees::Pips,
     intents::{
@@ -11,9 +11,10 @@ use crate::{
     public_key::PublicKey,
     token_id::{TokenId, nep141::Nep141TokenId, nep171::Nep171TokenId, nep245::Nep245TokenId},
 };
+use core::num::NonZeroU16;
 use defuse_bitmap::{U248, U256};
 use defuse_near_utils::Lock;
-use near_sdk::{AccountId, AccountIdRef};
+use near_sdk::{AccountId, AccountIdRef, CryptoHash};
 use std::{
     borrow::Cow,
     collections::{HashMap, HashSet},
@@ -64,6 +65,21 @@ where
         self.view.fee_collector()
     }
 
+    #[inline]
+    fn fee_shares(&self) -> impl Iterator<Item = (AccountId, Pips)> + '_ {
+        self.view.fee_shares()
+    }
+
+    #[inline]
+    fn is_fee_exempt(&self, signer_id: &AccountIdRef, token_id: &TokenId) -> bool {
+        self.view.is_fee_exempt(signer_id, token_id)
+    }
+
+    #[inline]
+    fn token_fee_override(&self, token_id: &TokenId) -> Option<Pips> {
+        self.view.token_fee_override(token_id)
+    }
+
     fn has_public_key(&self, account_id: &AccountIdRef, public_key: &PublicKey) -> bool {
         if let Some(account) = self.accounts.get(account_id).map(Lock::as_inner_unchecked) {
             if account.public_keys_added.contains(public_key) {
@@ -76,6 +92,23 @@ where
         self.view.has_public_key(account_id, public_key)
     }
 
+    fn public_key_expires_at(
+        &self,
+        account_id: &AccountIdRef,
+        public_key: &PublicKey,
+    ) -> Option<Timestamp> {
+        // keys added by intents never expire
+        if self
+            .accounts
+            .get(account_id)
+            .map(Lock::as_inner_unchecked)
+            .is_some_and(|a| a.public_keys_added.contains(public_key))
+        {
+            return None;
+        }
+        self.view.public_key_expires_at(account_id, public_key)
+    }
+
     fn iter_public_keys(&self, account_id: &AccountIdRef) -> impl Iterator<Item = PublicKey> + '_ {
         let account = self.accounts.get(account_id).map(Lock::as_inner_unchecked);
         self.view
@@ -98,6 +131,11 @@ where
             || self.view.is_nonce_used(account_id, nonce)
     }
 
+    #[inline]
+    fn is_intent_cancelled(&self, account_id: &AccountIdRef, intent_hash: CryptoHash) -> bool {
+        self.view.is_intent_cancelled(account_id, intent_hash)
+    }
+
     fn balance_of(&self, account_id: &AccountIdRef, token_id: &TokenId) -> u128 {
         self.accounts
             .get(account_id)
@@ -112,6 +150,18 @@ where
             .map_or_else(|| self.view.is_account_locked(account_id), Lock::is_locked)
     }
 
+    fn is_account_frozen(&self, account_id: &AccountIdRef) -> bool {
+        self.accounts
+            .get(account_id)
+            .is_some_and(|account| account.as_inner_unchecked().frozen)
+            || self.view.is_account_frozen(account_id)
+    }
+
+    #[inline]
+    fn is_token_denied(&self, token_id: &TokenId) -> bool {
+        self.view.is_token_denied(token_id)
+    }
+
     fn is_auth_by_predecessor_id_enabled(&self, account_id: &AccountIdRef) -> bool {
         let was_enabled = self.view.is_auth_by_predecessor_id_enabled(account_id);
         let toggled = self
@@ -125,6 +175,18 @@ where
     fn is_valid_salt(&self, salt: Salt) -> bool {
         self.view.is_valid_salt(salt)
     }
+
+    fn is_webauthn_origin_allowed(&self, account_id: &AccountIdRef, origin: &str) -> bool {
+        self.view.is_webauthn_origin_allowed(account_id, origin)
+    }
+
+    fn multisig_threshold(&self, account_id: &AccountIdRef) -> NonZeroU16 {
+        self.view.multisig_threshold(account_id)
+    }
+
+    fn is_erc1271_chain_allowed(&self, chain_id: u64) -> bool {
+        self.view.is_erc1271_chain_allowed(chain_id)
+    }
 }
 
 impl<W> State for CachedState<W>
@@ -146,7 +208,10 @@ where
             account.public_keys_added.insert(public_key)
         };
         if !added {
-            return Err(DefuseError::PublicKeyExists(account_id, public_key));
+            return Err(DefuseError::PublicKeyExists(
+                account_id,
+                Box::new(public_key),
+            ));
         }
         Ok(())
     }
@@ -166,7 +231,10 @@ where
             account.public_keys_added.remove(&public_key)
         };
         if !removed {
-            return Err(DefuseError::PublicKeyNotExist(account_id, public_key));
+            return Err(DefuseError::PublicKeyNotExist(
+                account_id,
+                Box::new(public_key),
+            ));
         }
         Ok(())
     }
@@ -257,6 +325,8 @@ where
     }
 
     fn ft_withdraw(&mut self, owner_id: &AccountIdRef, withdraw: FtWithdraw) -> Result<()> {
+        withdraw.withdraw_memo()?;
+
         self.internal_sub_balance(
             owner_id,
             std::iter::once((
@@ -292,6 +362,7 @@ where
         if withdraw.token_ids.len() != withdraw.amounts.len() || withdraw.token_ids.is_empty() {
             return Err(DefuseError::InvalidIntent);
         }
+        withdraw.withdraw_memo()?;
 
         self.internal_sub_balance(
             owner_id,
@@ -361,6 +432,18 @@ where
         Ok(was_enabled)
     }
 
+    fn freeze_account(&mut self, account_id: AccountId) -> Result<bool> {
+        if self.is_account_frozen(&account_id) {
+            return Ok(false);
+        }
+        self.accounts
+            .get_or_create(account_id, |owner_id| self.view.is_account_locked(owner_id))
+            // frozen accounts may be locked as well
+            .as_inner_unchecked_mut()
+            .frozen = true;
+        Ok(true)
+    }
+
     fn auth_call(&mut self, signer_id: &AccountIdRef, auth_call: AuthCall) -> Result<()> {
         if !auth_call.attached_deposit.is_zero() {
             self.internal_sub_balance(
@@ -429,6 +512,8 @@ pub struct CachedAccount {
 
     auth_by_predecessor_id_toggled: bool,
 
+    frozen: bool,
+
     public_keys_added: HashSet<PublicKey>,
     public_keys_removed: HashSet<PublicKey>,
 
@@ -1,5 +1,5 @@
 use crate::{
-    DefuseError, Nonce, NoncePrefix, Result, Salt,
+    DefuseError, Nonce, NoncePrefix, Result, Salt, Timestamp,
     amounts::Amounts,
     fees::Pips,
     intents::{
@@ -12,9 +12,10 @@ use crate::{
     public_key::PublicKey,
     token_id::TokenId,
 };
+use core::num::NonZeroU16;
 use defuse_map_utils::cleanup::DefaultMap;
 use defuse_nep245::{MtEvent, MtTransferEvent};
-use near_sdk::{AccountId, AccountIdRef, json_types::U128, near};
+use near_sdk::{AccountId, AccountIdRef, CryptoHash, json_types::U128, near};
 use serde_with::DisplayFromStr;
 use std::{
     borrow::Cow,
@@ -69,6 +70,21 @@ where
         self.state.fee_collector()
     }
 
+    #[inline]
+    fn fee_shares(&self) -> impl Iterator<Item = (AccountId, Pips)> + '_ {
+        self.state.fee_shares()
+    }
+
+    #[inline]
+    fn is_fee_exempt(&self, signer_id: &AccountIdRef, token_id: &TokenId) -> bool {
+        self.state.is_fee_exempt(signer_id, token_id)
+    }
+
+    #[inline]
+    fn token_fee_override(&self, token_id: &TokenId) -> Option<Pips> {
+        self.state.token_fee_override(token_id)
+    }
+
     #[inline]
     fn has_public_key(&self, account_id: &AccountIdRef, public_key: &PublicKey) -> bool {
         self.state.has_public_key(account_id, public_key)
@@ -84,6 +100,11 @@ where
         self.state.is_nonce_used(account_id, nonce)
     }
 
+    #[inline]
+    fn is_intent_cancelled(&self, account_id: &AccountIdRef, intent_hash: CryptoHash) -> bool {
+        self.state.is_intent_cancelled(account_id, intent_hash)
+    }
+
     #[inline]
     fn balance_of(&self, account_id: &AccountIdRef, token_id: &TokenId) -> u128 {
         self.state.balance_of(account_id, token_id)
@@ -94,6 +115,16 @@ where
         self.state.is_account_locked(account_id)
     }
 
+    #[inline]
+    fn is_account_frozen(&self, account_id: &AccountIdRef) -> bool {
+        self.state.is_account_frozen(account_id)
+    }
+
+    #[inline]
+    fn is_token_denied(&self, token_id: &TokenId) -> bool {
+        self.state.is_token_denied(token_id)
+    }
+
     #[inline]
     fn is_auth_by_predecessor_id_enabled(&self, account_id: &AccountIdRef) -> bool {
         self.state.is_auth_by_predecessor_id_enabled(account_id)
@@ -103,6 +134,29 @@ where
     fn is_valid_salt(&self, salt: Salt) -> bool {
         self.state.is_valid_salt(salt)
     }
+
+    fn is_webauthn_origin_allowed(&self, account_id: &AccountIdRef, origin: &str) -> bool {
+        self.state.is_webauthn_origin_allowed(account_id, origin)
+    }
+
+    #[inline]
+    fn multisig_threshold(&self, account_id: &AccountIdRef) -> NonZeroU16 {
+        self.state.multisig_threshold(account_id)
+    }
+
+    #[inline]
+    fn is_erc1271_chain_allowed(&self, chain_id: u64) -> bool {
+        self.state.is_erc1271_chain_allowed(chain_id)
+    }
+
+    #[inline]
+    fn public_key_expires_at(
+        &self,
+        account_id: &AccountIdRef,
+        public_key: &PublicKey,
+    ) -> Option<Timestamp> {
+        self.state.public_key_expires_at(account_id, public_key)
+    }
 }
 
 impl<S> State for Deltas<S>
@@ -209,6 +263,11 @@ where
         self.state.set_auth_by_predecessor_id(account_id, enable)
     }
 
+    #[inline]
+    fn freeze_account(&mut self, account_id: AccountId) -> Result<bool> {
+        self.state.freeze_account(account_id)
+    }
+
     #[inline]
     fn auth_call(&mut self, signer_id: &AccountIdRef, auth_call: AuthCall) -> Result<()> {
         self.state.auth_call(signer_id, auth_call)
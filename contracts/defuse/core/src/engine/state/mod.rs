@@ -2,7 +2,7 @@ pub mod cached;
 pub mod deltas;
 
 use crate::{
-    Nonce, NoncePrefix, Result, Salt,
+    Nonce, NoncePrefix, Result, Salt, Timestamp,
     amounts::Amounts,
     fees::Pips,
     intents::{
@@ -15,8 +15,9 @@ use crate::{
     token_id::{TokenId, nep141::Nep141TokenId},
 };
 use cached::CachedState;
+use core::num::NonZeroU16;
 use impl_tools::autoimpl;
-use near_sdk::{AccountId, AccountIdRef};
+use near_sdk::{AccountId, AccountIdRef, CryptoHash};
 use std::borrow::Cow;
 
 #[cfg(feature = "imt")]
@@ -35,25 +36,70 @@ pub trait StateView {
 
     fn fee(&self) -> Pips;
     fn fee_collector(&self) -> Cow<'_, AccountIdRef>;
+    /// Returns additional fee collectors along with their shares of
+    /// collected fees, while the rest goes to [`.fee_collector()`](Self::fee_collector)
+    fn fee_shares(&self) -> impl Iterator<Item = (AccountId, Pips)> + '_;
+    /// Returns whether `signer_id` is exempt from fees on `token_id`
+    fn is_fee_exempt(&self, signer_id: &AccountIdRef, token_id: &TokenId) -> bool;
+    /// Returns fee overridden for `token_id`, if any
+    fn token_fee_override(&self, token_id: &TokenId) -> Option<Pips>;
+    /// Returns fee applied to `token_id`
+    #[inline]
+    fn token_fee(&self, token_id: &TokenId) -> Pips {
+        self.token_fee_override(token_id)
+            .unwrap_or_else(|| self.fee())
+    }
 
     #[must_use]
     fn has_public_key(&self, account_id: &AccountIdRef, public_key: &PublicKey) -> bool;
     fn iter_public_keys(&self, account_id: &AccountIdRef) -> impl Iterator<Item = PublicKey> + '_;
 
+    /// Returns the time after which `public_key` can no longer be used
+    /// by the account, or `None` if it never expires
+    fn public_key_expires_at(
+        &self,
+        account_id: &AccountIdRef,
+        public_key: &PublicKey,
+    ) -> Option<Timestamp>;
+
     #[must_use]
     fn is_nonce_used(&self, account_id: &AccountIdRef, nonce: Nonce) -> bool;
 
+    /// Returns whether the account cancelled the intent with given hash
+    #[must_use]
+    fn is_intent_cancelled(&self, account_id: &AccountIdRef, intent_hash: CryptoHash) -> bool;
+
     #[must_use]
     fn balance_of(&self, account_id: &AccountIdRef, token_id: &TokenId) -> u128;
 
     fn is_account_locked(&self, account_id: &AccountIdRef) -> bool;
 
+    /// Returns whether the account was frozen by its owner
+    fn is_account_frozen(&self, account_id: &AccountIdRef) -> bool;
+
+    /// Returns whether the token was denylisted, so that it can't be
+    /// deposited or transferred but only withdrawn
+    fn is_token_denied(&self, token_id: &TokenId) -> bool;
+
     /// Returns whether authentication by `PREDECESSOR_ID` is enabled.
     fn is_auth_by_predecessor_id_enabled(&self, account_id: &AccountIdRef) -> bool;
 
     /// Returns whether salt in nonce is valid
     fn is_valid_salt(&self, salt: Salt) -> bool;
 
+    /// Returns whether `WebAuthn` assertions created on given `origin`
+    /// are accepted for the account, i.e. either the account has no
+    /// allowlist of origins configured or `origin` is in it
+    fn is_webauthn_origin_allowed(&self, account_id: &AccountIdRef, origin: &str) -> bool;
+
+    /// Returns minimum number of distinct public keys required to sign
+    /// intents on behalf of the account, which is 1 by default
+    fn multisig_threshold(&self, account_id: &AccountIdRef) -> NonZeroU16;
+
+    /// Returns whether ERC-1271 signatures of smart accounts deployed
+    /// on EVM chain with given `chain_id` are accepted
+    fn is_erc1271_chain_allowed(&self, chain_id: u64) -> bool;
+
     #[inline]
     fn cached(self) -> CachedState<Self>
     where
@@ -131,6 +177,10 @@ pub trait State: StateView {
     /// Returns whether authentication by `PREDECESSOR_ID` was toggled.
     fn set_auth_by_predecessor_id(&mut self, account_id: AccountId, enable: bool) -> Result<bool>;
 
+    /// Freezes the account.
+    /// Returns `false` if the account was already frozen.
+    fn freeze_account(&mut self, account_id: AccountId) -> Result<bool>;
+
     fn auth_call(&mut self, signer_id: &AccountIdRef, auth_call: AuthCall) -> Result<()>;
 
     fn mint(&mut self, owner_id: AccountId, tokens: Amounts, memo: Option<String>) -> Result<()>;
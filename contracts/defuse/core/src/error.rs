@@ -1,16 +1,23 @@
+use core::num::NonZeroU16;
+
 use crate::{
+    Timestamp,
     engine::deltas::InvariantViolated,
     public_key::PublicKey,
     token_id::{TokenId, TokenIdError, nep171::Nep171TokenId},
-    tokens::MAX_TOKEN_ID_LEN,
+    tokens::{MAX_TOKEN_ID_LEN, MAX_WITHDRAW_MEMO_LEN},
 };
+use defuse_near_utils::ErrorCode;
 use defuse_nep245::ErrorLogTooLong;
-use near_sdk::{AccountId, FunctionError, serde_json};
+use near_sdk::{
+    AccountId, FunctionError, NearToken,
+    serde_json::{self, json},
+};
 use thiserror::Error as ThisError;
 
 pub type Result<T, E = DefuseError> = ::core::result::Result<T, E>;
 
-#[derive(Debug, ThisError, FunctionError)]
+#[derive(Debug, ThisError)]
 pub enum DefuseError {
     #[error("account '{0}' not found")]
     AccountNotFound(AccountId),
@@ -18,18 +25,48 @@ pub enum DefuseError {
     #[error("account '{0}' is locked")]
     AccountLocked(AccountId),
 
+    #[error("account '{0}' is frozen by its owner")]
+    AccountFrozen(AccountId),
+
     #[error("authentication by PREDECESSOR_ID is disabled for account '{0}'")]
     AuthByPredecessorIdDisabled(AccountId),
 
     #[error("insufficient balance or overflow")]
     BalanceOverflow,
 
+    #[error("allowance exceeded")]
+    AllowanceExceeded,
+
     #[error("deadline has expired")]
     DeadlineExpired,
 
     #[error("deadline is greater than nonce")]
     DeadlineGreaterThanNonce,
 
+    #[error("can't be executed before {0}")]
+    NotBefore(Timestamp),
+
+    #[error("intent was cancelled by the signer")]
+    IntentCancelled,
+
+    #[error("intent group is incomplete")]
+    IntentGroupIncomplete,
+
+    #[error("intent group signers mismatch")]
+    IntentGroupSignersMismatch,
+
+    #[error("unsupported or malformed payload of standard '{0}'")]
+    UnsupportedPayload(String),
+
+    #[error("EVM signature oracle for ERC-1271 is not set")]
+    Erc1271OracleNotSet,
+
+    #[error("ERC-1271 signature of '{0}' can't be used by other accounts")]
+    Erc1271SignerMismatch(AccountId),
+
+    #[error("ERC-1271 signatures on EVM chain {0} are not allowed")]
+    Erc1271ChainNotAllowed(u64),
+
     #[error("gas overflow")]
     GasOverflow,
 
@@ -39,6 +76,12 @@ pub enum DefuseError {
     #[error("invalid signature")]
     InvalidSignature,
 
+    #[error("insufficient deposit: at least {0} is required")]
+    InsufficientDeposit(NearToken),
+
+    #[error("missing or invalid proof of possession of public key '{0}'")]
+    InvalidProofOfPossession(Box<PublicKey>),
+
     #[error(
         "invariant violated: {}",
         serde_json::to_string(.0).unwrap_or_else(|_| unreachable!())
@@ -48,6 +91,9 @@ pub enum DefuseError {
     #[error("JSON: {0}")]
     JSON(#[from] serde_json::Error),
 
+    #[error("multisig threshold of {0} distinct public keys is not reached")]
+    MultisigThresholdNotReached(NonZeroU16),
+
     #[error("NFT '{}' is already deposited", TokenId::Nep171(.0.clone()))]
     NftAlreadyDeposited(Nep171TokenId),
 
@@ -61,26 +107,162 @@ pub enum DefuseError {
     InvalidNonce,
 
     #[error("public key '{1}' already exists for account '{0}'")]
-    PublicKeyExists(AccountId, PublicKey),
+    PublicKeyExists(AccountId, Box<PublicKey>),
 
     #[error("public key '{1}' doesn't exist for account '{0}'")]
-    PublicKeyNotExist(AccountId, PublicKey),
+    PublicKeyNotExist(AccountId, Box<PublicKey>),
+
+    #[error("public key '{1}' has expired for account '{0}'")]
+    PublicKeyExpired(AccountId, Box<PublicKey>),
 
     #[error("token_id: {0}")]
     ParseTokenId(#[from] TokenIdError),
 
+    #[error("relayer is unknown, so relayer fee can't be paid")]
+    RelayerUnknown,
+
     #[error("wrong verifying_contract")]
     WrongVerifyingContract,
 
+    #[error("invalid structured withdrawal memo")]
+    InvalidWithdrawMemo,
+
     #[error("invalid salt")]
     InvalidSalt,
 
     #[error("maximum attempts to generate a new salt reached")]
     SaltGenerationFailed,
 
+    #[error("token '{0}' is denied")]
+    TokenDenied(TokenId),
+
     #[error("token_id is too long: max length is {MAX_TOKEN_ID_LEN}, got {0}")]
     TokenIdTooLarge(usize),
 
     #[error(transparent)]
     LogTooLong(#[from] ErrorLogTooLong),
+
+    #[error("WebAuthn origin '{1}' is not allowed for account '{0}'")]
+    WebAuthnOriginNotAllowed(AccountId, String),
+
+    #[error("withdrawal limit exceeded for '{0}'")]
+    WithdrawalLimitExceeded(TokenId),
+
+    #[error(
+        "structured withdrawal memo is too long: max length is {MAX_WITHDRAW_MEMO_LEN}, got {0}"
+    )]
+    WithdrawMemoTooLong(usize),
+}
+
+impl ErrorCode for DefuseError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::AccountNotFound(_) => "account_not_found",
+            Self::AccountLocked(_) => "account_locked",
+            Self::AccountFrozen(_) => "account_frozen",
+            Self::AuthByPredecessorIdDisabled(_) => "auth_by_predecessor_id_disabled",
+            Self::AllowanceExceeded => "allowance_exceeded",
+            Self::BalanceOverflow => "balance_overflow",
+            Self::DeadlineExpired => "deadline_expired",
+            Self::DeadlineGreaterThanNonce => "deadline_greater_than_nonce",
+            Self::NotBefore(_) => "not_before",
+            Self::IntentCancelled => "intent_cancelled",
+            Self::IntentGroupIncomplete => "intent_group_incomplete",
+            Self::IntentGroupSignersMismatch => "intent_group_signers_mismatch",
+            Self::UnsupportedPayload(_) => "unsupported_payload",
+            Self::Erc1271OracleNotSet => "erc1271_oracle_not_set",
+            Self::Erc1271SignerMismatch(_) => "erc1271_signer_mismatch",
+            Self::Erc1271ChainNotAllowed(_) => "erc1271_chain_not_allowed",
+            Self::GasOverflow => "gas_overflow",
+            Self::InvalidIntent => "invalid_intent",
+            Self::InvalidSignature => "invalid_signature",
+            Self::InsufficientDeposit(_) => "insufficient_deposit",
+            Self::InvalidProofOfPossession(_) => "invalid_proof_of_possession",
+            Self::InvariantViolated(_) => "invariant_violated",
+            Self::JSON(_) => "json",
+            Self::MultisigThresholdNotReached(_) => "multisig_threshold_not_reached",
+            Self::NftAlreadyDeposited(_) => "nft_already_deposited",
+            Self::NonceUsed => "nonce_used",
+            Self::NonceExpired => "nonce_expired",
+            Self::InvalidNonce => "invalid_nonce",
+            Self::PublicKeyExists(..) => "public_key_exists",
+            Self::PublicKeyNotExist(..) => "public_key_not_exist",
+            Self::PublicKeyExpired(..) => "public_key_expired",
+            Self::ParseTokenId(_) => "parse_token_id",
+            Self::RelayerUnknown => "relayer_unknown",
+            Self::WrongVerifyingContract => "wrong_verifying_contract",
+            Self::InvalidWithdrawMemo => "invalid_withdraw_memo",
+            Self::InvalidSalt => "invalid_salt",
+            Self::SaltGenerationFailed => "salt_generation_failed",
+            Self::TokenDenied(_) => "token_denied",
+            Self::TokenIdTooLarge(_) => "token_id_too_large",
+            Self::LogTooLong(_) => "log_too_long",
+            Self::WebAuthnOriginNotAllowed(..) => "webauthn_origin_not_allowed",
+            Self::WithdrawalLimitExceeded(_) => "withdrawal_limit_exceeded",
+            Self::WithdrawMemoTooLong(_) => "withdraw_memo_too_long",
+        }
+    }
+
+    fn details(&self) -> Option<serde_json::Value> {
+        Some(match self {
+            Self::AccountNotFound(account_id)
+            | Self::AccountLocked(account_id)
+            | Self::AccountFrozen(account_id)
+            | Self::AuthByPredecessorIdDisabled(account_id)
+            | Self::Erc1271SignerMismatch(account_id) => json!({
+                "account_id": account_id,
+            }),
+            Self::InvariantViolated(violated) => serde_json::to_value(violated).ok()?,
+            Self::NftAlreadyDeposited(token_id) => json!({
+                "token_id": TokenId::Nep171(token_id.clone()),
+            }),
+            Self::MultisigThresholdNotReached(threshold) => json!({
+                "threshold": threshold,
+            }),
+            Self::TokenDenied(token_id) | Self::WithdrawalLimitExceeded(token_id) => json!({
+                "token_id": token_id,
+            }),
+            Self::PublicKeyExists(account_id, public_key)
+            | Self::PublicKeyNotExist(account_id, public_key)
+            | Self::PublicKeyExpired(account_id, public_key) => json!({
+                "account_id": account_id,
+                "public_key": public_key,
+            }),
+            Self::InsufficientDeposit(required) => json!({
+                "required": required,
+            }),
+            Self::InvalidProofOfPossession(public_key) => json!({
+                "public_key": public_key,
+            }),
+            Self::WebAuthnOriginNotAllowed(account_id, origin) => json!({
+                "account_id": account_id,
+                "origin": origin,
+            }),
+            Self::UnsupportedPayload(standard) => json!({
+                "standard": standard,
+            }),
+            Self::Erc1271ChainNotAllowed(chain_id) => json!({
+                "chain_id": chain_id,
+            }),
+            Self::NotBefore(not_before) => json!({
+                "not_before": not_before,
+            }),
+            Self::TokenIdTooLarge(len) => json!({
+                "max_len": MAX_TOKEN_ID_LEN,
+                "len": len,
+            }),
+            Self::WithdrawMemoTooLong(len) => json!({
+                "max_len": MAX_WITHDRAW_MEMO_LEN,
+                "len": len,
+            }),
+            _ => return None,
+        })
+    }
+}
+
+impl FunctionError for DefuseError {
+    #[inline]
+    fn panic(&self) -> ! {
+        self.panic_with_envelope()
+    }
 }
@@ -3,19 +3,32 @@ use near_sdk::{near, serde::Deserialize};
 use std::borrow::Cow;
 
 use crate::{
-    accounts::{AccountEvent, NonceEvent, PublicKeyEvent, SaltRotationEvent},
-    fees::{FeeChangedEvent, FeeCollectorChangedEvent},
+    SaltRotationPolicy,
+    accounts::{
+        AccountEvent, IntentCancelledEvent, MultisigThresholdEvent, NonceEvent, PublicKeyEvent,
+        SaltRotationEvent, WebAuthnAllowedOriginsEvent,
+    },
+    allowances::AllowanceSetEvent,
+    fees::{
+        FeeChangedEvent, FeeCollectorChangedEvent, FeeExemptionsEvent, FeeShareSetEvent,
+        FeesAccruedEvent, TokenFeeSetEvent,
+    },
     intents::{
         MaybeIntentEvent,
-        account::SetAuthByPredecessorId,
+        account::{InvalidateNonces, SetAuthByPredecessorId},
         token_diff::TokenDiffEvent,
         tokens::{FtWithdraw, MtWithdraw, NativeWithdraw, NftWithdraw, StorageDeposit},
     },
-    tokens::TransferEvent,
+    limits::{DepositCapExceededEvent, DepositCapSetEvent, WithdrawalLimitSetEvent},
+    payload::{
+        erc1271::{Erc1271ChainAllowedEvent, Erc1271OracleSetEvent},
+        multi::UnsupportedPayloadEvent,
+    },
+    tokens::{DepositReferralEvent, TokenDenylistSetEvent, TransferEvent},
 };
 
 #[cfg(feature = "imt")]
-use crate::{intents::imt::ImtBurn, tokens::imt::ImtMintEvent};
+use crate::{checkpoint::StateCheckpoint, intents::imt::ImtBurn, tokens::imt::ImtMintEvent};
 
 #[must_use = "make sure to `.emit()` this event"]
 #[near(event_json(standard = "dip4"))]
@@ -32,6 +45,18 @@ pub enum DefuseEvent<'a> {
     FeeChanged(FeeChangedEvent),
     #[event_version("0.3.0")]
     FeeCollectorChanged(FeeCollectorChangedEvent<'a>),
+    #[event_version("0.4.3")]
+    #[from(skip)]
+    FeeExemptionsAdded(FeeExemptionsEvent<'a>),
+    #[event_version("0.4.3")]
+    #[from(skip)]
+    FeeExemptionsRemoved(FeeExemptionsEvent<'a>),
+    #[event_version("0.4.3")]
+    TokenFeeSet(TokenFeeSetEvent<'a>),
+    #[event_version("0.4.3")]
+    FeeShareSet(FeeShareSetEvent<'a>),
+    #[event_version("0.4.3")]
+    FeesAccrued(MaybeIntentEvent<AccountEvent<'a, FeesAccruedEvent>>),
 
     #[event_version("0.4.3")]
     Transfer(Cow<'a, [MaybeIntentEvent<AccountEvent<'a, TransferEvent<'a>>>]>),
@@ -71,11 +96,63 @@ pub enum DefuseEvent<'a> {
     #[from(skip)]
     AccountUnlocked(AccountEvent<'a, ()>),
 
+    /// Account was frozen by its owner
+    #[event_version("0.4.3")]
+    #[from(skip)]
+    AccountFrozen(AccountEvent<'a, ()>),
+    #[event_version("0.4.3")]
+    #[from(skip)]
+    AccountUnfrozen(AccountEvent<'a, ()>),
+
+    #[event_version("0.4.3")]
+    AllowanceSet(AccountEvent<'a, AllowanceSetEvent<'a>>),
+
     #[event_version("0.4.3")]
     SetAuthByPredecessorId(MaybeIntentEvent<AccountEvent<'a, Cow<'a, SetAuthByPredecessorId>>>),
 
+    #[event_version("0.4.3")]
+    IntentCancelled(AccountEvent<'a, IntentCancelledEvent>),
+
+    #[event_version("0.4.3")]
+    #[from(skip)]
+    NoncesInvalidated(MaybeIntentEvent<AccountEvent<'a, Cow<'a, InvalidateNonces>>>),
+
+    #[event_version("0.4.3")]
+    WebAuthnAllowedOriginsSet(AccountEvent<'a, WebAuthnAllowedOriginsEvent<'a>>),
+    #[event_version("0.4.3")]
+    MultisigThresholdSet(AccountEvent<'a, MultisigThresholdEvent>),
+
     #[event_version("0.4.0")]
     SaltRotation(SaltRotationEvent),
+    #[event_version("0.4.3")]
+    SaltRotationPolicySet(SaltRotationPolicy),
+
+    #[event_version("0.4.3")]
+    WithdrawalLimitSet(WithdrawalLimitSetEvent<'a>),
+
+    #[event_version("0.4.3")]
+    DepositCapSet(DepositCapSetEvent<'a>),
+    #[event_version("0.4.3")]
+    DepositCapExceeded(AccountEvent<'a, DepositCapExceededEvent<'a>>),
+
+    #[event_version("0.4.3")]
+    TokenDenylistSet(TokenDenylistSetEvent<'a>),
+
+    #[event_version("0.4.3")]
+    DepositReferral(AccountEvent<'a, DepositReferralEvent<'a>>),
+
+    #[event_version("0.4.3")]
+    Erc1271OracleSet(Erc1271OracleSetEvent<'a>),
+    #[event_version("0.4.3")]
+    Erc1271ChainAllowed(Erc1271ChainAllowedEvent),
+
+    #[event_version("0.4.3")]
+    UnsupportedPayloadRejected(UnsupportedPayloadEvent<'a>),
+
+    /// Published by the given account
+    #[cfg(feature = "imt")]
+    #[event_version("0.4.3")]
+    StateCheckpoint(AccountEvent<'a, StateCheckpoint>),
 }
 
 pub trait DefuseIntentEmit<'a>: Into<DefuseEvent<'a>> {
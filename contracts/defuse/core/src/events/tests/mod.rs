@@ -1,5 +1,6 @@
 mod v0_4_1;
 
+use core::num::NonZeroU16;
 use std::borrow::Cow;
 
 use defuse_fees::Pips;
@@ -8,23 +9,37 @@ use near_sdk::{AccountId, AccountIdRef, Gas, NearToken, json_types::U128, serde_
 use rstest::rstest;
 
 use crate::{
-    Salt,
-    accounts::{AccountEvent, NonceEvent, PublicKeyEvent, SaltRotationEvent},
+    Salt, SaltRotationPolicy,
+    accounts::{
+        AccountEvent, IntentCancelledEvent, MultisigThresholdEvent, NonceEvent, PublicKeyEvent,
+        SaltRotationEvent, WebAuthnAllowedOriginsEvent,
+    },
+    allowances::AllowanceSetEvent,
     amounts::Amounts,
     events::{DefuseEvent, tests::v0_4_1::DefuseEventV0_4_1},
-    fees::{FeeChangedEvent, FeeCollectorChangedEvent},
+    fees::{
+        FeeChangedEvent, FeeCollectorChangedEvent, FeeExemption, FeeExemptionsEvent,
+        FeeShareSetEvent, FeesAccruedEvent, TokenFeeSetEvent,
+    },
     intents::{
         MaybeIntentEvent,
-        account::SetAuthByPredecessorId,
+        account::{InvalidateNonces, SetAuthByPredecessorId},
         token_diff::{TokenDiff, TokenDiffEvent},
         tokens::{FtWithdraw, MtWithdraw, NativeWithdraw, NftWithdraw, StorageDeposit},
     },
+    limits::{
+        DepositCapExceededEvent, DepositCapSetEvent, WithdrawalLimit, WithdrawalLimitSetEvent,
+    },
+    payload::{
+        erc1271::{Erc1271ChainAllowedEvent, Erc1271OracleSetEvent},
+        multi::UnsupportedPayloadEvent,
+    },
     public_key::PublicKey,
-    tokens::TransferEvent,
+    tokens::{DepositReferralEvent, TokenDenylistSetEvent, TransferEvent},
 };
 
 #[cfg(feature = "imt")]
-use crate::{intents::imt::ImtBurn, tokens::imt::ImtMintEvent};
+use crate::{checkpoint::StateCheckpoint, intents::imt::ImtBurn, tokens::imt::ImtMintEvent};
 
 // NOTE:
 // 1. Adding a new event does not require backward compatibility
@@ -48,6 +63,35 @@ impl DefuseEventVersion {
                         // These events were added in v0.4.2, so they are not expected to be compatible with v0.4.1
                         return;
                     }
+                    #[cfg(feature = "imt")]
+                    DefuseEvent::StateCheckpoint(_) => {
+                        // This event was added in v0.4.3, so it's not expected to be compatible with v0.4.1
+                        return;
+                    }
+                    DefuseEvent::FeeExemptionsAdded(_)
+                    | DefuseEvent::FeeExemptionsRemoved(_)
+                    | DefuseEvent::TokenFeeSet(_)
+                    | DefuseEvent::FeeShareSet(_)
+                    | DefuseEvent::FeesAccrued(_)
+                    | DefuseEvent::WithdrawalLimitSet(_)
+                    | DefuseEvent::DepositCapSet(_)
+                    | DefuseEvent::DepositCapExceeded(_)
+                    | DefuseEvent::TokenDenylistSet(_)
+                    | DefuseEvent::DepositReferral(_)
+                    | DefuseEvent::WebAuthnAllowedOriginsSet(_)
+                    | DefuseEvent::MultisigThresholdSet(_)
+                    | DefuseEvent::Erc1271OracleSet(_)
+                    | DefuseEvent::Erc1271ChainAllowed(_)
+                    | DefuseEvent::UnsupportedPayloadRejected(_)
+                    | DefuseEvent::AccountFrozen(_)
+                    | DefuseEvent::AccountUnfrozen(_)
+                    | DefuseEvent::IntentCancelled(_)
+                    | DefuseEvent::AllowanceSet(_)
+                    | DefuseEvent::SaltRotationPolicySet(_)
+                    | DefuseEvent::NoncesInvalidated(_) => {
+                        // These events were added in v0.4.3, so they are not expected to be compatible with v0.4.1
+                        return;
+                    }
                     _ => serde_json::from_str::<DefuseEventV0_4_1>(&json)
                         .expect("deserialize with old event version"),
                 }
@@ -110,6 +154,51 @@ fn fee_collector_changed_event<'a>() -> DefuseEvent<'a> {
     })
 }
 
+fn fee_exemptions_event<'a>() -> FeeExemptionsEvent<'a> {
+    FeeExemptionsEvent {
+        exemptions: Cow::Owned(vec![
+            FeeExemption::Account(account().into()),
+            FeeExemption::Token(TokenId::Nep141("token.near".parse().unwrap())),
+        ]),
+    }
+}
+
+fn fee_exemptions_added_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::FeeExemptionsAdded(fee_exemptions_event())
+}
+
+fn fee_exemptions_removed_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::FeeExemptionsRemoved(fee_exemptions_event())
+}
+
+fn token_fee_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::TokenFeeSet(TokenFeeSetEvent {
+        token_id: Cow::Owned(TokenId::Nep141("token.near".parse().unwrap())),
+        fee: Some(Pips::from_pips(100).unwrap()),
+    })
+}
+
+fn fee_share_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::FeeShareSet(FeeShareSetEvent {
+        collector: account(),
+        share: Some(Pips::from_percent(20).unwrap()),
+    })
+}
+
+fn fees_accrued_intent_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::FeesAccrued(MaybeIntentEvent::new_intent(
+        AccountEvent {
+            account_id: account(),
+            event: FeesAccruedEvent {
+                tokens: Amounts::new(
+                    [(TokenId::Nep141("token.near".parse().unwrap()), 100)].into(),
+                ),
+            },
+        },
+        [0; 32],
+    ))
+}
+
 fn transfer_intent_event<'a>() -> DefuseEvent<'a> {
     DefuseEvent::Transfer(Cow::Owned(vec![MaybeIntentEvent::new_intent(
         AccountEvent {
@@ -244,6 +333,19 @@ fn storage_deposit_intent_event<'a>() -> DefuseEvent<'a> {
     )]))
 }
 
+#[cfg(feature = "imt")]
+fn state_checkpoint_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::StateCheckpoint(AccountEvent {
+        account_id: account(),
+        event: StateCheckpoint {
+            epoch: 1,
+            block_height: 100,
+            leaves: 10,
+            root: [1; 32],
+        },
+    })
+}
+
 #[cfg(feature = "imt")]
 fn imt_mint_intent_event<'a>() -> DefuseEvent<'a> {
     use std::collections::BTreeMap;
@@ -306,6 +408,32 @@ fn account_unlocked_event<'a>() -> DefuseEvent<'a> {
     })
 }
 
+fn account_frozen_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::AccountFrozen(AccountEvent {
+        account_id: account(),
+        event: (),
+    })
+}
+
+fn account_unfrozen_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::AccountUnfrozen(AccountEvent {
+        account_id: account(),
+        event: (),
+    })
+}
+
+fn allowance_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::AllowanceSet(AccountEvent {
+        account_id: account(),
+        event: AllowanceSetEvent {
+            spender_id: account(),
+            token_id: Cow::Owned(TokenId::Nep141("token.near".parse().unwrap())),
+            amount: U128(100),
+            expires_at: None,
+        },
+    })
+}
+
 fn set_auth_by_predecessor_id_intent_event<'a>() -> DefuseEvent<'a> {
     DefuseEvent::SetAuthByPredecessorId(MaybeIntentEvent::new_intent(
         AccountEvent {
@@ -323,6 +451,24 @@ fn set_auth_by_predecessor_id_direct_event<'a>() -> DefuseEvent<'a> {
     }))
 }
 
+fn webauthn_allowed_origins_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::WebAuthnAllowedOriginsSet(AccountEvent {
+        account_id: account(),
+        event: WebAuthnAllowedOriginsEvent {
+            allowed_origins: Some(Cow::Owned(vec!["https://near-intents.org".to_string()])),
+        },
+    })
+}
+
+fn multisig_threshold_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::MultisigThresholdSet(AccountEvent {
+        account_id: account(),
+        event: MultisigThresholdEvent {
+            threshold: NonZeroU16::new(2).unwrap(),
+        },
+    })
+}
+
 fn salt_rotation_event<'a>() -> DefuseEvent<'a> {
     DefuseEvent::SaltRotation(SaltRotationEvent {
         current: Salt::derive(3),
@@ -330,6 +476,100 @@ fn salt_rotation_event<'a>() -> DefuseEvent<'a> {
     })
 }
 
+fn salt_rotation_policy_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::SaltRotationPolicySet(SaltRotationPolicy {
+        min_interval_secs: 3600,
+        grace_period_secs: Some(86400),
+    })
+}
+
+fn withdrawal_limit_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::WithdrawalLimitSet(WithdrawalLimitSetEvent {
+        token_id: Cow::Owned(TokenId::Nep141("token.near".parse().unwrap())),
+        limit: Some(WithdrawalLimit {
+            window_secs: 3600,
+            total: Some(U128(1_000_000)),
+            per_account: None,
+        }),
+    })
+}
+
+fn deposit_cap_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::DepositCapSet(DepositCapSetEvent {
+        token_id: Cow::Owned(TokenId::Nep141("token.near".parse().unwrap())),
+        cap: Some(U128(1_000_000)),
+    })
+}
+
+fn deposit_cap_exceeded_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::DepositCapExceeded(AccountEvent {
+        account_id: account(),
+        event: DepositCapExceededEvent {
+            token_id: Cow::Owned(TokenId::Nep141("token.near".parse().unwrap())),
+            amount: U128(100),
+            cap: U128(1_000_000),
+        },
+    })
+}
+
+fn token_denylist_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::TokenDenylistSet(TokenDenylistSetEvent {
+        token_id: Cow::Owned(TokenId::Nep141("token.near".parse().unwrap())),
+        denied: true,
+    })
+}
+
+fn deposit_referral_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::DepositReferral(AccountEvent {
+        account_id: account(),
+        event: DepositReferralEvent {
+            referral: Cow::Borrowed(AccountIdRef::new_or_panic("referral.near")),
+            tokens: Amounts::new([(TokenId::Nep141("token.near".parse().unwrap()), 100)].into()),
+        },
+    })
+}
+
+fn erc1271_oracle_set_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::Erc1271OracleSet(Erc1271OracleSetEvent {
+        oracle_id: Some(Cow::Borrowed(AccountIdRef::new_or_panic("oracle.near"))),
+    })
+}
+
+fn erc1271_chain_allowed_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::Erc1271ChainAllowed(Erc1271ChainAllowedEvent {
+        chain_id: 1,
+        allowed: true,
+    })
+}
+
+fn unsupported_payload_rejected_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::UnsupportedPayloadRejected(UnsupportedPayloadEvent {
+        index: 1,
+        standard: Cow::Borrowed("unknown_standard"),
+    })
+}
+
+fn intent_cancelled_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::IntentCancelled(AccountEvent {
+        account_id: account(),
+        event: IntentCancelledEvent {
+            intent_hash: [1; 32],
+        },
+    })
+}
+
+fn nonces_invalidated_intent_event<'a>() -> DefuseEvent<'a> {
+    DefuseEvent::NoncesInvalidated(MaybeIntentEvent::new_intent(
+        AccountEvent {
+            account_id: account(),
+            event: Cow::Owned(InvalidateNonces {
+                nonces: vec![[1; 32], [2; 32]],
+            }),
+        },
+        [0; 32],
+    ))
+}
+
 fn get_all_events<'a>() -> Vec<DefuseEvent<'a>> {
     #[allow(unused_mut)]
     let mut all_events = vec![
@@ -337,6 +577,11 @@ fn get_all_events<'a>() -> Vec<DefuseEvent<'a>> {
         pk_added_intent_event(),
         fee_changed_event(),
         fee_collector_changed_event(),
+        fee_exemptions_added_event(),
+        fee_exemptions_removed_event(),
+        token_fee_set_event(),
+        fee_share_set_event(),
+        fees_accrued_intent_event(),
         transfer_intent_event(),
         token_diff_intent_event(),
         intents_executed_event(),
@@ -347,14 +592,34 @@ fn get_all_events<'a>() -> Vec<DefuseEvent<'a>> {
         storage_deposit_intent_event(),
         account_locked_event(),
         account_unlocked_event(),
+        account_frozen_event(),
+        account_unfrozen_event(),
+        allowance_set_event(),
         set_auth_by_predecessor_id_intent_event(),
         set_auth_by_predecessor_id_direct_event(),
+        webauthn_allowed_origins_set_event(),
+        multisig_threshold_set_event(),
         salt_rotation_event(),
+        salt_rotation_policy_set_event(),
+        withdrawal_limit_set_event(),
+        deposit_cap_set_event(),
+        deposit_cap_exceeded_event(),
+        token_denylist_set_event(),
+        deposit_referral_event(),
+        erc1271_oracle_set_event(),
+        erc1271_chain_allowed_event(),
+        unsupported_payload_rejected_event(),
+        nonces_invalidated_intent_event(),
+        intent_cancelled_event(),
     ];
 
     #[cfg(feature = "imt")]
     {
-        all_events.extend([imt_mint_intent_event(), imt_burn_intent_event()]);
+        all_events.extend([
+            imt_mint_intent_event(),
+            imt_burn_intent_event(),
+            state_checkpoint_event(),
+        ]);
     }
 
     all_events
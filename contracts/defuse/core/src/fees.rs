@@ -1,7 +1,10 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 pub use defuse_fees::{Pips, PipsOutOfRange};
 use near_sdk::{AccountId, AccountIdRef, near};
+use serde_with::DisplayFromStr;
+
+use crate::{amounts::Amounts, token_id::TokenId};
 
 #[near(serializers = [borsh, json])]
 #[derive(Debug, Clone)]
@@ -25,3 +28,60 @@ pub struct FeeCollectorChangedEvent<'a> {
     pub old_fee_collector: Cow<'a, AccountIdRef>,
     pub new_fee_collector: Cow<'a, AccountIdRef>,
 }
+
+/// Exemption from protocol fees
+#[near(serializers = [borsh, json])]
+#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FeeExemption {
+    /// No fees are taken from intents signed by this account
+    Account(AccountId),
+    /// No fees are taken on this token
+    Token(TokenId),
+}
+
+impl FeeExemption {
+    /// Returns whether this exemption applies to `token_id` spent by
+    /// `signer_id`
+    #[inline]
+    pub fn applies_to(&self, signer_id: &AccountIdRef, token_id: &TokenId) -> bool {
+        match self {
+            Self::Account(account_id) => account_id == signer_id,
+            Self::Token(exempt_token_id) => exempt_token_id == token_id,
+        }
+    }
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct FeeExemptionsEvent<'a> {
+    pub exemptions: Cow<'a, [FeeExemption]>,
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct TokenFeeSetEvent<'a> {
+    pub token_id: Cow<'a, TokenId>,
+    /// `None` means that the global fee applies to the token
+    pub fee: Option<Pips>,
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct FeeShareSetEvent<'a> {
+    pub collector: Cow<'a, AccountIdRef>,
+    /// `None` if the collector was removed
+    pub share: Option<Pips>,
+}
+
+/// Fees accrued to the collector, emitted only when collected fees
+/// are split across multiple collectors
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct FeesAccruedEvent {
+    #[serde_as(as = "Amounts<BTreeMap<_, DisplayFromStr>>")]
+    pub tokens: Amounts,
+}
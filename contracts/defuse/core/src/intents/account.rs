@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 
+use defuse_crypto::{Bls12381, Bls12381PublicKey, Bls12381Signature};
 use near_sdk::{AccountIdRef, CryptoHash, near};
+use serde_with::base64::Base64;
 
 use crate::{
-    Result,
+    DefuseError, Nonce, Result,
     accounts::{AccountEvent, PublicKeyEvent},
-    engine::{Engine, Inspector, State},
+    engine::{Engine, Inspector, State, StateView},
     events::DefuseEvent,
     intents::MaybeIntentEvent,
     public_key::PublicKey,
@@ -32,6 +34,28 @@ impl ExecutableIntent for AddPublicKey {
         engine: &mut Engine<S, I>,
         intent_hash: CryptoHash,
     ) -> Result<()>
+    where
+        S: State,
+        I: Inspector,
+    {
+        if matches!(self.public_key, PublicKey::Bls12381(_)) {
+            // see `AddBls12381PublicKey`
+            return Err(DefuseError::InvalidProofOfPossession(Box::new(
+                self.public_key,
+            )));
+        }
+
+        self.add_public_key(signer_id, engine, intent_hash)
+    }
+}
+
+impl AddPublicKey {
+    fn add_public_key<S, I>(
+        self,
+        signer_id: &AccountIdRef,
+        engine: &mut Engine<S, I>,
+        intent_hash: CryptoHash,
+    ) -> Result<()>
     where
         S: State,
         I: Inspector,
@@ -55,6 +79,40 @@ impl ExecutableIntent for AddPublicKey {
     }
 }
 
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone)]
+/// Same as `AddPublicKey`, but for BLS12-381 public keys, which can
+/// only be added along with the proof of possession of the corresponding
+/// secret key. Otherwise, anyone could register a rogue key, which
+/// cancels out keys of others in aggregated signatures.
+pub struct AddBls12381PublicKey {
+    pub public_key: Bls12381PublicKey,
+    /// Signature of `public_key` itself with
+    /// [`POP_DST`](Bls12381::POP_DST) domain separation tag
+    pub proof_of_possession: Bls12381Signature,
+}
+
+impl ExecutableIntent for AddBls12381PublicKey {
+    #[inline]
+    fn execute_intent<S, I>(
+        self,
+        signer_id: &AccountIdRef,
+        engine: &mut Engine<S, I>,
+        intent_hash: CryptoHash,
+    ) -> Result<()>
+    where
+        S: State,
+        I: Inspector,
+    {
+        let public_key = PublicKey::Bls12381(self.public_key.0);
+        if !Bls12381::verify_proof_of_possession(&self.public_key.0, &self.proof_of_possession.0) {
+            return Err(DefuseError::InvalidProofOfPossession(Box::new(public_key)));
+        }
+
+        AddPublicKey { public_key }.add_public_key(signer_id, engine, intent_hash)
+    }
+}
+
 #[near(serializers = [borsh, json])]
 #[derive(Debug, Clone)]
 /// Remove the public key associated with a given account. See `AddPublicKey`.
@@ -127,3 +185,92 @@ impl ExecutableIntent for SetAuthByPredecessorId {
         Ok(())
     }
 }
+
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone)]
+/// Marks given nonces as used, so that intents signed with them, but
+/// not executed yet, can't be executed anymore. Nonces which were
+/// already used are skipped.
+pub struct InvalidateNonces {
+    #[serde_as(as = "Vec<Base64>")]
+    pub nonces: Vec<Nonce>,
+}
+
+impl ExecutableIntent for InvalidateNonces {
+    fn execute_intent<S, I>(
+        self,
+        signer_id: &AccountIdRef,
+        engine: &mut Engine<S, I>,
+        intent_hash: CryptoHash,
+    ) -> Result<()>
+    where
+        S: State,
+        I: Inspector,
+    {
+        let mut invalidated = Vec::with_capacity(self.nonces.len());
+        for nonce in self.nonces {
+            if engine.state.is_nonce_used(signer_id, nonce) {
+                continue;
+            }
+            engine.state.commit_nonce(signer_id.to_owned(), nonce)?;
+            invalidated.push(nonce);
+        }
+
+        if !invalidated.is_empty() {
+            engine.inspector.on_event(DefuseEvent::NoncesInvalidated(
+                MaybeIntentEvent::new_intent(
+                    AccountEvent::new(
+                        Cow::Borrowed(signer_id),
+                        Cow::Owned(Self {
+                            nonces: invalidated,
+                        }),
+                    ),
+                    intent_hash,
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone, Default)]
+/// Freezes the signer, so that neither intents signed on its behalf
+/// nor withdrawals can be executed until it's unfrozen via
+/// `unfreeze_account()`. Meant to be used as a panic button when any
+/// of public keys is compromised.
+///
+/// NOTE: requires authentication by `PREDECESSOR_ID` to be enabled,
+/// since the account can be unfrozen only this way.
+pub struct FreezeAccount {}
+
+impl ExecutableIntent for FreezeAccount {
+    fn execute_intent<S, I>(
+        self,
+        signer_id: &AccountIdRef,
+        engine: &mut Engine<S, I>,
+        _intent_hash: CryptoHash,
+    ) -> Result<()>
+    where
+        S: State,
+        I: Inspector,
+    {
+        if !engine.state.is_auth_by_predecessor_id_enabled(signer_id) {
+            return Err(DefuseError::AuthByPredecessorIdDisabled(
+                signer_id.to_owned(),
+            ));
+        }
+
+        if engine.state.freeze_account(signer_id.to_owned())? {
+            engine
+                .inspector
+                .on_event(DefuseEvent::AccountFrozen(AccountEvent::new(
+                    Cow::Borrowed(signer_id),
+                    (),
+                )));
+        }
+
+        Ok(())
+    }
+}
@@ -6,27 +6,36 @@ pub mod tokens;
 #[cfg(feature = "imt")]
 pub mod imt;
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use derive_more::derive::From;
-use near_sdk::{AccountIdRef, CryptoHash, near};
-use serde_with::base58::Base58;
+use near_sdk::{AccountId, AccountIdRef, CryptoHash, near};
+use serde_with::{DisplayFromStr, base58::Base58, base64::Base64};
 
 #[cfg(feature = "imt")]
 use crate::intents::imt::{ImtBurn, ImtMint};
 
 use crate::{
-    Result,
+    DefuseError, Result, Timestamp,
+    amounts::Amounts,
     engine::{Engine, Inspector, State},
-    intents::{account::SetAuthByPredecessorId, auth::AuthCall},
+    intents::{
+        account::{FreezeAccount, InvalidateNonces, SetAuthByPredecessorId},
+        auth::AuthCall,
+    },
 };
 
 use self::{
-    account::{AddPublicKey, RemovePublicKey},
+    account::{AddBls12381PublicKey, AddPublicKey, RemovePublicKey},
     token_diff::TokenDiff,
     tokens::{FtWithdraw, MtWithdraw, NativeWithdraw, NftWithdraw, StorageDeposit, Transfer},
 };
 
+/// Memo of transfers paying [`DefuseIntents::relayer_fee`]
+pub const RELAYER_FEE_MEMO: &str = "relayer_fee";
+
 #[near(serializers = [json])]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct DefuseIntents {
     /// Sequence of intents to execute in given order. Empty list is also
     /// a valid sequence, i.e. it doesn't do anything, but still invalidates
@@ -34,6 +43,37 @@ pub struct DefuseIntents {
     /// WARNING: Promises created by different intents are executed concurrently and does not rely on the order of the intents in this structure
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub intents: Vec<Intent>,
+
+    /// Tokens to transfer from the signer to the account which submitted
+    /// `execute_intents()` transaction after all intents are executed,
+    /// so that third-party relayers can be compensated in-band
+    #[serde_as(as = "Amounts<BTreeMap<_, DisplayFromStr>>")]
+    #[serde(default, skip_serializing_if = "Amounts::is_empty")]
+    pub relayer_fee: Amounts,
+
+    /// Time before which intents can't be executed, so that pre-signed
+    /// payloads can be scheduled for the future
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<Timestamp>,
+
+    /// Group of payloads which can only be executed all together,
+    /// see [`IntentGroup`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<IntentGroup>,
+}
+
+/// Payloads referencing the same group `id` are executed only if each
+/// of `signers` submitted exactly one of them within the same
+/// `execute_intents()` call, so that counterparties can't be settled
+/// partially nor substituted by anyone else
+#[near(serializers = [json])]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntentGroup {
+    #[serde_as(as = "Base64")]
+    pub id: [u8; 32],
+
+    /// Signers of all payloads in the group
+    pub signers: BTreeSet<AccountId>,
 }
 
 #[near(serializers = [json])]
@@ -73,6 +113,15 @@ pub enum Intent {
     /// See [`AuthCall`]
     AuthCall(AuthCall),
 
+    /// See [`InvalidateNonces`]
+    InvalidateNonces(InvalidateNonces),
+
+    /// See [`FreezeAccount`]
+    FreezeAccount(FreezeAccount),
+
+    /// See [`AddBls12381PublicKey`]
+    AddBls12381PublicKey(AddBls12381PublicKey),
+
     // See [`ImtMint`]
     #[cfg(feature = "imt")]
     ImtMint(ImtMint),
@@ -108,7 +157,26 @@ impl ExecutableIntent for DefuseIntents {
         for intent in self.intents {
             intent.execute_intent(signer_id, engine, intent_hash)?;
         }
-        Ok(())
+
+        if self.relayer_fee.is_empty() {
+            return Ok(());
+        }
+        let relayer_id = engine
+            .relayer_id
+            .clone()
+            .ok_or(DefuseError::RelayerUnknown)?;
+        if relayer_id == signer_id {
+            // relayed by the signer itself
+            return Ok(());
+        }
+
+        Transfer {
+            receiver_id: relayer_id,
+            tokens: self.relayer_fee,
+            memo: Some(RELAYER_FEE_MEMO.to_string()),
+            notification: None,
+        }
+        .execute_intent(signer_id, engine, intent_hash)
     }
 }
 
@@ -137,6 +205,11 @@ impl ExecutableIntent for Intent {
                 intent.execute_intent(signer_id, engine, intent_hash)
             }
             Self::AuthCall(intent) => intent.execute_intent(signer_id, engine, intent_hash),
+            Self::InvalidateNonces(intent) => intent.execute_intent(signer_id, engine, intent_hash),
+            Self::FreezeAccount(intent) => intent.execute_intent(signer_id, engine, intent_hash),
+            Self::AddBls12381PublicKey(intent) => {
+                intent.execute_intent(signer_id, engine, intent_hash)
+            }
             #[cfg(feature = "imt")]
             Self::ImtMint(intent) => intent.execute_intent(signer_id, engine, intent_hash),
             #[cfg(feature = "imt")]
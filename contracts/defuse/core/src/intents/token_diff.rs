@@ -5,7 +5,7 @@ use crate::{
     amounts::Amounts,
     engine::{Engine, Inspector, State, StateView},
     events::DefuseEvent,
-    fees::Pips,
+    fees::{FeesAccruedEvent, Pips},
     intents::MaybeIntentEvent,
     token_id::{TokenId, TokenIdType},
 };
@@ -52,13 +52,15 @@ impl ExecutableIntent for TokenDiff {
             return Err(DefuseError::InvalidIntent);
         }
 
-        let protocol_fee = engine.state.fee();
         let mut fees_collected: Amounts = Amounts::default();
 
         for (token_id, delta) in &self.diff {
             if *delta == 0 {
                 return Err(DefuseError::InvalidIntent);
             }
+            if engine.state.is_token_denied(token_id) {
+                return Err(DefuseError::TokenDenied(token_id.clone()));
+            }
 
             // add delta to signer's account
             engine
@@ -66,8 +68,9 @@ impl ExecutableIntent for TokenDiff {
                 .internal_apply_deltas(signer_id, [(token_id.clone(), *delta)])?;
 
             // take fees only from negative deltas (i.e. token_in)
-            if *delta < 0 {
+            if *delta < 0 && !engine.state.is_fee_exempt(signer_id, token_id) {
                 let amount = delta.unsigned_abs();
+                let protocol_fee = engine.state.token_fee(token_id);
                 let fee = Self::token_fee(token_id, amount, protocol_fee).fee_ceil(amount);
 
                 // collect fee
@@ -92,17 +95,88 @@ impl ExecutableIntent for TokenDiff {
             .into(),
         ));
 
-        // deposit fees to collector
+        // deposit fees to collectors
         if !fees_collected.is_empty() {
-            engine
-                .state
-                .internal_add_balance(engine.state.fee_collector().into_owned(), fees_collected)?;
+            Self::distribute_fees(engine, &fees_collected, intent_hash)?;
         }
 
         Ok(())
     }
 }
 
+impl TokenDiff {
+    /// Splits collected fees across collectors according to their shares,
+    /// while the rest goes to the fee collector
+    fn distribute_fees<S, I>(
+        engine: &mut Engine<S, I>,
+        fees_collected: &Amounts,
+        intent_hash: CryptoHash,
+    ) -> Result<()>
+    where
+        S: State,
+        I: Inspector,
+    {
+        let shares: Vec<_> = engine.state.fee_shares().collect();
+        let mut rest = fees_collected.clone();
+
+        for (collector, share) in &shares {
+            let mut accrued = Amounts::<BTreeMap<TokenId, u128>>::default();
+            for (token_id, fee) in fees_collected {
+                let amount = share.fee(*fee);
+                if amount == 0 {
+                    continue;
+                }
+                rest.sub(token_id.clone(), amount)
+                    .ok_or(DefuseError::BalanceOverflow)?;
+                accrued
+                    .add(token_id.clone(), amount)
+                    .ok_or(DefuseError::BalanceOverflow)?;
+            }
+            if accrued.is_empty() {
+                continue;
+            }
+
+            Self::accrue_fees(engine, collector.clone(), accrued, intent_hash)?;
+        }
+
+        let fee_collector = engine.state.fee_collector().into_owned();
+        if shares.is_empty() {
+            engine.state.internal_add_balance(fee_collector, rest)
+        } else {
+            Self::accrue_fees(engine, fee_collector, rest, intent_hash)
+        }
+    }
+
+    fn accrue_fees<S, I>(
+        engine: &mut Engine<S, I>,
+        collector: AccountId,
+        tokens: Amounts,
+        intent_hash: CryptoHash,
+    ) -> Result<()>
+    where
+        S: State,
+        I: Inspector,
+    {
+        if tokens.is_empty() {
+            return Ok(());
+        }
+
+        engine
+            .inspector
+            .on_event(DefuseEvent::FeesAccrued(MaybeIntentEvent::new_intent(
+                AccountEvent::new(
+                    Cow::Borrowed(collector.as_ref()),
+                    FeesAccruedEvent {
+                        tokens: tokens.clone(),
+                    },
+                ),
+                intent_hash,
+            )));
+
+        engine.state.internal_add_balance(collector, tokens)
+    }
+}
+
 #[near(serializers = [json])]
 #[derive(Debug, Clone)]
 /// An event emitted when a `TokenDiff` intent is executed.
@@ -169,7 +243,7 @@ impl TokenDiff {
             // fee is taken only on negative deltas (i.e. token_in)
             delta.checked_mul_div_ceil(
                 Self::token_fee(token_id, delta.unsigned_abs(), fee)
-                    .invert()
+                    .complement()
                     .as_pips()
                     .into(),
                 Pips::MAX.as_pips().into(),
@@ -191,7 +265,7 @@ impl TokenDiff {
             closure.checked_mul_div_euclid(
                 Pips::MAX.as_pips().into(),
                 Self::token_fee(token_id, delta.unsigned_abs(), fee)
-                    .invert()
+                    .complement()
                     .as_pips()
                     .into(),
             )
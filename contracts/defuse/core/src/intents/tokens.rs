@@ -11,10 +11,10 @@ use crate::{
     DefuseError, Result,
     accounts::AccountEvent,
     amounts::Amounts,
-    engine::{Engine, Inspector, State},
+    engine::{Engine, Inspector, State, StateView},
     events::DefuseEvent,
     intents::MaybeIntentEvent,
-    tokens::{MT_ON_TRANSFER_GAS_DEFAULT, MT_ON_TRANSFER_GAS_MIN, TransferEvent},
+    tokens::{MT_ON_TRANSFER_GAS_DEFAULT, MT_ON_TRANSFER_GAS_MIN, TransferEvent, WithdrawMemo},
 };
 
 use super::ExecutableIntent;
@@ -96,6 +96,15 @@ impl ExecutableIntent for Transfer {
             return Err(DefuseError::InvalidIntent);
         }
 
+        if let Some(token_id) = self
+            .tokens
+            .iter()
+            .map(|(token_id, _)| token_id)
+            .find(|token_id| engine.state.is_token_denied(token_id))
+        {
+            return Err(DefuseError::TokenDenied(token_id.clone()));
+        }
+
         engine
             .inspector
             .on_event(DefuseEvent::Transfer(Cow::Borrowed(
@@ -176,6 +185,16 @@ impl FtWithdraw {
         self.msg.is_some()
     }
 
+    /// Parses and validates `memo` if it's structured, see [`WithdrawMemo`]
+    #[inline]
+    pub fn withdraw_memo(&self) -> Result<Option<WithdrawMemo>> {
+        self.memo
+            .as_deref()
+            .map(WithdrawMemo::parse)
+            .transpose()
+            .map(Option::flatten)
+    }
+
     /// Returns minimum required gas
     #[inline]
     pub fn min_gas(&self) -> Gas {
@@ -369,6 +388,16 @@ impl MtWithdraw {
         self.msg.is_some()
     }
 
+    /// Parses and validates `memo` if it's structured, see [`WithdrawMemo`]
+    #[inline]
+    pub fn withdraw_memo(&self) -> Result<Option<WithdrawMemo>> {
+        self.memo
+            .as_deref()
+            .map(WithdrawMemo::parse)
+            .transpose()
+            .map(Option::flatten)
+    }
+
     /// Returns minimum required gas
     #[inline]
     pub fn min_gas(&self) -> Gas {
@@ -1,10 +1,13 @@
 pub mod accounts;
+pub mod allowances;
 pub mod amounts;
+pub mod checkpoint;
 pub mod engine;
 mod error;
 pub mod events;
 pub mod fees;
 pub mod intents;
+pub mod limits;
 mod nonce;
 pub mod payload;
 mod public_key;
@@ -13,11 +16,22 @@ pub mod tokens;
 
 pub use self::{error::*, nonce::*, public_key::*, signature::*};
 
+pub use defuse_aptos as aptos;
+pub use defuse_bip137 as bip137;
 pub use defuse_crypto as crypto;
+pub use defuse_eip712 as eip712;
 pub use defuse_erc191 as erc191;
 pub use defuse_nep413 as nep413;
+pub use defuse_nostr as nostr;
+pub use defuse_p256 as p256;
 pub use defuse_sep53 as sep53;
+pub use defuse_siwe as siwe;
+pub use defuse_starknet as starknet;
+pub use defuse_sui as sui;
+pub use defuse_tezos as tezos;
 pub use defuse_time::Timestamp;
 pub use defuse_tip191 as tip191;
 pub use defuse_token_id as token_id;
 pub use defuse_ton_connect as ton_connect;
+pub use defuse_ton_proof as ton_proof;
+pub use defuse_xrpl as xrpl;
@@ -0,0 +1,221 @@
+use core::time::Duration;
+use std::borrow::Cow;
+
+use defuse_borsh_utils::As;
+use defuse_num_utils::CheckedMulDiv;
+use defuse_time::borsh::TimestampNanoSeconds;
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    near,
+};
+
+use crate::{Timestamp, token_id::TokenId};
+
+/// Rolling-window cap on withdrawals of a single token.
+///
+/// Withdrawn amounts are released linearly over the window, i.e. up
+/// to `cap` can be withdrawn at once and the capacity is restored at
+/// `cap / window_secs` per second.
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WithdrawalLimit {
+    pub window_secs: u32,
+
+    /// Max total amount withdrawn by all accounts within the window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<U128>,
+
+    /// Max amount withdrawn by a single account within the window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_account: Option<U128>,
+}
+
+impl WithdrawalLimit {
+    #[inline]
+    pub fn window(&self) -> Duration {
+        Duration::from_secs(self.window_secs.into())
+    }
+
+    #[inline]
+    pub fn cap(&self, scope: WithdrawalLimitScope) -> Option<u128> {
+        match scope {
+            WithdrawalLimitScope::Total => self.total,
+            WithdrawalLimitScope::PerAccount => self.per_account,
+        }
+        .map(|cap| cap.0)
+    }
+}
+
+#[near(serializers = [borsh, json])]
+#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WithdrawalLimitScope {
+    Total,
+    PerAccount,
+}
+
+/// Amount withdrawn within the rolling window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[borsh(crate = "::near_sdk::borsh")]
+pub struct WithdrawalUsage {
+    pub amount: u128,
+    #[borsh(
+        serialize_with = "As::<TimestampNanoSeconds<i64>>::serialize",
+        deserialize_with = "As::<TimestampNanoSeconds<i64>>::deserialize"
+    )]
+    pub updated_at: Timestamp,
+}
+
+impl WithdrawalUsage {
+    /// Returns amount which is still accounted within the window at `now`
+    pub fn used_at(&self, now: Timestamp, cap: u128, window: Duration) -> u128 {
+        let elapsed = now.duration_since(self.updated_at).unwrap_or_default();
+        if elapsed >= window {
+            return 0;
+        }
+        let released = cap
+            .checked_mul_div(elapsed.as_nanos(), window.as_nanos())
+            .unwrap_or(u128::MAX);
+        self.amount.saturating_sub(released)
+    }
+
+    /// Returns amount available for withdrawal at `now`
+    #[inline]
+    pub fn available_at(&self, now: Timestamp, cap: u128, window: Duration) -> u128 {
+        cap.saturating_sub(self.used_at(now, cap, window))
+    }
+
+    /// Records withdrawal of `amount` at `now`, unless it exceeds `cap`
+    pub fn record(
+        usage: Option<Self>,
+        amount: u128,
+        now: Timestamp,
+        cap: u128,
+        window: Duration,
+    ) -> Option<Self> {
+        let used = usage.map_or(0, |usage| usage.used_at(now, cap, window));
+        Some(Self {
+            amount: used.checked_add(amount).filter(|used| *used <= cap)?,
+            updated_at: now,
+        })
+    }
+
+    /// Releases `amount` of previously recorded withdrawal at `now`,
+    /// e.g. when it was refunded
+    #[must_use]
+    pub fn release(&self, amount: u128, now: Timestamp, cap: u128, window: Duration) -> Self {
+        Self {
+            amount: self.used_at(now, cap, window).saturating_sub(amount),
+            updated_at: now,
+        }
+    }
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct WithdrawalLimitSetEvent<'a> {
+    pub token_id: Cow<'a, TokenId>,
+    /// `None` if the limit was removed
+    pub limit: Option<WithdrawalLimit>,
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct DepositCapSetEvent<'a> {
+    pub token_id: Cow<'a, TokenId>,
+    /// `None` if the cap was removed
+    pub cap: Option<U128>,
+}
+
+/// Deposit was refunded, since it would make total supply
+/// of the token exceed its cap
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct DepositCapExceededEvent<'a> {
+    pub token_id: Cow<'a, TokenId>,
+    pub amount: U128,
+    pub cap: U128,
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_hours(1);
+    const CAP: u128 = 3600;
+
+    fn ts(secs: i64) -> Timestamp {
+        Timestamp::from_secs(secs).unwrap()
+    }
+
+    #[rstest]
+    #[case(0, 1000)]
+    #[case(100, 900)]
+    #[case(999, 1)]
+    #[case(1000, 0)]
+    #[case(3600, 0)]
+    #[case(10_000, 0)]
+    fn used_at(#[case] elapsed: i64, #[case] used: u128) {
+        let usage = WithdrawalUsage {
+            amount: 1000,
+            updated_at: ts(0),
+        };
+        assert_eq!(usage.used_at(ts(elapsed), CAP, WINDOW), used);
+        assert_eq!(usage.available_at(ts(elapsed), CAP, WINDOW), CAP - used);
+    }
+
+    #[test]
+    fn record() {
+        let usage = WithdrawalUsage::record(None, CAP, ts(0), CAP, WINDOW).unwrap();
+        assert_eq!(
+            WithdrawalUsage::record(Some(usage), 1, ts(0), CAP, WINDOW),
+            None
+        );
+
+        let usage = WithdrawalUsage::record(Some(usage), 100, ts(100), CAP, WINDOW).unwrap();
+        assert_eq!(usage.amount, CAP);
+        assert_eq!(usage.updated_at, ts(100));
+
+        assert_eq!(
+            WithdrawalUsage::record(Some(usage), 1, ts(100), CAP, WINDOW),
+            None
+        );
+        assert_eq!(
+            WithdrawalUsage::record(Some(usage), CAP, ts(100), CAP, WINDOW),
+            None
+        );
+        assert_eq!(
+            WithdrawalUsage::record(Some(usage), CAP, ts(3700), CAP, WINDOW)
+                .unwrap()
+                .amount,
+            CAP
+        );
+        assert_eq!(
+            WithdrawalUsage::record(None, CAP + 1, ts(0), CAP, WINDOW),
+            None
+        );
+    }
+
+    #[test]
+    fn release() {
+        let usage = WithdrawalUsage::record(None, 1000, ts(0), CAP, WINDOW).unwrap();
+
+        let released = usage.release(400, ts(100), CAP, WINDOW);
+        assert_eq!(released.amount, 500);
+        assert_eq!(released.updated_at, ts(100));
+
+        assert_eq!(usage.release(CAP, ts(0), CAP, WINDOW).amount, 0);
+    }
+
+    #[test]
+    fn zero_window() {
+        let usage = WithdrawalUsage::record(None, CAP, ts(0), CAP, Duration::ZERO).unwrap();
+        assert_eq!(usage.used_at(ts(0), CAP, Duration::ZERO), 0);
+    }
+}
@@ -5,7 +5,7 @@ mod versioned;
 pub use {
     expirable::ExpirableNonce,
     salted::SaltedNonce,
-    salted::{Salt, SaltRegistry},
+    salted::{Salt, SaltRegistry, SaltRotatedAt, SaltRotationPolicy},
     versioned::VersionedNonce,
 };
 
@@ -52,6 +52,11 @@ where
         self.0.cleanup_by_prefix(prefix)
     }
 
+    #[inline]
+    pub fn iter_by_prefix(&self, prefix: NoncePrefix) -> impl Iterator<Item = Nonce> + '_ {
+        self.0.iter_by_prefix(prefix)
+    }
+
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = Nonce> + '_
     where
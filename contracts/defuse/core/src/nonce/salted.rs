@@ -1,4 +1,6 @@
-use core::mem;
+use core::{mem, time::Duration};
+use defuse_borsh_utils::As;
+use defuse_time::borsh::TimestampNanoSeconds;
 use hex::FromHex;
 use near_sdk::{
     IntoStorageKey,
@@ -13,7 +15,7 @@ use std::{
     str::FromStr,
 };
 
-use crate::{DefuseError, Result};
+use crate::{DefuseError, Result, Timestamp};
 
 #[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
 #[derive(PartialEq, PartialOrd, Ord, Eq, Copy, Clone, SerializeDisplay, DeserializeFromStr)]
@@ -156,6 +158,47 @@ impl SaltRegistry {
     }
 }
 
+/// Constraints on rotations of the current salt
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SaltRotationPolicy {
+    /// Min time between consecutive rotations of the current salt
+    pub min_interval_secs: u32,
+
+    /// Time during which the previous salt remains valid after rotation,
+    /// `None` keeps it valid until invalidated explicitly
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grace_period_secs: Option<u32>,
+}
+
+impl SaltRotationPolicy {
+    /// Returns whether the current salt rotated at `last_rotated_at`
+    /// can be rotated again at `now`
+    #[inline]
+    pub fn can_rotate_at(&self, last_rotated_at: Timestamp, now: Timestamp) -> bool {
+        last_rotated_at + Duration::from_secs(self.min_interval_secs.into()) <= now
+    }
+
+    /// Returns whether the salt rotated at `rotated_at` is still
+    /// within the grace period at `now`
+    #[inline]
+    pub fn is_in_grace_period(&self, rotated_at: Timestamp, now: Timestamp) -> bool {
+        self.grace_period_secs
+            .is_none_or(|secs| now < rotated_at + Duration::from_secs(secs.into()))
+    }
+}
+
+/// Time when a salt stopped being the current one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[borsh(crate = "::near_sdk::borsh")]
+pub struct SaltRotatedAt(
+    #[borsh(
+        serialize_with = "As::<TimestampNanoSeconds<i64>>::serialize",
+        deserialize_with = "As::<TimestampNanoSeconds<i64>>::deserialize"
+    )]
+    pub Timestamp,
+);
+
 #[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 #[borsh(crate = "::near_sdk::borsh")]
 pub struct SaltedNonce<T>
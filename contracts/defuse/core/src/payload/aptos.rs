@@ -0,0 +1,28 @@
+use defuse_aptos::{AptosPayload, SignedAptosPayload};
+use near_sdk::{serde::de::DeserializeOwned, serde_json};
+
+use super::{DefusePayload, ExtractDefusePayload};
+
+impl<T> ExtractDefusePayload<T> for AptosPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.message)
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for SignedAptosPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        self.payload.extract_defuse_payload()
+    }
+}
@@ -0,0 +1,37 @@
+use defuse_crypto::{AggregatedSignedPayload, Payload};
+use defuse_digest::{Digest, sha2::Sha256};
+use near_sdk::{near, serde::de::DeserializeOwned, serde_json};
+
+use super::{DefusePayload, ExtractDefusePayload};
+
+/// Payload signed by multiple BLS12-381 keys with a single aggregated
+/// signature, e.g. by validators of a threshold committee.
+///
+/// **NOTE**: BLS12-381 keys can only be registered along with their
+/// proof of possession, which prevents rogue-key attacks on aggregation.
+pub type SignedBls12381AggregatedPayload = AggregatedSignedPayload<Bls12381Payload>;
+
+/// JSON-serialized [`DefusePayload`], whose SHA-256 hash is signed
+#[near(serializers = [json])]
+#[serde(transparent)]
+#[derive(Debug, Clone)]
+pub struct Bls12381Payload(pub String);
+
+impl Payload for Bls12381Payload {
+    #[inline]
+    fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.0.as_bytes()).into()
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for SignedBls12381AggregatedPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.payload.0)
+    }
+}
@@ -0,0 +1,15 @@
+use super::{DefusePayload, ExtractDefusePayload};
+use defuse_eip712::SignedEip712Payload;
+use near_sdk::{serde::de::DeserializeOwned, serde_json};
+
+impl<T> ExtractDefusePayload<T> for SignedEip712Payload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.payload.message)
+    }
+}
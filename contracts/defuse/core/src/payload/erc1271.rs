@@ -0,0 +1,163 @@
+use defuse_crypto::Payload;
+use defuse_erc191::Erc191Payload;
+use std::borrow::Cow;
+
+use near_sdk::{
+    AccountId, AccountIdRef, CryptoHash, near, serde::de::DeserializeOwned, serde_json,
+};
+use serde_with::hex::Hex;
+
+use super::{DefusePayload, ExtractDefusePayload};
+
+/// [ERC-1271](https://eips.ethereum.org/EIPS/eip-1271) signature of a smart
+/// contract account (e.g. Safe) over ERC-191 `personal_sign()` message.
+///
+/// Since state of EVM chains is not available on Near, these signatures
+/// can't be verified synchronously. Instead, verifier contract asks
+/// EVM signature oracle to call `isValidSignature(hash, signature)` on the
+/// smart account and holds the intent until the answer arrives.
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct SignedErc1271Payload {
+    pub payload: Erc191Payload,
+
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) chain id
+    /// the smart account is deployed on
+    pub chain_id: u64,
+
+    /// Hex-encoded address of the smart account
+    #[serde_as(as = "Hex")]
+    #[cfg_attr(feature = "abi", schemars(with = "String"))]
+    pub contract: [u8; 20],
+
+    /// Hex-encoded signature to be passed to `isValidSignature()`
+    #[serde_as(as = "Hex")]
+    #[cfg_attr(feature = "abi", schemars(with = "String"))]
+    pub signature: Vec<u8>,
+}
+
+impl SignedErc1271Payload {
+    /// Account id of the smart account, i.e. `{chain_id}.0x{address}`
+    /// with lowercase hex-encoded address. Intents signed by the smart
+    /// account can only be executed on behalf of this account.
+    ///
+    /// NOTE: `chain_id` is a part of the account id, since smart accounts
+    /// deployed at the same address on different chains may be controlled
+    /// by different owners.
+    #[inline]
+    pub fn signer_id(&self) -> AccountId {
+        format!("{}.0x{}", self.chain_id, hex::encode(self.contract))
+            .try_into()
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// What EVM signature oracle is asked to verify: hash of the payload
+    /// signed by smart account at `contract` address on `chain_id`
+    #[inline]
+    pub fn oracle_query(&self) -> (u64, [u8; 20], CryptoHash) {
+        (self.chain_id, self.contract, self.hash())
+    }
+}
+
+impl Payload for SignedErc1271Payload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for SignedErc1271Payload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.payload.0)
+    }
+}
+
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct Erc1271OracleSetEvent<'a> {
+    /// `None` means that ERC-1271 signatures are disabled
+    pub oracle_id: Option<Cow<'a, AccountIdRef>>,
+}
+
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct Erc1271ChainAllowedEvent {
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) chain id
+    pub chain_id: u64,
+    pub allowed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    fn signed() -> SignedErc1271Payload {
+        serde_json::from_str(r#"{
+  "payload": "{\"signer_id\":\"1.0x41675c099f32341bf84bfc5382af534df5c7461a\",\"verifying_contract\":\"intents.near\",\"deadline\":\"2030-01-01T00:00:00Z\",\"nonce\":\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=\",\"intents\":[]}",
+  "chain_id": 1,
+  "contract": "41675c099f32341bf84bfc5382af534df5c7461a",
+  "signature": "deadbeef"
+}"#).unwrap()
+    }
+
+    #[test]
+    fn deserialize() {
+        let signed = signed();
+        assert_eq!(signed.chain_id, 1);
+        assert_eq!(
+            signed.contract,
+            hex!("41675c099f32341bf84bfc5382af534df5c7461a")
+        );
+        assert_eq!(signed.signature, hex!("deadbeef"));
+    }
+
+    #[test]
+    fn signer_id() {
+        let signed = signed();
+        assert_eq!(
+            signed.signer_id(),
+            "1.0x41675c099f32341bf84bfc5382af534df5c7461a"
+        );
+
+        let payload: DefusePayload<serde_json::Value> =
+            signed.clone().extract_defuse_payload().unwrap();
+        assert_eq!(payload.signer_id, signed.signer_id());
+
+        // same smart account address on another chain is another account
+        let other_chain = SignedErc1271Payload {
+            chain_id: 10,
+            ..signed.clone()
+        };
+        assert_eq!(
+            other_chain.signer_id(),
+            "10.0x41675c099f32341bf84bfc5382af534df5c7461a"
+        );
+        assert_ne!(other_chain.signer_id(), signed.signer_id());
+
+        let max_chain = SignedErc1271Payload {
+            chain_id: u64::MAX,
+            ..signed
+        };
+        assert_eq!(
+            max_chain.signer_id(),
+            "18446744073709551615.0x41675c099f32341bf84bfc5382af534df5c7461a"
+        );
+    }
+
+    #[test]
+    fn hash() {
+        let signed = signed();
+        assert_eq!(
+            signed.hash(),
+            Erc191Payload::prehash(signed.payload.0.as_bytes())
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use super::{DefusePayload, ExtractDefusePayload};
-use defuse_erc191::SignedErc191Payload;
+use defuse_erc191::{SignedErc191Payload, SignedErc191ValidatorPayload};
 use near_sdk::{serde::de::DeserializeOwned, serde_json};
 
 impl<T> ExtractDefusePayload<T> for SignedErc191Payload
@@ -13,3 +13,15 @@ where
         serde_json::from_str(&self.payload.0)
     }
 }
+
+impl<T> ExtractDefusePayload<T> for SignedErc191ValidatorPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.payload.data)
+    }
+}
@@ -0,0 +1,108 @@
+use defuse_crypto::{Curve, Ed25519, Payload, SignedPayload, VerifiableCurve, serde::AsCurve};
+use defuse_digest::{Digest, sha2::Sha256};
+use near_sdk::{near, serde::de::DeserializeOwned, serde_json};
+
+use super::ExtractDefusePayload;
+
+/// Payload blind-signed by [Ledger NEAR app](https://github.com/LedgerHQ/app-near),
+/// which doesn't sign the data itself, but its SHA-256 hash displayed
+/// on the device, the same way as NEAR transactions.
+///
+/// Signatures of transactions can't be reused for payloads and vice versa,
+/// since borsh-serialized transaction is never a valid JSON.
+#[near(serializers = [borsh, json])]
+#[derive(Debug, Clone)]
+pub struct SignedLedgerPayload {
+    pub payload: String,
+
+    #[serde_as(as = "AsCurve<Ed25519>")]
+    pub public_key: <Ed25519 as Curve>::PublicKey,
+    #[serde_as(as = "AsCurve<Ed25519>")]
+    pub signature: <Ed25519 as Curve>::Signature,
+}
+
+impl Payload for SignedLedgerPayload {
+    #[inline]
+    fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.payload.as_bytes()).into()
+    }
+}
+
+impl SignedPayload for SignedLedgerPayload {
+    type PublicKey = <Ed25519 as Curve>::PublicKey;
+
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        Ed25519::verify(&self.signature, &self.hash(), &self.public_key)
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for SignedLedgerPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    fn extract_defuse_payload(self) -> Result<super::DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use near_sdk::bs58;
+
+    use super::*;
+
+    const PAYLOAD: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+    const PUBLIC_KEY: &str = "77CYvQcFvWCSGwVapLbkSHsJdF6dqJnrEFRqcH2rMr6L";
+    const SIGNATURE: &str =
+        "2gVff2ZsAGZs2isqLGvPdgGxag5E1AnH9XTe4A7H36hHcvtAFFE4xurmbG1KMSWuM2ckay4oStaFDQvp11qykYBw";
+    // signature of the payload itself, i.e. `raw_ed25519`
+    const RAW_SIGNATURE: &str =
+        "53uvaznpmhKijdiZrTnUD1MQKeMJd5CaxBnFnXj2T1vGt1fGSZakS1LwPmjMBAy4dHLWpFQQEFtqS1BDc8WzidfC";
+
+    fn signed(payload: &str, signature: &str) -> SignedLedgerPayload {
+        SignedLedgerPayload {
+            payload: payload.to_string(),
+            public_key: bs58::decode(PUBLIC_KEY)
+                .into_vec()
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            signature: bs58::decode(signature)
+                .into_vec()
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(
+            signed(PAYLOAD, SIGNATURE).hash(),
+            hex!("5ca83e4645fcfdb65feaceb0e8fb56e9dba2b02e6aaacc38f8615f888928c22c")
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let signed = signed(PAYLOAD, SIGNATURE);
+        assert_eq!(signed.verify(), Some(signed.public_key));
+    }
+
+    #[test]
+    fn tampered_payload() {
+        assert_eq!(
+            signed(&PAYLOAD.replace("alice.near", "bob.near"), SIGNATURE).verify(),
+            None
+        );
+    }
+
+    #[test]
+    fn raw_signature() {
+        assert_eq!(signed(PAYLOAD, RAW_SIGNATURE).verify(), None);
+    }
+}
@@ -1,11 +1,26 @@
+pub mod aptos;
+pub mod bip137;
+pub mod bls12381;
+pub mod eip712;
+pub mod erc1271;
 pub mod erc191;
+pub mod ledger;
 pub mod multi;
+pub mod multisig;
 pub mod nep413;
+pub mod nostr;
+pub mod p256;
 pub mod raw;
 pub mod sep53;
+pub mod siwe;
+pub mod starknet;
+pub mod sui;
+pub mod tezos;
 pub mod tip191;
 pub mod ton_connect;
+pub mod ton_proof;
 pub mod webauthn;
+pub mod xrpl;
 
 use core::convert::Infallible;
 
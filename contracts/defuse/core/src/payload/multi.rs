@@ -1,17 +1,35 @@
-use defuse_crypto::{Payload, SignedPayload};
-use defuse_erc191::SignedErc191Payload;
+use defuse_aptos::SignedAptosPayload;
+use defuse_bip137::SignedBip137Payload;
+use defuse_crypto::{P256CompressedPublicKey, Payload, SignedPayload, decompress_public_key};
+use defuse_digest::{Digest, sha2::Sha256};
+use defuse_eip712::SignedEip712Payload;
+use defuse_erc191::{SignedErc191Payload, SignedErc191ValidatorPayload};
 use defuse_nep413::SignedNep413Payload;
+use defuse_nostr::SignedNostrPayload;
+use defuse_p256::SignedP256Payload;
 use defuse_sep53::SignedSep53Payload;
+use defuse_siwe::SignedSiwePayload;
+use defuse_starknet::SignedStarknetPayload;
+use defuse_sui::SignedSuiPayload;
+use defuse_tezos::SignedTezosPayload;
 use defuse_tip191::SignedTip191Payload;
 use defuse_ton_connect::SignedTonConnectPayload;
+use defuse_ton_proof::SignedTonProofPayload;
+use defuse_xrpl::SignedXrplPayload;
 use derive_more::derive::From;
-use near_sdk::{CryptoHash, near, serde::de::DeserializeOwned, serde_json};
+use near_sdk::{
+    CryptoHash, near,
+    serde::de::{DeserializeOwned, Error as _},
+    serde_json::{self, Map, Value},
+};
+use std::borrow::Cow;
 
 use crate::public_key::PublicKey;
 
 use super::{
-    DefusePayload, ExtractDefusePayload, raw::SignedRawEd25519Payload,
-    webauthn::SignedWebAuthnPayload,
+    DefusePayload, ExtractDefusePayload, bls12381::SignedBls12381AggregatedPayload,
+    erc1271::SignedErc1271Payload, ledger::SignedLedgerPayload, multisig::MultisigPayload,
+    raw::SignedRawEd25519Payload, webauthn::SignedWebAuthnPayload,
 };
 
 #[near(serializers = [json])]
@@ -44,7 +62,7 @@ pub enum MultiPayload {
     /// `WebAuthn`: The standard for Passkeys.
     /// For more details, refer to [WebAuthn specification](https://w3c.github.io/webauthn/).
     #[serde(rename = "webauthn")]
-    WebAuthn(SignedWebAuthnPayload),
+    WebAuthn(Box<SignedWebAuthnPayload>),
 
     /// `TonConnect`: The standard for data signing in TON blockchain platform.
     /// For more details, refer to [TonConnect documentation](https://docs.tonconsole.com/academy/sign-data).
@@ -53,6 +71,108 @@ pub enum MultiPayload {
     /// SEP-53: The standard for signing data off-chain for Stellar accounts.
     /// See [SEP-53](https://github.com/stellar/stellar-protocol/blob/master/ecosystem/sep-0053.md)
     Sep53(SignedSep53Payload),
+
+    /// EIP-712: The standard for typed structured data signing in Ethereum, commonly used with `eth_signTypedData_v4()`.
+    /// For more details, refer to [EIP-712](https://eips.ethereum.org/EIPS/eip-712).
+    Eip712(SignedEip712Payload),
+
+    /// SIWE: Sign-In with Ethereum messages signed with `personal_sign()`.
+    /// For more details, refer to [EIP-4361](https://eips.ethereum.org/EIPS/eip-4361).
+    Siwe(SignedSiwePayload),
+
+    /// XRPL: Message signing by XRP Ledger wallets with Ed25519 or Secp256k1 keys.
+    /// For more details, refer to [ripple-keypairs](https://github.com/XRPLF/xrpl.js/tree/main/packages/ripple-keypairs).
+    Xrpl(SignedXrplPayload),
+
+    /// Aptos: Wallet standard `signMessage()` with Ed25519 keys, including `SingleKey` accounts.
+    /// For more details, refer to [AIP-62](https://github.com/aptos-foundation/AIPs/blob/main/aips/aip-62.md).
+    Aptos(SignedAptosPayload),
+
+    /// Sui: Wallet standard `signPersonalMessage()` with Ed25519, Secp256k1 or Secp256r1 keys.
+    /// For more details, refer to [Sui documentation](https://docs.sui.io/concepts/cryptography/transaction-auth/signatures).
+    Sui(SignedSuiPayload),
+
+    /// Tezos: `signPayload()` of Micheline-encoded string by Beacon-compatible wallets with tz1 (Ed25519) keys.
+    /// For more details, refer to [Beacon documentation](https://docs.walletbeacon.io/guides/sign-payload).
+    Tezos(SignedTezosPayload),
+
+    /// SNIP-12: The standard for typed data signing in Starknet, used by Argent X and Braavos wallets.
+    /// For more details, refer to [SNIP-12](https://github.com/starknet-io/SNIPs/blob/main/SNIPS/snip-12.md).
+    Starknet(SignedStarknetPayload),
+
+    /// `TonProof`: TON Connect `ton_proof` with the wallet address derived from its `StateInit`.
+    /// For more details, refer to [TON documentation](https://docs.ton.org/v3/guidelines/ton-connect/verifying-signed-in-users).
+    TonProof(SignedTonProofPayload),
+
+    /// BIP-137: Legacy Bitcoin `signmessage` by P2PKH addresses, supported by Electrum and hardware wallets.
+    /// For more details, refer to [BIP-137](https://github.com/bitcoin/bips/blob/master/bip-0137.mediawiki).
+    Bip137(SignedBip137Payload),
+
+    /// Multisig: k-of-n signatures over the same payload, each made with any of the standards above.
+    /// Verified against public keys registered for the signer, see [`MultisigPayload`].
+    Multisig(MultisigPayload),
+
+    /// ERC-1271: Signatures of smart contract accounts on EVM chains, e.g. Safe.
+    /// Verified asynchronously by EVM signature oracle, see [`SignedErc1271Payload`].
+    /// For more details, refer to [ERC-1271](https://eips.ethereum.org/EIPS/eip-1271).
+    Erc1271(SignedErc1271Payload),
+
+    /// ERC-191 version `0x00`: Data with intended validator, signed by EOAs for contracts
+    /// which relay pre-signed approvals, see [`SignedErc191ValidatorPayload`].
+    /// For more details, refer to [EIP-191](https://eips.ethereum.org/EIPS/eip-191).
+    Erc191Validator(SignedErc191ValidatorPayload),
+
+    /// Ledger: Blind signing by Ledger NEAR app, which signs SHA-256 hash of the data.
+    /// For more details, refer to [Ledger NEAR app](https://github.com/LedgerHQ/app-near).
+    Ledger(SignedLedgerPayload),
+
+    /// Nostr: Events signed with BIP-340 Schnorr signatures by NIP-07 browser extensions, e.g. Alby or nos2x.
+    /// For more details, refer to [NIP-07](https://github.com/nostr-protocol/nips/blob/master/07.md).
+    Nostr(SignedNostrPayload),
+
+    /// Raw P-256: ES256 signatures by keys in iOS Secure Enclave or Android Keystore, without `WebAuthn` envelope.
+    /// Accepts both ASN.1 DER and raw `r || s` signatures, see [`SignedP256Payload`].
+    RawP256(SignedP256Payload),
+
+    /// BLS12-381: n-of-n signatures over the same payload aggregated into a single one.
+    /// Verified against public keys registered for the signer, see [`SignedBls12381AggregatedPayload`].
+    Bls12381Aggregated(SignedBls12381AggregatedPayload),
+
+    /// Any other standard, e.g. added in a newer version of the verifier,
+    /// or malformed payload of one of the standards above.
+    /// Such payloads are rejected one by one without failing the whole
+    /// batch, see [`UnsupportedPayload`].
+    #[serde(untagged)]
+    #[cfg_attr(feature = "abi", schemars(skip))]
+    #[from(skip)]
+    Unsupported(UnsupportedPayload),
+}
+
+impl MultiPayload {
+    /// Returns origins of all `WebAuthn` assertions, including ones
+    /// nested in [`MultisigPayload`]
+    pub fn webauthn_origins(&self) -> Vec<String> {
+        match self {
+            Self::WebAuthn(payload) => payload.origin().into_iter().collect(),
+            Self::Multisig(payload) => payload
+                .signatures
+                .iter()
+                .flat_map(Self::webauthn_origins)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns hashes by which the payload can be cancelled: its own
+    /// hash and hashes of all payloads nested in [`MultisigPayload`],
+    /// so that wrapping a cancelled payload doesn't revive it
+    pub fn hashes(&self) -> Vec<CryptoHash> {
+        let mut hashes = vec![self.hash()];
+        if let Self::Multisig(payload) = self {
+            hashes.extend(payload.signatures.iter().flat_map(Self::hashes));
+        }
+        hashes
+    }
 }
 
 impl Payload for MultiPayload {
@@ -70,6 +190,23 @@ impl Payload for MultiPayload {
             Self::WebAuthn(payload) => payload.hash(),
             Self::TonConnect(payload) => payload.hash(),
             Self::Sep53(payload) => payload.hash(),
+            Self::Eip712(payload) => payload.hash(),
+            Self::Siwe(payload) => payload.hash(),
+            Self::Xrpl(payload) => payload.hash(),
+            Self::Aptos(payload) => payload.hash(),
+            Self::Sui(payload) => payload.hash(),
+            Self::Tezos(payload) => payload.hash(),
+            Self::Starknet(payload) => payload.hash(),
+            Self::TonProof(payload) => payload.hash(),
+            Self::Bip137(payload) => payload.hash(),
+            Self::Multisig(payload) => payload.hash(),
+            Self::Erc1271(payload) => payload.hash(),
+            Self::Erc191Validator(payload) => payload.hash(),
+            Self::Ledger(payload) => payload.hash(),
+            Self::Nostr(payload) => payload.hash(),
+            Self::RawP256(payload) => payload.hash(),
+            Self::Bls12381Aggregated(payload) => payload.hash(),
+            Self::Unsupported(payload) => payload.hash(),
         }
     }
 }
@@ -79,6 +216,7 @@ impl SignedPayload for MultiPayload {
 
     #[inline]
     fn verify(&self) -> Option<Self::PublicKey> {
+        #[allow(clippy::match_same_arms)]
         match self {
             Self::Nep413(payload) => payload.verify().map(PublicKey::Ed25519),
             Self::Erc191(payload) => payload.verify().map(PublicKey::Secp256k1),
@@ -87,6 +225,34 @@ impl SignedPayload for MultiPayload {
             Self::WebAuthn(payload) => payload.verify(),
             Self::TonConnect(payload) => payload.verify().map(PublicKey::Ed25519),
             Self::Sep53(payload) => payload.verify().map(PublicKey::Ed25519),
+            Self::Eip712(payload) => payload.verify().map(PublicKey::Secp256k1),
+            Self::Siwe(payload) => payload.verify().map(PublicKey::Secp256k1),
+            Self::Xrpl(payload) => payload.verify().map(Into::into),
+            Self::Aptos(payload) => payload.verify().map(PublicKey::Ed25519),
+            Self::Sui(payload) => payload.verify().map(Into::into),
+            Self::Tezos(payload) => payload.verify().map(PublicKey::Ed25519),
+            Self::Starknet(payload) => payload.verify().map(PublicKey::Stark),
+            Self::TonProof(payload) => payload.verify().map(PublicKey::Ed25519),
+            Self::Bip137(payload) => payload.verify().map(PublicKey::Secp256k1),
+            // there is no single signer, so it should be verified
+            // with `MultisigPayload::verify()` instead
+            Self::Multisig(_) => None,
+            // smart contract accounts have no public keys, so it should
+            // be verified by EVM signature oracle instead
+            Self::Erc1271(_) => None,
+            Self::Erc191Validator(payload) => payload.verify().map(PublicKey::Secp256k1),
+            Self::Ledger(payload) => payload.verify().map(PublicKey::Ed25519),
+            Self::Nostr(payload) => payload.verify().map(PublicKey::SchnorrSecp256k1),
+            Self::RawP256(payload) => payload
+                .verify()
+                .map(P256CompressedPublicKey)
+                .as_ref()
+                .and_then(decompress_public_key)
+                .map(PublicKey::P256),
+            // there is no single signer, so it should be verified
+            // with `SignedBls12381AggregatedPayload::verify()` instead
+            Self::Bls12381Aggregated(_) => None,
+            Self::Unsupported(_) => None,
         }
     }
 }
@@ -107,10 +273,60 @@ where
             Self::WebAuthn(payload) => payload.extract_defuse_payload(),
             Self::TonConnect(payload) => payload.extract_defuse_payload(),
             Self::Sep53(payload) => payload.extract_defuse_payload(),
+            Self::Eip712(payload) => payload.extract_defuse_payload(),
+            Self::Siwe(payload) => payload.extract_defuse_payload(),
+            Self::Xrpl(payload) => payload.extract_defuse_payload(),
+            Self::Aptos(payload) => payload.extract_defuse_payload(),
+            Self::Sui(payload) => payload.extract_defuse_payload(),
+            Self::Tezos(payload) => payload.extract_defuse_payload(),
+            Self::Starknet(payload) => payload.extract_defuse_payload(),
+            Self::TonProof(payload) => payload.extract_defuse_payload(),
+            Self::Bip137(payload) => payload.extract_defuse_payload(),
+            Self::Multisig(payload) => payload.extract_defuse_payload(),
+            Self::Erc1271(payload) => payload.extract_defuse_payload(),
+            Self::Erc191Validator(payload) => payload.extract_defuse_payload(),
+            Self::Ledger(payload) => payload.extract_defuse_payload(),
+            Self::Nostr(payload) => payload.extract_defuse_payload(),
+            Self::RawP256(payload) => payload.extract_defuse_payload(),
+            Self::Bls12381Aggregated(payload) => payload.extract_defuse_payload(),
+            Self::Unsupported(payload) => Err(serde_json::Error::custom(format_args!(
+                "unsupported or malformed payload of standard '{}'",
+                payload.standard,
+            ))),
         }
     }
 }
 
+/// Payload this version of the verifier can't handle: either signed
+/// with unknown standard or malformed. It never verifies and is rejected
+/// on its own, so that the rest of the batch is still executed.
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct UnsupportedPayload {
+    pub standard: String,
+
+    /// All other fields of the payload as-is
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+impl Payload for UnsupportedPayload {
+    /// Hash of the payload serialized back to JSON, so that distinct
+    /// unsupported payloads have distinct hashes
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        Sha256::digest(serde_json::to_vec(self).unwrap_or_else(|_| unreachable!())).into()
+    }
+}
+
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct UnsupportedPayloadEvent<'a> {
+    /// Index of the payload among signed ones
+    pub index: usize,
+    pub standard: Cow<'a, str>,
+}
+
 #[cfg(test)]
 mod tests {
     use near_sdk::bs58;
@@ -131,4 +347,42 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn unknown_standard() {
+        let p: MultiPayload =
+            serde_json::from_str(r#"{"standard":"unknown_standard","payload":"{}"}"#).unwrap();
+        let MultiPayload::Unsupported(ref unsupported) = p else {
+            panic!("expected unsupported payload: {p:?}");
+        };
+        assert_eq!(unsupported.standard, "unknown_standard");
+        assert_eq!(
+            serde_json::to_value(&p).unwrap(),
+            serde_json::json!({"standard": "unknown_standard", "payload": "{}"}),
+        );
+        assert_eq!(p.verify(), None);
+        assert_ne!(
+            p.hash(),
+            serde_json::from_str::<MultiPayload>(
+                r#"{"standard":"unknown_standard","payload":"{\"a\":1}"}"#
+            )
+            .unwrap()
+            .hash(),
+        );
+        assert!(
+            ExtractDefusePayload::<serde_json::Value>::extract_defuse_payload(p)
+                .unwrap_err()
+                .to_string()
+                .contains("'unknown_standard'")
+        );
+
+        // malformed payloads of known standards are rejected the same way
+        assert!(matches!(
+            serde_json::from_str::<MultiPayload>(r#"{"standard":"raw_ed25519","payload":"{}"}"#),
+            Ok(MultiPayload::Unsupported(UnsupportedPayload { standard, .. })) if standard == "raw_ed25519"
+        ));
+
+        // but the standard is required
+        assert!(serde_json::from_str::<MultiPayload>(r#"{"payload":"{}"}"#).is_err());
+    }
 }
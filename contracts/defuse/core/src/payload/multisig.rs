@@ -0,0 +1,173 @@
+use defuse_crypto::{Payload, SignedPayload};
+use defuse_digest::{Digest, sha2::Sha256};
+use near_sdk::{
+    CryptoHash, near,
+    serde::de::{DeserializeOwned, Error as _},
+    serde_json::{self, Value},
+};
+
+use crate::public_key::PublicKey;
+
+use super::{DefusePayload, ExtractDefusePayload, multi::MultiPayload};
+
+/// k-of-n multi-signature: the same [`DefusePayload`] signed by
+/// multiple keys, each of them possibly using a different standard.
+///
+/// **NOTE**: the number of distinct public keys is checked against
+/// multisig threshold configured by `signer_id` itself, which also
+/// applies to payloads signed by a single key.
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct MultisigPayload {
+    /// Nested multisig payloads are not allowed
+    pub signatures: Vec<MultiPayload>,
+}
+
+impl Payload for MultisigPayload {
+    /// `sha256(hash(signatures[0]) || ... || hash(signatures[n-1]))`
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.signatures
+            .iter()
+            .fold(Sha256::new(), |hasher, signed| {
+                hasher.chain_update(signed.hash())
+            })
+            .finalize()
+            .into()
+    }
+}
+
+impl SignedPayload for MultisigPayload {
+    /// Public keys of all signers in the same order as `signatures`
+    type PublicKey = Vec<PublicKey>;
+
+    fn verify(&self) -> Option<Self::PublicKey> {
+        if self.signatures.is_empty() {
+            return None;
+        }
+
+        self.signatures
+            .iter()
+            .map(|signed| {
+                if matches!(signed, MultiPayload::Multisig(_)) {
+                    return None;
+                }
+                signed.verify()
+            })
+            .collect()
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for MultisigPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    /// Extracts [`DefusePayload`] from each of the signatures and
+    /// ensures that all of them are equal
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        let mut payloads = self.signatures.into_iter().map(|signed| {
+            signed
+                .extract_defuse_payload()
+                .and_then(|payload: DefusePayload<Value>| serde_json::to_value(payload))
+        });
+
+        let payload = payloads
+            .next()
+            .ok_or_else(|| serde_json::Error::custom("no signatures"))??;
+        for other in payloads {
+            if other? != payload {
+                return Err(serde_json::Error::custom("signed payloads differ"));
+            }
+        }
+
+        serde_json::from_value(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intents::DefuseIntents;
+
+    use super::*;
+
+    const RAW_ED25519: &str = r#"{"standard":"raw_ed25519","payload":"{\"signer_id\":\"74affa71ab030d400fdfa1bed033dfa6fd3ae34f92d17c046ebe368e80d53751\",\"verifying_contract\":\"intents.near\",\"deadline\":{\"timestamp\":1732035219},\"nonce\":\"XVoKfmScb3G+XqH9ke/fSlJ/3xO59sNhCxhpG821BH8=\",\"intents\":[{\"intent\":\"token_diff\",\"diff\":{\"nep141:base-0x833589fcd6edb6e08f4c7c32d4f71b54bda02913.omft.near\":\"-1000\",\"nep141:eth-0xdac17f958d2ee523a2206206994597c13d831ec7.omft.near\":\"998\"}}]}","public_key":"ed25519:8rVvtHWFr8hasdQGGD5WiQBTyr4iH2ruEPPVfj491RPN","signature":"ed25519:3vtbNQJHZfuV1s5DykzyjkbNLc583hnkrhTz57eDhd966iqzkor6Twgr4Loh2C195SCSEsiGfrd6KcxpjNq9ZbVj"}"#;
+
+    fn multisig(signatures: Vec<MultiPayload>) -> MultisigPayload {
+        MultisigPayload { signatures }
+    }
+
+    fn raw_ed25519() -> MultiPayload {
+        serde_json::from_str(RAW_ED25519).unwrap()
+    }
+
+    /// Same as [`raw_ed25519()`], but with payload in the current
+    /// format, while the signature is no longer valid
+    fn unverified_raw_ed25519() -> MultiPayload {
+        let MultiPayload::RawEd25519(mut signed) = raw_ed25519() else {
+            unreachable!()
+        };
+        signed.payload = signed
+            .payload
+            .replace(r#"{"timestamp":1732035219}"#, r#""2024-11-19T16:53:39Z""#);
+        signed.into()
+    }
+
+    #[test]
+    fn verify() {
+        let public_key: PublicKey = "ed25519:8rVvtHWFr8hasdQGGD5WiQBTyr4iH2ruEPPVfj491RPN"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            multisig(vec![raw_ed25519(), raw_ed25519()]).verify(),
+            Some(vec![public_key, public_key])
+        );
+    }
+
+    #[test]
+    fn verify_empty() {
+        assert_eq!(multisig(vec![]).verify(), None);
+    }
+
+    #[test]
+    fn verify_nested() {
+        let nested = multisig(vec![raw_ed25519()]);
+        assert_eq!(
+            multisig(vec![raw_ed25519(), MultiPayload::Multisig(nested)]).verify(),
+            None
+        );
+    }
+
+    #[test]
+    fn extract_defuse_payload() {
+        let payload: DefusePayload<DefuseIntents> =
+            multisig(vec![unverified_raw_ed25519(), unverified_raw_ed25519()])
+                .extract_defuse_payload()
+                .unwrap();
+        assert_eq!(payload.verifying_contract, "intents.near");
+    }
+
+    #[test]
+    fn extract_different_payloads() {
+        let MultiPayload::RawEd25519(mut other) = unverified_raw_ed25519() else {
+            unreachable!()
+        };
+        other.payload = other.payload.replace("1000", "1001");
+
+        assert!(
+            ExtractDefusePayload::<DefuseIntents>::extract_defuse_payload(multisig(vec![
+                unverified_raw_ed25519(),
+                other.into(),
+            ]))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn hashes() {
+        let inner = raw_ed25519();
+        let signed = MultiPayload::Multisig(multisig(vec![inner.clone()]));
+        assert_eq!(signed.hashes(), vec![signed.hash(), inner.hash()]);
+    }
+}
@@ -0,0 +1,28 @@
+use defuse_p256::{P256Payload, SignedP256Payload};
+use near_sdk::{serde::de::DeserializeOwned, serde_json};
+
+use super::{DefusePayload, ExtractDefusePayload};
+
+impl<T> ExtractDefusePayload<T> for P256Payload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.0)
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for SignedP256Payload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        self.payload.extract_defuse_payload()
+    }
+}
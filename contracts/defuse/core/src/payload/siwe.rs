@@ -0,0 +1,132 @@
+use defuse_siwe::{SignedSiwePayload, SiwePayload};
+use impl_tools::autoimpl;
+use near_sdk::{
+    AccountId, near,
+    serde::de::{DeserializeOwned, Error},
+    serde_json,
+};
+
+use crate::Nonce;
+
+use super::{DefusePayload, ExtractDefusePayload};
+
+/// Defuse message embedded as a `statement` of SIWE message, while
+/// `deadline` and `nonce` are taken from `Expiration Time` and
+/// hex-encoded `Nonce` fields respectively.
+#[near(serializers = [json])]
+#[autoimpl(Deref using self.message)]
+#[autoimpl(DerefMut using self.message)]
+#[derive(Debug, Clone)]
+pub struct SiweDefuseMessage<T> {
+    pub signer_id: AccountId,
+    pub verifying_contract: AccountId,
+
+    #[serde(flatten)]
+    pub message: T,
+}
+
+impl<T> ExtractDefusePayload<T> for SiwePayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        let siwe = self.parse().map_err(Error::custom)?;
+
+        let SiweDefuseMessage {
+            signer_id,
+            verifying_contract,
+            message,
+        } = serde_json::from_str(
+            siwe.statement
+                .as_deref()
+                .ok_or_else(|| Error::missing_field("statement"))?,
+        )?;
+
+        let deadline = siwe
+            .expiration_time
+            .ok_or_else(|| Error::missing_field("expiration_time"))?;
+        if deadline < siwe.issued_at || siwe.not_before.is_some_and(|nbf| deadline < nbf) {
+            return Err(Error::custom(
+                "expiration_time is before issued_at or not_before",
+            ));
+        }
+
+        let mut nonce = Nonce::default();
+        hex::decode_to_slice(&siwe.nonce, &mut nonce)
+            .map_err(|_| Error::custom("nonce must be 32 hex-encoded bytes"))?;
+
+        Ok(DefusePayload {
+            signer_id,
+            verifying_contract,
+            deadline,
+            nonce,
+            message,
+        })
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for SignedSiwePayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        self.payload.extract_defuse_payload()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::intents::DefuseIntents;
+
+    use super::*;
+
+    fn message(nonce: &str, expiration_time: &str) -> SiwePayload {
+        SiwePayload(format!(
+            "example.com wants you to sign in with your Ethereum account:
+0x0551F7c9a91eE579C9e40444ffC490001C323108
+
+{{\"signer_id\":\"alice.near\",\"verifying_contract\":\"intents.near\",\"intents\":[]}}
+
+URI: https://example.com/login
+Version: 1
+Chain ID: 1
+Nonce: {nonce}
+Issued At: 2025-01-01T00:00:00Z
+Expiration Time: {expiration_time}"
+        ))
+    }
+
+    #[test]
+    fn extract() {
+        let payload: DefusePayload<DefuseIntents> =
+            message(&"01".repeat(32), "2030-01-01T00:00:00Z")
+                .extract_defuse_payload()
+                .unwrap();
+
+        assert_eq!(payload.signer_id.as_str(), "alice.near");
+        assert_eq!(payload.verifying_contract.as_str(), "intents.near");
+        assert_eq!(payload.deadline, "2030-01-01T00:00:00Z".parse().unwrap());
+        assert_eq!(payload.nonce, [1; 32]);
+        assert!(payload.intents.is_empty());
+    }
+
+    #[rstest]
+    #[case::short_nonce("01234567", "2030-01-01T00:00:00Z")]
+    #[case::expired_before_issued(&"00".repeat(32), "2024-01-01T00:00:00Z")]
+    fn invalid(#[case] nonce: &str, #[case] expiration_time: &str) {
+        assert!(
+            ExtractDefusePayload::<DefuseIntents>::extract_defuse_payload(message(
+                nonce,
+                expiration_time
+            ))
+            .is_err()
+        );
+    }
+}
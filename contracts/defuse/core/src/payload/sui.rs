@@ -0,0 +1,30 @@
+use defuse_crypto::P256UncompressedPublicKey;
+use defuse_sui::{SignedSuiPayload, SuiVerifiedKey};
+use near_sdk::{serde::de::DeserializeOwned, serde_json};
+
+use crate::PublicKey;
+
+use super::{DefusePayload, ExtractDefusePayload};
+
+impl From<SuiVerifiedKey> for PublicKey {
+    #[inline]
+    fn from(public_key: SuiVerifiedKey) -> Self {
+        match public_key {
+            SuiVerifiedKey::Ed25519(pk) => Self::Ed25519(pk),
+            SuiVerifiedKey::Secp256k1(pk) => Self::Secp256k1(pk),
+            SuiVerifiedKey::Secp256r1(pk) => Self::P256(P256UncompressedPublicKey(pk)),
+        }
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for SignedSuiPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.payload.0)
+    }
+}
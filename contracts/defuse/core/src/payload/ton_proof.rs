@@ -0,0 +1,37 @@
+use defuse_ton_proof::{SignedTonProofPayload, TonProofPayload};
+use near_sdk::{
+    serde::de::{DeserializeOwned, Error},
+    serde_json,
+};
+
+use super::{DefusePayload, ExtractDefusePayload};
+
+impl<T> ExtractDefusePayload<T> for SignedTonProofPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        self.payload.extract_defuse_payload()
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for TonProofPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        let p: DefusePayload<T> = serde_json::from_str(&self.payload)?;
+
+        // same as for TON Connect `signData`, see `ton_connect.rs`
+        if p.deadline < self.timestamp {
+            return Err(Error::custom("deadline < timestamp"));
+        }
+
+        Ok(p)
+    }
+}
@@ -1,8 +1,9 @@
 use defuse_crypto::{
-    Ed25519PublicKey, Ed25519Signature, P256Signature, Payload, SignedPayload, compress_public_key,
+    Ed25519PublicKey, Ed25519Signature, P256Signature, Payload, Rsa2048PublicKey, Rsa2048Signature,
+    SignedPayload, compress_public_key,
 };
 use defuse_digest::{Digest, sha2::Sha256};
-use defuse_webauthn::{Algorithm, Ed25519, P256, PayloadSignature, UserVerification};
+use defuse_webauthn::{Algorithm, Ed25519, P256, PayloadSignature, RS256, VerifyOptions};
 use near_sdk::{CryptoHash, near, serde::de::DeserializeOwned, serde_json};
 
 use crate::{PublicKey, Signature};
@@ -18,7 +19,15 @@ pub struct SignedWebAuthnPayload {
     // attribute: https://github.com/GREsau/schemars/blob/104b0fd65055d4b46f8dcbe38cdd2ef2c4098fe2/schemars_derive/src/lib.rs#L193-L206
     #[cfg_attr(feature = "abi", schemars(skip))]
     #[serde(flatten)]
-    pub signature: PayloadSignature<Ed25519OrP256>,
+    pub signature: PayloadSignature<WebAuthnAlgorithm>,
+}
+
+impl SignedWebAuthnPayload {
+    /// Origin of the assertion from `clientDataJSON`
+    #[inline]
+    pub fn origin(&self) -> Option<String> {
+        self.signature.client_data().map(|c| c.origin)
+    }
 }
 
 impl Payload for SignedWebAuthnPayload {
@@ -28,10 +37,12 @@ impl Payload for SignedWebAuthnPayload {
     }
 }
 
+/// Dispatches verification to the algorithm corresponding to the
+/// curve of the public key
 #[derive(Debug, Clone)]
-pub struct Ed25519OrP256;
+pub struct WebAuthnAlgorithm;
 
-impl Algorithm for Ed25519OrP256 {
+impl Algorithm for WebAuthnAlgorithm {
     type PublicKey = PublicKey;
 
     type Signature = Signature;
@@ -51,6 +62,12 @@ impl Algorithm for Ed25519OrP256 {
                 &P256Signature(*signature),
             ),
 
+            (PublicKey::Rsa(public_key), Signature::Rsa(signature)) => RS256::verify(
+                msg,
+                &Rsa2048PublicKey(*public_key),
+                &Rsa2048Signature(*signature),
+            ),
+
             _ => false,
         }
     }
@@ -62,7 +79,7 @@ impl SignedPayload for SignedWebAuthnPayload {
     #[inline]
     fn verify(&self) -> Option<Self::PublicKey> {
         self.signature
-            .verify(self.hash(), &self.public_key, UserVerification::Ignore)
+            .verify(self.hash(), &self.public_key, VerifyOptions::default())
             .then_some(&self.public_key)
             .copied()
     }
@@ -134,4 +151,103 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn rsa() {
+        let p: SignedWebAuthnPayload = serde_json::from_str(r#"{
+  "standard": "webauthn",
+  "payload": "{\"signer_id\":\"0xa9f4855bbe21c0a9dd9cf2f046cbee8f0b007b9d\",\"verifying_contract\":\"intents.near\",\"deadline\":\"2030-01-01T00:00:00Z\",\"nonce\":\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=\",\"intents\":[]}",
+  "public_key": "rsa2048:BaarhDBSWN1op8PFVkoRtPtGDrNsPwTct1fcZuwHfKtJUyYzxQCLQk6dWwvkhTMZEpUjFaTbeoiMdWNUrbFXqL84dvqUANHqq7V7iaLft94NocGo61AQVpz2Rxn7VG2q4JufDXcVWT935YMjgvrNDLMwGVCMNgR2H6s4mC44wyFNX9FF9HzBJNmRgQYsAmy81V9iu25FYGPVKo7gzsaGDfBTxork7RYzQtqgs2hvVXjYEH3ho2jrh9JBgPzNg11ibyMoibUrCQo8uxt2W8CApcRSg9etYJwk4M6CKrf4R1W2sDjHhtpxAzfwaAjHgGPu7pyf6KbbpdEps3iTKE2h3ZZ2maeLBN",
+  "signature": "rsa2048:76QwGjtrxUMpBjs3Tc3MPhrekMMzZuyyycGDyq59Ugo2XQkZsXSBhY4VVTz7vtJLDVpcyySt5fe2vK6qEFYEwdrW9ymY6qVbJSbwQBj9mrg7ZUPy3HGsjBFSfn6Q4AwHDgZs82tAKzeqMUs11NstNLHwsNUZ1JWNTWRdYeeh3V4FxSwrVq7uXZMRE3da68CmYpkaLumATX34doL5YqEbeqg37VpdpMt5qzYAveKimTUtFqes55TyHFpRCGuuWc2CTcvTTUy39PvosAhP58CPELBXsmnkQtkit7om9YkAc9sXH6r8Rno3A9TmrvfQSh3Cos6chTxH4acQyWVP27nMTTvnwYg7jG",
+  "client_data_json": "{\"type\":\"webauthn.get\",\"challenge\":\"JT11bCxRhywAj-dnOqI4xfEZcSWrXNfxJNhiB8zdywo\",\"origin\":\"http://localhost:3000\"}",
+  "authenticator_data": "SZYN5YgOjGh0NBcPZHZgW4_krrmihjLHmVzzuoMdl2MFAAAAAA"
+}"#).unwrap();
+
+        let public_key = p.verify().expect("invalid signature");
+        assert_eq!(
+            public_key,
+            "rsa2048:BaarhDBSWN1op8PFVkoRtPtGDrNsPwTct1fcZuwHfKtJUyYzxQCLQk6dWwvkhTMZEpUjFaTbeoiMdWNUrbFXqL84dvqUANHqq7V7iaLft94NocGo61AQVpz2Rxn7VG2q4JufDXcVWT935YMjgvrNDLMwGVCMNgR2H6s4mC44wyFNX9FF9HzBJNmRgQYsAmy81V9iu25FYGPVKo7gzsaGDfBTxork7RYzQtqgs2hvVXjYEH3ho2jrh9JBgPzNg11ibyMoibUrCQo8uxt2W8CApcRSg9etYJwk4M6CKrf4R1W2sDjHhtpxAzfwaAjHgGPu7pyf6KbbpdEps3iTKE2h3ZZ2maeLBN"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            public_key.to_implicit_account_id(),
+            AccountIdRef::new_or_panic("0xa9f4855bbe21c0a9dd9cf2f046cbee8f0b007b9d")
+        );
+    }
+
+    #[test]
+    fn allowed_origins() {
+        let p: SignedWebAuthnPayload = serde_json::from_str(r#" {
+  "standard": "webauthn",
+  "payload": "{\"signer_id\":\"19a8cd22b37802c3cbc0031f55c70f3858ac48dbfb7697c435da637fea0e0e47\",\"verifying_contract\":\"intents.near\",\"deadline\":{\"timestamp\":1732035219},\"nonce\":\"XVoKfmScb3G+XqH9ke/fSlJ/3xO59sNhCxhpG821BH8=\",\"intents\":[{\"intent\":\"token_diff\",\"diff\":{\"nep141:base-0x833589fcd6edb6e08f4c7c32d4f71b54bda02913.omft.near\":\"-1000\",\"nep141:eth-0xdac17f958d2ee523a2206206994597c13d831ec7.omft.near\":\"998\"}}]}",
+  "public_key": "ed25519:2jAUugnvWPvMaftKj5TDkyfsfxBwYjkMSf5MRtqDUMHY",
+  "signature": "ed25519:2yBp5oExa9BBZQf8habpjLUaSiprvT7srHrK38Bxt9zL1yrkQSeeXMLmkihKCd9frmTdk24YctUdzNN5nGqHWHgb",
+  "client_data_json": "{\"type\":\"webauthn.get\",\"challenge\":\"PfRFOFrLxCfyomuDryxhv6v2OzJIWqyMXaMikUYHSmY\",\"origin\":\"http://localhost:3000\"}",
+  "authenticator_data": "SZYN5YgOjGh0NBcPZHZgW4_krrmihjLHmVzzuoMdl2MFZ50DuA"
+}"#).unwrap();
+
+        assert_eq!(p.origin().as_deref(), Some("http://localhost:3000"));
+
+        let verify = |allowed_origins: &[&str]| {
+            let allowed_origins: Vec<String> =
+                allowed_origins.iter().copied().map(Into::into).collect();
+            p.signature.verify(
+                p.hash(),
+                &p.public_key,
+                VerifyOptions {
+                    allowed_origins: Some(&allowed_origins),
+                    ..Default::default()
+                },
+            )
+        };
+        assert!(verify(&[
+            "https://app.near-intents.org",
+            "http://localhost:3000"
+        ]));
+        assert!(!verify(&["https://app.near-intents.org"]));
+        assert!(!verify(&[]));
+
+        // top-level origin of same-origin assertions is the origin itself
+        assert!(p.signature.verify(
+            p.hash(),
+            &p.public_key,
+            VerifyOptions {
+                reject_cross_origin: true,
+                top_origin: Some("http://localhost:3000"),
+                ..Default::default()
+            },
+        ));
+    }
+
+    #[test]
+    fn cross_origin() {
+        let p: SignedWebAuthnPayload = serde_json::from_str(r#"{
+  "standard": "webauthn",
+  "payload": "{\"signer_id\":\"0xa9f4855bbe21c0a9dd9cf2f046cbee8f0b007b9d\",\"verifying_contract\":\"intents.near\",\"deadline\":\"2030-01-01T00:00:00Z\",\"nonce\":\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=\",\"intents\":[]}",
+  "public_key": "rsa2048:BaarhDBSWN1op8PFVkoRtPtGDrNsPwTct1fcZuwHfKtJUyYzxQCLQk6dWwvkhTMZEpUjFaTbeoiMdWNUrbFXqL84dvqUANHqq7V7iaLft94NocGo61AQVpz2Rxn7VG2q4JufDXcVWT935YMjgvrNDLMwGVCMNgR2H6s4mC44wyFNX9FF9HzBJNmRgQYsAmy81V9iu25FYGPVKo7gzsaGDfBTxork7RYzQtqgs2hvVXjYEH3ho2jrh9JBgPzNg11ibyMoibUrCQo8uxt2W8CApcRSg9etYJwk4M6CKrf4R1W2sDjHhtpxAzfwaAjHgGPu7pyf6KbbpdEps3iTKE2h3ZZ2maeLBN",
+  "signature": "rsa2048:8mLHjEdHYJS9m6KTGrrWEVXC57fUvdi3StXKwcecfDomSGWTbhCX4XdsSAQN8FMdQGrnDjPL4nCLEvSJWz6gUZT1aZNQUYWmPHdRNDNMQKuhGZTq5iwzQxasbs6ckA6JRYg7gbCbReHMjpxxXLKa1rWEeU1Jb8gY3ZwG1X74F3ThAzrr6LoF4d9Pjn8f4AyB53UxdjKVoZzuLuY1u9zbU1GcuQ2spqTbAwesGxYm9CayUYCFdGwA5GszHwhPBZw6jDTXZFJr4LbaaM5EarobR1f5QujS8UgYL66GUxuYsNqxSbCN4aHeFs4kJYEGikwcwArVcW1dWmQdtZXUo7D2MPDEG3hvyp",
+  "client_data_json": "{\"type\":\"webauthn.get\",\"challenge\":\"JT11bCxRhywAj-dnOqI4xfEZcSWrXNfxJNhiB8zdywo\",\"origin\":\"https://wallet.example.com\",\"crossOrigin\":true,\"topOrigin\":\"https://dapp.example.com\"}",
+  "authenticator_data": "7KGrVqR7FjESIOTYbmA0i0kvfLRolSkOF25wNZdC5BwFAAAAAA"
+}"#).unwrap();
+
+        let client_data = p.signature.client_data().unwrap();
+        assert!(client_data.cross_origin);
+        assert_eq!(client_data.top_origin(), "https://dapp.example.com");
+
+        let verify = |options| p.signature.verify(p.hash(), &p.public_key, options);
+        assert!(verify(VerifyOptions::default()));
+        assert!(!verify(VerifyOptions {
+            reject_cross_origin: true,
+            ..Default::default()
+        }));
+        assert!(verify(VerifyOptions {
+            top_origin: Some("https://dapp.example.com"),
+            ..Default::default()
+        }));
+        assert!(!verify(VerifyOptions {
+            top_origin: Some("https://wallet.example.com"),
+            ..Default::default()
+        }));
+    }
 }
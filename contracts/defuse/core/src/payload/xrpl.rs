@@ -0,0 +1,27 @@
+use super::{DefusePayload, ExtractDefusePayload};
+use defuse_xrpl::{SignedXrplPayload, XrplVerifiedKey};
+use near_sdk::{serde::de::DeserializeOwned, serde_json};
+
+use crate::PublicKey;
+
+impl From<XrplVerifiedKey> for PublicKey {
+    #[inline]
+    fn from(public_key: XrplVerifiedKey) -> Self {
+        match public_key {
+            XrplVerifiedKey::Ed25519(pk) => Self::Ed25519(pk),
+            XrplVerifiedKey::Secp256k1(pk) => Self::Secp256k1(pk),
+        }
+    }
+}
+
+impl<T> ExtractDefusePayload<T> for SignedXrplPayload
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn extract_defuse_payload(self) -> Result<DefusePayload<T>, Self::Error> {
+        serde_json::from_str(&self.payload.0)
+    }
+}
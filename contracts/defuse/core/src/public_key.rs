@@ -4,8 +4,8 @@ use core::{
 };
 
 use defuse_crypto::{
-    Curve, CurveType, Ed25519, P256, P256UncompressedPublicKey, ParseCurveError, Secp256k1,
-    TypedCurve,
+    Bls12381, Curve, CurveType, Ed25519, P256, P256UncompressedPublicKey, ParseCurveError, Rsa2048,
+    SchnorrSecp256k1, Secp256k1, Stark, TypedCurve,
 };
 use near_sdk::{AccountId, AccountIdRef, bs58, near};
 use serde_with::{DeserializeFromStr, SerializeDisplay};
@@ -21,6 +21,10 @@ pub enum PublicKey {
     Ed25519(<Ed25519 as Curve>::PublicKey) = 0,
     Secp256k1(<Secp256k1 as Curve>::PublicKey) = 1,
     P256(P256UncompressedPublicKey) = 2,
+    Stark(<Stark as Curve>::PublicKey) = 3,
+    Rsa(<Rsa2048 as Curve>::PublicKey) = 4,
+    SchnorrSecp256k1(<SchnorrSecp256k1 as Curve>::PublicKey) = 5,
+    Bls12381(<Bls12381 as Curve>::PublicKey) = 6,
 }
 
 impl PublicKey {
@@ -30,6 +34,10 @@ impl PublicKey {
             Self::Ed25519(_) => CurveType::Ed25519,
             Self::Secp256k1(_) => CurveType::Secp256k1,
             Self::P256(_) => CurveType::P256,
+            Self::Stark(_) => CurveType::Stark,
+            Self::Rsa(_) => CurveType::Rsa2048,
+            Self::SchnorrSecp256k1(_) => CurveType::SchnorrSecp256k1,
+            Self::Bls12381(_) => CurveType::Bls12381,
         }
     }
 
@@ -40,6 +48,10 @@ impl PublicKey {
             Self::Ed25519(data) => data,
             Self::Secp256k1(data) => data,
             Self::P256(data) => &data.0,
+            Self::Stark(data) => data,
+            Self::Rsa(data) => data,
+            Self::SchnorrSecp256k1(data) => data,
+            Self::Bls12381(data) => data,
         }
     }
 
@@ -79,6 +91,51 @@ impl PublicKey {
                     )
                 )
             }
+            Self::Stark(pk) => {
+                // Same schema as for P256, but with "stark" prefix:
+                // "0x" .. hex(keccak256("stark" .. pk)[12..32])
+                format!(
+                    "0x{}",
+                    hex::encode(
+                        &::near_sdk::env::keccak256_array([b"stark".as_slice(), pk].concat())
+                            [12..32]
+                    )
+                )
+            }
+            Self::Rsa(pk) => {
+                // Same schema as for P256, but with "rsa2048" prefix:
+                // "0x" .. hex(keccak256("rsa2048" .. pk)[12..32])
+                format!(
+                    "0x{}",
+                    hex::encode(
+                        &::near_sdk::env::keccak256_array([b"rsa2048".as_slice(), pk].concat())
+                            [12..32]
+                    )
+                )
+            }
+            Self::SchnorrSecp256k1(pk) => {
+                // Same schema as for P256, but with "schnorr_secp256k1" prefix:
+                // "0x" .. hex(keccak256("schnorr_secp256k1" .. pk)[12..32])
+                format!(
+                    "0x{}",
+                    hex::encode(
+                        &::near_sdk::env::keccak256_array(
+                            [b"schnorr_secp256k1".as_slice(), pk].concat()
+                        )[12..32]
+                    )
+                )
+            }
+            Self::Bls12381(pk) => {
+                // Same schema as for P256, but with "bls12381" prefix:
+                // "0x" .. hex(keccak256("bls12381" .. pk)[12..32])
+                format!(
+                    "0x{}",
+                    hex::encode(
+                        &::near_sdk::env::keccak256_array([b"bls12381".as_slice(), pk].concat())
+                            [12..32]
+                    )
+                )
+            }
         }
         .try_into()
         .unwrap_or_else(|_| unreachable!())
@@ -131,6 +188,12 @@ impl FromStr for PublicKey {
             CurveType::P256 => P256::parse_base58(data)
                 .map(P256UncompressedPublicKey)
                 .map(Self::P256),
+            CurveType::Stark => Stark::parse_base58(data).map(Self::Stark),
+            CurveType::Rsa2048 => Rsa2048::parse_base58(data).map(Self::Rsa),
+            CurveType::SchnorrSecp256k1 => {
+                SchnorrSecp256k1::parse_base58(data).map(Self::SchnorrSecp256k1)
+            }
+            CurveType::Bls12381 => Bls12381::parse_base58(data).map(Self::Bls12381),
         }
     }
 }
@@ -236,6 +299,22 @@ mod tests {
         "p256:3aMVMxsoAnHUbweXMtdKaN1uJaNwsfKv7wnc97SDGjXhyK62VyJwhPUPLZefKVthcoUcuWK6cqkSU4M542ipNxS3",
         "0x7edf07ede58238026db3f90fc8032633b69b8de5"
     )]
+    #[case(
+        "stark:5TagutioHgKLh7KZ1VEFBYfgRkPtqnKm9LoMnJMJugxm",
+        "0xba6c0d540b53ed4f6aee363887ad43dcfe2a54d7"
+    )]
+    #[case(
+        "rsa2048:BaarhDBSWN1op8PFVkoRtPtGDrNsPwTct1fcZuwHfKtJUyYzxQCLQk6dWwvkhTMZEpUjFaTbeoiMdWNUrbFXqL84dvqUANHqq7V7iaLft94NocGo61AQVpz2Rxn7VG2q4JufDXcVWT935YMjgvrNDLMwGVCMNgR2H6s4mC44wyFNX9FF9HzBJNmRgQYsAmy81V9iu25FYGPVKo7gzsaGDfBTxork7RYzQtqgs2hvVXjYEH3ho2jrh9JBgPzNg11ibyMoibUrCQo8uxt2W8CApcRSg9etYJwk4M6CKrf4R1W2sDjHhtpxAzfwaAjHgGPu7pyf6KbbpdEps3iTKE2h3ZZ2maeLBN",
+        "0xa9f4855bbe21c0a9dd9cf2f046cbee8f0b007b9d"
+    )]
+    #[case(
+        "schnorr_secp256k1:9ziQBABWnubqGoWsbz2MJyL5n8APaAhcSTD5hDLeEXhy",
+        "0xf0f55ca3296d5f9ac9782a632fbd370535e1c019"
+    )]
+    #[case(
+        "bls12381:6sRUAVSfz8K1YEpegsThyDZ7MjczkUfgcftgbgPs8BbxcBCT5t5MwsrYk7iXX7DtzS",
+        "0xf795025a3a287f120c2eea9d58a0a5299ac685ef"
+    )]
     fn to_implicit_account_id(#[case] pk: &str, #[case] expected: &str) {
         assert_eq!(
             pk.parse::<PublicKey>().unwrap().to_implicit_account_id(),
@@ -250,6 +329,14 @@ mod tests {
     #[case("secp256k1:")]
     #[case("p256:p3UPfBR3kWxE2C8wF1855eguaoRvoW6jV5ZXbu3sTTCs")]
     #[case("p256:")]
+    #[case("stark:5TagutioHgKLh7KZ1VEFBYfgRkPtqnKm9LoMnJMJ")]
+    #[case("stark:")]
+    #[case("rsa2048:5TagutioHgKLh7KZ1VEFBYfgRkPtqnKm9LoMnJMJugxm")]
+    #[case("rsa2048:")]
+    #[case("schnorr_secp256k1:5TagutioHgKLh7KZ1VEFBYfgRkPtqnKm9LoMnJMJ")]
+    #[case("schnorr_secp256k1:")]
+    #[case("bls12381:5TagutioHgKLh7KZ1VEFBYfgRkPtqnKm9LoMnJMJugxm")]
+    #[case("bls12381:")]
     fn parse_invalid_length(#[case] pk: &str) {
         assert_eq!(pk.parse::<PublicKey>(), Err(ParseCurveError::InvalidLength));
     }
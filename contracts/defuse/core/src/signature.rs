@@ -3,7 +3,10 @@ use core::{
     str::FromStr,
 };
 
-use defuse_crypto::{Curve, CurveType, Ed25519, P256, ParseCurveError, Secp256k1, TypedCurve};
+use defuse_crypto::{
+    Bls12381, Curve, CurveType, Ed25519, P256, ParseCurveError, Rsa2048, SchnorrSecp256k1,
+    Secp256k1, Stark, TypedCurve,
+};
 use near_sdk::{bs58, near};
 use serde_with::{DeserializeFromStr, SerializeDisplay};
 
@@ -16,6 +19,10 @@ pub enum Signature {
     Ed25519(<Ed25519 as Curve>::Signature) = 0,
     Secp256k1(<Secp256k1 as Curve>::Signature) = 1,
     P256(<P256 as Curve>::Signature) = 2,
+    Stark(<Stark as Curve>::Signature) = 3,
+    Rsa(<Rsa2048 as Curve>::Signature) = 4,
+    SchnorrSecp256k1(<SchnorrSecp256k1 as Curve>::Signature) = 5,
+    Bls12381(<Bls12381 as Curve>::Signature) = 6,
 }
 
 impl Signature {
@@ -25,6 +32,10 @@ impl Signature {
             Self::Ed25519(_) => CurveType::Ed25519,
             Self::Secp256k1(_) => CurveType::Secp256k1,
             Self::P256(_) => CurveType::P256,
+            Self::Stark(_) => CurveType::Stark,
+            Self::Rsa(_) => CurveType::Rsa2048,
+            Self::SchnorrSecp256k1(_) => CurveType::SchnorrSecp256k1,
+            Self::Bls12381(_) => CurveType::Bls12381,
         }
     }
 
@@ -35,6 +46,10 @@ impl Signature {
             Self::Ed25519(data) => data,
             Self::Secp256k1(data) => data,
             Self::P256(data) => data,
+            Self::Stark(data) => data,
+            Self::Rsa(data) => data,
+            Self::SchnorrSecp256k1(data) => data,
+            Self::Bls12381(data) => data,
         }
     }
 }
@@ -75,6 +90,12 @@ impl FromStr for Signature {
             CurveType::Ed25519 => Ed25519::parse_base58(data).map(Self::Ed25519),
             CurveType::Secp256k1 => Secp256k1::parse_base58(data).map(Self::Secp256k1),
             CurveType::P256 => P256::parse_base58(data).map(Self::P256),
+            CurveType::Stark => Stark::parse_base58(data).map(Self::Stark),
+            CurveType::Rsa2048 => Rsa2048::parse_base58(data).map(Self::Rsa),
+            CurveType::SchnorrSecp256k1 => {
+                SchnorrSecp256k1::parse_base58(data).map(Self::SchnorrSecp256k1)
+            }
+            CurveType::Bls12381 => Bls12381::parse_base58(data).map(Self::Bls12381),
         }
     }
 }
@@ -169,6 +190,8 @@ mod tests {
     #[case("secp256k1:")]
     #[case("p256:p3UPfBR3kWxE2C8wF1855eguaoRvoW6jV5ZXbu3sTTCs")]
     #[case("p256:")]
+    #[case("rsa2048:p3UPfBR3kWxE2C8wF1855eguaoRvoW6jV5ZXbu3sTTCs")]
+    #[case("rsa2048:")]
     fn parse_invalid_length(#[case] sig: &str) {
         assert_eq!(
             sig.parse::<Signature>(),
@@ -1,11 +1,14 @@
-use near_sdk::{AccountIdRef, Gas, near};
+use near_sdk::{AccountId, AccountIdRef, Gas, near, serde_json};
 use serde_with::DisplayFromStr;
-use std::{borrow::Cow, collections::BTreeMap};
+use std::{borrow::Cow, collections::BTreeMap, fmt};
 
-use crate::{amounts::Amounts, intents::tokens::Transfer};
+use crate::{DefuseError, Result, amounts::Amounts, intents::tokens::Transfer, token_id::TokenId};
 
 pub const MAX_TOKEN_ID_LEN: usize = 127;
 
+/// Max length of structured withdrawal memo, see [`WithdrawMemo`]
+pub const MAX_WITHDRAW_MEMO_LEN: usize = 512;
+
 pub const MT_ON_TRANSFER_GAS_MIN: Gas = Gas::from_tgas(5);
 pub const MT_ON_TRANSFER_GAS_DEFAULT: Gas = Gas::from_tgas(30);
 
@@ -91,3 +94,121 @@ impl<'a> From<&'a Transfer> for TransferEvent<'a> {
         }
     }
 }
+
+/// Deposit attributed to the referral
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct DepositReferralEvent<'a> {
+    pub referral: Cow<'a, AccountIdRef>,
+
+    #[serde_as(as = "Amounts<BTreeMap<_, DisplayFromStr>>")]
+    pub tokens: Amounts,
+}
+
+#[must_use = "make sure to `.emit()` this event"]
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct TokenDenylistSetEvent<'a> {
+    pub token_id: Cow<'a, TokenId>,
+    /// `false` if the token was removed from the denylist
+    pub denied: bool,
+}
+
+/// Structured memo of withdrawals bridged to other chains.
+///
+/// It's opt-in: only memos starting with [`WithdrawMemo::PREFIX`] are
+/// expected to be followed by this struct encoded as JSON, so that bridges
+/// and indexers can rely on it. All other memos are passed as-is.
+#[near(serializers = [json])]
+#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawMemo {
+    /// Destination chain, e.g. `eth:1`
+    pub dest_chain: String,
+    /// Address of the receiver on destination chain
+    pub dest_address: String,
+    /// Bridge to be used, if there are many for the destination chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bridge: Option<AccountId>,
+}
+
+impl WithdrawMemo {
+    /// Versioned prefix of structured memos
+    pub const PREFIX: &str = "memo:v1:";
+
+    pub const MAX_DEST_CHAIN_LEN: usize = 64;
+    pub const MAX_DEST_ADDRESS_LEN: usize = 256;
+
+    /// Parses given withdrawal memo if it's structured, i.e. starts
+    /// with [`WithdrawMemo::PREFIX`], and validates it
+    pub fn parse(memo: &str) -> Result<Option<Self>> {
+        let Some(json) = memo.strip_prefix(Self::PREFIX) else {
+            return Ok(None);
+        };
+        if memo.len() > MAX_WITHDRAW_MEMO_LEN {
+            return Err(DefuseError::WithdrawMemoTooLong(memo.len()));
+        }
+
+        let memo: Self = serde_json::from_str(json)?;
+        if memo.dest_chain.is_empty()
+            || memo.dest_chain.len() > Self::MAX_DEST_CHAIN_LEN
+            || memo.dest_address.is_empty()
+            || memo.dest_address.len() > Self::MAX_DEST_ADDRESS_LEN
+        {
+            return Err(DefuseError::InvalidWithdrawMemo);
+        }
+
+        Ok(Some(memo))
+    }
+}
+
+impl fmt::Display for WithdrawMemo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::PREFIX)?;
+        f.write_str(&serde_json::to_string(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn withdraw_memo() {
+        let memo = WithdrawMemo {
+            dest_chain: "eth:1".to_string(),
+            dest_address: "0x0000000000000000000000000000000000000001".to_string(),
+            bridge: None,
+        };
+        assert_eq!(
+            memo.to_string(),
+            r#"memo:v1:{"dest_chain":"eth:1","dest_address":"0x0000000000000000000000000000000000000001"}"#
+        );
+        assert_eq!(WithdrawMemo::parse(&memo.to_string()).unwrap(), Some(memo));
+
+        // plain memos are not validated
+        for memo in [
+            "",
+            "WITHDRAW_TO:0x0000000000000000000000000000000000000001",
+            r#"{"dest_chain":"eth:1","unknown":true}"#,
+            &"a".repeat(MAX_WITHDRAW_MEMO_LEN + 1),
+        ] {
+            assert_eq!(WithdrawMemo::parse(memo).unwrap(), None);
+        }
+
+        assert!(matches!(
+            WithdrawMemo::parse(r#"memo:v1:{"dest_chain":"eth:1","dest_address":""}"#),
+            Err(DefuseError::InvalidWithdrawMemo)
+        ));
+        assert!(
+            WithdrawMemo::parse(
+                r#"memo:v1:{"dest_chain":"eth:1","dest_address":"0x01","unknown":true}"#
+            )
+            .is_err()
+        );
+        assert!(matches!(
+            WithdrawMemo::parse(&format!("memo:v1:{}", "a".repeat(MAX_WITHDRAW_MEMO_LEN))),
+            Err(DefuseError::WithdrawMemoTooLong(_))
+        ));
+    }
+}
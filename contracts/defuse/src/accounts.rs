@@ -1,9 +1,33 @@
-use defuse_core::{Nonce, PublicKey};
-use defuse_serde_utils::base64::AsBase64;
+use core::num::NonZeroU16;
+use defuse_core::{
+    Nonce, PublicKey, Timestamp,
+    crypto::{Bls12381PublicKey, Bls12381Signature},
+    token_id::TokenId,
+};
+use defuse_serde_utils::{base58::AsBase58, base64::AsBase64};
 use near_plugins::AccessControllable;
-use near_sdk::{AccountId, ext_contract};
+use near_sdk::{AccountId, CryptoHash, NearToken, ext_contract, json_types::U128, near};
 use std::collections::HashSet;
 
+/// Storage deposit for public keys added with expiration, since they
+/// can be removed by anyone. Covers the largest public key stored along
+/// with its expiration.
+pub const EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT: NearToken = NearToken::from_millinear(10);
+
+/// Storage deposit for cancellation of an intent, which is kept forever
+/// and thus is not refunded
+pub const CANCELLED_INTENT_STORAGE_DEPOSIT: NearToken = NearToken::from_millinear(2);
+
+/// Max number of `WebAuthn` origins allowed per account
+pub const MAX_WEBAUTHN_ALLOWED_ORIGINS: usize = 8;
+/// Max length of a single `WebAuthn` origin
+pub const MAX_WEBAUTHN_ORIGIN_LEN: usize = 256;
+
+/// Storage deposit for `WebAuthn` origins allowed for the account.
+/// Covers [`MAX_WEBAUTHN_ALLOWED_ORIGINS`] origins of
+/// [`MAX_WEBAUTHN_ORIGIN_LEN`] each along with the account id.
+pub const WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT: NearToken = NearToken::from_millinear(25);
+
 #[ext_contract(ext_account_manager)]
 pub trait AccountManager {
     /// Check if account has given public key
@@ -13,9 +37,29 @@ pub trait AccountManager {
     fn public_keys_of(&self, account_id: &AccountId) -> HashSet<PublicKey>;
 
     /// Registers or re-activates `public_key` under the caller `account_id`.
+    /// If `expires_at` is given, the key can't be used to sign intents
+    /// after this time and can be removed by anyone via
+    /// [`cleanup_expired_keys`](AccountManager::cleanup_expired_keys).
+    ///
+    /// BLS12-381 public keys can't be added this way, see
+    /// [`add_bls12381_public_key`](AccountManager::add_bls12381_public_key).
+    ///
+    /// NOTE: MUST attach 1 yⓃ for security purposes, or at least
+    /// [`EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT`] if `expires_at` is given.
+    /// The surplus is refunded, while the deposit itself is refunded to
+    /// `account_id` once the key is removed.
+    fn add_public_key(&mut self, public_key: PublicKey, expires_at: Option<Timestamp>);
+
+    /// Registers BLS12-381 `public_key` under the caller `account_id`
+    /// if `proof_of_possession` is a valid signature of the `public_key`
+    /// itself. This prevents rogue-key attacks on aggregated signatures.
     ///
     /// NOTE: MUST attach 1 yⓃ for security purposes.
-    fn add_public_key(&mut self, public_key: PublicKey);
+    fn add_bls12381_public_key(
+        &mut self,
+        public_key: Bls12381PublicKey,
+        proof_of_possession: Bls12381Signature,
+    );
 
     /// Deactivate `public_key` from the caller `account_id`,
     /// i.e. this key can't be used to make any actions unless it's re-created.
@@ -23,11 +67,53 @@ pub trait AccountManager {
     /// NOTE: MUST attach 1 yⓃ for security purposes.
     fn remove_public_key(&mut self, public_key: PublicKey);
 
+    /// Returns the time after which `public_key` can no longer be used
+    /// by given `account_id`, or `None` if it never expires
+    fn public_key_expires_at(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> Option<Timestamp>;
+
+    /// Removes all expired public keys of given `account_id` and refunds
+    /// their storage deposits to it. Locked accounts are skipped.
+    /// Anyone can call this method.
+    fn cleanup_expired_keys(&mut self, account_id: AccountId);
+
     /// Returns whether given nonce was already used by the account
     /// NOTE: nonces are non-sequential and follow
     /// [permit2 nonce schema](https://docs.uniswap.org/contracts/permit2/reference/signature-transfer#nonce-schema).
     fn is_nonce_used(&self, account_id: &AccountId, nonce: AsBase64<Nonce>) -> bool;
 
+    /// Returns nonces used by the account, including ones invalidated
+    /// via `InvalidateNonces` intent, grouped by their bitmaps in order
+    /// of bitmap creation. `from_index` and `limit` paginate over bitmaps
+    /// rather than nonces, so each page contains up to `256 * limit` nonces.
+    /// NOTE: nonces with expired bitmaps are cleaned up and not returned.
+    fn nonces_of(
+        &self,
+        account_id: &AccountId,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<AsBase64<Nonce>>;
+
+    /// Returns whether given `account_id` cancelled the intent
+    /// with given hash
+    fn is_intent_cancelled(
+        &self,
+        account_id: &AccountId,
+        intent_hash: AsBase58<CryptoHash>,
+    ) -> bool;
+
+    /// Cancels the intent with given hash signed by the caller, so that
+    /// it can never be executed. Useful when the nonce it was signed
+    /// with is unknown and can't be invalidated.
+    ///
+    /// NOTE: MUST attach at least [`CANCELLED_INTENT_STORAGE_DEPOSIT`].
+    /// The surplus is refunded, as well as the whole deposit if the
+    /// intent was already cancelled.
+    fn cancel_intent(&mut self, intent_hash: AsBase58<CryptoHash>);
+
     /// Returns whether authentication by `PREDECESSOR_ID` is enabled
     /// for given `account_id`.
     ///
@@ -41,8 +127,96 @@ pub trait AccountManager {
     /// **WARN**: Doing so might lock you out of your funds if
     /// you don't have any other public keys added to your account.
     ///
+    /// NOTE: not allowed for frozen accounts, since they can be unfrozen
+    /// only via [`unfreeze_account`](AccountManager::unfreeze_account).
+    ///
     /// NOTE: MUST attach 1 yⓃ for security purposes.
     fn disable_auth_by_predecessor_id(&mut self);
+
+    /// Returns origins of `WebAuthn` assertions accepted for given
+    /// `account_id`, or `None` if assertions from any origin are accepted
+    fn webauthn_allowed_origins(&self, account_id: &AccountId) -> Option<Vec<String>>;
+
+    /// Restricts origins of `WebAuthn` assertions accepted for the caller,
+    /// so that passkey signatures created on phishing domains are rejected.
+    /// Passing `None` accepts assertions from any origin.
+    /// At most [`MAX_WEBAUTHN_ALLOWED_ORIGINS`] non-empty origins up to
+    /// [`MAX_WEBAUTHN_ORIGIN_LEN`] long each are allowed.
+    ///
+    /// NOTE: MUST attach at least [`WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT`]
+    /// when restricting origins for the first time, or 1 yⓃ otherwise for
+    /// security purposes. The surplus is refunded, while the deposit itself
+    /// is refunded once the restriction is removed by passing `None`.
+    fn set_webauthn_allowed_origins(&mut self, allowed_origins: Option<Vec<String>>);
+
+    /// Returns minimum number of distinct public keys required to sign
+    /// intents on behalf of given `account_id`, which is 1 by default
+    fn multisig_threshold(&self, account_id: &AccountId) -> NonZeroU16;
+
+    /// Sets minimum number of distinct public keys required to sign
+    /// intents on behalf of the caller, so that payloads signed by
+    /// fewer keys are rejected.
+    ///
+    /// **WARN**: Setting it above the number of public keys added to
+    /// your account leaves only `PREDECESSOR_ID` authentication.
+    ///
+    /// NOTE: MUST attach 1 yⓃ for security purposes.
+    fn set_multisig_threshold(&mut self, threshold: NonZeroU16);
+
+    /// Returns whether given `account_id` was frozen by its owner
+    fn is_account_frozen(&self, account_id: &AccountId) -> bool;
+
+    /// Freezes the caller, so that neither intents signed on its behalf
+    /// nor withdrawals can be executed until it's unfrozen. Meant to be
+    /// used as a panic button when any of public keys is compromised.
+    /// Returns `false` if the account was already frozen.
+    ///
+    /// NOTE: can also be done by any of public keys via
+    /// [`FreezeAccount`](defuse_core::intents::account::FreezeAccount) intent.
+    ///
+    /// NOTE: MUST attach 1 yⓃ for security purposes.
+    fn freeze_account(&mut self) -> bool;
+
+    /// Unfreezes the caller.
+    /// Returns `false` if the account wasn't frozen.
+    ///
+    /// NOTE: this doesn't unlock accounts locked via
+    /// [`force_lock_account`](ForceAccountManager::force_lock_account).
+    ///
+    /// NOTE: MUST attach 1 yⓃ for security purposes.
+    fn unfreeze_account(&mut self) -> bool;
+
+    /// Returns public keys, token balances, nonce stats and flags of given
+    /// `account_id` in a single call. Token balances are paginated via `from_index`
+    /// and `limit`, while all public keys are always returned.
+    fn account_state(
+        &self,
+        account_id: AccountId,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> AccountOverview;
+}
+
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct AccountOverview {
+    pub public_keys: Vec<PublicKeyOverview>,
+    pub token_balances: Vec<(TokenId, U128)>,
+    pub is_locked: bool,
+    pub is_frozen: bool,
+    pub is_auth_by_predecessor_id_enabled: bool,
+    /// Number of nonce bitmaps of the account, i.e. pages of
+    /// [`AccountManager::nonces_of`]
+    pub nonce_bitmaps: u32,
+}
+
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct PublicKeyOverview {
+    pub public_key: PublicKey,
+    /// `None` if the key never expires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
 }
 
 #[ext_contract(ext_force_account_manager)]
@@ -0,0 +1,44 @@
+use defuse_core::{Timestamp, token_id::TokenId};
+use near_sdk::{AccountId, NearToken, ext_contract, json_types::U128};
+
+/// Storage deposit for a single allowance. Covers the longest account
+/// ids of owner and spender along with the longest token id.
+pub const ALLOWANCE_STORAGE_DEPOSIT: NearToken = NearToken::from_millinear(5);
+
+#[ext_contract(ext_mt_allowances)]
+pub trait MultiTokenAllowances {
+    /// Allows `spender_id` to transfer up to `amount` of caller's `token_id`
+    /// via [`mt_transfer_from`](MultiTokenAllowances::mt_transfer_from),
+    /// replacing any previous allowance. Zero `amount` revokes it.
+    /// If `expires_at` is given, the allowance can't be spent after this time.
+    ///
+    /// NOTE: MUST attach at least [`ALLOWANCE_STORAGE_DEPOSIT`] when there
+    /// is no allowance yet, or 1 yⓃ otherwise for security purposes.
+    /// The surplus is refunded, while the deposit itself is refunded to
+    /// the caller once the allowance is revoked or fully spent.
+    fn mt_approve(
+        &mut self,
+        spender_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        expires_at: Option<Timestamp>,
+    );
+
+    /// Returns amount of `owner_id`'s `token_id` which `spender_id`
+    /// is still allowed to transfer
+    fn mt_allowance(&self, owner_id: AccountId, spender_id: AccountId, token_id: TokenId) -> U128;
+
+    /// Transfers `amount` of `owner_id`'s `token_id` to `receiver_id`
+    /// on behalf of the caller, decreasing the allowance given to it by
+    /// [`mt_approve`](MultiTokenAllowances::mt_approve).
+    ///
+    /// NOTE: MUST attach 1 yⓃ for security purposes.
+    fn mt_transfer_from(
+        &mut self,
+        owner_id: AccountId,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        memo: Option<String>,
+    );
+}
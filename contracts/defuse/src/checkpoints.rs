@@ -0,0 +1,16 @@
+use defuse_core::checkpoint::StateCheckpoint;
+use near_plugins::AccessControllable;
+use near_sdk::ext_contract;
+
+#[ext_contract(ext_state_checkpoints)]
+pub trait StateCheckpoints: AccessControllable {
+    /// Publishes a commitment to account balances and nonces at
+    /// `checkpoint.block_height`, computed off-chain.
+    /// Epochs MUST be sequential, starting from zero.
+    ///
+    /// See [`defuse_core::checkpoint`] for the proof format.
+    fn publish_state_checkpoint(&mut self, checkpoint: StateCheckpoint);
+
+    /// Returns checkpoint for given epoch, or the latest one if `None`
+    fn state_checkpoint(&self, epoch: Option<u64>) -> Option<StateCheckpoint>;
+}
@@ -111,7 +111,7 @@ impl AccountData {
         let mut legacy = B::new(self.prefix.as_slice(), &self.account_id);
 
         for pubkey in &self.public_keys {
-            assert!(legacy.add_public_key(&self.account_id, *pubkey));
+            assert!(legacy.add_public_key(&self.account_id, pubkey));
         }
 
         if let Some(pk) = PublicKey::from_implicit_account_id(&self.account_id)
@@ -148,7 +148,7 @@ impl AccountData {
 
 trait LegacyAccountBuilder {
     fn new(prefix: &[u8], account_id: &AccountId) -> Self;
-    fn add_public_key(&mut self, account_id: &AccountId, pk: PublicKey) -> bool;
+    fn add_public_key(&mut self, account_id: &AccountId, pk: &PublicKey) -> bool;
     fn remove_public_key(&mut self, account_id: &AccountId, pk: &PublicKey) -> bool;
     fn commit_nonce(&mut self, nonce: U256) -> Result<()>;
     fn add_balance(&mut self, token_id: TokenId, amount: u128) -> bool;
@@ -162,7 +162,7 @@ macro_rules! impl_legacy_account_builder {
                 <$account_type>::new(prefix, account_id)
             }
 
-            fn add_public_key(&mut self, account_id: &AccountId, pk: PublicKey) -> bool {
+            fn add_public_key(&mut self, account_id: &AccountId, pk: &PublicKey) -> bool {
                 self.add_public_key(account_id, pk)
             }
 
@@ -188,7 +188,7 @@ macro_rules! impl_lock_account_builder {
                 Lock::unlocked(<$account_type>::new(prefix, account_id))
             }
 
-            fn add_public_key(&mut self, account_id: &AccountId, pk: PublicKey) -> bool {
+            fn add_public_key(&mut self, account_id: &AccountId, pk: &PublicKey) -> bool {
                 self.get_mut().unwrap().add_public_key(account_id, pk)
             }
 
@@ -93,7 +93,7 @@ pub(super) mod tests {
 
         #[inline]
         #[must_use]
-        pub fn add_public_key(&mut self, me: &AccountIdRef, public_key: PublicKey) -> bool {
+        pub fn add_public_key(&mut self, me: &AccountIdRef, public_key: &PublicKey) -> bool {
             if !self.maybe_add_public_key(me, public_key) {
                 return false;
             }
@@ -101,7 +101,7 @@ pub(super) mod tests {
             DefuseEvent::PublicKeyAdded(MaybeIntentEvent::new_fn_call(AccountEvent::new(
                 Cow::Borrowed(me),
                 PublicKeyEvent {
-                    public_key: Cow::Borrowed(&public_key),
+                    public_key: Cow::Borrowed(public_key),
                 },
             )))
             .emit();
@@ -111,13 +111,13 @@ pub(super) mod tests {
 
         #[inline]
         #[must_use]
-        fn maybe_add_public_key(&mut self, me: &AccountIdRef, public_key: PublicKey) -> bool {
+        fn maybe_add_public_key(&mut self, me: &AccountIdRef, public_key: &PublicKey) -> bool {
             if me == public_key.to_implicit_account_id() {
                 let was_removed = self.is_implicit_public_key_removed();
                 self.set_implicit_public_key_removed(false);
                 was_removed
             } else {
-                self.public_keys.insert(public_key)
+                self.public_keys.insert(*public_key)
             }
         }
 
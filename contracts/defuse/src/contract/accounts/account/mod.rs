@@ -61,13 +61,13 @@ impl Account {
 
     #[inline]
     #[must_use]
-    pub fn add_public_key(&mut self, me: &AccountIdRef, public_key: PublicKey) -> bool {
+    pub fn add_public_key(&mut self, me: &AccountIdRef, public_key: &PublicKey) -> bool {
         if me == public_key.to_implicit_account_id() {
             let was_removed = self.is_implicit_public_key_removed();
             self.set_implicit_public_key_removed(false);
             was_removed
         } else {
-            self.public_keys.insert(public_key)
+            self.public_keys.insert(*public_key)
         }
     }
 
@@ -108,6 +108,11 @@ impl Account {
         self.nonces.commit(nonce)
     }
 
+    #[inline]
+    pub fn iter_nonces_by_prefix(&self, prefix: NoncePrefix) -> impl Iterator<Item = U256> + '_ {
+        self.nonces.iter_by_prefix(prefix)
+    }
+
     /// Clears the all nonces with corresponding prefix if it was expired/invalidated.
     /// Returns whether the nonces was cleared,
     /// regardless of whether it was previously committed or not.
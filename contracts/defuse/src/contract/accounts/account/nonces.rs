@@ -72,6 +72,16 @@ where
     pub fn cleanup_by_prefix(&mut self, prefix: NoncePrefix) -> bool {
         self.nonces.cleanup_by_prefix(prefix)
     }
+
+    /// Iterate over committed nonces with given prefix in both maps
+    #[inline]
+    pub fn iter_by_prefix(&self, prefix: NoncePrefix) -> impl Iterator<Item = Nonce> + '_ {
+        self.nonces.iter_by_prefix(prefix).chain(
+            self.legacy
+                .iter()
+                .flat_map(move |legacy| legacy.iter_by_prefix(prefix)),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1,29 +1,44 @@
 mod account;
 mod force;
+mod nonce_prefixes;
 mod state;
 
-pub use self::{account::*, state::*};
+pub use self::{account::*, nonce_prefixes::*, state::*};
 
+use core::num::NonZeroU16;
 use std::{borrow::Cow, collections::HashSet};
 
 use defuse_core::{
-    DefuseError, Nonce, PublicKey, Result,
-    accounts::{AccountEvent, PublicKeyEvent},
+    DefuseError, Nonce, PublicKey, Result, Timestamp,
+    accounts::{
+        AccountEvent, IntentCancelledEvent, MultisigThresholdEvent, PublicKeyEvent,
+        PublicKeyExpiration, WebAuthnAllowedOriginsEvent,
+    },
+    crypto::{Bls12381, Bls12381PublicKey, Bls12381Signature},
     engine::{State, StateView},
     events::DefuseEvent,
     intents::{MaybeIntentEvent, account::SetAuthByPredecessorId},
 };
 
-use defuse_near_utils::{Lock, NestPrefix};
-use defuse_serde_utils::base64::AsBase64;
+use defuse_near_utils::{Lock, NestPrefix, require_envelope};
+use defuse_serde_utils::{base58::AsBase58, base64::AsBase64};
 
 use near_sdk::{
-    AccountId, AccountIdRef, BorshStorageKey, FunctionError, IntoStorageKey, assert_one_yocto,
-    borsh::BorshSerialize, env, near, store::IterableMap,
+    AccountId, AccountIdRef, BorshStorageKey, CryptoHash, FunctionError, IntoStorageKey, NearToken,
+    Promise, assert_one_yocto,
+    borsh::BorshSerialize,
+    env,
+    json_types::U128,
+    near,
+    store::{IterableMap, IterableSet},
 };
 
 use crate::{
-    accounts::AccountManager,
+    accounts::{
+        AccountManager, AccountOverview, CANCELLED_INTENT_STORAGE_DEPOSIT,
+        EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT, MAX_WEBAUTHN_ALLOWED_ORIGINS, MAX_WEBAUTHN_ORIGIN_LEN,
+        PublicKeyOverview, WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT,
+    },
     contract::{Contract, ContractExt, accounts::AccountEntry},
 };
 
@@ -38,11 +53,45 @@ impl AccountManager for Contract {
     }
 
     #[payable]
-    fn add_public_key(&mut self, public_key: PublicKey) {
-        assert_one_yocto();
+    fn add_public_key(&mut self, public_key: PublicKey, expires_at: Option<Timestamp>) {
+        if expires_at.is_none() {
+            assert_one_yocto();
+        }
         let account_id = self.ensure_auth_predecessor_id();
+        if expires_at.is_some_and(|expires_at| expires_at <= Timestamp::now()) {
+            DefuseError::DeadlineExpired.panic();
+        }
+        if matches!(public_key, PublicKey::Bls12381(_)) {
+            // see `add_bls12381_public_key()`
+            DefuseError::InvalidProofOfPossession(Box::new(public_key)).panic();
+        }
 
         self.add_public_key_and_emit_event(account_id.as_ref(), public_key);
+
+        if let Some(expires_at) = expires_at {
+            Self::take_storage_deposit(&account_id, EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT);
+
+            self.public_key_expirations
+                .entry(account_id)
+                .or_default()
+                .insert(public_key, PublicKeyExpiration::new(expires_at));
+        }
+    }
+
+    #[payable]
+    fn add_bls12381_public_key(
+        &mut self,
+        public_key: Bls12381PublicKey,
+        proof_of_possession: Bls12381Signature,
+    ) {
+        assert_one_yocto();
+        let account_id = self.ensure_auth_predecessor_id();
+        if !Bls12381::verify_proof_of_possession(&public_key.0, &proof_of_possession.0) {
+            DefuseError::InvalidProofOfPossession(Box::new(PublicKey::Bls12381(public_key.0)))
+                .panic();
+        }
+
+        self.add_public_key_and_emit_event(account_id.as_ref(), PublicKey::Bls12381(public_key.0));
     }
 
     #[payable]
@@ -53,10 +102,92 @@ impl AccountManager for Contract {
         self.remove_public_key_and_emit_event(account_id.as_ref(), public_key);
     }
 
+    fn public_key_expires_at(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> Option<Timestamp> {
+        StateView::public_key_expires_at(self, account_id, public_key)
+    }
+
+    fn cleanup_expired_keys(&mut self, account_id: AccountId) {
+        if self.accounts.get(&account_id).is_some_and(Lock::is_locked) {
+            return;
+        }
+
+        let now = Timestamp::now();
+        let expired: Vec<PublicKey> = self
+            .public_key_expirations
+            .get(&account_id)
+            .into_iter()
+            .flatten()
+            .filter(|(_, expiration)| expiration.is_expired_at(now))
+            .map(|(public_key, _)| *public_key)
+            .collect();
+
+        for public_key in expired {
+            self.remove_public_key_and_emit_event(account_id.as_ref(), public_key);
+        }
+    }
+
     fn is_nonce_used(&self, account_id: &AccountId, nonce: AsBase64<Nonce>) -> bool {
         StateView::is_nonce_used(self, account_id, nonce.into_inner())
     }
 
+    fn nonces_of(
+        &self,
+        account_id: &AccountId,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<AsBase64<Nonce>> {
+        let (Some(account), Some(prefixes)) = (
+            self.accounts.get(account_id),
+            self.nonce_prefixes.get(account_id),
+        ) else {
+            return Vec::new();
+        };
+        let account = account.as_inner_unchecked();
+
+        prefixes
+            .iter()
+            .skip(from_index.unwrap_or_default().try_into().unwrap())
+            .take(limit.map_or(usize::MAX, |l| l.try_into().unwrap()))
+            .flat_map(|prefix| account.iter_nonces_by_prefix(*prefix))
+            .map(AsBase64)
+            .collect()
+    }
+
+    fn is_intent_cancelled(
+        &self,
+        account_id: &AccountId,
+        intent_hash: AsBase58<CryptoHash>,
+    ) -> bool {
+        StateView::is_intent_cancelled(self, account_id, intent_hash.into_inner())
+    }
+
+    #[payable]
+    fn cancel_intent(&mut self, intent_hash: AsBase58<CryptoHash>) {
+        let account_id = self.ensure_auth_predecessor_id();
+        let intent_hash = intent_hash.into_inner();
+        Self::take_storage_deposit(&account_id, CANCELLED_INTENT_STORAGE_DEPOSIT);
+
+        if self
+            .cancelled_intents
+            .insert((account_id.clone(), intent_hash))
+        {
+            DefuseEvent::IntentCancelled(AccountEvent::new(
+                account_id,
+                IntentCancelledEvent { intent_hash },
+            ))
+            .emit();
+        } else {
+            // nothing was stored
+            Promise::new(account_id)
+                .transfer(CANCELLED_INTENT_STORAGE_DEPOSIT)
+                .detach();
+        }
+    }
+
     fn is_auth_by_predecessor_id_enabled(&self, account_id: &AccountId) -> bool {
         StateView::is_auth_by_predecessor_id_enabled(self, account_id)
     }
@@ -64,17 +195,186 @@ impl AccountManager for Contract {
     #[payable]
     fn disable_auth_by_predecessor_id(&mut self) {
         assert_one_yocto();
+        let account_id = self.ensure_auth_predecessor_id();
+        // otherwise, the account could never be unfrozen
+        if StateView::is_account_frozen(self, &account_id) {
+            DefuseError::AccountFrozen(account_id).panic();
+        }
+
+        self.set_auth_by_predecessor_id_and_emit_event(&account_id, false, false)
+            .unwrap_or_else(|err| err.panic());
+    }
 
-        self.set_auth_by_predecessor_id_and_emit_event(
-            &self.ensure_auth_predecessor_id(),
-            false,
-            false,
-        )
-        .unwrap_or_else(|err| err.panic());
+    fn webauthn_allowed_origins(&self, account_id: &AccountId) -> Option<Vec<String>> {
+        self.webauthn_allowed_origins.get(account_id).cloned()
+    }
+
+    #[payable]
+    fn set_webauthn_allowed_origins(&mut self, allowed_origins: Option<Vec<String>>) {
+        let account_id = self.ensure_auth_predecessor_id();
+        if StateView::is_account_locked(self, &account_id) {
+            DefuseError::AccountLocked(account_id).panic();
+        }
+        if let Some(allowed_origins) = &allowed_origins {
+            require_envelope!(
+                allowed_origins.len() <= MAX_WEBAUTHN_ALLOWED_ORIGINS,
+                "too_many_webauthn_origins",
+                "too many WebAuthn origins"
+            );
+            require_envelope!(
+                allowed_origins
+                    .iter()
+                    .all(|origin| !origin.is_empty() && origin.len() <= MAX_WEBAUTHN_ORIGIN_LEN),
+                "invalid_webauthn_origin",
+                "WebAuthn origin is either empty or too long"
+            );
+        }
+
+        let was_set = self.webauthn_allowed_origins.contains_key(&account_id);
+        if allowed_origins.is_some() && !was_set {
+            Self::take_storage_deposit(&account_id, WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT);
+        } else {
+            assert_one_yocto();
+        }
+
+        DefuseEvent::WebAuthnAllowedOriginsSet(AccountEvent::new(
+            Cow::Borrowed(account_id.as_ref()),
+            WebAuthnAllowedOriginsEvent {
+                allowed_origins: allowed_origins.as_deref().map(Cow::Borrowed),
+            },
+        ))
+        .emit();
+
+        if let Some(allowed_origins) = allowed_origins {
+            self.webauthn_allowed_origins
+                .insert(account_id, allowed_origins);
+        } else if self.webauthn_allowed_origins.remove(&account_id).is_some() {
+            // storage deposit was paid by the account when setting origins
+            Promise::new(account_id)
+                .transfer(WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT)
+                .detach();
+        }
+    }
+
+    fn multisig_threshold(&self, account_id: &AccountId) -> NonZeroU16 {
+        StateView::multisig_threshold(self, account_id)
+    }
+
+    #[payable]
+    fn set_multisig_threshold(&mut self, threshold: NonZeroU16) {
+        assert_one_yocto();
+        let account_id = self.ensure_auth_predecessor_id();
+        if StateView::is_account_locked(self, &account_id) {
+            DefuseError::AccountLocked(account_id).panic();
+        }
+        require_envelope!(
+            StateView::multisig_threshold(self, &account_id) != threshold,
+            "unchanged",
+            "same"
+        );
+
+        DefuseEvent::MultisigThresholdSet(AccountEvent::new(
+            Cow::Borrowed(account_id.as_ref()),
+            MultisigThresholdEvent { threshold },
+        ))
+        .emit();
+
+        if threshold == NonZeroU16::MIN {
+            self.multisig_thresholds.remove(&account_id);
+        } else {
+            self.multisig_thresholds.insert(account_id, threshold);
+        }
+    }
+
+    fn is_account_frozen(&self, account_id: &AccountId) -> bool {
+        StateView::is_account_frozen(self, account_id)
+    }
+
+    #[payable]
+    fn freeze_account(&mut self) -> bool {
+        assert_one_yocto();
+        let account_id = self.ensure_auth_predecessor_id();
+
+        let frozen = self.frozen_accounts.insert(account_id.clone());
+        if frozen {
+            DefuseEvent::AccountFrozen(AccountEvent::new(account_id, ())).emit();
+        }
+        frozen
+    }
+
+    #[payable]
+    fn unfreeze_account(&mut self) -> bool {
+        assert_one_yocto();
+        let account_id = self.ensure_auth_predecessor_id();
+
+        let unfrozen = self.frozen_accounts.remove(&account_id);
+        if unfrozen {
+            DefuseEvent::AccountUnfrozen(AccountEvent::new(account_id, ())).emit();
+        }
+        unfrozen
+    }
+
+    fn account_state(
+        &self,
+        account_id: AccountId,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> AccountOverview {
+        let public_keys = StateView::iter_public_keys(self, &account_id)
+            .map(|public_key| PublicKeyOverview {
+                expires_at: StateView::public_key_expires_at(self, &account_id, &public_key),
+                public_key,
+            })
+            .collect();
+
+        let token_balances = self
+            .accounts
+            .get(&account_id)
+            .map(|account| {
+                let iter = account
+                    .as_inner_unchecked()
+                    .state
+                    .token_balances
+                    .iter()
+                    .skip(from_index.unwrap_or_default().try_into().unwrap())
+                    .map(|(token_id, amount)| (token_id.clone(), U128(*amount)));
+
+                match limit {
+                    Some(l) => iter.take(l.try_into().unwrap()).collect(),
+                    None => iter.collect(),
+                }
+            })
+            .unwrap_or_default();
+
+        AccountOverview {
+            public_keys,
+            token_balances,
+            is_locked: StateView::is_account_locked(self, &account_id),
+            is_frozen: StateView::is_account_frozen(self, &account_id),
+            is_auth_by_predecessor_id_enabled: StateView::is_auth_by_predecessor_id_enabled(
+                self,
+                &account_id,
+            ),
+            nonce_bitmaps: self
+                .nonce_prefixes
+                .get(&account_id)
+                .map_or(0, IterableSet::len),
+        }
     }
 }
 
 impl Contract {
+    /// Takes `deposit` for storage from the attached deposit and refunds
+    /// the surplus to `account_id`
+    pub(crate) fn take_storage_deposit(account_id: &AccountIdRef, deposit: NearToken) {
+        let surplus = env::attached_deposit()
+            .checked_sub(deposit)
+            .unwrap_or_else(|| DefuseError::InsufficientDeposit(deposit).panic());
+        if !surplus.is_zero() {
+            Promise::new(account_id.into()).transfer(surplus).detach();
+        }
+    }
+
     #[inline]
     pub fn ensure_auth_predecessor_id(&self) -> AccountId {
         let predecessor_account_id = env::predecessor_account_id();
@@ -0,0 +1,70 @@
+use defuse_core::NoncePrefix;
+use defuse_near_utils::NestPrefix;
+use near_sdk::{
+    AccountId, AccountIdRef, BorshStorageKey, IntoStorageKey,
+    borsh::BorshSerialize,
+    near,
+    store::{IterableSet, LookupMap},
+};
+
+/// Prefixes of nonce bitmaps of each account in order of their creation,
+/// since nonce bitmaps of accounts can't be iterated.
+///
+/// NOTE: prefixes are removed together with their bitmaps on cleanup,
+/// so the index never outgrows the bitmaps themselves.
+#[near(serializers = [borsh])]
+#[derive(Debug)]
+pub struct NoncePrefixes {
+    accounts: LookupMap<AccountId, IterableSet<NoncePrefix>>,
+    prefix: Vec<u8>,
+}
+
+impl NoncePrefixes {
+    #[inline]
+    pub fn new<S>(prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        let prefix = prefix.into_storage_key();
+        Self {
+            accounts: LookupMap::new(prefix.as_slice().nest(Prefix::Accounts)),
+            prefix,
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, account_id: &AccountIdRef) -> Option<&IterableSet<NoncePrefix>> {
+        self.accounts.get(account_id)
+    }
+
+    /// Returns whether the prefix was not indexed before
+    pub fn insert(&mut self, account_id: &AccountIdRef, prefix: NoncePrefix) -> bool {
+        self.accounts
+            .entry(account_id.to_owned())
+            .or_insert_with_key(|account_id| {
+                IterableSet::new(self.prefix.as_slice().nest(Prefix::Prefixes(account_id)))
+            })
+            .insert(prefix)
+    }
+
+    /// Returns whether the prefix was indexed
+    pub fn remove(&mut self, account_id: &AccountIdRef, prefix: &NoncePrefix) -> bool {
+        let Some(prefixes) = self.accounts.get_mut(account_id) else {
+            return false;
+        };
+        if !prefixes.remove(prefix) {
+            return false;
+        }
+        if prefixes.is_empty() {
+            self.accounts.remove(account_id);
+        }
+        true
+    }
+}
+
+#[derive(BorshSerialize, BorshStorageKey)]
+#[borsh(crate = "::near_sdk::borsh")]
+enum Prefix<'a> {
+    Accounts,
+    Prefixes(&'a AccountIdRef),
+}
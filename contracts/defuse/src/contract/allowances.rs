@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+
+use defuse_core::{
+    DefuseError, Timestamp,
+    accounts::AccountEvent,
+    allowances::{Allowance, AllowanceSetEvent},
+    events::DefuseEvent,
+    token_id::TokenId,
+};
+use near_plugins::{Pausable, pause};
+use near_sdk::{AccountId, FunctionError, Promise, assert_one_yocto, json_types::U128, near};
+
+use crate::allowances::{ALLOWANCE_STORAGE_DEPOSIT, MultiTokenAllowances};
+
+use super::{Contract, ContractExt};
+
+#[near]
+impl MultiTokenAllowances for Contract {
+    #[payable]
+    fn mt_approve(
+        &mut self,
+        spender_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        expires_at: Option<Timestamp>,
+    ) {
+        let owner_id = self.ensure_auth_predecessor_id();
+        if expires_at.is_some_and(|expires_at| expires_at <= Timestamp::now()) {
+            DefuseError::DeadlineExpired.panic();
+        }
+
+        let key = (owner_id.clone(), spender_id.clone(), token_id.clone());
+        if amount.0 != 0 && !self.allowances.contains_key(&key) {
+            Self::take_storage_deposit(&owner_id, ALLOWANCE_STORAGE_DEPOSIT);
+        } else {
+            assert_one_yocto();
+        }
+
+        if amount.0 == 0 {
+            if self.allowances.remove(&key).is_some() {
+                Self::refund_allowance_storage_deposit(owner_id.clone());
+            }
+        } else {
+            self.allowances.insert(
+                key,
+                Allowance {
+                    amount: amount.0,
+                    expires_at,
+                },
+            );
+        }
+
+        DefuseEvent::AllowanceSet(AccountEvent::new(
+            owner_id,
+            AllowanceSetEvent {
+                spender_id: Cow::Owned(spender_id),
+                token_id: Cow::Owned(token_id),
+                amount,
+                expires_at: expires_at.filter(|_| amount.0 != 0),
+            },
+        ))
+        .emit();
+    }
+
+    fn mt_allowance(&self, owner_id: AccountId, spender_id: AccountId, token_id: TokenId) -> U128 {
+        U128(
+            self.allowances
+                .get(&(owner_id, spender_id, token_id))
+                .map_or(0, |allowance| allowance.available_at(Timestamp::now())),
+        )
+    }
+
+    #[pause(name = "mt_transfer")]
+    #[payable]
+    fn mt_transfer_from(
+        &mut self,
+        owner_id: AccountId,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        memo: Option<String>,
+    ) {
+        assert_one_yocto();
+        let spender_id = self.ensure_auth_predecessor_id();
+        if self.frozen_accounts.contains(&owner_id) {
+            DefuseError::AccountFrozen(owner_id).panic();
+        }
+
+        let key = (owner_id.clone(), spender_id, token_id.clone());
+        let remaining = self
+            .allowances
+            .get(&key)
+            .map(|allowance| allowance.available_at(Timestamp::now()))
+            .and_then(|available| available.checked_sub(amount.0))
+            .unwrap_or_else(|| DefuseError::AllowanceExceeded.panic());
+        if remaining == 0 {
+            self.allowances.remove(&key);
+            Self::refund_allowance_storage_deposit(owner_id.clone());
+        } else if let Some(allowance) = self.allowances.get_mut(&key) {
+            allowance.amount = remaining;
+        }
+
+        self.internal_mt_batch_transfer(
+            &owner_id,
+            &receiver_id,
+            &[token_id.to_string()],
+            &[amount],
+            memo.as_deref(),
+            false,
+        )
+        .unwrap_or_else(|err| err.panic());
+    }
+}
+
+impl Contract {
+    /// Storage deposit was paid by the owner when approving
+    #[inline]
+    fn refund_allowance_storage_deposit(owner_id: AccountId) {
+        Promise::new(owner_id)
+            .transfer(ALLOWANCE_STORAGE_DEPOSIT)
+            .detach();
+    }
+}
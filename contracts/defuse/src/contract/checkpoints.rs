@@ -0,0 +1,48 @@
+use defuse_core::{
+    accounts::AccountEvent,
+    checkpoint::StateCheckpoint,
+    events::{DefuseEvent, DefuseIntentEmit},
+};
+use defuse_near_utils::require_envelope;
+use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
+use near_sdk::{assert_one_yocto, env, near};
+
+use crate::checkpoints::StateCheckpoints;
+
+use super::{Contract, ContractExt, Role};
+
+#[near]
+impl StateCheckpoints for Contract {
+    #[pause(name = "intents")]
+    #[access_control_any(roles(Role::DAO, Role::StateCheckpointPublisher))]
+    #[payable]
+    fn publish_state_checkpoint(&mut self, checkpoint: StateCheckpoint) {
+        assert_one_yocto();
+
+        require_envelope!(
+            u64::from(self.state_checkpoints.len()) == checkpoint.epoch,
+            "invalid_checkpoint_epoch",
+            "invalid epoch"
+        );
+        require_envelope!(
+            checkpoint.block_height <= env::block_height()
+                && self
+                    .state_checkpoint(None)
+                    .is_none_or(|last| last.block_height < checkpoint.block_height),
+            "invalid_checkpoint_block_height",
+            "invalid block height"
+        );
+        self.state_checkpoints.push(checkpoint);
+
+        DefuseEvent::StateCheckpoint(AccountEvent::new(env::predecessor_account_id(), checkpoint))
+            .emit();
+    }
+
+    fn state_checkpoint(&self, epoch: Option<u64>) -> Option<StateCheckpoint> {
+        let index = match epoch {
+            Some(epoch) => epoch.try_into().ok()?,
+            None => self.state_checkpoints.len().checked_sub(1)?,
+        };
+        self.state_checkpoints.get(index).copied()
+    }
+}
@@ -0,0 +1,94 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use defuse_core::{
+    accounts::AccountEvent,
+    events::{DefuseEvent, DefuseIntentEmit},
+    limits::{DepositCapExceededEvent, DepositCapSetEvent},
+    token_id::TokenId,
+};
+use defuse_near_utils::require_envelope;
+use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
+use near_sdk::{AccountIdRef, assert_one_yocto, json_types::U128, near};
+
+use crate::deposit_caps::DepositCapsManager;
+
+use super::{Contract, ContractExt, Role};
+
+#[near]
+impl DepositCapsManager for Contract {
+    #[pause(name = "intents")]
+    #[access_control_any(roles(Role::DAO, Role::DepositCapsManager))]
+    #[payable]
+    fn set_deposit_cap(&mut self, token_id: TokenId, cap: Option<U128>) {
+        assert_one_yocto();
+        let changed = match cap {
+            Some(cap) => self.deposit_caps.insert(token_id.clone(), cap.0) != Some(cap.0),
+            None => self.deposit_caps.remove(&token_id).is_some(),
+        };
+        require_envelope!(changed, "unchanged", "same");
+        DefuseEvent::DepositCapSet(DepositCapSetEvent {
+            token_id: Cow::Owned(token_id),
+            cap,
+        })
+        .emit();
+    }
+
+    fn deposit_cap(&self, token_id: TokenId) -> Option<U128> {
+        self.deposit_caps.get(&token_id).copied().map(U128)
+    }
+
+    fn deposit_caps(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<(TokenId, U128)> {
+        let iter = self
+            .deposit_caps
+            .iter()
+            .skip(from_index.unwrap_or_default().try_into().unwrap())
+            .map(|(token_id, cap)| (token_id.clone(), U128(*cap)));
+
+        match limit {
+            Some(l) => iter.take(l.try_into().unwrap()).collect(),
+            None => iter.collect(),
+        }
+    }
+}
+
+impl Contract {
+    /// Returns whether depositing given tokens would make total supply
+    /// of any of them exceed its cap. Emits [`DepositCapExceededEvent`]
+    /// for each such token.
+    pub(crate) fn exceeds_deposit_caps(
+        &self,
+        receiver_id: &AccountIdRef,
+        tokens: impl IntoIterator<Item = (TokenId, u128)>,
+    ) -> bool {
+        // the same token can occur multiple times in a single batch
+        let mut total_supplies = HashMap::new();
+        let mut exceeded = false;
+
+        for (token_id, amount) in tokens {
+            let Some(cap) = self.deposit_caps.get(&token_id).copied() else {
+                continue;
+            };
+
+            let total_supply = total_supplies
+                .entry(token_id.clone())
+                .or_insert_with(|| self.total_supplies.amount_for(&token_id));
+            *total_supply = total_supply.saturating_add(amount);
+            if *total_supply <= cap {
+                continue;
+            }
+
+            DefuseEvent::DepositCapExceeded(AccountEvent::new(
+                receiver_id,
+                DepositCapExceededEvent {
+                    token_id: Cow::Owned(token_id),
+                    amount: U128(amount),
+                    cap: U128(cap),
+                },
+            ))
+            .emit();
+            exceeded = true;
+        }
+
+        exceeded
+    }
+}
@@ -0,0 +1,50 @@
+use defuse_core::{
+    events::{DefuseEvent, DefuseIntentEmit},
+    payload::erc1271::{Erc1271ChainAllowedEvent, Erc1271OracleSetEvent},
+};
+use defuse_near_utils::require_envelope;
+use near_plugins::{AccessControllable, access_control_any};
+use near_sdk::{AccountId, assert_one_yocto, near};
+
+use crate::erc1271::Erc1271OracleManager;
+
+use super::{Contract, ContractExt, Role};
+
+#[near]
+impl Erc1271OracleManager for Contract {
+    #[access_control_any(roles(Role::DAO))]
+    #[payable]
+    fn set_erc1271_oracle(&mut self, oracle_id: Option<AccountId>) {
+        assert_one_yocto();
+        require_envelope!(self.erc1271_oracle != oracle_id, "unchanged", "same");
+
+        DefuseEvent::Erc1271OracleSet(Erc1271OracleSetEvent {
+            oracle_id: oracle_id.as_deref().map(Into::into),
+        })
+        .emit();
+
+        self.erc1271_oracle = oracle_id;
+    }
+
+    fn erc1271_oracle(&self) -> Option<AccountId> {
+        self.erc1271_oracle.clone()
+    }
+
+    #[access_control_any(roles(Role::DAO))]
+    #[payable]
+    fn set_erc1271_chain_allowed(&mut self, chain_id: u64, allowed: bool) {
+        assert_one_yocto();
+        let changed = if allowed {
+            self.erc1271_chains.insert(chain_id)
+        } else {
+            self.erc1271_chains.remove(&chain_id)
+        };
+        require_envelope!(changed, "unchanged", "same");
+
+        DefuseEvent::Erc1271ChainAllowed(Erc1271ChainAllowedEvent { chain_id, allowed }).emit();
+    }
+
+    fn erc1271_chains(&self) -> Vec<u64> {
+        self.erc1271_chains.iter().copied().collect()
+    }
+}
@@ -2,11 +2,17 @@ use core::mem;
 use std::borrow::Cow;
 
 use defuse_core::{
-    events::DefuseIntentEmit,
-    fees::{FeeChangedEvent, FeeCollectorChangedEvent, Pips},
+    engine::StateView,
+    events::{DefuseEvent, DefuseIntentEmit},
+    fees::{
+        FeeChangedEvent, FeeCollectorChangedEvent, FeeExemption, FeeExemptionsEvent,
+        FeeShareSetEvent, Pips, TokenFeeSetEvent,
+    },
+    token_id::TokenId,
 };
+use defuse_near_utils::require_envelope;
 use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
-use near_sdk::{AccountId, assert_one_yocto, near, require};
+use near_sdk::{AccountId, assert_one_yocto, near};
 
 use crate::fees::FeesManager;
 
@@ -19,7 +25,7 @@ impl FeesManager for Contract {
     #[payable]
     fn set_fee(&mut self, #[allow(unused_mut)] mut fee: Pips) {
         assert_one_yocto();
-        require!(self.fees.fee != fee, "same");
+        require_envelope!(self.fees.fee != fee, "unchanged", "same");
         mem::swap(&mut self.fees.fee, &mut fee);
         FeeChangedEvent {
             old_fee: fee,
@@ -37,7 +43,11 @@ impl FeesManager for Contract {
     #[payable]
     fn set_fee_collector(&mut self, #[allow(unused_mut)] mut fee_collector: AccountId) {
         assert_one_yocto();
-        require!(self.fees.fee_collector != fee_collector, "same");
+        require_envelope!(
+            self.fees.fee_collector != fee_collector,
+            "unchanged",
+            "same"
+        );
         mem::swap(&mut self.fees.fee_collector, &mut fee_collector);
         FeeCollectorChangedEvent {
             old_fee_collector: fee_collector.into(),
@@ -49,4 +59,123 @@ impl FeesManager for Contract {
     fn fee_collector(&self) -> &AccountId {
         &self.fees.fee_collector
     }
+
+    #[pause(name = "intents")]
+    #[access_control_any(roles(Role::DAO, Role::FeesManager))]
+    #[payable]
+    fn set_fee_share(&mut self, collector: AccountId, share: Option<Pips>) {
+        assert_one_yocto();
+        let old_share = if let Some(share) = share {
+            require_envelope!(!share.is_zero(), "zero_share", "zero share");
+            self.fee_shares.insert(collector.clone(), share)
+        } else {
+            self.fee_shares.remove(&collector)
+        };
+        require_envelope!(old_share != share, "unchanged", "same");
+        require_envelope!(
+            self.fee_shares
+                .values()
+                .try_fold(Pips::ZERO, |total, share| total.checked_add(*share))
+                .is_some(),
+            "fee_shares_exceeded",
+            "fee shares exceed 100%"
+        );
+        DefuseEvent::FeeShareSet(FeeShareSetEvent {
+            collector: Cow::Owned(collector),
+            share,
+        })
+        .emit();
+    }
+
+    fn fee_shares(&self) -> Vec<(AccountId, Pips)> {
+        StateView::fee_shares(self).collect()
+    }
+
+    #[pause(name = "intents")]
+    #[access_control_any(roles(Role::DAO, Role::FeesManager))]
+    #[payable]
+    fn add_fee_exemptions(&mut self, exemptions: Vec<FeeExemption>) {
+        assert_one_yocto();
+        let added: Vec<_> = exemptions
+            .into_iter()
+            .filter(|exemption| self.fee_exemptions.insert(exemption.clone()))
+            .collect();
+        require_envelope!(!added.is_empty(), "unchanged", "same");
+        DefuseEvent::FeeExemptionsAdded(FeeExemptionsEvent {
+            exemptions: added.into(),
+        })
+        .emit();
+    }
+
+    #[pause(name = "intents")]
+    #[access_control_any(roles(Role::DAO, Role::FeesManager))]
+    #[payable]
+    fn remove_fee_exemptions(&mut self, exemptions: Vec<FeeExemption>) {
+        assert_one_yocto();
+        let removed: Vec<_> = exemptions
+            .into_iter()
+            .filter(|exemption| self.fee_exemptions.remove(exemption))
+            .collect();
+        require_envelope!(!removed.is_empty(), "unchanged", "same");
+        DefuseEvent::FeeExemptionsRemoved(FeeExemptionsEvent {
+            exemptions: removed.into(),
+        })
+        .emit();
+    }
+
+    fn is_fee_exemption(&self, exemption: FeeExemption) -> bool {
+        self.fee_exemptions.contains(&exemption)
+    }
+
+    fn fee_exemptions(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<FeeExemption> {
+        let iter = self
+            .fee_exemptions
+            .iter()
+            .skip(from_index.unwrap_or_default().try_into().unwrap())
+            .cloned();
+
+        match limit {
+            Some(l) => iter.take(l.try_into().unwrap()).collect(),
+            None => iter.collect(),
+        }
+    }
+
+    #[pause(name = "intents")]
+    #[access_control_any(roles(Role::DAO, Role::FeesManager))]
+    #[payable]
+    fn set_token_fee(&mut self, token_id: TokenId, fee: Option<Pips>) {
+        assert_one_yocto();
+        let old_fee = if let Some(fee) = fee {
+            self.token_fees.insert(token_id.clone(), fee)
+        } else {
+            self.token_fees.remove(&token_id)
+        };
+        require_envelope!(old_fee != fee, "unchanged", "same");
+        DefuseEvent::TokenFeeSet(TokenFeeSetEvent {
+            token_id: Cow::Owned(token_id),
+            fee,
+        })
+        .emit();
+    }
+
+    fn token_fee(&self, token_id: TokenId) -> Pips {
+        StateView::token_fee(self, &token_id)
+    }
+
+    fn token_fee_overrides(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<(TokenId, Pips)> {
+        let iter = self
+            .token_fees
+            .iter()
+            .skip(from_index.unwrap_or_default().try_into().unwrap())
+            .map(|(token_id, fee)| (token_id.clone(), *fee));
+
+        match limit {
+            Some(l) => iter.take(l.try_into().unwrap()).collect(),
+            None => iter.collect(),
+        }
+    }
 }
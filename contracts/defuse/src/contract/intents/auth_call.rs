@@ -1,7 +1,7 @@
 use defuse_auth_call::ext_auth_callee;
 use defuse_core::intents::auth::AuthCall;
-use defuse_near_utils::promise_result_checked_void;
-use near_sdk::{AccountId, Gas, NearToken, Promise, near, require};
+use defuse_near_utils::{promise_result_checked_void, require_envelope};
+use near_sdk::{AccountId, Gas, NearToken, Promise, near};
 
 use crate::contract::{Contract, ContractExt};
 
@@ -17,8 +17,9 @@ impl Contract {
     #[private]
     pub fn do_auth_call(signer_id: AccountId, auth_call: AuthCall) -> Promise {
         if !auth_call.attached_deposit.is_zero() {
-            require!(
+            require_envelope!(
                 promise_result_checked_void(0).is_ok(),
+                "near_withdraw_failed",
                 "near_withdraw failed",
             );
         }
@@ -0,0 +1,85 @@
+use defuse_core::{
+    DefuseError, Result,
+    crypto::Payload,
+    engine::StateView,
+    payload::{erc1271::SignedErc1271Payload, multi::MultiPayload},
+};
+use defuse_near_utils::promise_result_json;
+use defuse_serde_utils::hex::AsHex;
+use near_plugins::{Pausable, pause};
+use near_sdk::{AccountId, Gas, Promise, env, near};
+
+use crate::{
+    contract::{Contract, ContractExt},
+    erc1271::ext_erc1271_oracle,
+};
+
+#[near]
+impl Contract {
+    pub(crate) const ERC1271_ORACLE_GAS: Gas = Gas::from_tgas(10);
+    pub(crate) const DO_EXECUTE_ERC1271_INTENTS_MIN_GAS: Gas = Gas::from_tgas(30);
+
+    /// Executes intents after EVM signature oracle verified all
+    /// ERC-1271 signatures among them
+    #[private]
+    #[pause(name = "intents")]
+    pub fn do_execute_erc1271_intents(&mut self, signed: Vec<MultiPayload>, relayer_id: AccountId) {
+        let verified = erc1271_payloads(&signed)
+            .zip(0..)
+            .filter(|(_, result_idx)| matches!(promise_result_json::<bool>(*result_idx), Ok(true)))
+            .map(|(payload, _)| payload.oracle_query())
+            .collect::<Vec<_>>();
+
+        self.internal_execute_intents(signed, verified, relayer_id);
+    }
+}
+
+impl Contract {
+    /// Asks EVM signature oracle to verify all ERC-1271 signatures and
+    /// holds the intents until the answers arrive
+    pub(crate) fn verify_erc1271_and_execute_intents(
+        &self,
+        signed: Vec<MultiPayload>,
+        relayer_id: AccountId,
+    ) -> Result<Promise> {
+        let oracle_id = self
+            .erc1271_oracle
+            .clone()
+            .ok_or(DefuseError::Erc1271OracleNotSet)?;
+
+        if let Some(payload) = erc1271_payloads(&signed)
+            .find(|payload| !self.is_erc1271_chain_allowed(payload.chain_id))
+        {
+            return Err(DefuseError::Erc1271ChainNotAllowed(payload.chain_id));
+        }
+
+        let verify = erc1271_payloads(&signed)
+            .map(|payload| {
+                ext_erc1271_oracle::ext(oracle_id.clone())
+                    .with_static_gas(Self::ERC1271_ORACLE_GAS)
+                    // do not distribute remaining gas here
+                    .with_unused_gas_weight(0)
+                    .erc1271_is_valid_signature(
+                        payload.chain_id,
+                        AsHex(payload.contract),
+                        AsHex(payload.hash()),
+                        AsHex(payload.signature.clone()),
+                    )
+            })
+            .reduce(Promise::and)
+            .ok_or(DefuseError::InvalidSignature)?;
+
+        Ok(verify.then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(Self::DO_EXECUTE_ERC1271_INTENTS_MIN_GAS)
+                .do_execute_erc1271_intents(signed, relayer_id),
+        ))
+    }
+}
+
+pub fn erc1271_payloads(signed: &[MultiPayload]) -> impl Iterator<Item = &SignedErc1271Payload> {
+    signed.iter().filter_map(|signed| match signed {
+        MultiPayload::Erc1271(payload) => Some(payload),
+        _ => None,
+    })
+}
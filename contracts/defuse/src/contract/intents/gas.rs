@@ -0,0 +1,172 @@
+use defuse_core::{
+    DefuseError,
+    intents::{DefuseIntents, Intent, tokens::NotifyOnTransfer},
+    payload::{DefusePayload, ExtractDefusePayload, multi::MultiPayload},
+    tokens::{MT_ON_TRANSFER_GAS_DEFAULT, MT_ON_TRANSFER_GAS_MIN},
+};
+use defuse_near_utils::ErrorCode;
+use defuse_wnear::NEAR_WITHDRAW_GAS;
+use near_sdk::Gas;
+
+use crate::{
+    contract::Contract,
+    simulation_output::{GasEstimate, PayloadGasEstimate},
+};
+
+impl Contract {
+    /// Execution of `execute_intents()` itself apart from payloads
+    const EXECUTE_INTENTS_BASE_GAS: Gas = Gas::from_tgas(5);
+    /// Verification of a single signature, chosen with curves verified
+    /// without host functions (e.g. P-256) in mind
+    const VERIFY_SIGNATURE_GAS: Gas = Gas::from_tgas(20);
+    /// Nonce, deadline and state changes made by a single intent
+    /// within the `execute_intents()` receipt
+    const EXECUTE_INTENT_GAS: Gas = Gas::from_tgas(2);
+
+    pub(crate) fn estimate_signed_intents_gas(signed: Vec<MultiPayload>) -> GasEstimate {
+        let mut total = Self::EXECUTE_INTENTS_BASE_GAS;
+        let mut erc1271 = 0;
+
+        let intents = signed
+            .into_iter()
+            .map(|signed| {
+                match &signed {
+                    // rejected during execution without failing the batch
+                    MultiPayload::Unsupported(payload) => {
+                        return PayloadGasEstimate::Err {
+                            error: DefuseError::UnsupportedPayload(payload.standard.clone())
+                                .to_envelope(),
+                        };
+                    }
+                    MultiPayload::Erc1271(_) => erc1271 += 1,
+                    MultiPayload::Multisig(multisig) => {
+                        total = total.saturating_add(
+                            Self::VERIFY_SIGNATURE_GAS
+                                .saturating_mul(multisig.signatures.len().try_into().unwrap()),
+                        );
+                    }
+                    _ => total = total.saturating_add(Self::VERIFY_SIGNATURE_GAS),
+                }
+
+                let DefusePayload::<DefuseIntents> {
+                    message: intents, ..
+                } = match signed.extract_defuse_payload() {
+                    Ok(payload) => payload,
+                    Err(err) => {
+                        return PayloadGasEstimate::Err {
+                            error: DefuseError::from(err).to_envelope(),
+                        };
+                    }
+                };
+
+                if !intents.relayer_fee.is_empty() {
+                    // relayer fee is paid with an implicit transfer
+                    total = total.saturating_add(Self::EXECUTE_INTENT_GAS);
+                }
+
+                PayloadGasEstimate::Ok {
+                    intents: intents
+                        .intents
+                        .iter()
+                        .map(Self::estimate_intent_gas)
+                        .inspect(|gas| total = total.saturating_add(*gas))
+                        .collect(),
+                }
+            })
+            .collect();
+
+        if erc1271 > 0 {
+            // intents are held until EVM signature oracle answers
+            total = total
+                .saturating_add(Self::ERC1271_ORACLE_GAS.saturating_mul(erc1271))
+                .saturating_add(Self::DO_EXECUTE_ERC1271_INTENTS_MIN_GAS);
+        }
+
+        GasEstimate { intents, total }
+    }
+
+    /// Returns gas of the intent itself along with static gas of
+    /// all receipts it schedules, including callbacks
+    fn estimate_intent_gas(intent: &Intent) -> Gas {
+        let receipts = match intent {
+            Intent::Transfer(transfer) => transfer
+                .notification
+                .as_ref()
+                .map_or(Gas::from_gas(0), |notification| {
+                    Self::notification_gas(notification, transfer.tokens.len())
+                }),
+            Intent::FtWithdraw(withdraw) => withdraw
+                .storage_deposit
+                .map_or(Gas::from_gas(0), |_| {
+                    NEAR_WITHDRAW_GAS.saturating_add(Self::DO_FT_WITHDRAW_GAS)
+                })
+                .saturating_add(withdraw.min_gas())
+                .saturating_add(Self::FT_RESOLVE_WITHDRAW_GAS),
+            Intent::NftWithdraw(withdraw) => withdraw
+                .storage_deposit
+                .map_or(Gas::from_gas(0), |_| {
+                    NEAR_WITHDRAW_GAS.saturating_add(Self::DO_NFT_WITHDRAW_GAS)
+                })
+                .saturating_add(withdraw.min_gas())
+                .saturating_add(Self::NFT_RESOLVE_WITHDRAW_GAS),
+            Intent::MtWithdraw(withdraw) => withdraw
+                .storage_deposit
+                .map_or(Gas::from_gas(0), |_| {
+                    NEAR_WITHDRAW_GAS.saturating_add(Self::DO_MT_WITHDRAW_GAS)
+                })
+                .saturating_add(withdraw.min_gas())
+                .saturating_add(Self::mt_resolve_withdraw_gas(withdraw.token_ids.len())),
+            Intent::NativeWithdraw(_) => {
+                NEAR_WITHDRAW_GAS.saturating_add(Self::DO_NATIVE_WITHDRAW_GAS)
+            }
+            Intent::StorageDeposit(_) => {
+                NEAR_WITHDRAW_GAS.saturating_add(Self::DO_STORAGE_DEPOSIT_GAS)
+            }
+            Intent::AuthCall(auth_call) => if auth_call.attached_deposit.is_zero() {
+                // do_auth_call() is called directly
+                Gas::from_gas(0)
+            } else {
+                NEAR_WITHDRAW_GAS
+            }
+            // same as `auth_call_callback_gas()`, but saturating: `min_gas`
+            // that big can't be attached anyway, so execution would fail
+            .saturating_add(Self::DO_AUTH_CALL_MIN_GAS)
+            .saturating_add(
+                auth_call
+                    .state_init
+                    .as_ref()
+                    .map_or(Gas::from_gas(0), |_| Self::STATE_INIT_GAS),
+            )
+            .saturating_add(auth_call.min_gas()),
+            #[cfg(feature = "imt")]
+            Intent::ImtMint(mint) => mint
+                .notification
+                .as_ref()
+                .map_or(Gas::from_gas(0), |notification| {
+                    Self::notification_gas(notification, mint.tokens.len())
+                }),
+            // proof of possession is verified within the same receipt
+            Intent::AddBls12381PublicKey(_) => Self::VERIFY_SIGNATURE_GAS,
+            Intent::AddPublicKey(_)
+            | Intent::RemovePublicKey(_)
+            | Intent::TokenDiff(_)
+            | Intent::SetAuthByPredecessorId(_)
+            | Intent::InvalidateNonces(_)
+            | Intent::FreezeAccount(_) => Gas::from_gas(0),
+            #[cfg(feature = "imt")]
+            Intent::ImtBurn(_) => Gas::from_gas(0),
+        };
+
+        Self::EXECUTE_INTENT_GAS.saturating_add(receipts)
+    }
+
+    /// `mt_on_transfer()` with `min_gas` adjusted the same way as
+    /// during execution, followed by `mt_resolve_transfer()`
+    fn notification_gas(notification: &NotifyOnTransfer, token_count: usize) -> Gas {
+        notification
+            .min_gas
+            .unwrap_or(MT_ON_TRANSFER_GAS_DEFAULT)
+            .max(MT_ON_TRANSFER_GAS_MIN)
+            .saturating_add(Self::mt_resolve_gas(token_count))
+    }
+}
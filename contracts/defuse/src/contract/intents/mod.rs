@@ -1,5 +1,7 @@
 mod auth_call;
+mod erc1271;
 mod execute;
+mod gas;
 mod relayer;
 pub mod simulate;
 mod state;
@@ -7,16 +9,17 @@ mod state;
 use defuse_core::{
     DefuseError,
     engine::{Engine, StateView},
-    payload::multi::MultiPayload,
+    payload::{erc1271::SignedErc1271Payload, multi::MultiPayload},
 };
+use erc1271::erc1271_payloads;
 use execute::ExecuteInspector;
 use near_plugins::{Pausable, pause};
-use near_sdk::{FunctionError, near};
+use near_sdk::{AccountId, CryptoHash, FunctionError, env, near};
 use simulate::SimulateInspector;
 
 use crate::{
     intents::Intents,
-    simulation_output::{SimulationOutput, StateOutput},
+    simulation_output::{GasEstimate, SimulationOutput, StateOutput},
 };
 
 use super::{Contract, ContractExt};
@@ -25,26 +28,31 @@ use super::{Contract, ContractExt};
 impl Intents for Contract {
     #[pause(name = "intents")]
     fn execute_intents(&mut self, signed: Vec<MultiPayload>) {
-        if let Some(event) = Engine::new(self, ExecuteInspector::default())
-            .execute_signed_intents(signed)
-            .unwrap_or_else(|e| e.panic())
-            .as_mt_event()
-        {
-            // NOTE: Not all `mt_transfer` events are refundable, but it's safe to check them
-            // all at once since non-refundable transfers only increase the potential refund
-            // log size without affecting correctness. This can actually prevent resolve transfer
-            // from failing due to too long event log !!!
-            event
-                .check_refund()
-                .unwrap_or_else(|err| err.panic())
-                .emit();
+        let relayer_id = env::predecessor_account_id();
+        if erc1271_payloads(&signed).next().is_some() {
+            self.verify_erc1271_and_execute_intents(signed, relayer_id)
+                .unwrap_or_else(|e| e.panic())
+                .detach();
+            return;
         }
+
+        self.internal_execute_intents(signed, [], relayer_id);
     }
 
     #[pause(name = "intents")]
-    fn simulate_intents(&self, signed: Vec<MultiPayload>) -> SimulationOutput {
+    fn simulate_intents(
+        &self,
+        signed: Vec<MultiPayload>,
+        relayer_id: Option<AccountId>,
+    ) -> SimulationOutput {
         let mut inspector = SimulateInspector::default();
-        let engine = Engine::new(self.cached(), &mut inspector);
+        // ERC-1271 signatures can't be verified in view calls,
+        // so they are assumed to be valid
+        let engine = Engine::new(self.cached(), &mut inspector)
+            .with_erc1271_verified(
+                erc1271_payloads(&signed).map(SignedErc1271Payload::oracle_query),
+            )
+            .with_relayer_id(relayer_id);
 
         let invariant_violated = match engine.execute_signed_intents(signed) {
             // do not log transfers
@@ -62,4 +70,34 @@ impl Intents for Contract {
             },
         }
     }
+
+    fn estimate_intents_gas(&self, signed: Vec<MultiPayload>) -> GasEstimate {
+        Self::estimate_signed_intents_gas(signed)
+    }
+}
+
+impl Contract {
+    pub(crate) fn internal_execute_intents(
+        &mut self,
+        signed: Vec<MultiPayload>,
+        erc1271_verified: impl IntoIterator<Item = (u64, [u8; 20], CryptoHash)>,
+        relayer_id: AccountId,
+    ) {
+        if let Some(event) = Engine::new(self, ExecuteInspector::default())
+            .with_erc1271_verified(erc1271_verified)
+            .with_relayer_id(relayer_id)
+            .execute_signed_intents(signed)
+            .unwrap_or_else(|e| e.panic())
+            .as_mt_event()
+        {
+            // NOTE: Not all `mt_transfer` events are refundable, but it's safe to check them
+            // all at once since non-refundable transfers only increase the potential refund
+            // log size without affecting correctness. This can actually prevent resolve transfer
+            // from failing due to too long event log !!!
+            event
+                .check_refund()
+                .unwrap_or_else(|err| err.panic())
+                .emit();
+        }
+    }
 }
@@ -1,6 +1,6 @@
-use defuse_near_utils::method_name;
+use defuse_near_utils::{method_name, require_envelope};
 use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
-use near_sdk::{Allowance, Promise, PublicKey, assert_one_yocto, env, near, require};
+use near_sdk::{Allowance, Promise, PublicKey, assert_one_yocto, env, near};
 
 use crate::{
     contract::{Contract, ContractExt, Role},
@@ -28,7 +28,11 @@ impl RelayerKeys for Contract {
 
     #[private]
     fn do_add_relayer_key(&mut self, public_key: PublicKey) {
-        require!(self.relayer_keys.insert(public_key), "key already exists",);
+        require_envelope!(
+            self.relayer_keys.insert(public_key),
+            "relayer_key_exists",
+            "key already exists",
+        );
     }
 
     #[pause(name = "intents")]
@@ -36,7 +40,11 @@ impl RelayerKeys for Contract {
     #[payable]
     fn delete_relayer_key(&mut self, public_key: PublicKey) -> Promise {
         assert_one_yocto();
-        require!(self.relayer_keys.remove(&public_key), "key not found");
+        require_envelope!(
+            self.relayer_keys.remove(&public_key),
+            "relayer_key_not_found",
+            "key not found"
+        );
 
         Promise::new(env::current_account_id()).delete_key(public_key)
     }
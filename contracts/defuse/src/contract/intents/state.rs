@@ -1,8 +1,9 @@
+use core::num::NonZeroU16;
 use defuse_core::{
-    DefuseError, Nonce, NoncePrefix, PublicKey, Result, Salt,
+    DefuseError, Nonce, NoncePrefix, PublicKey, Result, Salt, Timestamp,
     amounts::Amounts,
     engine::{State, StateView},
-    fees::Pips,
+    fees::{FeeExemption, Pips},
     intents::{
         auth::AuthCall,
         tokens::{
@@ -13,10 +14,16 @@ use defuse_core::{
 };
 use defuse_near_utils::Lock;
 use defuse_wnear::{NEAR_WITHDRAW_GAS, ext_wnear};
-use near_sdk::{AccountId, AccountIdRef, Gas, NearToken, PromiseOrValue, env, json_types::U128};
+use near_sdk::{
+    AccountId, AccountIdRef, CryptoHash, Gas, NearToken, Promise, PromiseOrValue, env,
+    json_types::U128,
+};
 use std::borrow::Cow;
 
-use crate::contract::{Contract, accounts::Account};
+use crate::{
+    accounts::EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT,
+    contract::{Contract, accounts::Account},
+};
 
 impl StateView for Contract {
     #[inline]
@@ -39,6 +46,30 @@ impl StateView for Contract {
         Cow::Borrowed(self.state.fees.fee_collector.as_ref())
     }
 
+    #[inline]
+    fn fee_shares(&self) -> impl Iterator<Item = (AccountId, Pips)> + '_ {
+        self.state
+            .fee_shares
+            .iter()
+            .map(|(collector, share)| (collector.clone(), *share))
+    }
+
+    #[inline]
+    fn is_fee_exempt(&self, signer_id: &AccountIdRef, token_id: &TokenId) -> bool {
+        self.state
+            .fee_exemptions
+            .contains(&FeeExemption::Account(signer_id.to_owned()))
+            || self
+                .state
+                .fee_exemptions
+                .contains(&FeeExemption::Token(token_id.clone()))
+    }
+
+    #[inline]
+    fn token_fee_override(&self, token_id: &TokenId) -> Option<Pips> {
+        self.state.token_fees.get(token_id).copied()
+    }
+
     #[inline]
     fn has_public_key(&self, account_id: &AccountIdRef, public_key: &PublicKey) -> bool {
         self.accounts
@@ -71,6 +102,12 @@ impl StateView for Contract {
             .is_some_and(|account| account.is_nonce_used(nonce))
     }
 
+    #[inline]
+    fn is_intent_cancelled(&self, account_id: &AccountIdRef, intent_hash: CryptoHash) -> bool {
+        self.cancelled_intents
+            .contains(&(account_id.to_owned(), intent_hash))
+    }
+
     #[inline]
     fn balance_of(&self, account_id: &AccountIdRef, token_id: &TokenId) -> u128 {
         self.accounts
@@ -85,6 +122,16 @@ impl StateView for Contract {
         self.accounts.get(account_id).is_some_and(Lock::is_locked)
     }
 
+    #[inline]
+    fn is_account_frozen(&self, account_id: &AccountIdRef) -> bool {
+        self.frozen_accounts.contains(account_id)
+    }
+
+    #[inline]
+    fn is_token_denied(&self, token_id: &TokenId) -> bool {
+        self.denied_tokens.contains(token_id)
+    }
+
     #[inline]
     fn is_auth_by_predecessor_id_enabled(&self, account_id: &AccountIdRef) -> bool {
         self.accounts
@@ -95,6 +142,41 @@ impl StateView for Contract {
 
     fn is_valid_salt(&self, salt: Salt) -> bool {
         self.salts.is_valid(salt)
+            && self
+                .salt_rotation
+                .is_in_grace_period(salt, Timestamp::now())
+    }
+
+    fn is_webauthn_origin_allowed(&self, account_id: &AccountIdRef, origin: &str) -> bool {
+        self.webauthn_allowed_origins
+            .get(account_id)
+            .is_none_or(|allowed| allowed.iter().any(|allowed| allowed == origin))
+    }
+
+    #[inline]
+    fn multisig_threshold(&self, account_id: &AccountIdRef) -> NonZeroU16 {
+        self.multisig_thresholds
+            .get(account_id)
+            .copied()
+            .unwrap_or(NonZeroU16::MIN)
+    }
+
+    #[inline]
+    fn is_erc1271_chain_allowed(&self, chain_id: u64) -> bool {
+        self.erc1271_chains.contains(&chain_id)
+    }
+
+    #[inline]
+    fn public_key_expires_at(
+        &self,
+        account_id: &AccountIdRef,
+        public_key: &PublicKey,
+    ) -> Option<Timestamp> {
+        self.state
+            .public_key_expirations
+            .get(account_id)?
+            .get(public_key)
+            .map(|expiration| expiration.expires_at)
     }
 }
 
@@ -105,12 +187,11 @@ impl State for Contract {
             .get_or_create(account_id.clone())
             .get_mut()
             .ok_or_else(|| DefuseError::AccountLocked(account_id.clone()))?
-            .add_public_key(&account_id, public_key)
+            .add_public_key(&account_id, &public_key)
             .then_some(())
-            .ok_or(DefuseError::PublicKeyExists(account_id, public_key))
+            .ok_or_else(|| DefuseError::PublicKeyExists(account_id, Box::new(public_key)))
     }
 
-    #[inline]
     fn remove_public_key(&mut self, account_id: AccountId, public_key: PublicKey) -> Result<()> {
         self.accounts
             .get_or_create(account_id.clone())
@@ -118,7 +199,25 @@ impl State for Contract {
             .ok_or_else(|| DefuseError::AccountLocked(account_id.clone()))?
             .remove_public_key(&account_id, &public_key)
             .then_some(())
-            .ok_or(DefuseError::PublicKeyNotExist(account_id, public_key))
+            .ok_or_else(|| {
+                DefuseError::PublicKeyNotExist(account_id.clone(), Box::new(public_key))
+            })?;
+
+        // expiration is dropped along with the key, so that re-added
+        // key doesn't inherit it
+        if let Some(expirations) = self.state.public_key_expirations.get_mut(&account_id) {
+            let had_expiration = expirations.remove(&public_key).is_some();
+            if expirations.is_empty() {
+                self.state.public_key_expirations.remove(&account_id);
+            }
+            if had_expiration {
+                // storage deposit was paid by the account when adding the key
+                Promise::new(account_id)
+                    .transfer(EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT)
+                    .detach();
+            }
+        }
+        Ok(())
     }
 
     #[inline]
@@ -126,8 +225,11 @@ impl State for Contract {
         self.accounts
             .get_or_create(account_id.clone())
             .get_mut()
-            .ok_or(DefuseError::AccountLocked(account_id))?
-            .commit_nonce(nonce)
+            .ok_or_else(|| DefuseError::AccountLocked(account_id.clone()))?
+            .commit_nonce(nonce)?;
+        let [prefix @ .., _] = nonce;
+        self.nonce_prefixes.insert(&account_id, prefix);
+        Ok(())
     }
 
     #[inline]
@@ -142,7 +244,11 @@ impl State for Contract {
             .ok_or_else(|| DefuseError::AccountNotFound(account_id.to_owned()))?
             .as_inner_unchecked_mut();
 
-        Ok(account.cleanup_nonce_by_prefix(prefix))
+        let cleaned = account.cleanup_nonce_by_prefix(prefix);
+        if cleaned {
+            self.nonce_prefixes.remove(account_id, &prefix);
+        }
+        Ok(cleaned)
     }
 
     fn internal_add_balance(
@@ -301,6 +407,11 @@ impl State for Contract {
         self.internal_set_auth_by_predecessor_id(&account_id, enable, false)
     }
 
+    #[inline]
+    fn freeze_account(&mut self, account_id: AccountId) -> Result<bool> {
+        Ok(self.frozen_accounts.insert(account_id))
+    }
+
     fn auth_call(&mut self, signer_id: &AccountIdRef, auth_call: AuthCall) -> Result<()> {
         if auth_call.attached_deposit.is_zero() {
             Self::do_auth_call(signer_id.to_owned(), auth_call)
@@ -339,7 +450,7 @@ impl State for Contract {
 
     #[inline]
     fn mint(&mut self, owner_id: AccountId, tokens: Amounts, memo: Option<String>) -> Result<()> {
-        self.deposit(owner_id, tokens, memo.as_deref())
+        self.deposit(owner_id, tokens, memo.as_deref(), false)
     }
 
     #[inline]
@@ -349,7 +460,7 @@ impl State for Contract {
         tokens: Amounts,
         memo: Option<String>,
     ) -> Result<()> {
-        self.withdraw(owner_id, tokens, memo, false)
+        self.withdraw(owner_id, tokens, memo, false).map(drop)
     }
 }
 
@@ -2,25 +2,33 @@
 mod abi;
 mod accounts;
 mod admin;
+mod allowances;
+#[cfg(feature = "imt")]
+mod checkpoints;
 pub mod config;
+mod deposit_caps;
+mod erc1271;
 mod events;
 mod fees;
 mod garbage_collector;
 mod intents;
 mod salts;
 mod state;
+mod token_denylist;
 mod tokens;
 mod upgrade;
 mod versioned;
+mod withdrawal_limits;
 
 use core::iter;
 
 use defuse_borsh_utils::As;
 use defuse_core::Result;
+use defuse_near_utils::require_envelope;
 use impl_tools::autoimpl;
 use near_plugins::{AccessControlRole, AccessControllable, Pausable, access_control};
 use near_sdk::{
-    BorshStorageKey, IntoStorageKey, PanicOnDefault, borsh::BorshDeserialize, near, require,
+    BorshStorageKey, IntoStorageKey, PanicOnDefault, borsh::BorshDeserialize, near,
     store::LookupSet,
 };
 use versioned::MaybeVersionedContractStorage;
@@ -55,6 +63,16 @@ pub enum Role {
     GarbageCollector,
 
     UnrestrictedAccountManager,
+
+    WithdrawalLimitsManager,
+
+    DepositCapsManager,
+
+    TokenDenylistManager,
+
+    StateCheckpointPublisher,
+
+    WithdrawalLimitsExempt,
 }
 
 #[access_control(role_type(Role))]
@@ -120,7 +138,7 @@ impl Contract {
 
     fn init_acl(&mut self, roles: RolesConfig) {
         let mut acl = self.acl_get_or_init();
-        require!(
+        require_envelope!(
             roles
                 .super_admins
                 .into_iter()
@@ -135,6 +153,7 @@ impl Contract {
                     .into_iter()
                     .flat_map(|(role, grantees)| iter::repeat(role).zip(grantees))
                     .all(|(role, grantee)| acl.grant_role_unchecked(role, &grantee)),
+            "invalid_roles",
             "failed to set roles"
         );
     }
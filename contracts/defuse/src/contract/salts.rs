@@ -1,13 +1,66 @@
 use std::collections::BTreeSet;
 
-use defuse_core::{Salt, accounts::SaltRotationEvent, events::DefuseIntentEmit};
+use defuse_core::{
+    Salt, SaltRotatedAt, SaltRotationPolicy, Timestamp,
+    accounts::SaltRotationEvent,
+    engine::StateView,
+    events::{DefuseEvent, DefuseIntentEmit},
+};
 
+use defuse_near_utils::require_envelope;
 use near_plugins::{AccessControllable, access_control_any};
-use near_sdk::{FunctionError, assert_one_yocto, near};
+use near_sdk::{FunctionError, IntoStorageKey, assert_one_yocto, near, store::LookupMap};
 
 use super::{Contract, ContractExt, Role};
 use crate::salts::SaltManager;
 
+/// Rotation policy of the current salt along with times when
+/// previous salts stopped being current
+#[near(serializers = [borsh])]
+#[derive(Debug)]
+pub struct SaltRotation {
+    pub policy: SaltRotationPolicy,
+    last_rotated_at: Option<SaltRotatedAt>,
+    rotated_at: LookupMap<Salt, SaltRotatedAt>,
+}
+
+impl SaltRotation {
+    #[inline]
+    pub fn new<S>(prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self {
+            policy: SaltRotationPolicy::default(),
+            last_rotated_at: None,
+            rotated_at: LookupMap::new(prefix),
+        }
+    }
+
+    #[inline]
+    pub fn can_rotate_at(&self, now: Timestamp) -> bool {
+        self.last_rotated_at
+            .is_none_or(|SaltRotatedAt(at)| self.policy.can_rotate_at(at, now))
+    }
+
+    /// Records that `previous` salt stopped being current at `now`
+    #[inline]
+    pub fn record(&mut self, previous: Salt, now: Timestamp) {
+        self.rotated_at.insert(previous, SaltRotatedAt(now));
+        self.last_rotated_at = Some(SaltRotatedAt(now));
+    }
+
+    /// Returns whether given salt is either current or still within
+    /// the grace period. Salts rotated before rotations were recorded
+    /// are never expired.
+    #[inline]
+    pub fn is_in_grace_period(&self, salt: Salt, now: Timestamp) -> bool {
+        self.rotated_at
+            .get(&salt)
+            .is_none_or(|SaltRotatedAt(at)| self.policy.is_in_grace_period(*at, now))
+    }
+}
+
 #[near]
 impl SaltManager for Contract {
     #[access_control_any(roles(Role::DAO, Role::SaltManager))]
@@ -15,7 +68,15 @@ impl SaltManager for Contract {
     fn update_current_salt(&mut self) -> Salt {
         assert_one_yocto();
 
-        self.salts.set_new().unwrap_or_else(|err| err.panic());
+        let now = Timestamp::now();
+        require_envelope!(
+            self.salt_rotation.can_rotate_at(now),
+            "salt_rotated_too_recently",
+            "salt was rotated too recently"
+        );
+
+        let previous = self.salts.set_new().unwrap_or_else(|err| err.panic());
+        self.salt_rotation.record(previous, now);
         let current = self.salts.current();
 
         SaltRotationEvent {
@@ -50,10 +111,24 @@ impl SaltManager for Contract {
     }
 
     fn is_valid_salt(&self, salt: Salt) -> bool {
-        self.salts.is_valid(salt)
+        StateView::is_valid_salt(self, salt)
     }
 
     fn current_salt(&self) -> Salt {
         self.salts.current()
     }
+
+    #[access_control_any(roles(Role::DAO, Role::SaltManager))]
+    #[payable]
+    fn set_salt_rotation_policy(&mut self, policy: SaltRotationPolicy) {
+        assert_one_yocto();
+        require_envelope!(self.salt_rotation.policy != policy, "unchanged", "same");
+
+        self.salt_rotation.policy = policy;
+        DefuseEvent::SaltRotationPolicySet(policy).emit();
+    }
+
+    fn salt_rotation_policy(&self) -> SaltRotationPolicy {
+        self.salt_rotation.policy
+    }
 }
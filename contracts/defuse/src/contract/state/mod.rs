@@ -1,11 +1,32 @@
 mod v0;
+mod v1;
 
 pub use v0::ContractStateV0;
+pub use v1::ContractStateV1;
 
-use defuse_core::{SaltRegistry, amounts::Amounts, fees::FeesConfig, token_id::TokenId};
+use core::num::NonZeroU16;
+use std::collections::BTreeMap;
+
+use defuse_core::{
+    PublicKey, SaltRegistry,
+    accounts::PublicKeyExpiration,
+    allowances::Allowance,
+    amounts::Amounts,
+    checkpoint::StateCheckpoint,
+    fees::{FeeExemption, FeesConfig, Pips},
+    token_id::TokenId,
+};
 use defuse_near_utils::NestPrefix;
+use defuse_nep245::metadata::MTTokenMetadataAll;
 use near_sdk::{
-    AccountId, BorshStorageKey, IntoStorageKey, borsh::BorshSerialize, near, store::IterableMap,
+    AccountId, BorshStorageKey, CryptoHash, IntoStorageKey,
+    borsh::BorshSerialize,
+    near,
+    store::{IterableMap, IterableSet, LookupMap, LookupSet, Vector},
+};
+
+use crate::contract::{
+    accounts::NoncePrefixes, salts::SaltRotation, withdrawal_limits::WithdrawalLimits,
 };
 
 pub type TokenBalances = Amounts<IterableMap<TokenId, u128>>;
@@ -20,6 +41,70 @@ pub struct ContractState {
     pub fees: FeesConfig,
 
     pub salts: SaltRegistry,
+
+    pub fee_exemptions: IterableSet<FeeExemption>,
+
+    pub withdrawal_limits: WithdrawalLimits,
+
+    /// Max total supply per token, deposits exceeding it are refunded
+    pub deposit_caps: IterableMap<TokenId, u128>,
+
+    /// Published state checkpoints indexed by epoch
+    pub state_checkpoints: Vector<StateCheckpoint>,
+
+    /// Origins of `WebAuthn` assertions accepted for the account,
+    /// while accounts without an entry accept any origin
+    pub webauthn_allowed_origins: LookupMap<AccountId, Vec<String>>,
+
+    /// Minimum number of distinct public keys required to sign intents
+    /// on behalf of the account, while accounts without an entry
+    /// require a single one
+    pub multisig_thresholds: LookupMap<AccountId, NonZeroU16>,
+
+    /// EVM signature oracle used to verify ERC-1271 signatures
+    /// of smart contract accounts
+    pub erc1271_oracle: Option<AccountId>,
+
+    /// EVM chains on which ERC-1271 signatures of smart contract
+    /// accounts are accepted
+    pub erc1271_chains: IterableSet<u64>,
+
+    /// Time after which public keys can no longer be used by
+    /// the account, while keys without an entry never expire
+    pub public_key_expirations: LookupMap<AccountId, BTreeMap<PublicKey, PublicKeyExpiration>>,
+
+    /// Fees overriding the global one for specific tokens
+    pub token_fees: IterableMap<TokenId, Pips>,
+
+    /// Accounts frozen by their owners, which can neither execute
+    /// intents nor withdraw until unfrozen
+    pub frozen_accounts: LookupSet<AccountId>,
+
+    /// Hashes of intents cancelled by their signers, so that these
+    /// can never be executed regardless of their nonces
+    pub cancelled_intents: LookupSet<(AccountId, CryptoHash)>,
+
+    /// Prefixes of nonce bitmaps of each account, see [`NoncePrefixes`]
+    pub nonce_prefixes: NoncePrefixes,
+
+    /// Amounts of tokens that spenders are allowed to transfer
+    /// on behalf of owners, keyed by `(owner_id, spender_id, token_id)`
+    pub allowances: LookupMap<(AccountId, AccountId, TokenId), Allowance>,
+
+    /// Metadata of tokens cached from their underlying contracts
+    pub mt_metadata: LookupMap<TokenId, MTTokenMetadataAll>,
+
+    /// Rotation policy of the current salt along with times
+    /// when previous salts stopped being current
+    pub salt_rotation: SaltRotation,
+
+    /// Tokens denylisted by the DAO, which can neither be deposited
+    /// nor transferred, but only withdrawn
+    pub denied_tokens: IterableSet<TokenId>,
+
+    /// Shares of collected fees accrued to additional collectors,
+    /// while the rest goes to the fee collector
+    pub fee_shares: IterableMap<AccountId, Pips>,
 }
 
 impl ContractState {
@@ -37,6 +122,30 @@ impl ContractState {
             wnear_id,
             fees,
             salts: SaltRegistry::new(prefix.as_slice().nest(Prefix::Salts)),
+            fee_exemptions: IterableSet::new(prefix.as_slice().nest(Prefix::FeeExemptions)),
+            withdrawal_limits: WithdrawalLimits::new(
+                prefix.as_slice().nest(Prefix::WithdrawalLimits),
+            ),
+            deposit_caps: IterableMap::new(prefix.as_slice().nest(Prefix::DepositCaps)),
+            state_checkpoints: Vector::new(prefix.as_slice().nest(Prefix::StateCheckpoints)),
+            webauthn_allowed_origins: LookupMap::new(
+                prefix.as_slice().nest(Prefix::WebAuthnAllowedOrigins),
+            ),
+            multisig_thresholds: LookupMap::new(prefix.as_slice().nest(Prefix::MultisigThresholds)),
+            erc1271_oracle: None,
+            erc1271_chains: IterableSet::new(prefix.as_slice().nest(Prefix::Erc1271Chains)),
+            public_key_expirations: LookupMap::new(
+                prefix.as_slice().nest(Prefix::PublicKeyExpirations),
+            ),
+            token_fees: IterableMap::new(prefix.as_slice().nest(Prefix::TokenFees)),
+            frozen_accounts: LookupSet::new(prefix.as_slice().nest(Prefix::FrozenAccounts)),
+            cancelled_intents: LookupSet::new(prefix.as_slice().nest(Prefix::CancelledIntents)),
+            nonce_prefixes: NoncePrefixes::new(prefix.as_slice().nest(Prefix::NoncePrefixes)),
+            allowances: LookupMap::new(prefix.as_slice().nest(Prefix::Allowances)),
+            mt_metadata: LookupMap::new(prefix.as_slice().nest(Prefix::MtMetadata)),
+            salt_rotation: SaltRotation::new(prefix.as_slice().nest(Prefix::SaltRotation)),
+            denied_tokens: IterableSet::new(prefix.as_slice().nest(Prefix::DeniedTokens)),
+            fee_shares: IterableMap::new(prefix.as_slice().nest(Prefix::FeeShares)),
         }
     }
 }
@@ -46,4 +155,21 @@ impl ContractState {
 enum Prefix {
     TotalSupplies,
     Salts,
+    FeeExemptions,
+    WithdrawalLimits,
+    DepositCaps,
+    StateCheckpoints,
+    WebAuthnAllowedOrigins,
+    PublicKeyExpirations,
+    TokenFees,
+    FrozenAccounts,
+    CancelledIntents,
+    Allowances,
+    MtMetadata,
+    SaltRotation,
+    DeniedTokens,
+    FeeShares,
+    Erc1271Chains,
+    MultisigThresholds,
+    NoncePrefixes,
 }
@@ -4,7 +4,7 @@ use near_sdk::{AccountId, IntoStorageKey, near};
 
 use crate::contract::{
     MigrateStorageWithPrefix,
-    state::{ContractState, Prefix, TokenBalances},
+    state::{ContractState, ContractStateV1, Prefix, TokenBalances},
 };
 
 #[near(serializers = [borsh])]
@@ -29,12 +29,16 @@ impl MigrateStorageWithPrefix<ContractStateV0> for ContractState {
     where
         S: IntoStorageKey,
     {
-        Self {
-            total_supplies,
-            wnear_id,
-            fees,
-            salts: SaltRegistry::new(prefix.into_storage_key().nest(Prefix::Salts)),
-        }
+        let prefix = prefix.into_storage_key();
+        Self::migrate(
+            ContractStateV1 {
+                total_supplies,
+                wnear_id,
+                fees,
+                salts: SaltRegistry::new(prefix.as_slice().nest(Prefix::Salts)),
+            },
+            prefix,
+        )
     }
 }
 
@@ -0,0 +1,73 @@
+use defuse_core::{SaltRegistry, fees::FeesConfig};
+use defuse_near_utils::NestPrefix;
+use near_sdk::{
+    AccountId, IntoStorageKey, near,
+    store::{IterableMap, IterableSet, LookupMap, LookupSet, Vector},
+};
+
+use crate::contract::{
+    MigrateStorageWithPrefix,
+    accounts::NoncePrefixes,
+    salts::SaltRotation,
+    state::{ContractState, Prefix, TokenBalances},
+    withdrawal_limits::WithdrawalLimits,
+};
+
+#[near(serializers = [borsh])]
+#[derive(Debug)]
+pub struct ContractStateV1 {
+    pub total_supplies: TokenBalances,
+
+    pub wnear_id: AccountId,
+
+    pub fees: FeesConfig,
+
+    pub salts: SaltRegistry,
+}
+
+impl MigrateStorageWithPrefix<ContractStateV1> for ContractState {
+    fn migrate<S>(
+        ContractStateV1 {
+            total_supplies,
+            wnear_id,
+            fees,
+            salts,
+        }: ContractStateV1,
+        prefix: S,
+    ) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        let prefix = prefix.into_storage_key();
+        Self {
+            total_supplies,
+            wnear_id,
+            fees,
+            salts,
+            fee_exemptions: IterableSet::new(prefix.as_slice().nest(Prefix::FeeExemptions)),
+            withdrawal_limits: WithdrawalLimits::new(
+                prefix.as_slice().nest(Prefix::WithdrawalLimits),
+            ),
+            deposit_caps: IterableMap::new(prefix.as_slice().nest(Prefix::DepositCaps)),
+            state_checkpoints: Vector::new(prefix.as_slice().nest(Prefix::StateCheckpoints)),
+            webauthn_allowed_origins: LookupMap::new(
+                prefix.as_slice().nest(Prefix::WebAuthnAllowedOrigins),
+            ),
+            multisig_thresholds: LookupMap::new(prefix.as_slice().nest(Prefix::MultisigThresholds)),
+            erc1271_oracle: None,
+            erc1271_chains: IterableSet::new(prefix.as_slice().nest(Prefix::Erc1271Chains)),
+            public_key_expirations: LookupMap::new(
+                prefix.as_slice().nest(Prefix::PublicKeyExpirations),
+            ),
+            token_fees: IterableMap::new(prefix.as_slice().nest(Prefix::TokenFees)),
+            frozen_accounts: LookupSet::new(prefix.as_slice().nest(Prefix::FrozenAccounts)),
+            cancelled_intents: LookupSet::new(prefix.as_slice().nest(Prefix::CancelledIntents)),
+            nonce_prefixes: NoncePrefixes::new(prefix.as_slice().nest(Prefix::NoncePrefixes)),
+            allowances: LookupMap::new(prefix.as_slice().nest(Prefix::Allowances)),
+            mt_metadata: LookupMap::new(prefix.as_slice().nest(Prefix::MtMetadata)),
+            salt_rotation: SaltRotation::new(prefix.as_slice().nest(Prefix::SaltRotation)),
+            denied_tokens: IterableSet::new(prefix.as_slice().nest(Prefix::DeniedTokens)),
+            fee_shares: IterableMap::new(prefix.as_slice().nest(Prefix::FeeShares)),
+        }
+    }
+}
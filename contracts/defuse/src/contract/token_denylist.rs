@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use defuse_core::{
+    engine::StateView,
+    events::{DefuseEvent, DefuseIntentEmit},
+    token_id::TokenId,
+    tokens::TokenDenylistSetEvent,
+};
+use defuse_near_utils::require_envelope;
+use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
+use near_sdk::{assert_one_yocto, near};
+
+use crate::token_denylist::TokenDenylistManager;
+
+use super::{Contract, ContractExt, Role};
+
+#[near]
+impl TokenDenylistManager for Contract {
+    #[pause(name = "intents")]
+    #[access_control_any(roles(Role::DAO, Role::TokenDenylistManager))]
+    #[payable]
+    fn set_token_denied(&mut self, token_id: TokenId, denied: bool) {
+        assert_one_yocto();
+        let changed = if denied {
+            self.denied_tokens.insert(token_id.clone())
+        } else {
+            self.denied_tokens.remove(&token_id)
+        };
+        require_envelope!(changed, "unchanged", "same");
+        DefuseEvent::TokenDenylistSet(TokenDenylistSetEvent {
+            token_id: Cow::Owned(token_id),
+            denied,
+        })
+        .emit();
+    }
+
+    fn is_token_denied(&self, token_id: TokenId) -> bool {
+        StateView::is_token_denied(self, &token_id)
+    }
+
+    fn denied_tokens(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<TokenId> {
+        let iter = self
+            .denied_tokens
+            .iter()
+            .skip(from_index.unwrap_or_default().try_into().unwrap())
+            .cloned();
+
+        match limit {
+            Some(l) => iter.take(l.try_into().unwrap()).collect(),
+            None => iter.collect(),
+        }
+    }
+}
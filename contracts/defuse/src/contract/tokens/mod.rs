@@ -4,13 +4,21 @@ mod nep141;
 mod nep171;
 mod nep245;
 
-use super::Contract;
-use defuse_core::{DefuseError, Result, token_id::TokenId};
+use super::{Contract, Role};
+use defuse_core::{
+    DefuseError, Result, Timestamp,
+    accounts::AccountEvent,
+    amounts::Amounts,
+    events::{DefuseEvent, DefuseIntentEmit},
+    token_id::TokenId,
+    tokens::DepositReferralEvent,
+};
 use defuse_near_utils::{Lock, REFUND_MEMO, promise_result_checked_json_with_len};
 use defuse_nep245::{MtBurnEvent, MtEvent, MtMintEvent};
 use itertools::{Either, Itertools};
-use near_sdk::{AccountId, AccountIdRef, FunctionError, Gas, env, json_types::U128};
-use std::borrow::Cow;
+use near_plugins::AccessControllable;
+use near_sdk::{AccountId, AccountIdRef, FunctionError, Gas, json_types::U128};
+use std::{borrow::Cow, collections::BTreeMap};
 
 pub const STORAGE_DEPOSIT_GAS: Gas = Gas::from_tgas(10);
 
@@ -20,6 +28,7 @@ impl Contract {
         owner_id: AccountId,
         tokens: impl IntoIterator<Item = (TokenId, u128)>,
         memo: Option<&str>,
+        force: bool,
     ) -> Result<()> {
         let owner = self
             .storage
@@ -39,6 +48,10 @@ impl Contract {
             if amount == 0 {
                 return Err(DefuseError::InvalidIntent);
             }
+            // forced deposits (i.e. refunds) bypass the denylist
+            if !force && self.storage.state.denied_tokens.contains(&token_id) {
+                return Err(DefuseError::TokenDenied(token_id));
+            }
 
             mint_event.token_ids.to_mut().push(token_id.to_string());
             mint_event.amounts.to_mut().push(U128(amount));
@@ -75,13 +88,67 @@ impl Contract {
         Ok(())
     }
 
+    /// Attributes deposit of given tokens to the referral
+    pub(crate) fn deposit_referral(
+        receiver_id: &AccountIdRef,
+        referral: AccountId,
+        tokens: impl IntoIterator<Item = (TokenId, u128)>,
+    ) -> Result<()> {
+        let tokens = Amounts::<BTreeMap<TokenId, u128>>::default()
+            .with_add_many(tokens)
+            .ok_or(DefuseError::BalanceOverflow)?;
+
+        DefuseEvent::DepositReferral(AccountEvent::new(
+            receiver_id,
+            DepositReferralEvent {
+                referral: referral.into(),
+                tokens,
+            },
+        ))
+        .emit();
+
+        Ok(())
+    }
+
+    /// Refunds failed withdrawal back to `owner_id` and releases it from
+    /// withdrawal limits if it was `limited`
+    pub(crate) fn refund_withdrawal(
+        &mut self,
+        owner_id: AccountId,
+        tokens: impl IntoIterator<Item = (TokenId, u128)>,
+        limited: bool,
+    ) {
+        let tokens: Vec<_> = tokens.into_iter().collect();
+        if limited {
+            let now = Timestamp::now();
+            for (token_id, amount) in &tokens {
+                self.withdrawal_limits
+                    .release(&owner_id, token_id, *amount, now);
+            }
+        }
+
+        self.deposit(owner_id, tokens, Some(REFUND_MEMO), true)
+            .unwrap_or_else(|err| err.panic());
+    }
+
+    /// Returns whether the withdrawal was recorded in withdrawal limits,
+    /// so that it should be released from them if refunded
     pub(crate) fn withdraw(
         &mut self,
         owner_id: &AccountIdRef,
         token_amounts: impl IntoIterator<Item = (TokenId, u128)>,
         memo: Option<impl Into<String>>,
         force: bool,
-    ) -> Result<()> {
+    ) -> Result<bool> {
+        // force withdrawals bypass freezes as well as locks
+        if !force && self.frozen_accounts.contains(owner_id) {
+            return Err(DefuseError::AccountFrozen(owner_id.to_owned()));
+        }
+
+        // force withdrawals and exempt accounts bypass withdrawal limits
+        let limited =
+            !force && !self.acl_has_role(Role::WithdrawalLimitsExempt.into(), owner_id.to_owned());
+
         let owner = self
             .storage
             .accounts
@@ -98,11 +165,24 @@ impl Contract {
             memo: memo.map(Into::into).map(Into::into),
         };
 
+        let now = Timestamp::now();
+
         for (token_id, amount) in token_amounts {
             if amount == 0 {
                 return Err(DefuseError::InvalidIntent);
             }
 
+            if limited
+                && self
+                    .storage
+                    .state
+                    .withdrawal_limits
+                    .record(owner_id, &token_id, amount, now)
+                    .is_err()
+            {
+                return Err(DefuseError::WithdrawalLimitExceeded(token_id));
+            }
+
             burn_event.token_ids.to_mut().push(token_id.to_string());
             burn_event.amounts.to_mut().push(U128(amount));
 
@@ -126,7 +206,7 @@ impl Contract {
             self.runtime.postponed_burns.mt_burn(burn_event);
         }
 
-        Ok(())
+        Ok(limited)
     }
 }
 
@@ -138,7 +218,7 @@ impl Contract {
 
         let token_count: u64 = token_count
             .try_into()
-            .unwrap_or_else(|_| env::panic_str(&format!("token_count overflow: {token_count}")));
+            .unwrap_or_else(|_| DefuseError::GasOverflow.panic());
 
         MT_RESOLVE_DEPOSIT_BASE_GAS
             .checked_add(
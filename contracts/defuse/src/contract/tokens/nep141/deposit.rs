@@ -1,7 +1,8 @@
 use defuse_core::token_id::{TokenId, nep141::Nep141TokenId};
+use defuse_near_utils::{ErrorCode, require_envelope};
 use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
 use near_plugins::{Pausable, pause};
-use near_sdk::{AccountId, FunctionError, PromiseOrValue, env, json_types::U128, near, require};
+use near_sdk::{AccountId, FunctionError, PromiseOrValue, env, json_types::U128, near};
 
 use crate::{
     contract::{Contract, ContractExt},
@@ -22,26 +23,39 @@ impl FungibleTokenReceiver for Contract {
         amount: U128,
         msg: String,
     ) -> PromiseOrValue<U128> {
-        require!(amount.0 > 0, "zero amount");
+        require_envelope!(amount.0 > 0, "zero_amount", "zero amount");
 
         let token_id = TokenId::Nep141(Nep141TokenId::new(env::predecessor_account_id()));
 
         let DepositMessage {
             receiver_id,
             action,
+            referral,
         } = if msg.is_empty() {
             DepositMessage::new(sender_id.clone())
         } else {
-            msg.parse().unwrap_or_else(|e| panic!("{e}"))
+            msg.parse::<DepositMessage>()
+                .unwrap_or_else(|err| err.panic_with_envelope())
         };
 
+        if self.exceeds_deposit_caps(&receiver_id, [(token_id.clone(), amount.0)]) {
+            // refund
+            return PromiseOrValue::Value(amount);
+        }
+
         self.deposit(
             receiver_id.clone(),
             [(token_id.clone(), amount.0)],
             Some("deposit"),
+            false,
         )
         .unwrap_or_else(|err| err.panic());
 
+        if let Some(referral) = referral {
+            Self::deposit_referral(&receiver_id, referral, [(token_id.clone(), amount.0)])
+                .unwrap_or_else(|err| err.panic());
+        }
+
         let Some(action) = action else {
             return PromiseOrValue::Value(0.into());
         };
@@ -1,17 +1,97 @@
-use defuse_core::intents::tokens::NativeWithdraw;
-use defuse_near_utils::promise_result_checked_void;
-use near_sdk::{Gas, Promise, near, require};
+use defuse_core::{
+    DefuseError,
+    engine::StateView,
+    intents::tokens::NativeWithdraw,
+    token_id::{TokenId, nep141::Nep141TokenId},
+};
+use defuse_near_utils::{promise_result_checked_void, require_envelope};
+use defuse_wnear::{NEAR_DEPOSIT_GAS, ext_wnear};
+use near_plugins::{Pausable, pause};
+use near_sdk::{
+    AccountId, FunctionError, Gas, NearToken, Promise, PromiseOrValue, env, json_types::U128, near,
+};
 
-use crate::contract::{Contract, ContractExt};
+use crate::{
+    contract::{Contract, ContractExt},
+    tokens::nep141::NativeDepositor,
+};
+
+#[near]
+impl NativeDepositor for Contract {
+    #[pause]
+    #[payable]
+    fn near_deposit(&mut self, receiver_id: Option<AccountId>) -> PromiseOrValue<U128> {
+        let amount = env::attached_deposit();
+        require_envelope!(!amount.is_zero(), "zero_amount", "zero amount");
+
+        let sender_id = env::predecessor_account_id();
+        let receiver_id = receiver_id.unwrap_or_else(|| sender_id.clone());
+
+        let token_id: TokenId = Nep141TokenId::new(self.wnear_id.clone()).into();
+        // reject before wrapping, so that NEAR doesn't get stuck as wNEAR
+        if self.is_token_denied(&token_id) {
+            DefuseError::TokenDenied(token_id).panic();
+        }
+        if self.exceeds_deposit_caps(&receiver_id, [(token_id, amount.as_yoctonear())]) {
+            // refund
+            Promise::new(sender_id).transfer(amount).detach();
+            return PromiseOrValue::Value(U128(0));
+        }
+
+        ext_wnear::ext(self.wnear_id.clone())
+            .with_attached_deposit(amount)
+            .with_static_gas(NEAR_DEPOSIT_GAS)
+            // do not distribute remaining gas here
+            .with_unused_gas_weight(0)
+            .near_deposit()
+            .then(
+                // do_native_deposit only after wrapping NEAR
+                Self::ext(env::current_account_id())
+                    .with_static_gas(Self::DO_NATIVE_DEPOSIT_GAS)
+                    // do not distribute remaining gas here
+                    .with_unused_gas_weight(0)
+                    .do_native_deposit(sender_id, receiver_id, U128(amount.as_yoctonear())),
+            )
+            .into()
+    }
+}
 
 #[near]
 impl Contract {
+    pub(crate) const DO_NATIVE_DEPOSIT_GAS: Gas = Gas::from_tgas(10);
     pub(crate) const DO_NATIVE_WITHDRAW_GAS: Gas = Gas::from_tgas(12);
 
+    #[private]
+    pub fn do_native_deposit(
+        &mut self,
+        sender_id: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    ) -> U128 {
+        if promise_result_checked_void(0).is_err() {
+            // refund
+            Promise::new(sender_id)
+                .transfer(NearToken::from_yoctonear(amount.0))
+                .detach();
+            return U128(0);
+        }
+
+        self.deposit(
+            receiver_id,
+            [(Nep141TokenId::new(self.wnear_id.clone()).into(), amount.0)],
+            Some("deposit"),
+            false,
+        )
+        .unwrap_or_else(|err| err.panic());
+
+        amount
+    }
+
     #[private]
     pub fn do_native_withdraw(withdraw: NativeWithdraw) -> Promise {
-        require!(
+        require_envelope!(
             promise_result_checked_void(0).is_ok(),
+            "near_withdraw_failed",
             "near_withdraw failed",
         );
 
@@ -1,7 +1,7 @@
 use defuse_core::intents::tokens::StorageDeposit;
-use defuse_near_utils::promise_result_checked_void;
+use defuse_near_utils::{promise_result_checked_void, require_envelope};
 use near_contract_standards::storage_management::ext_storage_management;
-use near_sdk::{Gas, Promise, near, require};
+use near_sdk::{Gas, Promise, near};
 
 use crate::contract::{Contract, ContractExt, tokens::STORAGE_DEPOSIT_GAS};
 
@@ -12,8 +12,9 @@ impl Contract {
 
     #[private]
     pub fn do_storage_deposit(storage_deposit: StorageDeposit) -> Promise {
-        require!(
+        require_envelope!(
             promise_result_checked_void(0).is_ok(),
+            "near_withdraw_failed",
             "near_withdraw failed",
         );
 
@@ -9,7 +9,9 @@ use defuse_core::{
     DefuseError, Result, engine::StateView, intents::tokens::FtWithdraw,
     token_id::nep141::Nep141TokenId,
 };
-use defuse_near_utils::{REFUND_MEMO, promise_result_checked_json, promise_result_checked_void};
+use defuse_near_utils::{
+    PromiseResultError, promise_result_checked_void, promise_result_json, require_envelope,
+};
 
 use defuse_wnear::{NEAR_WITHDRAW_GAS, ext_wnear};
 use near_contract_standards::{
@@ -18,7 +20,7 @@ use near_contract_standards::{
 use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
 use near_sdk::{
     AccountId, FunctionError, Gas, NearToken, Promise, PromiseOrValue, assert_one_yocto, env,
-    json_types::U128, near, require,
+    json_types::U128, near,
 };
 
 #[near]
@@ -58,7 +60,9 @@ impl Contract {
         withdraw: FtWithdraw,
         force: bool,
     ) -> Result<PromiseOrValue<U128>> {
-        self.withdraw(
+        withdraw.withdraw_memo()?;
+
+        let limited = self.withdraw(
             &owner_id,
             iter::once((
                 Nep141TokenId::new(withdraw.token.clone()).into(),
@@ -101,7 +105,13 @@ impl Contract {
                 .with_static_gas(Self::FT_RESOLVE_WITHDRAW_GAS)
                 // do not distribute remaining gas here
                 .with_unused_gas_weight(0)
-                .ft_resolve_withdraw(withdraw.token, owner_id, withdraw.amount, is_call),
+                .ft_resolve_withdraw(
+                    withdraw.token,
+                    owner_id,
+                    withdraw.amount,
+                    is_call,
+                    Some(limited),
+                ),
         )
         .into())
     }
@@ -109,8 +119,8 @@ impl Contract {
 
 #[near]
 impl Contract {
-    const FT_RESOLVE_WITHDRAW_GAS: Gas = Gas::from_tgas(5);
-    const DO_FT_WITHDRAW_GAS: Gas = Gas::from_tgas(5)
+    pub(crate) const FT_RESOLVE_WITHDRAW_GAS: Gas = Gas::from_tgas(5);
+    pub(crate) const DO_FT_WITHDRAW_GAS: Gas = Gas::from_tgas(5)
         // do_ft_withdraw() method is called externally
         // only with storage_deposit
         .saturating_add(STORAGE_DEPOSIT_GAS);
@@ -119,8 +129,9 @@ impl Contract {
     pub fn do_ft_withdraw(withdraw: FtWithdraw) -> Promise {
         let min_gas = withdraw.min_gas();
         let p = if let Some(storage_deposit) = withdraw.storage_deposit {
-            require!(
+            require_envelope!(
                 promise_result_checked_void(0).is_ok(),
+                "near_withdraw_failed",
                 "near_withdraw failed",
             );
 
@@ -156,16 +167,17 @@ impl FungibleTokenWithdrawResolver for Contract {
         sender_id: AccountId,
         amount: U128,
         is_call: bool,
+        limited: Option<bool>,
     ) -> U128 {
         let used = if is_call {
             // `ft_transfer_call` returns successfully transferred amount
-            match promise_result_checked_json::<U128>(0) {
-                Ok(Ok(used)) => used.0.min(amount.0),
-                Ok(Err(_deserialize_err)) => 0,
+            match promise_result_json::<U128>(0) {
+                Ok(used) => used.0.min(amount.0),
+                Err(PromiseResultError::Deserialize(_)) => 0,
                 // do not refund on failed `ft_transfer_call` due to
                 // NEP-141 vulnerability: `ft_resolve_transfer` fails to
                 // read result of `ft_on_transfer` due to insufficient gas
-                Err(_) => amount.0,
+                Err(PromiseResultError::Failed | PromiseResultError::TooLong(_)) => amount.0,
             }
         } else {
             // `ft_transfer` returns empty result on success
@@ -178,12 +190,11 @@ impl FungibleTokenWithdrawResolver for Contract {
 
         let refund = amount.0.saturating_sub(used);
         if refund > 0 {
-            self.deposit(
+            self.refund_withdrawal(
                 sender_id,
                 [(Nep141TokenId::new(token).into(), refund)],
-                Some(REFUND_MEMO),
-            )
-            .unwrap_or_else(|err| err.panic());
+                limited.unwrap_or_default(),
+            );
         }
 
         U128(used)
@@ -3,6 +3,7 @@ use defuse_core::{
     token_id::{TokenId, nep171::Nep171TokenId},
     tokens::MAX_TOKEN_ID_LEN,
 };
+use defuse_near_utils::ErrorCode;
 use near_contract_standards::non_fungible_token::core::NonFungibleTokenReceiver;
 use near_plugins::{Pausable, pause};
 use near_sdk::{AccountId, FunctionError, PromiseOrValue, env, json_types::U128, near};
@@ -34,10 +35,12 @@ impl NonFungibleTokenReceiver for Contract {
         let DepositMessage {
             receiver_id,
             action,
+            referral,
         } = if msg.is_empty() {
             DepositMessage::new(sender_id.clone())
         } else {
-            msg.parse().unwrap_or_else(|e| panic!("{e}"))
+            msg.parse::<DepositMessage>()
+                .unwrap_or_else(|err| err.panic_with_envelope())
         };
 
         let core_token_id: TokenId =
@@ -47,9 +50,15 @@ impl NonFungibleTokenReceiver for Contract {
             receiver_id.clone(),
             [(core_token_id.clone(), 1)],
             Some("deposit"),
+            false,
         )
         .unwrap_or_else(|err| err.panic());
 
+        if let Some(referral) = referral {
+            Self::deposit_referral(&receiver_id, referral, [(core_token_id.clone(), 1)])
+                .unwrap_or_else(|err| err.panic());
+        }
+
         let Some(action) = action else {
             return PromiseOrValue::Value(false);
         };
@@ -11,7 +11,9 @@ use defuse_core::{
     intents::tokens::NftWithdraw,
     token_id::{nep141::Nep141TokenId, nep171::Nep171TokenId},
 };
-use defuse_near_utils::{REFUND_MEMO, promise_result_checked_json, promise_result_checked_void};
+use defuse_near_utils::{
+    PromiseResultError, promise_result_checked_void, promise_result_json, require_envelope,
+};
 
 use defuse_wnear::{NEAR_WITHDRAW_GAS, ext_wnear};
 use near_contract_standards::{
@@ -21,7 +23,7 @@ use near_contract_standards::{
 use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
 use near_sdk::{
     AccountId, FunctionError, Gas, NearToken, Promise, PromiseOrValue, assert_one_yocto, env,
-    json_types::U128, near, require,
+    json_types::U128, near,
 };
 use std::iter;
 
@@ -62,7 +64,7 @@ impl Contract {
         withdraw: NftWithdraw,
         force: bool,
     ) -> Result<PromiseOrValue<bool>> {
-        self.withdraw(
+        let limited = self.withdraw(
             &owner_id,
             iter::once((
                 Nep171TokenId::new(withdraw.token.clone(), withdraw.token_id.clone()).into(),
@@ -105,7 +107,13 @@ impl Contract {
                 .with_static_gas(Self::NFT_RESOLVE_WITHDRAW_GAS)
                 // do not distribute remaining gas here
                 .with_unused_gas_weight(0)
-                .nft_resolve_withdraw(withdraw.token, owner_id, withdraw.token_id, is_call),
+                .nft_resolve_withdraw(
+                    withdraw.token,
+                    owner_id,
+                    withdraw.token_id,
+                    is_call,
+                    Some(limited),
+                ),
         )
         .into())
     }
@@ -113,8 +121,8 @@ impl Contract {
 
 #[near]
 impl Contract {
-    const NFT_RESOLVE_WITHDRAW_GAS: Gas = Gas::from_tgas(5);
-    const DO_NFT_WITHDRAW_GAS: Gas = Gas::from_tgas(5)
+    pub(crate) const NFT_RESOLVE_WITHDRAW_GAS: Gas = Gas::from_tgas(5);
+    pub(crate) const DO_NFT_WITHDRAW_GAS: Gas = Gas::from_tgas(5)
         // do_nft_withdraw() method is called externally
         // only with storage_deposit
         .saturating_add(STORAGE_DEPOSIT_GAS);
@@ -123,8 +131,9 @@ impl Contract {
     pub fn do_nft_withdraw(withdraw: NftWithdraw) -> Promise {
         let min_gas = withdraw.min_gas();
         let p = if let Some(storage_deposit) = withdraw.storage_deposit {
-            require!(
+            require_envelope!(
                 promise_result_checked_void(0).is_ok(),
+                "near_withdraw_failed",
                 "near_withdraw failed",
             );
 
@@ -166,16 +175,17 @@ impl NonFungibleTokenWithdrawResolver for Contract {
         sender_id: AccountId,
         token_id: non_fungible_token::TokenId,
         is_call: bool,
+        limited: Option<bool>,
     ) -> bool {
         let used = if is_call {
             // `nft_transfer_call` returns true if token was successfully transferred
-            match promise_result_checked_json::<bool>(0) {
-                Ok(Ok(used)) => used,
-                Ok(Err(_deserialization_err)) => false,
+            match promise_result_json::<bool>(0) {
+                Ok(used) => used,
+                Err(PromiseResultError::Deserialize(_)) => false,
                 // do not refund on failed `nft_transfer_call` due to
                 // NEP-141 vulnerability: `nft_resolve_transfer` fails to
                 // read result of `nft_on_transfer` due to insufficient gas
-                Err(_) => true,
+                Err(PromiseResultError::Failed | PromiseResultError::TooLong(_)) => true,
             }
         } else {
             // `nft_transfer` returns empty result on success
@@ -183,12 +193,11 @@ impl NonFungibleTokenWithdrawResolver for Contract {
         };
 
         if !used {
-            self.deposit(
+            self.refund_withdrawal(
                 sender_id,
                 [(Nep171TokenId::new(token, token_id).into(), 1)],
-                Some(REFUND_MEMO),
-            )
-            .unwrap_or_else(|err| err.panic());
+                limited.unwrap_or_default(),
+            );
         }
 
         used
@@ -1,12 +1,16 @@
-use crate::contract::{Contract, ContractExt};
+use crate::{
+    contract::{Contract, ContractExt},
+    tokens::nep245::MultiTokenBatchBalances,
+};
 use defuse_core::{
     DefuseError, Result, engine::StateView, intents::tokens::NotifyOnTransfer, token_id::TokenId,
 };
+use defuse_near_utils::require_envelope;
 use defuse_nep245::{MtEvent, MtTransferEvent, MultiTokenCore, receiver::ext_mt_receiver};
 use near_plugins::{Pausable, pause};
 use near_sdk::{
     AccountId, AccountIdRef, FunctionError, Gas, NearToken, Promise, PromiseOrValue,
-    assert_one_yocto, env, json_types::U128, near, require,
+    assert_one_yocto, env, json_types::U128, near,
 };
 use std::borrow::Cow;
 
@@ -41,7 +45,11 @@ impl MultiTokenCore for Contract {
         memo: Option<String>,
     ) {
         assert_one_yocto();
-        require!(approvals.is_none(), "approvals are not supported");
+        require_envelope!(
+            approvals.is_none(),
+            "approvals_not_supported",
+            "approvals are not supported"
+        );
 
         self.internal_mt_batch_transfer(
             &self.ensure_auth_predecessor_id(),
@@ -87,7 +95,11 @@ impl MultiTokenCore for Contract {
         msg: String,
     ) -> PromiseOrValue<Vec<U128>> {
         assert_one_yocto();
-        require!(approvals.is_none(), "approvals are not supported");
+        require_envelope!(
+            approvals.is_none(),
+            "approvals_not_supported",
+            "approvals are not supported"
+        );
 
         self.internal_mt_batch_transfer_call(
             self.ensure_auth_predecessor_id(),
@@ -148,6 +160,36 @@ impl MultiTokenCore for Contract {
     }
 }
 
+#[near]
+impl MultiTokenBatchBalances for Contract {
+    fn mt_batch_balance_of_many(
+        &self,
+        account_ids: Vec<AccountId>,
+        token_ids: Vec<defuse_nep245::TokenId>,
+    ) -> Vec<Vec<U128>> {
+        // parse each token id only once
+        let token_ids: Vec<Option<TokenId>> = token_ids
+            .iter()
+            .map(|token_id| token_id.parse().ok())
+            .collect();
+
+        account_ids
+            .iter()
+            .map(|account_id| {
+                token_ids
+                    .iter()
+                    .map(|token_id| {
+                        token_id
+                            .as_ref()
+                            .map_or(0, |token_id| self.balance_of(account_id, token_id))
+                    })
+                    .map(U128)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
 impl Contract {
     pub(crate) fn internal_mt_balance_of(
         &self,
@@ -178,6 +220,10 @@ impl Contract {
                 return Err(DefuseError::InvalidIntent);
             }
             let token_id: TokenId = token_id.parse()?;
+            // force transfers bypass the denylist
+            if !force && self.is_token_denied(&token_id) {
+                return Err(DefuseError::TokenDenied(token_id));
+            }
 
             self.accounts
                 .get_mut(sender_id)
@@ -307,7 +353,7 @@ impl Contract {
     }
 
     #[must_use]
-    fn mt_resolve_gas(token_count: usize) -> Gas {
+    pub(crate) fn mt_resolve_gas(token_count: usize) -> Gas {
         // These represent a linear model total_gas_cost = per_token*n + base,
         // where `n` is the number of tokens.
         const MT_RESOLVE_TRANSFER_PER_TOKEN_GAS: Gas = Gas::from_tgas(2);
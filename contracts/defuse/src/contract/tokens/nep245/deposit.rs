@@ -1,7 +1,8 @@
 use defuse_core::{DefuseError, token_id::nep245::Nep245TokenId, tokens::MAX_TOKEN_ID_LEN};
+use defuse_near_utils::{ErrorCode, require_envelope};
 use defuse_nep245::receiver::MultiTokenReceiver;
 use near_plugins::{Pausable, pause};
-use near_sdk::{AccountId, FunctionError, PromiseOrValue, env, json_types::U128, near, require};
+use near_sdk::{AccountId, FunctionError, PromiseOrValue, env, json_types::U128, near};
 
 use crate::{
     contract::{Contract, ContractExt},
@@ -26,20 +27,23 @@ impl MultiTokenReceiver for Contract {
     ) -> PromiseOrValue<Vec<U128>> {
         let token = env::predecessor_account_id();
 
-        require!(!amounts.is_empty(), "invalid args");
+        require_envelope!(!amounts.is_empty(), "invalid_args", "invalid args");
 
-        require!(
+        require_envelope!(
             token_ids.len() == amounts.len(),
+            "invalid_args",
             "NEP-245: Contract MUST panic if `token_ids` length does not equals `amounts` length"
         );
 
-        require!(
+        require_envelope!(
             previous_owner_ids.len() == token_ids.len(),
+            "invalid_args",
             "NEP-245: Contract MUST panic if `previous_owner_ids` length does not equals `token_ids` length"
         );
 
-        require!(
+        require_envelope!(
             token != env::current_account_id(),
+            "self_wrapping",
             "self-wrapping is not allowed"
         );
 
@@ -57,21 +61,45 @@ impl MultiTokenReceiver for Contract {
         let DepositMessage {
             receiver_id,
             action,
+            referral,
         } = if msg.is_empty() {
             DepositMessage::new(sender_id.clone())
         } else {
-            msg.parse().unwrap_or_else(|e| panic!("{e}"))
+            msg.parse::<DepositMessage>()
+                .unwrap_or_else(|err| err.panic_with_envelope())
         };
 
+        if self.exceeds_deposit_caps(
+            &receiver_id,
+            core_token_ids
+                .clone()
+                .zip(amounts.iter().map(|amount| amount.0)),
+        ) {
+            // refund
+            return PromiseOrValue::Value(amounts);
+        }
+
         self.deposit(
             receiver_id.clone(),
             core_token_ids
                 .clone()
                 .zip(amounts.iter().map(|amount| amount.0)),
             Some("deposit"),
+            false,
         )
         .unwrap_or_else(|err| err.panic());
 
+        if let Some(referral) = referral {
+            Self::deposit_referral(
+                &receiver_id,
+                referral,
+                core_token_ids
+                    .clone()
+                    .zip(amounts.iter().map(|amount| amount.0)),
+            )
+            .unwrap_or_else(|err| err.panic());
+        }
+
         let Some(action) = action else {
             return PromiseOrValue::Value(vec![U128(0); token_ids.len()]);
         };
@@ -1,7 +1,11 @@
-use crate::contract::{Contract, ContractExt};
-use defuse_core::token_id::TokenIdType;
+use crate::{
+    contract::{Contract, ContractExt},
+    tokens::nep245::{MtCursor, MtTokensPage, MultiTokenCursorEnumeration},
+};
+use defuse_core::token_id::{TokenId, TokenIdType};
+use defuse_near_utils::{ErrorEnvelope, require_envelope};
 use defuse_nep245::{Token, enumeration::MultiTokenEnumeration};
-use near_sdk::{AccountId, json_types::U128, near};
+use near_sdk::{AccountId, AccountIdRef, json_types::U128, near};
 
 #[near]
 impl MultiTokenEnumeration for Contract {
@@ -49,12 +53,7 @@ impl MultiTokenEnumeration for Contract {
             .skip(from_index)
             .map(|(token_id, _amount)| Token {
                 token_id: token_id.to_string(),
-                owner_id: match TokenIdType::from(token_id) {
-                    TokenIdType::Nep171 => Some(account_id.clone()),
-                    TokenIdType::Nep141 | TokenIdType::Nep245 => None,
-                    #[cfg(feature = "imt")]
-                    TokenIdType::Imt => None,
-                },
+                owner_id: Self::mt_token_owner(&account_id, token_id),
             });
 
         match limit {
@@ -63,3 +62,90 @@ impl MultiTokenEnumeration for Contract {
         }
     }
 }
+
+#[near]
+impl MultiTokenCursorEnumeration for Contract {
+    fn mt_tokens_page(&self, cursor: Option<MtCursor>, limit: Option<u32>) -> MtTokensPage {
+        Self::mt_page(
+            self.state.total_supplies.iter(),
+            cursor,
+            limit,
+            |_token_id| None,
+        )
+    }
+
+    fn mt_tokens_for_owner_page(
+        &self,
+        account_id: AccountId,
+        cursor: Option<MtCursor>,
+        limit: Option<u32>,
+    ) -> MtTokensPage {
+        let Some(account) = self.accounts.get(&account_id) else {
+            return MtTokensPage {
+                tokens: Vec::new(),
+                next_cursor: None,
+            };
+        };
+
+        Self::mt_page(
+            account.as_inner_unchecked().state.token_balances.iter(),
+            cursor,
+            limit,
+            |token_id| Self::mt_token_owner(&account_id, token_id),
+        )
+    }
+}
+
+impl Contract {
+    fn mt_token_owner(account_id: &AccountIdRef, token_id: &TokenId) -> Option<AccountId> {
+        match TokenIdType::from(token_id) {
+            TokenIdType::Nep171 => Some(account_id.to_owned()),
+            TokenIdType::Nep141 | TokenIdType::Nep245 => None,
+            #[cfg(feature = "imt")]
+            TokenIdType::Imt => None,
+        }
+    }
+
+    /// Returns up to `limit` tokens preceding `cursor`, i.e. scanning
+    /// from the end of the map to its start.
+    ///
+    /// NOTE: the map is scanned backwards, since removals move the last
+    /// entry into the place of the removed one: this way entries can only
+    /// move from already scanned positions to not yet scanned ones, but
+    /// never vice versa.
+    fn mt_page<'a>(
+        token_ids: impl ExactSizeIterator<Item = (&'a TokenId, &'a u128)>,
+        cursor: Option<MtCursor>,
+        limit: Option<u32>,
+        owner_id: impl Fn(&TokenId) -> Option<AccountId>,
+    ) -> MtTokensPage {
+        require_envelope!(limit != Some(0), "zero_limit", "zero limit");
+        let limit: Option<usize> = limit.map(|l| l.try_into().unwrap());
+
+        let len = token_ids.len();
+        let end = cursor.map_or(len, |cursor| {
+            <[u8; 8]>::try_from(cursor.into_inner().as_slice())
+                .ok()
+                .map(u64::from_be_bytes)
+                .and_then(|end| usize::try_from(end).ok())
+                .unwrap_or_else(|| ErrorEnvelope::new("invalid_cursor", "invalid cursor").panic())
+                // entries might have been removed since the cursor was issued
+                .min(len)
+        });
+        let start = limit.map_or(0, |limit| end.saturating_sub(limit));
+
+        MtTokensPage {
+            tokens: token_ids
+                // underlying iterators seek without reading skipped entries
+                .skip(start)
+                .take(end - start)
+                .map(|(token_id, _amount)| Token {
+                    token_id: token_id.to_string(),
+                    owner_id: owner_id(token_id),
+                })
+                .collect(),
+            next_cursor: (start > 0)
+                .then(|| MtCursor::from(u64::try_from(start).unwrap().to_be_bytes().to_vec())),
+        }
+    }
+}
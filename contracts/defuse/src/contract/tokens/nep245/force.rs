@@ -1,9 +1,10 @@
 #![allow(clippy::too_many_arguments)]
 
+use defuse_near_utils::require_envelope;
 use defuse_nep245::TokenId;
 use near_plugins::{AccessControllable, access_control_any};
 use near_sdk::{
-    AccountId, FunctionError, PromiseOrValue, assert_one_yocto, json_types::U128, near, require,
+    AccountId, FunctionError, PromiseOrValue, assert_one_yocto, json_types::U128, near,
 };
 
 use crate::{
@@ -46,7 +47,11 @@ impl MultiTokenForcedCore for Contract {
         memo: Option<String>,
     ) {
         assert_one_yocto();
-        require!(approvals.is_none(), "approvals are not supported");
+        require_envelope!(
+            approvals.is_none(),
+            "approvals_not_supported",
+            "approvals are not supported"
+        );
 
         self.internal_mt_batch_transfer(
             &owner_id,
@@ -95,7 +100,11 @@ impl MultiTokenForcedCore for Contract {
         msg: String,
     ) -> PromiseOrValue<Vec<U128>> {
         assert_one_yocto();
-        require!(approvals.is_none(), "approvals are not supported");
+        require_envelope!(
+            approvals.is_none(),
+            "approvals_not_supported",
+            "approvals are not supported"
+        );
 
         self.internal_mt_batch_transfer_call(
             owner_id,
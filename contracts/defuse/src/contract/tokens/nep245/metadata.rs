@@ -0,0 +1,188 @@
+use defuse_core::{DefuseError, token_id::TokenId};
+use defuse_near_utils::{ErrorEnvelope, StorageTracker, require_envelope};
+use defuse_nep245::metadata::{
+    MT_METADATA_SPEC, MTBaseTokenMetadata, MTContractMetadata, MTTokenMetadata, MTTokenMetadataAll,
+    MultiTokenMetadata, ext_mt_metadata,
+};
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, ext_ft_metadata};
+use near_plugins::{Pausable, pause};
+use near_sdk::{AccountId, FunctionError, Gas, NearToken, Promise, env, near, serde_json};
+
+use crate::{
+    contract::{Contract, ContractExt},
+    tokens::nep245::MultiTokenMetadataCache,
+};
+
+#[near]
+impl MultiTokenMetadata for Contract {
+    fn mt_metadata_contract(&self) -> MTContractMetadata {
+        MTContractMetadata {
+            spec: MT_METADATA_SPEC.to_string(),
+            name: env::current_account_id().to_string(),
+        }
+    }
+
+    fn mt_metadata_token_all(
+        &self,
+        token_ids: Vec<defuse_nep245::TokenId>,
+    ) -> Vec<Option<MTTokenMetadataAll>> {
+        token_ids
+            .iter()
+            .map(|token_id| self.cached_mt_metadata(token_id).cloned())
+            .collect()
+    }
+
+    fn mt_metadata_token_by_token_id(
+        &self,
+        token_ids: Vec<defuse_nep245::TokenId>,
+    ) -> Vec<Option<MTTokenMetadata>> {
+        token_ids
+            .iter()
+            .map(|token_id| {
+                self.cached_mt_metadata(token_id)
+                    .map(|metadata| metadata.token.clone())
+            })
+            .collect()
+    }
+
+    fn mt_metadata_base_by_token_id(
+        &self,
+        token_ids: Vec<defuse_nep245::TokenId>,
+    ) -> Vec<Option<MTBaseTokenMetadata>> {
+        token_ids
+            .iter()
+            .map(|token_id| {
+                self.cached_mt_metadata(token_id)
+                    .map(|metadata| metadata.base.clone())
+            })
+            .collect()
+    }
+
+    fn mt_metadata_base_by_metadata_id(
+        &self,
+        base_metadata_ids: Vec<String>,
+    ) -> Vec<Option<MTBaseTokenMetadata>> {
+        // each token has its own base metadata with the same id
+        self.mt_metadata_base_by_token_id(base_metadata_ids)
+    }
+}
+
+#[near]
+impl MultiTokenMetadataCache for Contract {
+    #[pause]
+    #[payable]
+    fn mt_metadata_sync(&mut self, token_id: defuse_nep245::TokenId) -> Promise {
+        let token_id: TokenId = token_id
+            .parse()
+            .unwrap_or_else(|err| DefuseError::from(err).panic());
+        require_envelope!(
+            self.total_supplies.amount_for(&token_id) > 0,
+            "token_has_no_supply",
+            "token has no supply"
+        );
+
+        match &token_id {
+            TokenId::Nep141(token) => ext_ft_metadata::ext(token.contract_id.clone())
+                .with_static_gas(Self::MT_METADATA_FETCH_GAS)
+                .ft_metadata(),
+            TokenId::Nep245(token) => ext_mt_metadata::ext(token.contract_id.clone())
+                .with_static_gas(Self::MT_METADATA_FETCH_GAS)
+                .mt_metadata_token_all(vec![token.mt_token_id.clone()]),
+            _ => ErrorEnvelope::new(
+                "unsupported_token_type",
+                "metadata of this token type can't be synced",
+            )
+            .panic(),
+        }
+        .then(
+            Self::ext(env::current_account_id())
+                .with_attached_deposit(env::attached_deposit())
+                .with_static_gas(Self::MT_RESOLVE_METADATA_SYNC_GAS)
+                // do not distribute remaining gas here
+                .with_unused_gas_weight(0)
+                .mt_resolve_metadata_sync(token_id, env::predecessor_account_id()),
+        )
+    }
+}
+
+#[near]
+impl Contract {
+    const MT_METADATA_FETCH_GAS: Gas = Gas::from_tgas(5);
+    const MT_RESOLVE_METADATA_SYNC_GAS: Gas = Gas::from_tgas(10);
+
+    /// Max length of metadata returned by underlying contracts
+    const MT_METADATA_MAX_LENGTH: usize = 32 * 1024;
+
+    #[private]
+    #[payable]
+    pub fn mt_resolve_metadata_sync(&mut self, token_id: TokenId, sender_id: AccountId) -> bool {
+        let deposit = env::attached_deposit();
+
+        let Some(metadata) = env::promise_result_checked(0, Self::MT_METADATA_MAX_LENGTH)
+            .ok()
+            .and_then(|value| Self::parse_mt_metadata(&token_id, &value))
+        else {
+            Self::refund_deposit(sender_id, deposit);
+            return false;
+        };
+
+        let storage = StorageTracker::start();
+        let previous = self.mt_metadata.insert(token_id.clone(), metadata);
+        self.mt_metadata.flush();
+        let cost = storage.finish().cost();
+
+        if cost > deposit {
+            // not enough deposit to cover storage, restore previous one
+            if let Some(previous) = previous {
+                self.mt_metadata.insert(token_id, previous);
+            } else {
+                self.mt_metadata.remove(&token_id);
+            }
+            Self::refund_deposit(sender_id, deposit);
+            return false;
+        }
+
+        Self::refund_deposit(sender_id, deposit.saturating_sub(cost));
+        true
+    }
+}
+
+impl Contract {
+    fn cached_mt_metadata(&self, token_id: &str) -> Option<&MTTokenMetadataAll> {
+        self.mt_metadata.get(&token_id.parse().ok()?)
+    }
+
+    fn parse_mt_metadata(token_id: &TokenId, value: &[u8]) -> Option<MTTokenMetadataAll> {
+        let mut metadata = match token_id {
+            TokenId::Nep141(_) => {
+                let ft = serde_json::from_slice::<FungibleTokenMetadata>(value).ok()?;
+                MTTokenMetadataAll {
+                    base: MTBaseTokenMetadata {
+                        name: ft.name,
+                        symbol: Some(ft.symbol),
+                        icon: ft.icon,
+                        decimals: Some(ft.decimals.to_string()),
+                        reference: ft.reference,
+                        reference_hash: ft.reference_hash,
+                        ..Default::default()
+                    },
+                    token: MTTokenMetadata::default(),
+                }
+            }
+            TokenId::Nep245(_) => serde_json::from_slice::<Vec<Option<MTTokenMetadataAll>>>(value)
+                .ok()?
+                .into_iter()
+                .next()
+                .flatten()?,
+            _ => return None,
+        };
+        metadata.base.id = token_id.to_string();
+        Some(metadata)
+    }
+
+    fn refund_deposit(receiver_id: AccountId, amount: NearToken) {
+        if !amount.is_zero() {
+            Promise::new(receiver_id).transfer(amount).detach();
+        }
+    }
+}
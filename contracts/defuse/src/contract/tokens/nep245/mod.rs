@@ -2,5 +2,6 @@ mod core;
 mod deposit;
 mod enumeration;
 mod force;
+mod metadata;
 mod resolver;
 mod withdraw;
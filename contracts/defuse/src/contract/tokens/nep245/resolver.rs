@@ -1,10 +1,13 @@
 use std::borrow::Cow;
 
-use defuse_near_utils::{Lock, REFUND_MEMO, promise_result_checked_json_with_len};
+use defuse_core::DefuseError;
+use defuse_near_utils::{
+    Lock, REFUND_MEMO, promise_result_checked_json_with_len, require_envelope,
+};
 use defuse_nep245::{
     ClearedApproval, MtEvent, MtTransferEvent, TokenId, resolver::MultiTokenResolver,
 };
-use near_sdk::{AccountId, json_types::U128, near, require};
+use near_sdk::{AccountId, FunctionError, json_types::U128, near};
 
 use crate::contract::{Contract, ContractExt};
 
@@ -19,11 +22,16 @@ impl MultiTokenResolver for Contract {
         #[allow(unused_mut)] mut amounts: Vec<U128>,
         approvals: Option<Vec<Option<Vec<ClearedApproval>>>>,
     ) -> Vec<U128> {
-        require!(approvals.is_none(), "approvals are not supported");
-        require!(
+        require_envelope!(
+            approvals.is_none(),
+            "approvals_not_supported",
+            "approvals are not supported"
+        );
+        require_envelope!(
             !token_ids.is_empty()
                 && previous_owner_ids.len() == token_ids.len()
                 && amounts.len() == token_ids.len(),
+            "invalid_args",
             "invalid args"
         );
 
@@ -37,12 +45,17 @@ impl MultiTokenResolver for Contract {
 
         for ((token_id, previous_owner_id), (amount, refund)) in token_ids
             .iter()
-            .map(|token_id| token_id.parse().unwrap_or_else(|e| panic!("{e}")))
+            .map(|token_id| {
+                token_id
+                    .parse()
+                    .unwrap_or_else(|err| DefuseError::from(err).panic())
+            })
             .zip(previous_owner_ids)
             .zip(amounts.iter_mut().zip(&mut refunds))
         {
-            require!(
+            require_envelope!(
                 sender_id == previous_owner_id,
+                "approvals_not_supported",
                 "approvals are not supported"
             );
 
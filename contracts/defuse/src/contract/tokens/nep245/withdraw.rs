@@ -13,7 +13,7 @@ use defuse_core::{
     token_id::{nep141::Nep141TokenId, nep245::Nep245TokenId},
 };
 use defuse_near_utils::{
-    REFUND_MEMO, promise_result_checked_json_with_len, promise_result_checked_void,
+    PromiseResultError, promise_result_checked_void, promise_result_json_with_len, require_envelope,
 };
 use defuse_nep245::ext_mt_core;
 use defuse_wnear::{NEAR_WITHDRAW_GAS, ext_wnear};
@@ -21,7 +21,7 @@ use near_contract_standards::storage_management::ext_storage_management;
 use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
 use near_sdk::{
     AccountId, FunctionError, Gas, NearToken, Promise, PromiseOrValue, assert_one_yocto, env,
-    json_types::U128, near, require,
+    json_types::U128, near,
 };
 
 #[near]
@@ -66,8 +66,9 @@ impl Contract {
         if withdraw.token_ids.len() != withdraw.amounts.len() || withdraw.token_ids.is_empty() {
             return Err(DefuseError::InvalidIntent);
         }
+        withdraw.withdraw_memo()?;
 
-        self.withdraw(
+        let limited = self.withdraw(
             &owner_id,
             withdraw
                 .token_ids
@@ -119,13 +120,14 @@ impl Contract {
                     withdraw.token_ids,
                     withdraw.amounts,
                     is_call,
+                    Some(limited),
                 ),
         )
         .into())
     }
 
     #[must_use]
-    fn mt_resolve_withdraw_gas(token_count: usize) -> Gas {
+    pub(crate) fn mt_resolve_withdraw_gas(token_count: usize) -> Gas {
         // Values chosen to be similar to `MT_RESOLVE_TRANSFER_*` values
         const MT_RESOLVE_WITHDRAW_PER_TOKEN_GAS: Gas = Gas::from_tgas(2);
         const MT_RESOLVE_WITHDRAW_BASE_GAS: Gas = Gas::from_tgas(8);
@@ -146,7 +148,7 @@ impl Contract {
 
 #[near]
 impl Contract {
-    const DO_MT_WITHDRAW_GAS: Gas = Gas::from_tgas(5)
+    pub(crate) const DO_MT_WITHDRAW_GAS: Gas = Gas::from_tgas(5)
         // do_nft_withdraw() method is called externally
         // only with storage_deposit
         .saturating_add(STORAGE_DEPOSIT_GAS);
@@ -155,8 +157,9 @@ impl Contract {
     pub fn do_mt_withdraw(withdraw: MtWithdraw) -> Promise {
         let min_gas = withdraw.min_gas();
         let p = if let Some(storage_deposit) = withdraw.storage_deposit {
-            require!(
+            require_envelope!(
                 promise_result_checked_void(0).is_ok(),
+                "near_withdraw_failed",
                 "near_withdraw failed",
             );
 
@@ -206,21 +209,23 @@ impl MultiTokenWithdrawResolver for Contract {
         token_ids: Vec<defuse_nep245::TokenId>,
         amounts: Vec<U128>,
         is_call: bool,
+        limited: Option<bool>,
     ) -> Vec<U128> {
-        require!(
+        require_envelope!(
             token_ids.len() == amounts.len() && !amounts.is_empty(),
+            "invalid_args",
             "invalid args"
         );
 
         let mut used = if is_call {
             // `mt_batch_transfer_call` returns successfully transferred amounts
-            match promise_result_checked_json_with_len::<Vec<U128>>(0, amounts.len()) {
-                Ok(Ok(used)) if used.len() == amounts.len() => used,
-                Ok(_) => vec![U128(0); amounts.len()],
+            match promise_result_json_with_len::<Vec<U128>>(0, amounts.len()) {
+                Ok(used) if used.len() == amounts.len() => used,
+                Ok(_) | Err(PromiseResultError::Deserialize(_)) => vec![U128(0); amounts.len()],
                 // do not refund on failed `mt_batch_transfer_call` due to
                 // NEP-141 vulnerability: `mt_resolve_transfer` fails to
                 // read result of `mt_on_transfer` due to insufficient gas
-                Err(_) => amounts.clone(),
+                Err(PromiseResultError::Failed | PromiseResultError::TooLong(_)) => amounts.clone(),
             }
         } else {
             // `mt_batch_transfer` returns empty result on success
@@ -231,7 +236,7 @@ impl MultiTokenWithdrawResolver for Contract {
             }
         };
 
-        self.deposit(
+        self.refund_withdrawal(
             sender_id,
             token_ids
                 .into_iter()
@@ -247,9 +252,8 @@ impl MultiTokenWithdrawResolver for Contract {
                         None
                     }
                 }),
-            Some(REFUND_MEMO),
-        )
-        .unwrap_or_else(|err| err.panic());
+            limited.unwrap_or_default(),
+        );
 
         used
     }
@@ -1,4 +1,5 @@
 mod v0;
+mod v1;
 
 use std::{
     borrow::Cow,
@@ -14,12 +15,15 @@ use near_sdk::{
 
 use super::ContractStorage;
 use v0::ContractStorageV0;
+use v1::ContractStorageV1;
 
 /// Versioned [Contract] state for de/serialization.
 #[derive(Debug)]
 #[near(serializers = [borsh])]
+#[allow(clippy::large_enum_variant)] // only lives during (de)serialization
 enum VersionedContractStorage<'a> {
     V0(Cow<'a, PanicOnClone<ContractStorageV0>>),
+    V1(Cow<'a, PanicOnClone<ContractStorageV1>>),
     // When upgrading to a new version, given current version `N`:
     // 1. Copy current `ContractStorage` struct definition and name it `ContractStorageVN`
     // 2. Add variant `VN(Cow<'a, PanicOnClone<ContractStorageVN>>)` before `Latest`
@@ -34,6 +38,7 @@ impl From<VersionedContractStorage<'_>> for ContractStorage {
         // safe to call `Cow::<PanicOnClone<_>>::into_owned()` here.
         match versioned {
             VersionedContractStorage::V0(contract) => contract.into_owned().into_inner().into(),
+            VersionedContractStorage::V1(contract) => contract.into_owned().into_inner().into(),
             VersionedContractStorage::Latest(contract) => contract.into_owned().into_inner(),
         }
     }
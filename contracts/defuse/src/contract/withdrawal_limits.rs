@@ -0,0 +1,217 @@
+use std::borrow::Cow;
+
+use defuse_core::{
+    Timestamp,
+    events::{DefuseEvent, DefuseIntentEmit},
+    limits::{WithdrawalLimit, WithdrawalLimitScope, WithdrawalLimitSetEvent, WithdrawalUsage},
+    token_id::TokenId,
+};
+use defuse_near_utils::{NestPrefix, require_envelope};
+use near_plugins::{AccessControllable, Pausable, access_control_any, pause};
+use near_sdk::{
+    AccountId, AccountIdRef, BorshStorageKey, IntoStorageKey, assert_one_yocto,
+    borsh::BorshSerialize,
+    json_types::U128,
+    near,
+    store::{IterableMap, LookupMap},
+};
+
+use crate::withdrawal_limits::WithdrawalLimitsManager;
+
+use super::{Contract, ContractExt, Role};
+
+/// Per-token withdrawal limits along with amounts withdrawn
+/// within current rolling windows.
+#[near(serializers = [borsh])]
+#[derive(Debug)]
+pub struct WithdrawalLimits {
+    limits: IterableMap<TokenId, WithdrawalLimit>,
+    usage: LookupMap<UsageKey, WithdrawalUsage>,
+}
+
+impl WithdrawalLimits {
+    #[inline]
+    pub fn new<S>(prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        let prefix = prefix.into_storage_key();
+        Self {
+            limits: IterableMap::new(prefix.as_slice().nest(Prefix::Limits)),
+            usage: LookupMap::new(prefix.as_slice().nest(Prefix::Usage)),
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, token_id: &TokenId) -> Option<&WithdrawalLimit> {
+        self.limits.get(token_id)
+    }
+
+    /// Returns whether the limit was changed
+    pub fn set(&mut self, token_id: TokenId, limit: Option<WithdrawalLimit>) -> bool {
+        match limit {
+            Some(limit) => self.limits.insert(token_id, limit) != Some(limit),
+            None => self.limits.remove(&token_id).is_some(),
+        }
+    }
+
+    /// Returns amount of `token_id` available for withdrawal at `now`,
+    /// or `None` if there is no limit.
+    pub fn available(
+        &self,
+        owner_id: Option<&AccountIdRef>,
+        token_id: &TokenId,
+        now: Timestamp,
+    ) -> Option<u128> {
+        let limit = self.limits.get(token_id)?;
+        Self::scopes(owner_id, token_id)
+            .into_iter()
+            .filter_map(|(scope, key)| {
+                let cap = limit.cap(scope)?;
+                Some(
+                    self.usage
+                        .get(&key?)
+                        .map_or(cap, |usage| usage.available_at(now, cap, limit.window())),
+                )
+            })
+            .min()
+    }
+
+    /// Records withdrawal of `amount` by `owner_id` at `now`.
+    /// Nothing is recorded if any of the limits would be exceeded.
+    pub fn record(
+        &mut self,
+        owner_id: &AccountIdRef,
+        token_id: &TokenId,
+        amount: u128,
+        now: Timestamp,
+    ) -> Result<(), WithdrawalLimitScope> {
+        let Some(limit) = self.limits.get(token_id).copied() else {
+            return Ok(());
+        };
+
+        let updates = Self::scopes(Some(owner_id), token_id)
+            .into_iter()
+            .filter_map(|(scope, key)| Some((scope, key?, limit.cap(scope)?)))
+            .map(|(scope, key, cap)| {
+                WithdrawalUsage::record(
+                    self.usage.get(&key).copied(),
+                    amount,
+                    now,
+                    cap,
+                    limit.window(),
+                )
+                .map(|usage| (key, usage))
+                .ok_or(scope)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.usage.extend(updates);
+        Ok(())
+    }
+
+    /// Releases previously recorded withdrawal of `amount` by `owner_id`
+    /// at `now`, so that refunded withdrawals don't consume the limits
+    pub fn release(
+        &mut self,
+        owner_id: &AccountIdRef,
+        token_id: &TokenId,
+        amount: u128,
+        now: Timestamp,
+    ) {
+        let Some(limit) = self.limits.get(token_id).copied() else {
+            return;
+        };
+
+        for (scope, key) in Self::scopes(Some(owner_id), token_id) {
+            let (Some(key), Some(cap)) = (key, limit.cap(scope)) else {
+                continue;
+            };
+            if let Some(usage) = self.usage.get_mut(&key) {
+                *usage = usage.release(amount, now, cap, limit.window());
+            }
+        }
+    }
+
+    fn scopes(
+        owner_id: Option<&AccountIdRef>,
+        token_id: &TokenId,
+    ) -> [(WithdrawalLimitScope, Option<UsageKey>); 2] {
+        [
+            (
+                WithdrawalLimitScope::Total,
+                Some(UsageKey::Total(token_id.clone())),
+            ),
+            (
+                WithdrawalLimitScope::PerAccount,
+                owner_id.map(|owner_id| UsageKey::Account(owner_id.to_owned(), token_id.clone())),
+            ),
+        ]
+    }
+}
+
+#[near(serializers = [borsh])]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum UsageKey {
+    Total(TokenId),
+    Account(AccountId, TokenId),
+}
+
+#[derive(BorshSerialize, BorshStorageKey)]
+#[borsh(crate = "::near_sdk::borsh")]
+enum Prefix {
+    Limits,
+    Usage,
+}
+
+#[near]
+impl WithdrawalLimitsManager for Contract {
+    #[pause(name = "intents")]
+    #[access_control_any(roles(Role::DAO, Role::WithdrawalLimitsManager))]
+    #[payable]
+    fn set_withdrawal_limit(&mut self, token_id: TokenId, limit: Option<WithdrawalLimit>) {
+        assert_one_yocto();
+        require_envelope!(
+            self.withdrawal_limits.set(token_id.clone(), limit),
+            "unchanged",
+            "same"
+        );
+        DefuseEvent::WithdrawalLimitSet(WithdrawalLimitSetEvent {
+            token_id: Cow::Owned(token_id),
+            limit,
+        })
+        .emit();
+    }
+
+    fn withdrawal_limit(&self, token_id: TokenId) -> Option<WithdrawalLimit> {
+        self.withdrawal_limits.get(&token_id).copied()
+    }
+
+    fn withdrawal_limits(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<(TokenId, WithdrawalLimit)> {
+        let iter = self
+            .withdrawal_limits
+            .limits
+            .iter()
+            .skip(from_index.unwrap_or_default().try_into().unwrap())
+            .map(|(token_id, limit)| (token_id.clone(), *limit));
+
+        match limit {
+            Some(l) => iter.take(l.try_into().unwrap()).collect(),
+            None => iter.collect(),
+        }
+    }
+
+    fn withdrawal_limit_available(
+        &self,
+        token_id: TokenId,
+        account_id: Option<AccountId>,
+    ) -> Option<U128> {
+        self.withdrawal_limits
+            .available(account_id.as_deref(), &token_id, Timestamp::now())
+            .map(U128)
+    }
+}
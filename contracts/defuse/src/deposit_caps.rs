@@ -0,0 +1,13 @@
+use defuse_core::token_id::TokenId;
+use near_plugins::AccessControllable;
+use near_sdk::{ext_contract, json_types::U128};
+
+#[ext_contract(ext_deposit_caps_manager)]
+#[allow(clippy::module_name_repetitions)]
+pub trait DepositCapsManager: AccessControllable {
+    /// Sets max total supply of given token, so that NEP-141 and NEP-245
+    /// deposits exceeding it get refunded. `None` removes the cap.
+    fn set_deposit_cap(&mut self, token_id: TokenId, cap: Option<U128>);
+    fn deposit_cap(&self, token_id: TokenId) -> Option<U128>;
+    fn deposit_caps(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<(TokenId, U128)>;
+}
@@ -0,0 +1,38 @@
+use defuse_serde_utils::hex::AsHex;
+use near_plugins::AccessControllable;
+use near_sdk::{AccountId, CryptoHash, ext_contract};
+
+#[ext_contract(ext_erc1271_oracle_manager)]
+pub trait Erc1271OracleManager: AccessControllable {
+    /// Sets EVM signature oracle used to verify ERC-1271 signatures of
+    /// smart contract accounts. `None` disables such signatures.
+    ///
+    /// NOTE: MUST attach 1 yⓃ for security purposes.
+    fn set_erc1271_oracle(&mut self, oracle_id: Option<AccountId>);
+
+    fn erc1271_oracle(&self) -> Option<AccountId>;
+
+    /// Allows or disallows ERC-1271 signatures of smart contract
+    /// accounts deployed on EVM chain with given `chain_id`
+    ///
+    /// NOTE: MUST attach 1 yⓃ for security purposes.
+    fn set_erc1271_chain_allowed(&mut self, chain_id: u64, allowed: bool);
+
+    fn erc1271_chains(&self) -> Vec<u64>;
+}
+
+/// Interface of EVM signature oracle, which has access to the state
+/// of EVM chains
+#[ext_contract(ext_erc1271_oracle)]
+pub trait Erc1271Oracle {
+    /// Returns whether `isValidSignature(hash, signature)` of smart
+    /// contract at `contract` address on EVM chain `chain_id` returns
+    /// ERC-1271 magic value `0x1626ba7e`
+    fn erc1271_is_valid_signature(
+        &self,
+        chain_id: u64,
+        contract: AsHex<[u8; 20]>,
+        hash: AsHex<CryptoHash>,
+        signature: AsHex<Vec<u8>>,
+    ) -> bool;
+}
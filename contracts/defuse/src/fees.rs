@@ -1,4 +1,7 @@
-use defuse_core::fees::Pips;
+use defuse_core::{
+    fees::{FeeExemption, Pips},
+    token_id::TokenId,
+};
 use near_plugins::AccessControllable;
 use near_sdk::{AccountId, ext_contract};
 
@@ -11,4 +14,28 @@ pub trait FeesManager: AccessControllable {
 
     fn set_fee_collector(&mut self, fee_collector: AccountId);
     fn fee_collector(&self) -> &AccountId;
+
+    /// Sets share of collected fees accrued to given collector,
+    /// while `None` removes it. The rest of collected fees goes
+    /// to the fee collector.
+    fn set_fee_share(&mut self, collector: AccountId, share: Option<Pips>);
+    fn fee_shares(&self) -> Vec<(AccountId, Pips)>;
+
+    /// Exempts given accounts and/or tokens from fees
+    fn add_fee_exemptions(&mut self, exemptions: Vec<FeeExemption>);
+    /// Revokes given fee exemptions
+    fn remove_fee_exemptions(&mut self, exemptions: Vec<FeeExemption>);
+    fn is_fee_exemption(&self, exemption: FeeExemption) -> bool;
+    fn fee_exemptions(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<FeeExemption>;
+
+    /// Overrides fee for given token, while `None` resets it
+    /// to the global one
+    fn set_token_fee(&mut self, token_id: TokenId, fee: Option<Pips>);
+    /// Returns fee applied to given token
+    fn token_fee(&self, token_id: TokenId) -> Pips;
+    fn token_fee_overrides(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<(TokenId, Pips)>;
 }
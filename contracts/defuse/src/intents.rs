@@ -1,17 +1,42 @@
 use defuse_core::payload::multi::MultiPayload;
 
 use near_plugins::AccessControllable;
-use near_sdk::{Promise, PublicKey, ext_contract};
+use near_sdk::{AccountId, Promise, PublicKey, ext_contract};
 
 use crate::{fees::FeesManager, salts::SaltManager};
 
-pub use crate::simulation_output::{SimulationOutput, StateOutput};
+pub use crate::simulation_output::{GasEstimate, SimulationOutput, StateOutput};
 
 #[ext_contract(ext_intents)]
 pub trait Intents: FeesManager + SaltManager {
+    /// Verifies and executes signed intents.
+    ///
+    /// Relayer fees of executed intents are paid to the caller.
+    ///
+    /// If any of them is signed by ERC-1271 smart account, then all
+    /// intents are held until EVM signature oracle verifies these
+    /// signatures and executed in a separate receipt.
     fn execute_intents(&mut self, signed: Vec<MultiPayload>);
 
-    fn simulate_intents(&self, signed: Vec<MultiPayload>) -> SimulationOutput;
+    /// Simulates execution of signed intents.
+    ///
+    /// Relayer fees are paid to `relayer_id`, so it's required
+    /// for simulating intents with non-empty `relayer_fee`.
+    ///
+    /// NOTE: ERC-1271 signatures are not verified during simulation.
+    fn simulate_intents(
+        &self,
+        signed: Vec<MultiPayload>,
+        relayer_id: Option<AccountId>,
+    ) -> SimulationOutput;
+
+    /// Returns conservative estimate of gas to attach to
+    /// `execute_intents()` for given signed intents, including static
+    /// gas of withdrawals, notifications, auth calls and their callbacks.
+    ///
+    /// NOTE: neither signatures nor state are checked, so the estimate
+    /// doesn't guarantee that execution succeeds.
+    fn estimate_intents_gas(&self, signed: Vec<MultiPayload>) -> GasEstimate;
 }
 
 #[ext_contract(ext_relayer_keys)]
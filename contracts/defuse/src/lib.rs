@@ -3,6 +3,11 @@
 pub mod contract;
 
 pub mod accounts;
+pub mod allowances;
+#[cfg(feature = "imt")]
+pub mod checkpoints;
+pub mod deposit_caps;
+pub mod erc1271;
 #[cfg(feature = "far")]
 pub mod far;
 pub mod fees;
@@ -10,7 +15,9 @@ pub mod garbage_collector;
 pub mod intents;
 pub mod salts;
 pub mod simulation_output;
+pub mod token_denylist;
 pub mod tokens;
+pub mod withdrawal_limits;
 
 pub use defuse_core as core;
 pub use defuse_nep245 as nep245;
@@ -18,7 +25,8 @@ pub use defuse_nep245 as nep245;
 use defuse_admin_utils::full_access_keys::FullAccessKeys;
 use defuse_controller::ControllerUpgradable;
 use defuse_nep245::{
-    MultiTokenCore, enumeration::MultiTokenEnumeration, receiver::MultiTokenReceiver,
+    MultiTokenCore, enumeration::MultiTokenEnumeration, metadata::MultiTokenMetadata,
+    receiver::MultiTokenReceiver,
 };
 use near_contract_standards::{
     fungible_token::receiver::FungibleTokenReceiver,
@@ -26,15 +34,22 @@ use near_contract_standards::{
 };
 use near_plugins::{AccessControllable, Pausable};
 
-use crate::{accounts::ForceAccountManager, tokens::nep245::MultiTokenForcedCore};
+use crate::{
+    accounts::ForceAccountManager,
+    tokens::nep245::{MultiTokenCursorEnumeration, MultiTokenForcedCore},
+};
 
 use self::{
     accounts::AccountManager,
+    allowances::MultiTokenAllowances,
     intents::{Intents, RelayerKeys},
     tokens::{
-        nep141::{FungibleTokenForceWithdrawer, FungibleTokenWithdrawer},
+        nep141::{FungibleTokenForceWithdrawer, FungibleTokenWithdrawer, NativeDepositor},
         nep171::{NonFungibleTokenForceWithdrawer, NonFungibleTokenWithdrawer},
-        nep245::{MultiTokenForcedWithdrawer, MultiTokenWithdrawer},
+        nep245::{
+            MultiTokenBatchBalances, MultiTokenForcedWithdrawer, MultiTokenMetadataCache,
+            MultiTokenWithdrawer,
+        },
     },
 };
 
@@ -43,9 +58,12 @@ pub trait Defuse:
     + RelayerKeys
     + AccountManager
     + MultiTokenCore
+    + MultiTokenBatchBalances
+    + MultiTokenAllowances
     // NEP-141 deposits/withdrawals
     + FungibleTokenReceiver
     + FungibleTokenWithdrawer
+    + NativeDepositor
     // NEP-171 deposits/withdrawals
     + NonFungibleTokenReceiver
     + NonFungibleTokenWithdrawer
@@ -53,6 +71,9 @@ pub trait Defuse:
     + MultiTokenReceiver
     + MultiTokenWithdrawer
     + MultiTokenEnumeration
+    + MultiTokenCursorEnumeration
+    + MultiTokenMetadata
+    + MultiTokenMetadataCache
     // Governance
     + AccessControllable
     + MultiTokenForcedCore
@@ -1,10 +1,12 @@
-use defuse_core::Salt;
+use defuse_core::{Salt, SaltRotationPolicy};
 use near_sdk::ext_contract;
 
 #[ext_contract(ext_salt_manager)]
 #[allow(clippy::module_name_repetitions)]
 pub trait SaltManager {
-    /// Sets the current salt to a new one, previous salt remains valid.
+    /// Sets the current salt to a new one, previous salt remains valid
+    /// during the grace period of [`SaltRotationPolicy`].
+    /// Fails if called earlier than its min interval after the last rotation.
     /// Returns the new current salt.
     fn update_current_salt(&mut self) -> Salt;
 
@@ -18,4 +20,13 @@ pub trait SaltManager {
 
     /// Returns the current salt
     fn current_salt(&self) -> Salt;
+
+    /// Sets constraints on rotations of the current salt.
+    /// The grace period also applies to salts rotated before.
+    ///
+    /// NOTE: MUST attach 1 yⓃ for security purposes.
+    fn set_salt_rotation_policy(&mut self, policy: SaltRotationPolicy);
+
+    /// Returns constraints on rotations of the current salt
+    fn salt_rotation_policy(&self) -> SaltRotationPolicy;
 }
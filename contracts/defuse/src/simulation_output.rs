@@ -6,7 +6,8 @@ use defuse_core::{
     intents::MaybeIntentEvent,
 };
 
-use near_sdk::near;
+use defuse_near_utils::ErrorEnvelope;
+use near_sdk::{Gas, near};
 
 #[near(serializers = [json])]
 #[derive(Debug, Clone)]
@@ -47,3 +48,25 @@ pub struct StateOutput {
 
     pub current_salt: Salt,
 }
+
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct GasEstimate {
+    /// Estimate for each of signed payloads in the same order
+    pub intents: Vec<PayloadGasEstimate>,
+
+    /// Total gas to attach to `execute_intents()`, which also covers
+    /// signatures verification
+    pub total: Gas,
+}
+
+#[near(serializers = [json])]
+#[serde(tag = "status", rename_all = "snake_case")]
+#[derive(Debug, Clone)]
+pub enum PayloadGasEstimate {
+    /// Gas of each intent within the payload, including static gas
+    /// of receipts scheduled by it
+    Ok { intents: Vec<Gas> },
+    /// The payload can't be decoded, so its execution would fail
+    Err { error: ErrorEnvelope<'static> },
+}
@@ -0,0 +1,14 @@
+use defuse_core::token_id::TokenId;
+use near_plugins::AccessControllable;
+use near_sdk::ext_contract;
+
+#[ext_contract(ext_token_denylist_manager)]
+#[allow(clippy::module_name_repetitions)]
+pub trait TokenDenylistManager: AccessControllable {
+    /// Adds given token to the denylist or removes it from there.
+    /// Deposits and transfers of denied tokens are rejected, while
+    /// withdrawals are still allowed, so that holders can get rid of them.
+    fn set_token_denied(&mut self, token_id: TokenId, denied: bool);
+    fn is_token_denied(&self, token_id: TokenId) -> bool;
+    fn denied_tokens(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<TokenId>;
+}
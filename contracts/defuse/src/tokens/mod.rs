@@ -10,6 +10,7 @@ use core::{
 };
 
 use defuse_core::{intents::tokens::NotifyOnTransfer, payload::multi::MultiPayload};
+use defuse_near_utils::ErrorCode;
 use near_sdk::{AccountId, account_id::ParseAccountError, near, serde_json};
 use thiserror::Error as ThisError;
 
@@ -21,6 +22,10 @@ pub struct DepositMessage {
 
     #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
     pub action: Option<DepositAction>,
+
+    /// Optional referral to attribute the deposit to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referral: Option<AccountId>,
 }
 
 impl DepositMessage {
@@ -29,6 +34,7 @@ impl DepositMessage {
         Self {
             receiver_id,
             action: None,
+            referral: None,
         }
     }
 
@@ -37,17 +43,25 @@ impl DepositMessage {
         self.action = action.into();
         self
     }
+
+    #[inline]
+    pub fn with_referral(mut self, referral: impl Into<Option<AccountId>>) -> Self {
+        self.referral = referral.into();
+        self
+    }
 }
 
 impl Display for DepositMessage {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.action {
-            None => f.write_str(self.receiver_id.as_str()),
-            Some(DepositAction::Execute(exec)) if exec.execute_intents.is_empty() => {
+            None if self.referral.is_none() => f.write_str(self.receiver_id.as_str()),
+            Some(DepositAction::Execute(exec))
+                if exec.execute_intents.is_empty() && self.referral.is_none() =>
+            {
                 f.write_str(self.receiver_id.as_str())
             }
-            Some(_) => f.write_str(&serde_json::to_string(self).unwrap_or_else(|e| panic!("{e}"))),
+            _ => f.write_str(&serde_json::to_string(self).unwrap_or_else(|e| panic!("{e}"))),
         }
     }
 }
@@ -92,6 +106,13 @@ pub enum ParseDepositMessageError {
     JSON(#[from] serde_json::Error),
 }
 
+impl ErrorCode for ParseDepositMessageError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "invalid_deposit_message"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,6 +186,7 @@ mod tests {
             action: Some(DepositAction::Notify(NotifyOnTransfer::new(
                 "hello".to_string(),
             ))),
+            referral: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
 
@@ -182,6 +204,7 @@ mod tests {
                 execute_intents: vec![],
                 refund_if_fails: true,
             })),
+            referral: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
 
@@ -206,6 +229,7 @@ mod tests {
             action: Some(DepositAction::Notify(NotifyOnTransfer::new(
                 "test".to_string(),
             ))),
+            referral: None,
         };
         let display = msg.to_string();
 
@@ -213,6 +237,17 @@ mod tests {
         assert!(display.contains("alice.near"));
     }
 
+    #[test]
+    fn test_display_with_referral() {
+        // Display for message with referral (should be JSON)
+        let msg = DepositMessage::new("alice.near".parse().unwrap())
+            .with_referral("referral.near".parse::<AccountId>().unwrap());
+        let display = msg.to_string();
+
+        assert!(display.starts_with('{'));
+        assert!(display.contains("\"referral\":\"referral.near\""));
+    }
+
     #[test]
     fn test_from_str_simple() {
         // Parse simple account ID
@@ -237,6 +272,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_str_json_with_referral() {
+        // Parse JSON with referral along with notify action
+        let json = r#"{"receiver_id":"alice.near","msg":"test","referral":"referral.near"}"#;
+        let msg: DepositMessage = json.parse().unwrap();
+
+        assert_eq!(msg.receiver_id.as_str(), "alice.near");
+        assert_eq!(msg.referral.unwrap().as_str(), "referral.near");
+        assert!(matches!(msg.action, Some(DepositAction::Notify(_))));
+    }
+
     #[test]
     fn test_from_str_json_with_notify() {
         // Parse JSON with notify action
@@ -286,6 +332,7 @@ mod tests {
                 execute_intents: vec![],
                 refund_if_fails: true,
             })),
+            referral: None,
         };
 
         assert_eq!(msg.receiver_id.as_str(), "alice.near");
@@ -305,6 +352,7 @@ mod tests {
             action: Some(DepositAction::Notify(NotifyOnTransfer::new(
                 "test".to_string(),
             ))),
+            referral: None,
         };
 
         assert_eq!(msg.receiver_id.as_str(), "alice.near");
@@ -20,14 +20,27 @@ pub trait FungibleTokenWithdrawer: FungibleTokenReceiver + FungibleTokenWithdraw
     ) -> PromiseOrValue<U128>;
 }
 
+#[ext_contract(ext_native_depositor)]
+pub trait NativeDepositor {
+    /// Wraps attached NEAR into `wNEAR` and deposits it to `receiver_id`
+    /// or to the caller, if not given. Attached NEAR is refunded to the
+    /// caller if wrapping fails.
+    ///
+    /// Returns amount of `wNEAR` deposited.
+    fn near_deposit(&mut self, receiver_id: Option<AccountId>) -> PromiseOrValue<U128>;
+}
+
 #[ext_contract(ext_ft_withdraw_resolver)]
 pub trait FungibleTokenWithdrawResolver {
+    /// `limited` tells whether the withdrawal was recorded in withdrawal
+    /// limits, so that refunded amounts are released from them
     fn ft_resolve_withdraw(
         &mut self,
         token: AccountId,
         sender_id: AccountId,
         amount: U128,
         is_call: bool,
+        limited: Option<bool>,
     ) -> U128;
 }
 
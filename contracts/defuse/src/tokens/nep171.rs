@@ -24,12 +24,15 @@ pub trait NonFungibleTokenWithdrawer:
 
 #[ext_contract(ext_nft_withdraw_resolver)]
 pub trait NonFungibleTokenWithdrawResolver {
+    /// `limited` tells whether the withdrawal was recorded in withdrawal
+    /// limits, so that refunded amounts are released from them
     fn nft_resolve_withdraw(
         &mut self,
         token: AccountId,
         sender_id: AccountId,
         token_id: TokenId,
         is_call: bool,
+        limited: Option<bool>,
     ) -> bool;
 }
 
@@ -1,8 +1,12 @@
 #![allow(clippy::too_many_arguments)]
 
-use defuse_nep245::{MultiTokenCore, TokenId, receiver::MultiTokenReceiver};
+use defuse_nep245::{
+    MultiTokenCore, Token, TokenId, enumeration::MultiTokenEnumeration,
+    metadata::MultiTokenMetadata, receiver::MultiTokenReceiver,
+};
+use defuse_serde_utils::base64::AsBase64;
 use near_plugins::AccessControllable;
-use near_sdk::{AccountId, PromiseOrValue, ext_contract, json_types::U128};
+use near_sdk::{AccountId, Promise, PromiseOrValue, ext_contract, json_types::U128, near};
 
 #[ext_contract(ext_mt_withdraw)]
 pub trait MultiTokenWithdrawer: MultiTokenReceiver + MultiTokenWithdrawResolver {
@@ -25,6 +29,8 @@ pub trait MultiTokenWithdrawer: MultiTokenReceiver + MultiTokenWithdrawResolver
 
 #[ext_contract(mt_withdraw_resolver)]
 pub trait MultiTokenWithdrawResolver {
+    /// `limited` tells whether the withdrawal was recorded in withdrawal
+    /// limits, so that refunded amounts are released from them
     fn mt_resolve_withdraw(
         &mut self,
         token: AccountId,
@@ -32,6 +38,7 @@ pub trait MultiTokenWithdrawResolver {
         token_ids: Vec<TokenId>,
         amounts: Vec<U128>,
         is_call: bool,
+        limited: Option<bool>,
     ) -> Vec<U128>;
 }
 
@@ -95,3 +102,63 @@ pub trait MultiTokenForcedWithdrawer: MultiTokenWithdrawer + AccessControllable
         msg: Option<String>,
     ) -> PromiseOrValue<Vec<U128>>;
 }
+
+#[ext_contract(ext_mt_batch_balances)]
+pub trait MultiTokenBatchBalances: MultiTokenCore {
+    /// Same as [`.mt_batch_balance_of()`](MultiTokenCore::mt_batch_balance_of),
+    /// but for multiple accounts at once. Returns balances of each of
+    /// `token_ids` for each of `account_ids`, in the same order.
+    fn mt_batch_balance_of_many(
+        &self,
+        account_ids: Vec<AccountId>,
+        token_ids: Vec<TokenId>,
+    ) -> Vec<Vec<U128>>;
+}
+
+/// Opaque cursor for [`MultiTokenCursorEnumeration`]
+pub type MtCursor = AsBase64<Vec<u8>>;
+
+#[near(serializers = [json])]
+#[derive(Debug, Clone)]
+pub struct MtTokensPage {
+    pub tokens: Vec<Token>,
+    /// Cursor to fetch the next page with, `None` if this page is the last one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<MtCursor>,
+}
+
+/// Cursor-based alternative to index-based [`MultiTokenEnumeration`].
+///
+/// Tokens are returned in reverse storage order, so that no token is
+/// skipped even if tokens are added or removed between calls: each token
+/// present during the whole scan is returned at least once. Removing
+/// a token might cause another one, already returned, to be returned
+/// again on subsequent pages.
+#[ext_contract(ext_mt_cursor_enumeration)]
+pub trait MultiTokenCursorEnumeration: MultiTokenEnumeration {
+    /// Returns tokens preceding `cursor`, or from the end if it's `None`
+    fn mt_tokens_page(&self, cursor: Option<MtCursor>, limit: Option<u32>) -> MtTokensPage;
+
+    /// Same as [`.mt_tokens_page()`](Self::mt_tokens_page), but only
+    /// for tokens owned by `account_id`
+    fn mt_tokens_for_owner_page(
+        &self,
+        account_id: AccountId,
+        cursor: Option<MtCursor>,
+        limit: Option<u32>,
+    ) -> MtTokensPage;
+}
+
+/// Metadata of wrapped NEP-141 and NEP-245 tokens is derived from their
+/// underlying contracts. Since it can't be fetched from view methods, it's
+/// cached by [`.mt_metadata_sync()`](Self::mt_metadata_sync) first.
+#[ext_contract(ext_mt_metadata_cache)]
+pub trait MultiTokenMetadataCache: MultiTokenMetadata {
+    /// Fetches metadata of given token from its underlying contract and
+    /// caches it, replacing the previously cached one. Returns whether
+    /// the metadata was cached.
+    ///
+    /// NOTE: MUST attach enough deposit to cover storage of the metadata,
+    /// the rest is refunded.
+    fn mt_metadata_sync(&mut self, token_id: TokenId) -> Promise;
+}
@@ -0,0 +1,27 @@
+use defuse_core::{limits::WithdrawalLimit, token_id::TokenId};
+use near_plugins::AccessControllable;
+use near_sdk::{AccountId, ext_contract, json_types::U128};
+
+#[ext_contract(ext_withdrawal_limits_manager)]
+#[allow(clippy::module_name_repetitions)]
+pub trait WithdrawalLimitsManager: AccessControllable {
+    /// Sets rolling-window withdrawal limit for given token.
+    /// `None` removes the limit.
+    ///
+    /// NOTE: force withdrawals are not subject to limits.
+    fn set_withdrawal_limit(&mut self, token_id: TokenId, limit: Option<WithdrawalLimit>);
+    fn withdrawal_limit(&self, token_id: TokenId) -> Option<WithdrawalLimit>;
+    fn withdrawal_limits(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<(TokenId, WithdrawalLimit)>;
+
+    /// Returns amount of given token which can be withdrawn at the moment
+    /// (by `account_id`, if given), or `None` if there is no limit.
+    fn withdrawal_limit_available(
+        &self,
+        token_id: TokenId,
+        account_id: Option<AccountId>,
+    ) -> Option<U128>;
+}
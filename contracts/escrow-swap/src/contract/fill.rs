@@ -1,7 +1,7 @@
 use std::{borrow::Cow, collections::BTreeMap};
 
 use defuse_near_utils::PromiseExt;
-use defuse_num_utils::{CheckedDiv, CheckedMul};
+use defuse_num_utils::{CheckedDivRound, CheckedMulRound, Rounding};
 use defuse_time::Timestamp;
 use near_sdk::{AccountId, AccountIdRef, FunctionError, Promise, PromiseOrValue};
 
@@ -130,7 +130,8 @@ impl State {
         taker_price: UD128,
         partial_fills_allowed: bool,
     ) -> Result<(u128, u128)> {
-        let taker_want_src = <u128 as CheckedDiv<UD128>>::checked_div(taker_dst_in, taker_price)
+        let taker_want_src = taker_dst_in
+            .checked_div_round(taker_price, Rounding::Floor)
             .ok_or(Error::IntegerOverflow)?;
         if taker_want_src < self.maker_src_remaining {
             if !partial_fills_allowed {
@@ -141,7 +142,7 @@ impl State {
             Ok((
                 self.maker_src_remaining,
                 self.maker_src_remaining
-                    .checked_mul_ceil(taker_price)
+                    .checked_mul_round(taker_price, Rounding::Ceil)
                     .ok_or(Error::IntegerOverflow)?,
             ))
         }
@@ -195,7 +196,8 @@ impl ProtocolFees {
             surplus: if self.surplus.is_zero() {
                 0
             } else {
-                let maker_want_dst = <u128 as CheckedMul<UD128>>::checked_mul(src_out, maker_price)
+                let maker_want_dst = src_out
+                    .checked_mul_round(maker_price, Rounding::Floor)
                     .ok_or(Error::IntegerOverflow)?;
                 let surplus = taker_dst_used.saturating_sub(maker_want_dst);
                 self.surplus.fee_ceil(surplus)
@@ -3,7 +3,7 @@ mod nep141;
 #[cfg(feature = "nep245")]
 mod nep245;
 
-use defuse_near_utils::{promise_result_checked_json, promise_result_checked_void};
+use defuse_near_utils::{PromiseResultError, promise_result_checked_void, promise_result_json};
 use defuse_time::Timestamp;
 use near_sdk::{AccountId, Gas, Promise, PromiseOrValue, json_types::U128, near, serde_json};
 use serde_with::DisplayFromStr;
@@ -161,19 +161,23 @@ impl Sent {
                 #[cfg(feature = "nep141")]
                 TokenIdType::Nep141 => {
                     // `ft_transfer_call` returns successfully transferred amount
-                    match promise_result_checked_json::<U128>(result_idx) {
-                        Ok(Ok(used)) => used.0,
-                        Ok(Err(_deserialize_err)) => 0,
-                        Err(_) => self.amount,
+                    match promise_result_json::<U128>(result_idx) {
+                        Ok(used) => used.0,
+                        Err(PromiseResultError::Deserialize(_)) => 0,
+                        Err(PromiseResultError::Failed | PromiseResultError::TooLong(_)) => {
+                            self.amount
+                        }
                     }
                 }
                 #[cfg(feature = "nep245")]
                 TokenIdType::Nep245 => {
                     // `mt_transfer_call` returns successfully transferred amounts
-                    match promise_result_checked_json::<[U128; 1]>(result_idx) {
-                        Ok(Ok([used])) => used.0,
-                        Ok(Err(_deserialize_err)) => 0,
-                        Err(_) => self.amount,
+                    match promise_result_json::<[U128; 1]>(result_idx) {
+                        Ok([used]) => used.0,
+                        Err(PromiseResultError::Deserialize(_)) => 0,
+                        Err(PromiseResultError::Failed | PromiseResultError::TooLong(_)) => {
+                            self.amount
+                        }
                     }
                 }
             }
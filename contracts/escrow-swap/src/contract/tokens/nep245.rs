@@ -1,6 +1,7 @@
+use defuse_near_utils::require_envelope;
 use defuse_nep245::{ext_mt_core, receiver::MultiTokenReceiver};
 use near_sdk::{
-    AccountId, FunctionError, Gas, NearToken, PromiseOrValue, env, json_types::U128, near, require,
+    AccountId, FunctionError, Gas, NearToken, PromiseOrValue, env, json_types::U128, near,
 };
 
 use crate::{
@@ -19,8 +20,9 @@ impl MultiTokenReceiver for Contract {
         amounts: Vec<U128>,
         msg: String,
     ) -> PromiseOrValue<Vec<U128>> {
-        require!(
+        require_envelope!(
             single(previous_owner_ids).as_ref() == Some(&sender_id),
+            "approvals_not_supported",
             "approvals are not supported"
         );
 
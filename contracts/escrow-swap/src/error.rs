@@ -1,9 +1,10 @@
+use defuse_near_utils::ErrorCode;
 use near_sdk::{FunctionError, borsh::io, serde_json};
 use thiserror::Error as ThisError;
 
 pub type Result<T, E = Error> = ::core::result::Result<T, E>;
 
-#[derive(Debug, ThisError, FunctionError)]
+#[derive(Debug, ThisError)]
 pub enum Error {
     #[error("borsh: {0}")]
     Borsh(io::Error),
@@ -36,3 +37,32 @@ pub enum Error {
     #[error("wrong token")]
     WrongToken,
 }
+
+impl ErrorCode for Error {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Borsh(_) => "borsh",
+            Self::CleanupInProgress => "cleanup_in_progress",
+            Self::Closed => "closed",
+            Self::DeadlineExpired => "deadline_expired",
+            Self::ExcessiveFees => "excessive_fees",
+            Self::ExcessiveGas => "excessive_gas",
+            Self::IntegerOverflow => "integer_overflow",
+            Self::InsufficientAmount => "insufficient_amount",
+            Self::InvalidData => "invalid_data",
+            Self::JSON(_) => "json",
+            Self::PartialFillsNotAllowed => "partial_fills_not_allowed",
+            Self::PriceTooLow => "price_too_low",
+            Self::SameTokens => "same_tokens",
+            Self::Unauthorized => "unauthorized",
+            Self::WrongToken => "wrong_token",
+        }
+    }
+}
+
+impl FunctionError for Error {
+    #[inline]
+    fn panic(&self) -> ! {
+        self.panic_with_envelope()
+    }
+}
@@ -196,8 +196,8 @@ impl Params {
 
     fn validate_fees(&self) -> Result<()> {
         self.total_fee()
-            .is_some_and(|total| total <= Self::MAX_FEE)
-            .then_some(())
+            .and_then(|total| total.at_most(Self::MAX_FEE))
+            .map(|_| ())
             .ok_or(Error::ExcessiveFees)
     }
 }
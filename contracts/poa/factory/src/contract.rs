@@ -2,7 +2,7 @@ use core::iter;
 use std::collections::{HashMap, HashSet};
 
 use defuse_admin_utils::full_access_keys::FullAccessKeys;
-use defuse_near_utils::gas_left;
+use defuse_near_utils::{GasGuard, StorageTracker};
 use defuse_poa_token::ext_poa_fungible_token;
 use near_contract_standards::fungible_token::{core::ext_ft_core, metadata::FungibleTokenMetadata};
 use near_plugins::{
@@ -99,15 +99,11 @@ impl PoaFactory for Contract {
             metadata.assert_valid();
         }
 
-        let initial_storage = env::storage_usage();
+        let storage = StorageTracker::start();
         require!(self.tokens.insert(token.clone()), "token exists");
-        let current_storage = env::storage_usage();
         require!(
             env::attached_deposit()
-                >= POA_TOKEN_INIT_BALANCE.saturating_add(
-                    env::storage_byte_cost()
-                        .saturating_mul(current_storage.saturating_sub(initial_storage).into())
-                ),
+                >= POA_TOKEN_INIT_BALANCE.saturating_add(storage.finish().cost()),
             "not enough deposit attached to deploy PoA token"
         );
 
@@ -158,11 +154,9 @@ impl PoaFactory for Contract {
         let token_id = Self::token_id(token);
 
         if let Some(msg) = msg {
-            require!(
-                gas_left()
-                    > POA_TOKEN_FT_DEPOSIT_GAS.saturating_add(POA_TOKEN_FT_TRANSFER_CALL_MIN_GAS),
-                "insufficient gas"
-            );
+            GasGuard::new(POA_TOKEN_FT_DEPOSIT_GAS)
+                .reserve(POA_TOKEN_FT_TRANSFER_CALL_MIN_GAS)
+                .require("ft_deposit");
             ext_poa_fungible_token::ext(token_id.clone())
                 .with_attached_deposit(env::attached_deposit())
                 .with_static_gas(POA_TOKEN_FT_DEPOSIT_GAS)
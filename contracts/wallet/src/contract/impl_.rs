@@ -194,6 +194,24 @@ contract_impl! {
         }
 
     }
+
+    #[cfg_attr(
+        feature = "ton-w5",
+        near(contract_metadata(
+            standard(standard = "wallet-ton-w5", version = "1.0.0")
+        ))
+    )] {
+        use defuse_crypto::Ed25519;
+
+        use crate::signature::TonW5;
+
+        impl ContractImpl for Contract {
+            /// Ed25519 signature over TON wallet v5 signed request,
+            /// so TON wallets can sign requests as if it was their
+            /// own external message.
+            type SigningStandard = TonW5<Ed25519>;
+        }
+    }
 }
 
 impl Deref for Contract {
@@ -1,13 +1,17 @@
 mod borsh;
 mod domain;
-#[cfg(feature = "ed25519")]
+#[cfg(any(feature = "ed25519", feature = "ton-w5"))]
 pub mod ed25519;
 mod hash;
 pub mod no_sign;
+#[cfg(feature = "ton-w5")]
+mod ton_w5;
 
 #[cfg(feature = "webauthn")]
 pub mod webauthn;
 
+#[cfg(feature = "ton-w5")]
+pub use self::ton_w5::*;
 pub use self::{borsh::*, domain::*, hash::*};
 
 /// Signing standard, which defines the public key and how `signature` on
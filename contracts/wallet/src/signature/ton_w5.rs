@@ -0,0 +1,69 @@
+use core::marker::PhantomData;
+
+use defuse_digest::{Digest, sha2::Sha256};
+use defuse_wallet_core::RequestMessage;
+
+use crate::signature::SigningStandard;
+
+/// [`SigningStandard`] mirroring signed external messages of
+/// [TON wallet v5](https://github.com/ton-blockchain/wallet-contract-v5),
+/// so that the wallet-contract can be driven by a TON wallet key.
+///
+/// The underlying signing standard `S` verifies the signature over
+/// representation hash of the following cell:
+/// ```tlb
+/// signed_request#7369676e wallet_id:int32 valid_until:uint32
+///                         msg_seqno:uint32 request_hash:bits256 = SignedRequest;
+/// ```
+/// where:
+/// * `wallet_id` is first 4 bytes of `sha256(chain_id)`
+/// * `valid_until` is `created_at + timeout` in UNIX seconds
+/// * `msg_seqno` is `nonce`
+/// * `request_hash` is [`RequestMessage::hash`]
+pub struct TonW5<S>(PhantomData<S>)
+where
+    S: SigningStandard<[u8; 32]> + ?Sized;
+
+impl<S> TonW5<S>
+where
+    S: SigningStandard<[u8; 32]> + ?Sized,
+{
+    /// `signed_request#7369676e`, i.e. `"sign"`
+    pub const PREFIX: [u8; 4] = *b"sign";
+
+    pub fn wallet_id(chain_id: &str) -> i32 {
+        let hash: [u8; 32] = Sha256::digest(chain_id).into();
+        i32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+    }
+
+    /// Representation hash of `SignedRequest` cell
+    pub fn hash(msg: &RequestMessage) -> Option<[u8; 32]> {
+        let valid_until =
+            u32::try_from(msg.created_at.checked_add_unsigned(msg.timeout)?.as_secs()).ok()?;
+
+        Some(
+            Sha256::new()
+                // d1: no references, ordinary cell, level 0
+                // d2: 48 bytes of data, which is aligned to byte boundary
+                .chain_update([0x00, 0x60])
+                .chain_update(Self::PREFIX)
+                .chain_update(Self::wallet_id(&msg.chain_id).to_be_bytes())
+                .chain_update(valid_until.to_be_bytes())
+                .chain_update(msg.nonce.to_be_bytes())
+                .chain_update(msg.hash())
+                .finalize()
+                .into(),
+        )
+    }
+}
+
+impl<S> SigningStandard<&RequestMessage> for TonW5<S>
+where
+    S: SigningStandard<[u8; 32]> + ?Sized,
+{
+    type PublicKey = S::PublicKey;
+
+    fn verify(msg: &RequestMessage, public_key: &Self::PublicKey, signature: &str) -> bool {
+        Self::hash(msg).is_some_and(|hash| S::verify(hash, public_key, signature))
+    }
+}
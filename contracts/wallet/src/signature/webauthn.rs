@@ -22,6 +22,6 @@ where
             return false;
         };
 
-        signature.verify(msg, public_key, UserVerification::Ignore)
+        signature.verify(msg, public_key, VerifyOptions::default())
     }
 }
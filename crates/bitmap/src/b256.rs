@@ -87,26 +87,40 @@ where
         }
     }
 
+    /// Iterate over set U256 with given prefix
+    #[inline]
+    pub fn iter_by_prefix(&self, prefix: U248) -> impl Iterator<Item = U256> + '_ {
+        self.0
+            .get(&prefix)
+            .into_iter()
+            .flat_map(move |bitmap| Self::iter_bitmap(prefix, bitmap))
+    }
+
     /// Iterate over set U256
     #[inline]
     pub fn as_iter(&self) -> impl Iterator<Item = U256> + '_
     where
         T: IterableMap,
     {
-        self.0.iter().flat_map(|(prefix, bitmap)| {
-            (0..=u8::MAX)
-                .filter(|&bit_pos| {
-                    let byte = bitmap[usize::from(bit_pos / 8)];
-                    let byte_mask = 1 << (bit_pos % 8);
-                    byte & byte_mask != 0
-                })
-                .map(|bit_pos| {
-                    let mut nonce: U256 = [0; 32];
-                    nonce[..prefix.len()].copy_from_slice(prefix);
-                    nonce[prefix.len()..].copy_from_slice(&[bit_pos]);
-                    nonce
-                })
-        })
+        self.0
+            .iter()
+            .flat_map(|(prefix, bitmap)| Self::iter_bitmap(*prefix, bitmap))
+    }
+
+    #[inline]
+    fn iter_bitmap(prefix: U248, bitmap: &U256) -> impl Iterator<Item = U256> + '_ {
+        (0..=u8::MAX)
+            .filter(|&bit_pos| {
+                let byte = bitmap[usize::from(bit_pos / 8)];
+                let byte_mask = 1 << (bit_pos % 8);
+                byte & byte_mask != 0
+            })
+            .map(move |bit_pos| {
+                let mut nonce: U256 = [0; 32];
+                nonce[..prefix.len()].copy_from_slice(&prefix);
+                nonce[prefix.len()..].copy_from_slice(&[bit_pos]);
+                nonce
+            })
     }
 }
 
@@ -155,5 +169,11 @@ mod tests {
 
         let all: HashSet<_> = m.as_iter().collect();
         assert_eq!(all, nonces.iter().copied().collect());
+
+        let by_prefix: HashSet<_> = nonces
+            .iter()
+            .flat_map(|[prefix @ .., _]| m.iter_by_prefix(*prefix))
+            .collect();
+        assert_eq!(by_prefix, all);
     }
 }
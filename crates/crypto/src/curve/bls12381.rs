@@ -0,0 +1,690 @@
+#[cfg(not(near))]
+use {
+    blstrs::{Bls12, G1Affine, G2Affine, G2Projective},
+    pairing::{
+        MillerLoopResult, MultiMillerLoop,
+        group::{Curve as _, Group, prime::PrimeCurveAffine},
+    },
+};
+
+use crate::{Curve, Payload, SignedPayload, VerifiableCurve};
+
+/// BLS signatures over BLS12-381 in "minimal-pubkey-size" variant, i.e.
+/// public keys are in G1 and signatures are in G2, with proof-of-possession
+/// ciphersuite, see
+/// [draft-irtf-cfrg-bls-signature](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05)
+pub struct Bls12381;
+
+impl Bls12381 {
+    pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    /// Domain separation tag for proofs of possession
+    pub const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    /// Verifies that `proof` is a signature of `public_key` itself, i.e.
+    /// `PopVerify`, which proves the possession of the corresponding
+    /// secret key.
+    pub fn verify_proof_of_possession(
+        public_key: &<Self as Curve>::PublicKey,
+        proof: &<Self as Curve>::Signature,
+    ) -> bool {
+        let (Some(pk), Some(proof)) = (g1_from_compressed(public_key), g2_from_compressed(proof))
+        else {
+            return false;
+        };
+        pairing_check(&pk, public_key, Self::POP_DST, &proof)
+    }
+
+    /// Verifies aggregated signature of the same message by all given
+    /// public keys, i.e. `FastAggregateVerify`.
+    ///
+    /// **NOTE**: in order to prevent rogue-key attacks, callers MUST
+    /// ensure that the possession of each public key was proven
+    /// beforehand, see [`Bls12381::verify_proof_of_possession`].
+    pub fn verify_aggregate(
+        signature: &<Self as Curve>::Signature,
+        message: &<Self as Curve>::Message,
+        public_keys: &[<Self as Curve>::PublicKey],
+    ) -> bool {
+        if public_keys.is_empty() {
+            return false;
+        }
+
+        let Some(public_key) = public_keys
+            .iter()
+            .map(g1_from_compressed)
+            .collect::<Option<Vec<_>>>()
+            .as_deref()
+            .and_then(g1_sum)
+        else {
+            return false;
+        };
+
+        g2_from_compressed(signature)
+            .is_some_and(|signature| pairing_check(&public_key, message, Self::DST, &signature))
+    }
+
+    /// Aggregates signatures into a single one
+    #[cfg(not(near))]
+    pub fn aggregate_signatures<'a>(
+        signatures: impl IntoIterator<Item = &'a <Self as Curve>::Signature>,
+    ) -> Option<<Self as Curve>::Signature> {
+        signatures
+            .into_iter()
+            .map(g2_from_compressed)
+            .try_fold(G2Projective::identity(), |acc, sig| {
+                Some(acc + G2Projective::from(sig?))
+            })
+            .map(|aggregated| aggregated.to_affine().to_compressed())
+    }
+}
+
+impl Curve for Bls12381 {
+    /// Compressed G1 point
+    type PublicKey = [u8; 48];
+
+    /// Compressed G2 point
+    type Signature = [u8; 96];
+
+    /// Arbitrary message to be hashed to G2
+    type Message = [u8];
+
+    type VerifyingKey = Self::PublicKey;
+}
+
+impl VerifiableCurve for Bls12381 {
+    fn verify(
+        signature: &Self::Signature,
+        message: &Self::Message,
+        public_key: &Self::VerifyingKey,
+    ) -> Option<Self::PublicKey> {
+        pairing_check(
+            &g1_from_compressed(public_key)?,
+            message,
+            Self::DST,
+            &g2_from_compressed(signature)?,
+        )
+        .then_some(public_key)
+        .copied()
+    }
+}
+
+/// Uncompressed G1 point as encoded by host functions
+#[cfg(near)]
+type G1 = [u8; 96];
+/// Uncompressed G2 point as encoded by host functions
+#[cfg(near)]
+type G2 = [u8; 192];
+#[cfg(not(near))]
+type G1 = G1Affine;
+#[cfg(not(near))]
+type G2 = G2Affine;
+
+/// Negated generator of G1, uncompressed
+#[cfg_attr(not(near), allow(dead_code))]
+const MINUS_G1_GENERATOR: [u8; 96] = hex_literal::hex!(
+    "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+    "114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca"
+);
+
+/// Checks that `e(pk, H(m)) == e(g1, sig)`
+fn pairing_check(public_key: &G1, message: &[u8], dst: &[u8], signature: &G2) -> bool {
+    let hm = hash_to_g2(message, dst);
+
+    cfg_select! {
+        near => {
+            // host function also checks that all points are in
+            // corresponding prime-order subgroups
+            host::pairing_check(
+                &[
+                    public_key.as_slice(),
+                    hm.as_slice(),
+
+                    MINUS_G1_GENERATOR.as_slice(),
+                    signature.as_slice(),
+                ]
+                .concat(),
+            )
+        }
+        _ => {
+            Bls12::multi_miller_loop(&[
+                (public_key, &hm.into()),
+                (&-G1Affine::generator(), &(*signature).into()),
+            ])
+            .final_exponentiation()
+            .is_identity()
+            .into()
+        }
+    }
+}
+
+/// Sum of given non-empty set of points, returns `None` if any of them
+/// is not in the prime-order subgroup
+#[cfg_attr(not(near), allow(clippy::unnecessary_wraps))]
+fn g1_sum(points: &[G1]) -> Option<G1> {
+    cfg_select! {
+        near => {
+            /// Scalar `1` in little-endian
+            const ONE: [u8; 32] = {
+                let mut one = [0; 32];
+                one[0] = 1;
+                one
+            };
+
+            // unlike p1_sum, multiexp checks subgroup membership of
+            // each point
+            host::call(
+                near_sys::bls12381_g1_multiexp,
+                &points
+                    .iter()
+                    .flat_map(|p| p.iter().chain(&ONE))
+                    .copied()
+                    .collect::<Vec<_>>(),
+            )
+        }
+        _ => {
+            // points are already checked to be torsion-free on
+            // decompression
+            Some(
+                points
+                    .iter()
+                    .map(blstrs::G1Projective::from)
+                    .sum::<blstrs::G1Projective>()
+                    .to_affine(),
+            )
+        }
+    }
+}
+
+/// `hash_to_curve` with `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite, see
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.8.2)
+fn hash_to_g2(message: &[u8], dst: &[u8]) -> G2 {
+    cfg_select! {
+        near => {
+            // u[0] and u[1], each of them is an element of Fp2, which is
+            // encoded by host functions as `c1 || c0`
+            let [u0c0, u0c1, u1c0, u1c1] = hash_to_field(message, dst);
+            let q: [u8; 2 * size_of::<G2>()] = host::call(
+                near_sys::bls12381_map_fp2_to_g2,
+                &[u0c1, u0c0, u1c1, u1c0].concat(),
+            )
+            .expect("map_fp2_to_g2: field elements are reduced");
+            let (q0, q1) = q.split_at(size_of::<G2>());
+
+            // map_to_curve() already clears cofactor
+            host::call(
+                near_sys::bls12381_p2_sum,
+                &[
+                    &[0u8], // + (positive)
+                    q0,
+                    &[0u8], // + (positive)
+                    q1,
+                ]
+                .concat(),
+            )
+            .expect("p2_sum: mapped points are on curve")
+        }
+        _ => G2Projective::hash_to_curve(message, dst, b"").to_affine(),
+    }
+}
+
+/// `hash_to_field(msg, 2)` for Fp2, i.e. big-endian coordinates
+/// `[u0.c0, u0.c1, u1.c0, u1.c1]`
+#[cfg_attr(not(near), allow(dead_code))]
+fn hash_to_field(message: &[u8], dst: &[u8]) -> [[u8; 48]; 4] {
+    /// `L = ceil((ceil(log2(p)) + k) / 8)`, where `k = 128`
+    const L: usize = 64;
+
+    let uniform = expand_message_xmd::<{ 4 * L }>(message, dst);
+    core::array::from_fn(|i| {
+        fp_reduce(
+            uniform[i * L..(i + 1) * L]
+                .try_into()
+                .unwrap_or_else(|_| unreachable!()),
+        )
+    })
+}
+
+/// `expand_message_xmd` with SHA-256, see
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.1)
+#[cfg_attr(not(near), allow(dead_code))]
+fn expand_message_xmd<const N: usize>(message: &[u8], dst: &[u8]) -> [u8; N] {
+    use defuse_digest::{Digest, sha2::Sha256};
+
+    const B_IN_BYTES: usize = 32;
+    const S_IN_BYTES: usize = 64;
+
+    let ell = N.div_ceil(B_IN_BYTES);
+    let (Ok(ell), Ok(len), Ok(dst_len)) =
+        (u8::try_from(ell), u16::try_from(N), u8::try_from(dst.len()))
+    else {
+        unreachable!("expand_message_xmd: invalid params")
+    };
+
+    let b0: [u8; B_IN_BYTES] = Sha256::new()
+        .chain_update([0; S_IN_BYTES])
+        .chain_update(message)
+        .chain_update(len.to_be_bytes())
+        .chain_update([0])
+        .chain_update(dst)
+        .chain_update([dst_len])
+        .finalize()
+        .into();
+
+    let mut uniform = [0; N];
+    let mut bi = [0; B_IN_BYTES];
+    for (i, chunk) in (1..=ell).zip(uniform.chunks_mut(B_IN_BYTES)) {
+        for (b, b0) in bi.iter_mut().zip(b0) {
+            *b ^= b0;
+        }
+        bi = Sha256::new()
+            .chain_update(bi)
+            .chain_update([i])
+            .chain_update(dst)
+            .chain_update([dst_len])
+            .finalize()
+            .into();
+        chunk.copy_from_slice(&bi[..chunk.len()]);
+    }
+    uniform
+}
+
+/// Reduces big-endian integer modulo field characteristic `p`
+#[cfg_attr(not(near), allow(dead_code))]
+fn fp_reduce(be_bytes: &[u8; 64]) -> [u8; 48] {
+    /// `p` as little-endian limbs
+    const P: [u64; 6] = [
+        0xb9fe_ffff_ffff_aaab,
+        0x1eab_fffe_b153_ffff,
+        0x6730_d2a0_f6b0_f624,
+        0x6477_4b84_f385_12bf,
+        0x4b1b_a7b6_434b_acd7,
+        0x1a01_11ea_397f_e69a,
+    ];
+
+    // shift-and-subtract: `r < p < 2^381` always fits into 6 limbs
+    // after doubling
+    let mut r = [0u64; 6];
+    for byte in be_bytes {
+        for bit in (0..8).rev() {
+            let mut carry = u64::from((byte >> bit) & 1);
+            for limb in &mut r {
+                let next = *limb >> 63;
+                *limb = (*limb << 1) | carry;
+                carry = next;
+            }
+
+            // r >= p
+            if r.iter().rev().cmp(P.iter().rev()).is_ge() {
+                let mut borrow = false;
+                for (limb, p) in r.iter_mut().zip(P) {
+                    let (d, b1) = limb.overflowing_sub(p);
+                    let (d, b2) = d.overflowing_sub(u64::from(borrow));
+                    *limb = d;
+                    borrow = b1 | b2;
+                }
+            }
+        }
+    }
+
+    let mut out = [0; 48];
+    for (chunk, limb) in out.chunks_exact_mut(8).zip(r.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    out
+}
+
+/// Flag of compressed encoding, which marks the point at infinity
+#[cfg(near)]
+const INFINITY_FLAG: u8 = 0x40;
+
+#[inline]
+fn g1_from_compressed(bytes: &[u8; 48]) -> Option<G1> {
+    cfg_select! {
+        near => {
+            // host function only checks that the point is on curve, while
+            // subgroup is checked by pairing and multiexp
+            if bytes[0] & INFINITY_FLAG != 0 {
+                return None;
+            }
+            host::call(near_sys::bls12381_p1_decompress, bytes)
+        }
+        _ => Option::from(G1Affine::from_compressed(bytes)).filter(|p: &G1Affine| {
+            (!p.is_identity() & p.is_on_curve() & p.is_torsion_free()).into()
+        }),
+    }
+}
+
+#[inline]
+fn g2_from_compressed(bytes: &[u8; 96]) -> Option<G2> {
+    cfg_select! {
+        near => {
+            // host function only checks that the point is on curve, while
+            // subgroup is checked by pairing
+            if bytes[0] & INFINITY_FLAG != 0 {
+                return None;
+            }
+            host::call(near_sys::bls12381_p2_decompress, bytes)
+        }
+        _ => Option::from(G2Affine::from_compressed(bytes)).filter(|p: &G2Affine| {
+            (!p.is_identity() & p.is_on_curve() & p.is_torsion_free()).into()
+        }),
+    }
+}
+
+#[cfg(near)]
+mod host {
+    /// Same register as `near_sdk` uses for atomic operations
+    const REGISTER_ID: u64 = u64::MAX - 2;
+
+    /// Calls BLS12-381 host function, which writes the result to the
+    /// register on success, or returns non-zero status on invalid input
+    pub fn call<const N: usize>(
+        f: unsafe extern "C" fn(u64, u64, u64) -> u64,
+        input: &[u8],
+    ) -> Option<[u8; N]> {
+        let mut output = [0; N];
+        // SAFETY: `input` is a valid memory region and the register is
+        // only read after the host function has written to it
+        unsafe {
+            if f(len(input), addr(input.as_ptr()), REGISTER_ID) != 0
+                || near_sys::register_len(REGISTER_ID) != len(&output)
+            {
+                return None;
+            }
+            near_sys::read_register(REGISTER_ID, addr(output.as_mut_ptr()));
+        }
+        Some(output)
+    }
+
+    pub fn pairing_check(input: &[u8]) -> bool {
+        // SAFETY: `input` is a valid memory region
+        unsafe { near_sys::bls12381_pairing_check(len(input), addr(input.as_ptr())) == 0 }
+    }
+
+    #[inline]
+    fn len(data: &[u8]) -> u64 {
+        data.len().try_into().unwrap_or_else(|_| unreachable!())
+    }
+
+    #[inline]
+    fn addr(ptr: *const u8) -> u64 {
+        ptr.addr().try_into().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+/// Payload signed by multiple parties with a single aggregated
+/// BLS signature
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Debug, Clone)]
+pub struct AggregatedSignedPayload<P> {
+    pub payload: P,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "::serde_with::As::<Vec<crate::serde::AsCurve<Bls12381>>>"),
+        cfg_attr(feature = "abi", schemars(with = "Vec<String>"))
+    )]
+    pub public_keys: Vec<<Bls12381 as Curve>::PublicKey>,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "::serde_with::As::<crate::serde::AsCurve<Bls12381>>"),
+        cfg_attr(feature = "abi", schemars(with = "String"))
+    )]
+    pub signature: <Bls12381 as Curve>::Signature,
+}
+
+impl<P> Payload for AggregatedSignedPayload<P>
+where
+    P: Payload,
+{
+    #[inline]
+    fn hash(&self) -> crate::CryptoHash {
+        self.payload.hash()
+    }
+}
+
+impl<P> SignedPayload for AggregatedSignedPayload<P>
+where
+    P: Payload,
+{
+    /// Public keys of all signers
+    type PublicKey = Vec<<Bls12381 as Curve>::PublicKey>;
+
+    /// Verifies that all public keys signed the hash of the payload.
+    ///
+    /// **NOTE**: see [`Bls12381::verify_aggregate`] for rogue-key attacks
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        Bls12381::verify_aggregate(&self.signature, &self.payload.hash(), &self.public_keys)
+            .then(|| self.public_keys.clone())
+    }
+}
+
+#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bls12381PublicKey(
+    // schemars ignores `with` at struct level for newtypes; must be on the field
+    #[cfg_attr(all(feature = "abi", feature = "serde"), schemars(with = "String"))]
+    pub  <Bls12381 as Curve>::PublicKey,
+);
+
+#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bls12381Signature(
+    // schemars ignores `with` at struct level for newtypes; must be on the field
+    #[cfg_attr(all(feature = "abi", feature = "serde"), schemars(with = "String"))]
+    pub  <Bls12381 as Curve>::Signature,
+);
+
+#[cfg(feature = "parse")]
+const _: () = {
+    use crate::{CurveType, ParseCurveError, TypedCurve};
+    use core::fmt::{self, Debug, Display};
+    use std::str::FromStr;
+
+    impl TypedCurve for Bls12381 {
+        const CURVE_TYPE: CurveType = CurveType::Bls12381;
+    }
+
+    impl Debug for Bls12381PublicKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    impl Display for Bls12381PublicKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&<Bls12381 as TypedCurve>::to_base58(self.0))
+        }
+    }
+
+    impl FromStr for Bls12381PublicKey {
+        type Err = ParseCurveError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Bls12381::parse_base58(s).map(Self)
+        }
+    }
+
+    impl Debug for Bls12381Signature {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    impl Display for Bls12381Signature {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&<Bls12381 as TypedCurve>::to_base58(self.0))
+        }
+    }
+
+    impl FromStr for Bls12381Signature {
+        type Err = ParseCurveError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Bls12381::parse_base58(s).map(Self)
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use blstrs::{G1Projective, Scalar};
+    use hex_literal::hex;
+    use pairing::group::Curve as _;
+
+    use super::*;
+
+    struct Message(&'static [u8; 32]);
+
+    impl Payload for Message {
+        fn hash(&self) -> crate::CryptoHash {
+            *self.0
+        }
+    }
+
+    fn keypair(sk: u64) -> (Scalar, [u8; 48]) {
+        let sk = Scalar::from(sk);
+        (
+            sk,
+            (G1Projective::generator() * sk).to_affine().to_compressed(),
+        )
+    }
+
+    fn sign(sk: Scalar, message: &[u8]) -> [u8; 96] {
+        (G2Projective::hash_to_curve(message, Bls12381::DST, b"") * sk)
+            .to_affine()
+            .to_compressed()
+    }
+
+    #[test]
+    fn verify() {
+        let (sk, pk) = keypair(42);
+        let signature = sign(sk, b"hello");
+
+        assert_eq!(Bls12381::verify(&signature, b"hello", &pk), Some(pk));
+        assert_eq!(Bls12381::verify(&signature, b"world", &pk), None);
+    }
+
+    #[test]
+    fn verify_aggregate() {
+        let message = Message(&[7; 32]);
+        let signers = [1, 2, 3].map(keypair);
+        let signatures = signers.map(|(sk, _)| sign(sk, &message.hash()));
+
+        let payload = AggregatedSignedPayload {
+            payload: message,
+            public_keys: signers.iter().map(|(_, pk)| *pk).collect(),
+            signature: Bls12381::aggregate_signatures(&signatures).unwrap(),
+        };
+        assert_eq!(payload.verify(), Some(payload.public_keys.clone()));
+
+        // one of the signers is missing
+        let partial = AggregatedSignedPayload {
+            signature: Bls12381::aggregate_signatures(&signatures[..2]).unwrap(),
+            ..payload
+        };
+        assert_eq!(partial.verify(), None);
+    }
+
+    #[test]
+    fn verify_proof_of_possession() {
+        let (sk, pk) = keypair(42);
+        let proof = (G2Projective::hash_to_curve(&pk, Bls12381::POP_DST, b"") * sk)
+            .to_affine()
+            .to_compressed();
+
+        assert!(Bls12381::verify_proof_of_possession(&pk, &proof));
+        // signature of the public key with signing DST is not a proof
+        assert!(!Bls12381::verify_proof_of_possession(&pk, &sign(sk, &pk)));
+        assert!(!Bls12381::verify_proof_of_possession(
+            &keypair(43).1,
+            &proof
+        ));
+    }
+
+    #[test]
+    fn minus_g1_generator() {
+        assert_eq!(
+            MINUS_G1_GENERATOR,
+            (-G1Affine::generator()).to_uncompressed()
+        );
+    }
+
+    #[test]
+    fn hash_to_g2() {
+        for message in [b"".as_slice(), b"abc", &[0xff; 200]] {
+            assert_eq!(
+                super::hash_to_g2(message, Bls12381::DST),
+                G2Projective::hash_to_curve(message, Bls12381::DST, b"").to_affine(),
+            );
+        }
+    }
+
+    #[test]
+    fn fp_reduce() {
+        const P: [u8; 48] = hex!(
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"
+        );
+        let wide = |be: [u8; 48]| {
+            let mut wide = [0; 64];
+            wide[16..].copy_from_slice(&be);
+            wide
+        };
+
+        assert_eq!(super::fp_reduce(&[0; 64]), [0; 48]);
+        assert_eq!(super::fp_reduce(&wide(P)), [0; 48]);
+
+        let mut p_plus_one = P;
+        *p_plus_one.last_mut().unwrap() += 1;
+        let mut one = [0; 48];
+        one[47] = 1;
+        assert_eq!(super::fp_reduce(&wide(p_plus_one)), one);
+
+        // (2^512 - 1) mod p
+        assert_eq!(
+            super::fp_reduce(&[0xff; 64]),
+            hex!(
+                "02cb5d3a884e56c4fab7cd07ee4e16bc15efebb5d396d7cf82383087033108464532383fa8eaff4e967d3988a62b6c9c"
+            ),
+        );
+    }
+
+    #[test]
+    fn verify_aggregate_empty() {
+        let (sk, _) = keypair(1);
+        assert!(!Bls12381::verify_aggregate(
+            &sign(sk, b"hello"),
+            b"hello",
+            &[]
+        ));
+    }
+}
@@ -1,3 +1,8 @@
+#[cfg(feature = "bls12381")]
+mod bls12381;
+#[cfg(feature = "bls12381")]
+pub use self::bls12381::*;
+
 #[cfg(feature = "ed25519")]
 mod ed25519;
 #[cfg(feature = "ed25519")]
@@ -13,6 +18,21 @@ mod p256;
 #[cfg(feature = "p256")]
 pub use self::p256::*;
 
+#[cfg(feature = "rsa")]
+mod rsa;
+#[cfg(feature = "rsa")]
+pub use self::rsa::*;
+
+#[cfg(feature = "schnorr-secp256k1")]
+mod schnorr_secp256k1;
+#[cfg(feature = "schnorr-secp256k1")]
+pub use self::schnorr_secp256k1::*;
+
+#[cfg(feature = "stark")]
+mod stark;
+#[cfg(feature = "stark")]
+pub use self::stark::*;
+
 pub trait Curve {
     type PublicKey;
     type Signature;
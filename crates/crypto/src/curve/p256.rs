@@ -187,3 +187,21 @@ pub fn compress_public_key(public_key: P256UncompressedPublicKey) -> P256Compres
         .try_into()
         .map_or_else(|_| unreachable!(), P256CompressedPublicKey)
 }
+
+/// Converts from compressed form (i.e. `x` coordinate with leading SEC1
+/// tag byte) into untagged uncompressed form (i.e. concatenated `x || y`
+/// coordinates with no leading SEC1 tag byte)
+pub fn decompress_public_key(
+    public_key: &P256CompressedPublicKey,
+) -> Option<P256UncompressedPublicKey> {
+    let uncompressed: [u8; 65] = VerifyingKey::from_sec1_bytes(&public_key.0)
+        .ok()?
+        .to_encoded_point(false)
+        .as_bytes()
+        .try_into()
+        .ok()?;
+    let [0x04, untagged @ ..] = uncompressed else {
+        return None;
+    };
+    Some(P256UncompressedPublicKey(untagged))
+}
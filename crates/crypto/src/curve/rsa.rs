@@ -0,0 +1,374 @@
+use crate::{CryptoHash, Curve, VerifiableCurve};
+
+/// RSA with 2048-bit modulus and public exponent `e = 65537`.
+/// Signatures are verified according to RSASSA-PKCS1-v1_5 with SHA-256,
+/// see [RFC 8017](https://datatracker.ietf.org/doc/html/rfc8017#section-8.2)
+pub struct Rsa2048;
+
+impl Rsa2048 {
+    /// Length of the modulus in bytes
+    pub const MODULUS_LEN: usize = 256;
+
+    /// The only supported public exponent
+    pub const PUBLIC_EXPONENT: u32 = 65537;
+
+    /// DER-encoded `DigestInfo` prefix for SHA-256, see
+    /// [RFC 8017](https://datatracker.ietf.org/doc/html/rfc8017#section-9.2)
+    const SHA256_DIGEST_INFO: [u8; 19] = [
+        0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+        0x05, 0x00, 0x04, 0x20,
+    ];
+
+    /// `EMSA-PKCS1-v1_5` encoding:
+    /// `0x00 || 0x01 || 0xFF..0xFF || 0x00 || DigestInfo || hash`
+    fn encode(prehashed: &CryptoHash) -> [u8; Self::MODULUS_LEN] {
+        let mut em = [0xFF; Self::MODULUS_LEN];
+        em[0] = 0x00;
+        em[1] = 0x01;
+
+        let (_, t) = em.split_at_mut(Self::MODULUS_LEN - Self::SHA256_DIGEST_INFO.len() - 32 - 1);
+        let (separator, t) = t.split_first_mut().unwrap_or_else(|| unreachable!());
+        *separator = 0x00;
+        let (digest_info, hash) = t.split_at_mut(Self::SHA256_DIGEST_INFO.len());
+        digest_info.copy_from_slice(&Self::SHA256_DIGEST_INFO);
+        hash.copy_from_slice(prehashed);
+
+        em
+    }
+}
+
+impl Curve for Rsa2048 {
+    /// Big-endian encoded modulus `n`
+    type PublicKey = [u8; Self::MODULUS_LEN];
+
+    /// Big-endian encoded signature representative `s`
+    type Signature = [u8; Self::MODULUS_LEN];
+
+    /// Output of SHA-256
+    type Message = CryptoHash;
+
+    type VerifyingKey = Self::PublicKey;
+}
+
+impl VerifiableCurve for Rsa2048 {
+    fn verify(
+        signature: &Self::Signature,
+        prehashed: &Self::Message,
+        public_key: &Self::VerifyingKey,
+    ) -> Option<Self::PublicKey> {
+        let n = from_be_bytes(public_key);
+        // modulus must be odd and exactly 2048 bits long
+        if n[0] & 1 == 0 || n[LIMBS - 1] >> 31 == 0 {
+            return None;
+        }
+
+        // signature representative must be in range [0, n - 1]
+        let s = from_be_bytes(signature);
+        if !lt(&s, &n) {
+            return None;
+        }
+
+        (to_be_bytes(&Montgomery::new(n).pow_65537(&s)) == Self::encode(prehashed))
+            .then_some(public_key)
+            .copied()
+    }
+}
+
+/// Number of 32-bit limbs in 2048-bit integer.
+///
+/// We intentionally use 32-bit limbs with 64-bit intermediates rather
+/// than 64-bit limbs, since WASM doesn't have native 128-bit arithmetic
+const LIMBS: usize = Rsa2048::MODULUS_LEN / 4;
+
+/// Little-endian limbs
+type Uint = [u32; LIMBS];
+
+fn from_be_bytes(bytes: &[u8; Rsa2048::MODULUS_LEN]) -> Uint {
+    let mut limbs = [0; LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks_exact(4)) {
+        *limb = u32::from_be_bytes(chunk.try_into().unwrap_or_else(|_| unreachable!()));
+    }
+    limbs
+}
+
+fn to_be_bytes(limbs: &Uint) -> [u8; Rsa2048::MODULUS_LEN] {
+    let mut bytes = [0; Rsa2048::MODULUS_LEN];
+    for (chunk, limb) in bytes.rchunks_exact_mut(4).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+/// Returns `(lo, hi)` halves of `x`
+#[inline]
+const fn split(x: u64) -> (u32, u32) {
+    let [l0, l1, l2, l3, h0, h1, h2, h3] = x.to_le_bytes();
+    (
+        u32::from_le_bytes([l0, l1, l2, l3]),
+        u32::from_le_bytes([h0, h1, h2, h3]),
+    )
+}
+
+/// `a < b`
+fn lt(a: &Uint, b: &Uint) -> bool {
+    a.iter().rev().cmp(b.iter().rev()).is_lt()
+}
+
+/// `a -= b`, ignoring the final borrow
+fn sub_assign(a: &mut Uint, b: &Uint) {
+    let mut borrow = false;
+    for (a, b) in a.iter_mut().zip(b) {
+        let (d, b1) = a.overflowing_sub(*b);
+        let (d, b2) = d.overflowing_sub(u32::from(borrow));
+        *a = d;
+        borrow = b1 | b2;
+    }
+}
+
+/// Montgomery arithmetic modulo odd `n` with `R = 2^2048`
+struct Montgomery {
+    n: Uint,
+    /// `-n^(-1) mod 2^32`
+    n0_inv: u32,
+    /// `R^2 mod n`
+    r2: Uint,
+}
+
+impl Montgomery {
+    /// `n` must be odd and have its most significant bit set
+    fn new(n: Uint) -> Self {
+        // Newton's iteration: each step doubles the number of correct
+        // bits, while `n0 * n0 == 1 (mod 8)` for odd `n0`
+        let mut inv = n[0];
+        for _ in 0..4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(n[0].wrapping_mul(inv)));
+        }
+
+        // R mod n = R - n, since n < R < 2n
+        let mut r2 = [0; LIMBS];
+        sub_assign(&mut r2, &n);
+
+        // R^2 mod n = (R mod n) * 2^2048 mod n
+        for _ in 0..LIMBS * 32 {
+            let carry = r2[LIMBS - 1] >> 31 == 1;
+            let mut prev = 0;
+            for limb in &mut r2 {
+                let next = *limb >> 31;
+                *limb = (*limb << 1) | prev;
+                prev = next;
+            }
+            if carry || !lt(&r2, &n) {
+                sub_assign(&mut r2, &n);
+            }
+        }
+
+        Self {
+            n,
+            n0_inv: inv.wrapping_neg(),
+            r2,
+        }
+    }
+
+    /// `a * b * R^(-1) mod n` using Coarsely Integrated Operand Scanning
+    fn mul(&self, a: &Uint, b: &Uint) -> Uint {
+        let mut t = [0u32; LIMBS + 2];
+
+        for &b in b {
+            // t += a * b
+            let mut carry = 0;
+            for (t, &a) in t.iter_mut().zip(a) {
+                (*t, carry) = split(u64::from(*t) + u64::from(a) * u64::from(b) + u64::from(carry));
+            }
+            (t[LIMBS], carry) = split(u64::from(t[LIMBS]) + u64::from(carry));
+            t[LIMBS + 1] = carry;
+
+            // t = (t + m * n) / 2^32
+            let m = t[0].wrapping_mul(self.n0_inv);
+            let (_, mut carry) = split(u64::from(t[0]) + u64::from(m) * u64::from(self.n[0]));
+            for j in 1..LIMBS {
+                (t[j - 1], carry) =
+                    split(u64::from(t[j]) + u64::from(m) * u64::from(self.n[j]) + u64::from(carry));
+            }
+            (t[LIMBS - 1], carry) = split(u64::from(t[LIMBS]) + u64::from(carry));
+            t[LIMBS] = t[LIMBS + 1] + carry;
+        }
+
+        // t < 2n, so at most one subtraction is needed
+        let mut r: Uint = t[..LIMBS].try_into().unwrap_or_else(|_| unreachable!());
+        if t[LIMBS] != 0 || !lt(&r, &self.n) {
+            sub_assign(&mut r, &self.n);
+        }
+        r
+    }
+
+    /// `x^65537 mod n`
+    fn pow_65537(&self, x: &Uint) -> Uint {
+        let x = self.mul(x, &self.r2);
+        let mut y = x;
+        for _ in 0..16 {
+            y = self.mul(&y, &y);
+        }
+        y = self.mul(&y, &x);
+
+        let mut one = [0; LIMBS];
+        one[0] = 1;
+        self.mul(&y, &one)
+    }
+}
+
+/// Big-endian encoded 2048-bit modulus with `e = 65537`
+#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Rsa2048PublicKey(
+    // schemars ignores `with` at struct level for newtypes; must be on the field
+    #[cfg_attr(all(feature = "abi", feature = "serde"), schemars(with = "String"))]
+    pub  <Rsa2048 as Curve>::PublicKey,
+);
+
+#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Rsa2048Signature(
+    // schemars ignores `with` at struct level for newtypes; must be on the field
+    #[cfg_attr(all(feature = "abi", feature = "serde"), schemars(with = "String"))]
+    pub  <Rsa2048 as Curve>::Signature,
+);
+
+#[cfg(feature = "parse")]
+const _: () = {
+    use crate::{CurveType, ParseCurveError, TypedCurve};
+    use core::fmt::{self, Debug, Display};
+    use std::str::FromStr;
+
+    impl TypedCurve for Rsa2048 {
+        const CURVE_TYPE: CurveType = CurveType::Rsa2048;
+    }
+
+    impl Debug for Rsa2048PublicKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    impl Display for Rsa2048PublicKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&<Rsa2048 as TypedCurve>::to_base58(self.0))
+        }
+    }
+
+    impl FromStr for Rsa2048PublicKey {
+        type Err = ParseCurveError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Rsa2048::parse_base58(s).map(Self)
+        }
+    }
+
+    impl Debug for Rsa2048Signature {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    impl Display for Rsa2048Signature {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&<Rsa2048 as TypedCurve>::to_base58(self.0))
+        }
+    }
+
+    impl FromStr for Rsa2048Signature {
+        type Err = ParseCurveError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Rsa2048::parse_base58(s).map(Self)
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    const PUBLIC_KEY: [u8; 256] = hex!(
+        "e4e38511dcab8785290692a4660a1a09e09e44fb5d102340676d2f5faf4a35b45f1faf516b745e34687b5f6d9dfa005a7edd4f2e6964701f6020a3f377680833e46dca389c0231de22cb42d879b20f8c4f23fc3149b848870691f949bd832a30a08e8f40f227d0a7f10505b117b204a6cdc6e0c00f09b461719f8e6505b7c0f126ba05f9b4a7630a7aa6fd242047951b74208e3b942e6e07ef1fe709dcd271da00a815fe959df7626112c4d4bf1881b54e5fd5d8168ef2ebd81eb9327cea69cc2bd9ebcf3b7ab7c1ae1e0b807fc06dabd3cc041b1bc81adfaf60f02613f48031217391f570ae88dbe0889b0bf0af35bd34dba00123f6317b17fdb67ac3840c9d"
+    );
+
+    // sha256(REFERENCE_MESSAGE)
+    const HASH: CryptoHash =
+        hex!("5ca83e4645fcfdb65feaceb0e8fb56e9dba2b02e6aaacc38f8615f888928c22c");
+
+    const SIGNATURE: [u8; 256] = hex!(
+        "d3d3cd3333eaa38db2fd6d038d5a4845278003895c7219e07e4cd1e03cb2ff4bb34b8cc18fd8eeab81fb1eed5f50468757cca79ef9815abb0b0522693925a813eab3efb8555ef912e1bb0d4985d862e2eebe670c183f292507980dd11ad86fb5a3adbf7172d54f89f813f8da7a6298cd265556c15d636f2d610fb522427ab3f64f5511b7b5b53b3edaff4e6c54f7ceb1da3d1105fb60dd90c99d2a5d2bae5e9557e8d8be0aa2aca5a36ca1de2012c5fbf7b0e850cec98017ba2b4cfd7bb51d8151f5e77cf2a8e92b2c95f3edbd4319e9104d02c2c5e23a46ded1c863d88f77200f79a5623c5d84fed04111a6db0f0943300a2061f6852cfc520e390a0e885832"
+    );
+
+    #[test]
+    fn verify() {
+        assert_eq!(
+            Rsa2048::verify(&SIGNATURE, &HASH, &PUBLIC_KEY),
+            Some(PUBLIC_KEY)
+        );
+    }
+
+    #[test]
+    fn invalid_hash() {
+        let mut hash = HASH;
+        hash[0] ^= 1;
+        assert_eq!(Rsa2048::verify(&SIGNATURE, &hash, &PUBLIC_KEY), None);
+    }
+
+    #[test]
+    fn invalid_signature() {
+        let mut signature = SIGNATURE;
+        signature[255] ^= 1;
+        assert_eq!(Rsa2048::verify(&signature, &HASH, &PUBLIC_KEY), None);
+    }
+
+    #[test]
+    fn signature_out_of_range() {
+        assert_eq!(Rsa2048::verify(&PUBLIC_KEY, &HASH, &PUBLIC_KEY), None);
+    }
+
+    #[test]
+    fn modulus_too_short() {
+        let mut public_key = PUBLIC_KEY;
+        public_key[0] = 0;
+        assert_eq!(Rsa2048::verify(&SIGNATURE, &HASH, &public_key), None);
+    }
+
+    #[test]
+    fn montgomery() {
+        let n = from_be_bytes(&PUBLIC_KEY);
+        let mont = Montgomery::new(n);
+
+        let mut two = [0; LIMBS];
+        two[0] = 2;
+        let mut one = [0; LIMBS];
+        one[0] = 1;
+        assert_eq!(mont.pow_65537(&one), one);
+        // to and from Montgomery form
+        assert_eq!(mont.mul(&mont.mul(&two, &mont.r2), &one), two);
+    }
+}
@@ -0,0 +1,165 @@
+use k256::schnorr::{Signature, VerifyingKey, signature::hazmat::PrehashVerifier};
+
+use crate::{CryptoHash, Curve, VerifiableCurve};
+
+/// Schnorr signatures over secp256k1, see
+/// [BIP-340](https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki)
+pub struct SchnorrSecp256k1;
+
+impl Curve for SchnorrSecp256k1 {
+    /// x-only public key, i.e. `x` coordinate of the point with even `y`
+    type PublicKey = [u8; 32];
+
+    /// Concatenated `R.x || s`
+    type Signature = [u8; 64];
+
+    /// 32-byte message, e.g. taproot sighash or Nostr event id
+    type Message = CryptoHash;
+
+    type VerifyingKey = Self::PublicKey;
+}
+
+impl VerifiableCurve for SchnorrSecp256k1 {
+    fn verify(
+        signature: &Self::Signature,
+        message: &Self::Message,
+        public_key: &Self::VerifyingKey,
+    ) -> Option<Self::PublicKey> {
+        let signature = Signature::try_from(signature.as_slice()).ok()?;
+
+        VerifyingKey::from_bytes(public_key)
+            .ok()?
+            .verify_prehash(message, &signature)
+            .is_ok()
+            .then_some(public_key)
+            .copied()
+    }
+}
+
+#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SchnorrSecp256k1PublicKey(
+    // schemars ignores `with` at struct level for newtypes; must be on the field
+    #[cfg_attr(all(feature = "abi", feature = "serde"), schemars(with = "String"))]
+    pub  <SchnorrSecp256k1 as Curve>::PublicKey,
+);
+
+#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SchnorrSecp256k1Signature(
+    // schemars ignores `with` at struct level for newtypes; must be on the field
+    #[cfg_attr(all(feature = "abi", feature = "serde"), schemars(with = "String"))]
+    pub  <SchnorrSecp256k1 as Curve>::Signature,
+);
+
+#[cfg(feature = "parse")]
+const _: () = {
+    use crate::{CurveType, ParseCurveError, TypedCurve};
+    use core::fmt::{self, Debug, Display};
+    use std::str::FromStr;
+
+    impl TypedCurve for SchnorrSecp256k1 {
+        const CURVE_TYPE: CurveType = CurveType::SchnorrSecp256k1;
+    }
+
+    impl Debug for SchnorrSecp256k1PublicKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    impl Display for SchnorrSecp256k1PublicKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&<SchnorrSecp256k1 as TypedCurve>::to_base58(self.0))
+        }
+    }
+
+    impl FromStr for SchnorrSecp256k1PublicKey {
+        type Err = ParseCurveError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            SchnorrSecp256k1::parse_base58(s).map(Self)
+        }
+    }
+
+    impl Debug for SchnorrSecp256k1Signature {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    impl Display for SchnorrSecp256k1Signature {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&<SchnorrSecp256k1 as TypedCurve>::to_base58(self.0))
+        }
+    }
+
+    impl FromStr for SchnorrSecp256k1Signature {
+        type Err = ParseCurveError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            SchnorrSecp256k1::parse_base58(s).map(Self)
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // BIP-340 test vector #0
+    #[case(
+        hex!("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+        [0; 32],
+        hex!("e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0"),
+    )]
+    // sha256("hello"), private key: a4b319a82adfc43584e4537fec97a80516e16673db382cd91eba97abbab8ca56
+    #[case(
+        hex!("85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b8"),
+        hex!("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"),
+        hex!("a0287f1dbf37ea1f3e0b999bf35a0080fb5f38c22060058b7c78159d312d8b7a2dae9558bf758b119769a2ae8204eef42517ab182f06de6687d88e0305e2db1f"),
+    )]
+    fn verify(
+        #[case] public_key: [u8; 32],
+        #[case] message: CryptoHash,
+        #[case] signature: [u8; 64],
+    ) {
+        assert_eq!(
+            SchnorrSecp256k1::verify(&signature, &message, &public_key),
+            Some(public_key)
+        );
+
+        let mut other_message = message;
+        other_message[0] ^= 1;
+        assert_eq!(
+            SchnorrSecp256k1::verify(&signature, &other_message, &public_key),
+            None
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            curves::stark_curve::StarkCurve, point::ShortWeierstrassProjectivePoint,
+            traits::IsShortWeierstrass,
+        },
+        traits::{FromAffine, IsEllipticCurve},
+    },
+    field::{
+        element::FieldElement,
+        fields::{
+            fft_friendly::stark_252_prime_field::{
+                MontgomeryConfigStark252PrimeField, Stark252PrimeField,
+            },
+            montgomery_backed_prime_fields::{IsModulus, U256PrimeField},
+        },
+    },
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+
+use crate::{CryptoHash, Curve, VerifiableCurve};
+
+/// Elliptic curve used by Starknet
+pub struct Stark;
+
+impl Curve for Stark {
+    /// Big-endian encoded `x` coordinate
+    type PublicKey = [u8; 32];
+
+    /// Concatenated big-endian encoded `r || s`
+    type Signature = [u8; 64];
+
+    /// Big-endian encoded field element, i.e. output of Pedersen or
+    /// Poseidon hash
+    type Message = CryptoHash;
+
+    type VerifyingKey = Self::PublicKey;
+}
+
+impl VerifiableCurve for Stark {
+    /// ECDSA verification over the Stark curve, same as
+    /// [`starknet_crypto::verify()`](https://docs.rs/starknet-crypto/0.7.4/starknet_crypto/fn.verify.html)
+    fn verify(
+        signature: &Self::Signature,
+        message: &Self::Message,
+        public_key: &Self::VerifyingKey,
+    ) -> Option<Self::PublicKey> {
+        let (r, s) = signature.split_at(32);
+        let [message, r, s] = [message.as_slice(), r, s].map(U256::from_bytes_be);
+        let (message, r, s) = (message.ok()?, r.ok()?, s.ok()?);
+
+        if message >= ELEMENT_UPPER_BOUND
+            || r == U256::from_u64(0)
+            || r >= ELEMENT_UPPER_BOUND
+            || s == U256::from_u64(0)
+            || s >= ELEMENT_UPPER_BOUND
+        {
+            return None;
+        }
+
+        let public_key_point = point_from_x(public_key)?;
+
+        let w = StarkScalar::new(s).inv().ok()?;
+        if w.representative() >= ELEMENT_UPPER_BOUND {
+            return None;
+        }
+
+        let zw_g = StarkCurve::generator()
+            .operate_with_self((StarkScalar::new(message) * w).representative());
+        let rw_q = public_key_point.operate_with_self((StarkScalar::new(r) * w).representative());
+
+        let x_is_r = |point: ShortWeierstrassProjectivePoint<StarkCurve>| {
+            !point.is_neutral_element() && point.to_affine().x().representative() == r
+        };
+
+        (x_is_r(zw_g.operate_with(&rw_q)) || x_is_r(zw_g.operate_with(&rw_q.neg())))
+            .then_some(*public_key)
+    }
+}
+
+/// Upper bound for `r`, `s` and message hash, i.e. `2^251`
+const ELEMENT_UPPER_BOUND: U256 =
+    U256::from_hex_unchecked("800000000000000000000000000000000000000000000000000000000000000");
+
+#[derive(Clone, Debug, Hash, Copy)]
+struct StarkCurveOrder;
+
+impl IsModulus<U256> for StarkCurveOrder {
+    const MODULUS: U256 =
+        U256::from_hex_unchecked("800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f");
+}
+
+/// Scalar modulo order of the Stark curve
+type StarkScalar = FieldElement<U256PrimeField<StarkCurveOrder>>;
+
+/// Returns either of two curve points with given big-endian encoded `x`,
+/// rejecting non-canonical encodings, i.e. values greater than the modulus
+fn point_from_x(x: &[u8; 32]) -> Option<ShortWeierstrassProjectivePoint<StarkCurve>> {
+    let x = U256::from_bytes_be(x).ok()?;
+    if x >= MontgomeryConfigStark252PrimeField::MODULUS {
+        return None;
+    }
+    let x = FieldElement::<Stark252PrimeField>::new(x);
+
+    let (y, _) = (x.square() * x + StarkCurve::a() * x + StarkCurve::b()).sqrt()?;
+    ShortWeierstrassProjectivePoint::from_affine(x, y).ok()
+}
+
+#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct StarkPublicKey(
+    // schemars ignores `with` at struct level for newtypes; must be on the field
+    #[cfg_attr(all(feature = "abi", feature = "serde"), schemars(with = "String"))]
+    pub  <Stark as Curve>::PublicKey,
+);
+
+#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct StarkSignature(
+    // schemars ignores `with` at struct level for newtypes; must be on the field
+    #[cfg_attr(all(feature = "abi", feature = "serde"), schemars(with = "String"))]
+    pub  <Stark as Curve>::Signature,
+);
+
+#[cfg(feature = "parse")]
+const _: () = {
+    use crate::{CurveType, ParseCurveError, TypedCurve};
+    use core::fmt::{self, Debug, Display};
+    use std::str::FromStr;
+
+    impl TypedCurve for Stark {
+        const CURVE_TYPE: CurveType = CurveType::Stark;
+    }
+
+    impl Debug for StarkPublicKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    impl Display for StarkPublicKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&<Stark as TypedCurve>::to_base58(self.0))
+        }
+    }
+
+    impl FromStr for StarkPublicKey {
+        type Err = ParseCurveError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Stark::parse_base58(s).map(Self)
+        }
+    }
+
+    impl Debug for StarkSignature {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    impl Display for StarkSignature {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&<Stark as TypedCurve>::to_base58(self.0))
+        }
+    }
+
+    impl FromStr for StarkSignature {
+        type Err = ParseCurveError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Stark::parse_base58(s).map(Self)
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    use super::*;
+
+    // test vectors from `starknet-crypto`
+    #[rstest]
+    #[case::valid(
+        hex!("01ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("0411494b501a98abd8262b0da1351e17899a0c4ef23dd2f96fec5ba847310b20
+              0405c3191ab3883ef2b763af35bc5f5d15b3b4e99461d70e84c654a351a7c81b"),
+        true,
+    )]
+    #[case::invalid_message(
+        hex!("077a4b314db07c45076d11f62b6f9e748a39790441823307743cf00d6597ea43"),
+        hex!("0397e76d1667c4454bfb83514e120583af836f8e32a516765497823eabe16a3f"),
+        hex!("0173fd03d8b008ee7432977ac27d1e9d1a1f6c98b1a2f05fa84a21c84c44e882
+              01f2c44a7798f55192f153b4c48ea5c1241fbb69e6132cc8a0da9c5b62a4286e"),
+        false,
+    )]
+    #[case::invalid_public_key(
+        hex!("03ee9bffffffffff26ffffffff60ffffffffffffffffffffffffffff004accff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("0411494b501a98abd8262b0da1351e17899a0c4ef23dd2f96fec5ba847310b20
+              0405c3191ab3883ef2b763af35bc5f5d15b3b4e99461d70e84c654a351a7c81b"),
+        false,
+    )]
+    fn verify(
+        #[case] public_key: [u8; 32],
+        #[case] message: CryptoHash,
+        #[case] signature: [u8; 64],
+        #[case] valid: bool,
+    ) {
+        assert_eq!(
+            Stark::verify(&signature, &message, &public_key),
+            valid.then_some(public_key)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_out_of_range() {
+        let public_key = hex!("01ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca");
+        let signature = hex!(
+            "0411494b501a98abd8262b0da1351e17899a0c4ef23dd2f96fec5ba847310b20
+             0405c3191ab3883ef2b763af35bc5f5d15b3b4e99461d70e84c654a351a7c81b"
+        );
+
+        let mut message = [0; 32];
+        message[0] = 0x08;
+        assert_eq!(Stark::verify(&signature, &message, &public_key), None);
+
+        let mut zero_s = signature;
+        zero_s[32..].fill(0);
+        assert_eq!(
+            Stark::verify(
+                &zero_s,
+                &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+                &public_key
+            ),
+            None
+        );
+    }
+}
@@ -13,17 +13,41 @@ pub use self::{curve::*, payload::*};
 
 #[cfg(all(
     feature = "parse",
-    any(feature = "ed25519", feature = "secp256k1", feature = "p256")
+    any(
+        feature = "bls12381",
+        feature = "ed25519",
+        feature = "secp256k1",
+        feature = "p256",
+        feature = "schnorr-secp256k1",
+        feature = "rsa",
+        feature = "stark"
+    )
 ))]
 mod parse;
 #[cfg(all(
     feature = "parse",
-    any(feature = "ed25519", feature = "secp256k1", feature = "p256")
+    any(
+        feature = "bls12381",
+        feature = "ed25519",
+        feature = "secp256k1",
+        feature = "p256",
+        feature = "schnorr-secp256k1",
+        feature = "rsa",
+        feature = "stark"
+    )
 ))]
 pub use self::parse::*;
 
 #[cfg(all(
-    any(feature = "ed25519", feature = "secp256k1", feature = "p256"),
+    any(
+        feature = "bls12381",
+        feature = "ed25519",
+        feature = "secp256k1",
+        feature = "p256",
+        feature = "schnorr-secp256k1",
+        feature = "rsa",
+        feature = "stark"
+    ),
     feature = "serde"
 ))]
 pub mod serde;
@@ -37,6 +37,14 @@ pub enum CurveType {
     Secp256k1 = 1,
     #[cfg(feature = "p256")]
     P256 = 2,
+    #[cfg(feature = "stark")]
+    Stark = 3,
+    #[cfg(feature = "schnorr-secp256k1")]
+    SchnorrSecp256k1 = 4,
+    #[cfg(feature = "bls12381")]
+    Bls12381 = 5,
+    #[cfg(feature = "rsa")]
+    Rsa2048 = 6,
 }
 
 #[derive(Debug, ThisError, PartialEq, Eq)]
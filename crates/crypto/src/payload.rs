@@ -19,6 +19,18 @@ pub trait Payload {
     fn hash(&self) -> CryptoHash;
 }
 
+/// Extension of [`Payload`] for free-form text messages, which can be
+/// bound to an application-specific context.
+///
+/// Wallets sign such messages the same way for any dApp, so a signature
+/// requested by one application could be replayed to another one.
+/// Prefixing the context to the message before applying the envelope
+/// of the signing standard makes such signatures incompatible.
+pub trait DomainSeparatedPayload: Payload {
+    /// Same as [`Payload::hash`], but of the message prefixed with `context`
+    fn hash_with_context(&self, context: &[u8]) -> CryptoHash;
+}
+
 /// Extension of [`Payload`] for types that include a signature.
 ///
 /// Implementers verify the signature and, when successful, return the
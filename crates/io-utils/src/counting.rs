@@ -0,0 +1,61 @@
+use std::io::{Read, Result};
+
+/// A reader that wraps another reader and counts all bytes read.
+pub struct CountingReader<R> {
+    reader: R,
+    count: u64,
+}
+
+impl<R> CountingReader<R> {
+    #[inline]
+    pub const fn new(reader: R) -> Self {
+        Self { reader, count: 0 }
+    }
+
+    /// Returns the number of bytes read so far
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> (R, u64) {
+        (self.reader, self.count)
+    }
+}
+
+impl<R> Read for CountingReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.reader.read(buf)?;
+        self.count = self.count.saturating_add(n.try_into().unwrap_or(u64::MAX));
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::ReadExt;
+
+    use super::*;
+
+    #[test]
+    fn counts_bytes_read() {
+        let mut reader = Cursor::new(b"hello world").counting();
+
+        let mut buf = [0u8; 5];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(reader.count(), 5);
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b" world");
+
+        let (_reader, count) = reader.into_inner();
+        assert_eq!(count, 11);
+    }
+}
@@ -0,0 +1,120 @@
+use std::io::{Read, Result, Write};
+
+use digest::{Digest, Output};
+
+/// A reader that wraps another reader and hashes all bytes read.
+pub struct HashingReader<R, D> {
+    reader: R,
+    digest: D,
+}
+
+impl<R, D> HashingReader<R, D>
+where
+    D: Digest,
+{
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            digest: D::new(),
+        }
+    }
+
+    /// Returns the inner reader and the hash of all bytes read
+    #[inline]
+    pub fn finalize(self) -> (R, Output<D>) {
+        (self.reader, self.digest.finalize())
+    }
+}
+
+impl<R, D> Read for HashingReader<R, D>
+where
+    R: Read,
+    D: Digest,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.reader.read(buf)?;
+        self.digest.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// A writer that wraps another writer and hashes all bytes written.
+pub struct HashingWriter<W, D> {
+    writer: W,
+    digest: D,
+}
+
+impl<W, D> HashingWriter<W, D>
+where
+    D: Digest,
+{
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            digest: D::new(),
+        }
+    }
+
+    /// Returns the inner writer and the hash of all bytes written
+    #[inline]
+    pub fn finalize(self) -> (W, Output<D>) {
+        (self.writer, self.digest.finalize())
+    }
+}
+
+impl<W, D> Write for HashingWriter<W, D>
+where
+    W: Write,
+    D: Digest,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        // hash only bytes accepted by the inner writer
+        let n = self.writer.write(buf)?;
+        self.digest.update(&buf[..n]);
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Cursor};
+
+    use defuse_digest::sha2::Sha256;
+    use hex_literal::hex;
+
+    use crate::{ReadExt, WriteExt};
+
+    use super::*;
+
+    const HELLO_WORLD_SHA256: [u8; 32] =
+        hex!("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9");
+
+    #[test]
+    fn hashing_reader() {
+        let mut reader = Cursor::new(b"hello world").hashing_reader::<Sha256>();
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, b"hello world");
+
+        let (_reader, hash) = reader.finalize();
+        assert_eq!(hash[..], HELLO_WORLD_SHA256);
+    }
+
+    #[test]
+    fn hashing_writer() {
+        let mut writer = Vec::new().hashing_writer::<Sha256>();
+        io::copy(&mut Cursor::new(b"hello world"), &mut writer).unwrap();
+
+        let (written, hash) = writer.finalize();
+        assert_eq!(written, b"hello world");
+        assert_eq!(hash[..], HELLO_WORLD_SHA256);
+    }
+}
@@ -1,5 +1,12 @@
+mod counting;
+mod hashing;
+
+pub use self::{counting::*, hashing::*};
+
 use std::io::{Read, Result, Write};
 
+use digest::Digest;
+
 pub trait ReadExt: Read {
     /// Creates a `TeeReader` that wraps the current reader and duplicates all read bytes into the given writer.
     fn tee<W>(self, writer: W) -> TeeReader<Self, W>
@@ -12,9 +19,38 @@ pub trait ReadExt: Read {
             writer,
         }
     }
+
+    /// Creates a [`CountingReader`] that counts all bytes read.
+    fn counting(self) -> CountingReader<Self>
+    where
+        Self: Sized,
+    {
+        CountingReader::new(self)
+    }
+
+    /// Creates a [`HashingReader`] that hashes all bytes read with `D`.
+    fn hashing_reader<D>(self) -> HashingReader<Self, D>
+    where
+        Self: Sized,
+        D: Digest,
+    {
+        HashingReader::new(self)
+    }
 }
 impl<R> ReadExt for R where R: Read {}
 
+pub trait WriteExt: Write {
+    /// Creates a [`HashingWriter`] that hashes all bytes written with `D`.
+    fn hashing_writer<D>(self) -> HashingWriter<Self, D>
+    where
+        Self: Sized,
+        D: Digest,
+    {
+        HashingWriter::new(self)
+    }
+}
+impl<W> WriteExt for W where W: Write {}
+
 /// A reader that wraps another reader and writes all bytes read into an internal writer.
 pub struct TeeReader<R, W> {
     reader: R,
@@ -2,6 +2,7 @@ mod checked;
 mod core;
 pub mod enumeration;
 mod events;
+pub mod metadata;
 pub mod receiver;
 pub mod resolver;
 mod token;
@@ -0,0 +1,82 @@
+use near_sdk::{ext_contract, json_types::Base64VecU8, near};
+
+use crate::TokenId;
+
+/// Version of [multi-token metadata standard](https://nomicon.io/Standards/Tokens/MultiToken/Metadata)
+pub const MT_METADATA_SPEC: &str = "mt-1.0.0";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[near(serializers = [json])]
+pub struct MTContractMetadata {
+    pub spec: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[near(serializers = [json, borsh])]
+pub struct MTBaseTokenMetadata {
+    pub name: String,
+    pub id: String,
+    pub symbol: Option<String>,
+    pub icon: Option<String>,
+    pub decimals: Option<String>,
+    pub base_uri: Option<String>,
+    pub reference: Option<String>,
+    pub copies: Option<u64>,
+    pub reference_hash: Option<Base64VecU8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[near(serializers = [json, borsh])]
+pub struct MTTokenMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub media: Option<String>,
+    pub media_hash: Option<Base64VecU8>,
+    pub issued_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub starts_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub extra: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<Base64VecU8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[near(serializers = [json, borsh])]
+pub struct MTTokenMetadataAll {
+    pub base: MTBaseTokenMetadata,
+    pub token: MTTokenMetadata,
+}
+
+/// A trait representing the [multi-token metadata standard](https://nomicon.io/Standards/Tokens/MultiToken/Metadata#interface).
+#[ext_contract(ext_mt_metadata)]
+pub trait MultiTokenMetadata {
+    /// Returns metadata of the contract itself
+    fn mt_metadata_contract(&self) -> MTContractMetadata;
+
+    /// Returns both base and token-specific metadata for each of given
+    /// `token_ids`, or `None` for tokens without metadata
+    fn mt_metadata_token_all(&self, token_ids: Vec<TokenId>) -> Vec<Option<MTTokenMetadataAll>>;
+
+    /// Returns token-specific metadata for each of given `token_ids`,
+    /// or `None` for tokens without metadata
+    fn mt_metadata_token_by_token_id(
+        &self,
+        token_ids: Vec<TokenId>,
+    ) -> Vec<Option<MTTokenMetadata>>;
+
+    /// Returns base metadata for each of given `token_ids`,
+    /// or `None` for tokens without metadata
+    fn mt_metadata_base_by_token_id(
+        &self,
+        token_ids: Vec<TokenId>,
+    ) -> Vec<Option<MTBaseTokenMetadata>>;
+
+    /// Returns base metadata for each of given `base_metadata_ids`,
+    /// or `None` for unknown ids
+    fn mt_metadata_base_by_metadata_id(
+        &self,
+        base_metadata_ids: Vec<String>,
+    ) -> Vec<Option<MTBaseTokenMetadata>>;
+}
@@ -0,0 +1,170 @@
+use std::borrow::Cow;
+
+use near_sdk::{env, near, serde::Deserialize, serde_json};
+
+/// Errors with stable machine-readable codes, so that clients can
+/// match on [`.code()`](ErrorCode::code) instead of parsing
+/// human-readable messages, which can change between releases.
+pub trait ErrorCode: ToString {
+    /// Stable error code, MUST NOT change between releases
+    fn code(&self) -> &'static str;
+
+    /// Optional structured details of the error
+    #[inline]
+    fn details(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    #[inline]
+    fn to_envelope(&self) -> ErrorEnvelope<'static> {
+        ErrorEnvelope {
+            code: self.code().into(),
+            message: self.to_string().into(),
+            details: self.details(),
+        }
+    }
+
+    /// Panics with JSON-serialized [`ErrorEnvelope`]
+    #[inline]
+    fn panic_with_envelope(&self) -> ! {
+        self.to_envelope().panic()
+    }
+}
+
+/// Standard error envelope, which contracts panic with, i.e.
+/// `{"code":"...","message":"...","details":{...}}`.
+#[near(serializers = [json])]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorEnvelope<'a> {
+    pub code: Cow<'a, str>,
+    pub message: Cow<'a, str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+impl<'a> ErrorEnvelope<'a> {
+    #[must_use]
+    #[inline]
+    pub fn new(code: impl Into<Cow<'a, str>>, message: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    #[inline]
+    pub fn panic(&self) -> ! {
+        env::panic_str(
+            &serde_json::to_string(self).unwrap_or_else(|_| env::panic_str(self.message.as_ref())),
+        )
+    }
+
+    /// Extracts the envelope from the panic message, which can be
+    /// prefixed by the runtime, e.g. `Smart contract panicked: {...}`.
+    #[must_use]
+    pub fn parse(panic_msg: &str) -> Option<ErrorEnvelope<'static>> {
+        let json = &panic_msg[panic_msg.find('{')?..];
+        let mut de = serde_json::Deserializer::from_str(json);
+        Deserialize::deserialize(&mut de).ok()
+    }
+}
+
+/// Like [`near_sdk::require!`], but panics with [`ErrorEnvelope`]
+/// carrying given stable code
+#[macro_export]
+macro_rules! require_envelope {
+    ($cond:expr, $code:literal, $msg:expr $(,)?) => {
+        if !$cond {
+            $crate::ErrorEnvelope::new($code, $msg).panic()
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug)]
+    enum Error {
+        NotFound(&'static str),
+        Overflow,
+    }
+
+    impl core::fmt::Display for Error {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::NotFound(account_id) => write!(f, "account '{account_id}' not found"),
+                Self::Overflow => f.write_str("integer overflow"),
+            }
+        }
+    }
+
+    impl ErrorCode for Error {
+        fn code(&self) -> &'static str {
+            match self {
+                Self::NotFound(_) => "account_not_found",
+                Self::Overflow => "integer_overflow",
+            }
+        }
+
+        fn details(&self) -> Option<serde_json::Value> {
+            match self {
+                Self::NotFound(account_id) => Some(json!({ "account_id": account_id })),
+                Self::Overflow => None,
+            }
+        }
+    }
+
+    #[test]
+    fn envelope() {
+        let envelope = Error::NotFound("alice.near").to_envelope();
+        assert_eq!(
+            serde_json::to_value(&envelope).unwrap(),
+            json!({
+                "code": "account_not_found",
+                "message": "account 'alice.near' not found",
+                "details": {"account_id": "alice.near"},
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_string(&Error::Overflow.to_envelope()).unwrap(),
+            r#"{"code":"integer_overflow","message":"integer overflow"}"#
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let envelope = Error::NotFound("alice.near").to_envelope();
+        let msg = format!(
+            "Smart contract panicked: {}",
+            serde_json::to_string(&envelope).unwrap()
+        );
+        assert_eq!(ErrorEnvelope::parse(&msg), Some(envelope));
+
+        assert_eq!(ErrorEnvelope::parse("Smart contract panicked: oops"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "integer_overflow")]
+    fn panic() {
+        Error::Overflow.panic_with_envelope();
+    }
+
+    #[test]
+    #[should_panic(expected = "unchanged")]
+    fn require() {
+        require_envelope!(true, "unchanged", "same");
+        require_envelope!(false, "unchanged", "same");
+    }
+}
@@ -1,6 +1,118 @@
+use core::fmt;
+
 use near_sdk::{Gas, env};
 
 #[inline]
 pub fn gas_left() -> Gas {
     env::prepaid_gas().saturating_sub(env::used_gas())
 }
+
+/// Panics if less than `min` gas is left, e.g. before spawning a
+/// promise chain that requires at least `min` gas to complete.
+///
+/// Error message is formatted as
+/// `{context}: insufficient gas: required {min}, left {left}`.
+#[inline]
+#[track_caller]
+pub fn require_gas(min: Gas, context: &str) -> Gas {
+    GasGuard::new(min).require(context)
+}
+
+/// Accumulates gas requirements of subsequent calls and checks
+/// them against [`gas_left()`] at once.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GasGuard {
+    required: Gas,
+}
+
+impl GasGuard {
+    #[inline]
+    pub const fn new(required: Gas) -> Self {
+        Self { required }
+    }
+
+    /// Reserve additional `gas`
+    #[inline]
+    pub const fn reserve(self, gas: Gas) -> Self {
+        Self {
+            required: self.required.saturating_add(gas),
+        }
+    }
+
+    #[inline]
+    pub const fn required(&self) -> Gas {
+        self.required
+    }
+
+    /// Returns gas left if it's enough to cover requirements.
+    #[inline]
+    pub fn check(self) -> Result<Gas, InsufficientGas> {
+        let left = gas_left();
+        if left < self.required {
+            return Err(InsufficientGas {
+                required: self.required,
+                left,
+            });
+        }
+        Ok(left)
+    }
+
+    /// Same as [`.check()`](Self::check), but panics with given
+    /// `context` on insufficient gas.
+    #[inline]
+    #[track_caller]
+    pub fn require(self, context: &str) -> Gas {
+        self.check()
+            .unwrap_or_else(|err| env::panic_str(&format!("{context}: {err}")))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsufficientGas {
+    pub required: Gas,
+    pub left: Gas,
+}
+
+impl fmt::Display for InsufficientGas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "insufficient gas: required {}, left {}",
+            self.required, self.left
+        )
+    }
+}
+
+impl std::error::Error for InsufficientGas {}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::{test_utils::VMContextBuilder, testing_env};
+
+    use super::*;
+
+    fn setup(prepaid_gas: Gas) {
+        testing_env!(VMContextBuilder::new().prepaid_gas(prepaid_gas).build());
+    }
+
+    #[test]
+    fn check() {
+        setup(Gas::from_tgas(100));
+
+        let guard = GasGuard::new(Gas::from_tgas(30)).reserve(Gas::from_tgas(20));
+        assert_eq!(guard.required(), Gas::from_tgas(50));
+        assert!(guard.check().unwrap() >= Gas::from_tgas(50));
+
+        let err = guard.reserve(Gas::from_tgas(60)).check().unwrap_err();
+        assert_eq!(err.required, Gas::from_tgas(110));
+        assert!(err.left <= Gas::from_tgas(100));
+    }
+
+    #[test]
+    #[should_panic(expected = "ft_transfer_call: insufficient gas: required 300.0 Tgas")]
+    fn require() {
+        setup(Gas::from_tgas(100));
+        require_gas(Gas::from_tgas(300), "ft_transfer_call");
+    }
+}
@@ -1,12 +1,17 @@
+mod error;
 mod event;
 pub use event::{REFUND_MEMO, TOTAL_LOG_LENGTH_LIMIT};
 mod gas;
 mod lock;
+mod message;
 mod panic_on_clone;
 mod prefix;
 mod promise;
+mod storage;
 
-pub use self::{gas::*, lock::*, panic_on_clone::*, prefix::*, promise::*};
+pub use self::{
+    error::*, gas::*, lock::*, message::*, panic_on_clone::*, prefix::*, promise::*, storage::*,
+};
 
 #[macro_export]
 macro_rules! method_name {
@@ -6,18 +6,81 @@ use near_sdk::{
     near,
 };
 
-/// A persistent lock, which stores its state (whether it's locked or unlocked)
-/// on-chain, so that the inner value can be accessed depending on
-/// the current state of the lock.
-#[derive(Debug, Default, PartialEq, Eq)]
+/// State of the [`Lock`].
+///
+/// Borsh representation of `Unlocked` and `Locked` is the same as
+/// of `bool`, so the order of variants MUST NOT be changed.
 #[near(serializers = [borsh, json])]
-pub struct Lock<T> {
+#[serde(from = "LockStateJson", into = "LockStateJson")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LockState {
+    #[default]
+    Unlocked,
+    Locked,
+    /// Multi-step write was started with [`Lock::begin_write`], but
+    /// was never finished with [`Lock::finish_write`], so the inner value
+    /// might be partially modified.
+    Poisoned,
+}
+
+impl LockState {
+    #[must_use]
+    #[inline]
+    pub const fn is_unlocked(self) -> bool {
+        matches!(self, Self::Unlocked)
+    }
+}
+
+/// JSON representation of [`LockState`], which is flattened into
+/// [`Lock`] for backwards-compatibility with `{"locked": true, ...}`
+#[near(serializers = [json])]
+struct LockStateJson {
     #[serde(
         default,
         // do not serialize `false`
         skip_serializing_if = "::core::ops::Not::not"
     )]
     locked: bool,
+    #[serde(default, skip_serializing_if = "::core::ops::Not::not")]
+    poisoned: bool,
+}
+
+impl From<LockState> for LockStateJson {
+    #[inline]
+    fn from(state: LockState) -> Self {
+        Self {
+            locked: matches!(state, LockState::Locked),
+            poisoned: matches!(state, LockState::Poisoned),
+        }
+    }
+}
+
+impl From<LockStateJson> for LockState {
+    #[inline]
+    fn from(LockStateJson { locked, poisoned }: LockStateJson) -> Self {
+        if poisoned {
+            Self::Poisoned
+        } else if locked {
+            Self::Locked
+        } else {
+            Self::Unlocked
+        }
+    }
+}
+
+/// A persistent lock, which stores its state (whether it's locked or unlocked)
+/// on-chain, so that the inner value can be accessed depending on
+/// the current state of the lock.
+///
+/// Reads are allowed while the lock is [locked](LockState::Locked),
+/// while writes are only allowed when it's [unlocked](LockState::Unlocked).
+/// A [poisoned](LockState::Poisoned) lock allows neither until it gets
+/// [cleared](Self::clear_poison).
+#[derive(Debug, Default, PartialEq, Eq)]
+#[near(serializers = [borsh, json])]
+pub struct Lock<T> {
+    #[serde(flatten)]
+    state: LockState,
     #[serde(flatten)]
     value: T,
 }
@@ -26,7 +89,20 @@ impl<T> Lock<T> {
     #[must_use]
     #[inline]
     pub const fn new(locked: bool, value: T) -> Self {
-        Self { locked, value }
+        Self::with_state(
+            if locked {
+                LockState::Locked
+            } else {
+                LockState::Unlocked
+            },
+            value,
+        )
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn with_state(state: LockState, value: T) -> Self {
+        Self { state, value }
     }
 
     #[must_use]
@@ -43,10 +119,20 @@ impl<T> Lock<T> {
 
     #[inline]
     pub const fn set_locked(&mut self, locked: bool) -> &mut Self {
-        self.locked = locked;
+        self.state = if locked {
+            LockState::Locked
+        } else {
+            LockState::Unlocked
+        };
         self
     }
 
+    #[must_use]
+    #[inline]
+    pub const fn state(&self) -> LockState {
+        self.state
+    }
+
     /// # Safety
     /// This method bypasses lock state checks. Use only when you need to access
     /// the inner value regardless of lock state, such as for read operations
@@ -69,16 +155,80 @@ impl<T> Lock<T> {
         self.value
     }
 
+    /// Returns whether the lock is not [unlocked](LockState::Unlocked),
+    /// i.e. poisoned locks are considered locked, too.
     #[must_use]
     #[inline]
     pub const fn is_locked(&self) -> bool {
-        self.locked
+        !self.state.is_unlocked()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_poisoned(&self) -> bool {
+        matches!(self.state, LockState::Poisoned)
+    }
+
+    /// Returns the inner value unless the lock is poisoned
+    #[must_use]
+    #[inline]
+    pub const fn read(&self) -> Option<&T> {
+        if self.is_poisoned() {
+            return None;
+        }
+        Some(self.as_inner_unchecked())
+    }
+
+    /// Returns the inner value only if the lock is unlocked
+    #[must_use]
+    #[inline]
+    pub const fn write(&mut self) -> Option<&mut T> {
+        self.get_mut()
+    }
+
+    /// Starts a write which spans multiple steps (e.g. cross-contract
+    /// calls), so that if it doesn't get [finished](Self::finish_write),
+    /// the lock stays poisoned and it's detectable on next access.
+    ///
+    /// Returns `None` if the lock is not unlocked.
+    #[must_use]
+    #[inline]
+    pub const fn begin_write(&mut self) -> Option<&mut T> {
+        if self.is_locked() {
+            return None;
+        }
+        self.state = LockState::Poisoned;
+        Some(self.as_inner_unchecked_mut())
+    }
+
+    /// Finishes the write started with [`.begin_write()`](Self::begin_write)
+    /// and unlocks the lock.
+    ///
+    /// Returns `None` if the lock is not poisoned.
+    #[must_use]
+    #[inline]
+    pub const fn finish_write(&mut self) -> Option<&mut T> {
+        self.clear_poison()
+    }
+
+    /// Unlocks the poisoned lock. Intended for admins after
+    /// the inner value was inspected and repaired.
+    ///
+    /// Returns `None` if the lock is not poisoned.
+    #[must_use]
+    #[inline]
+    pub const fn clear_poison(&mut self) -> Option<&mut T> {
+        if !self.is_poisoned() {
+            return None;
+        }
+        self.state = LockState::Unlocked;
+        Some(self.as_inner_unchecked_mut())
     }
 
     #[must_use]
     #[inline]
     pub const fn as_locked(&self) -> Option<&T> {
-        if !self.is_locked() {
+        if !matches!(self.state, LockState::Locked) {
             return None;
         }
         Some(self.as_inner_unchecked())
@@ -87,7 +237,7 @@ impl<T> Lock<T> {
     #[must_use]
     #[inline]
     pub const fn as_locked_mut(&mut self) -> Option<&mut T> {
-        if !self.is_locked() {
+        if !matches!(self.state, LockState::Locked) {
             return None;
         }
         Some(self.as_inner_unchecked_mut())
@@ -106,7 +256,7 @@ impl<T> Lock<T> {
     #[must_use]
     #[inline]
     pub fn into_locked(self) -> Option<T> {
-        if !self.is_locked() {
+        if !matches!(self.state, LockState::Locked) {
             return None;
         }
         Some(self.value)
@@ -118,13 +268,13 @@ impl<T> Lock<T> {
         if self.is_locked() {
             return None;
         }
-        self.locked = true;
+        self.state = LockState::Locked;
         Some(self.as_inner_unchecked_mut())
     }
 
     #[inline]
     pub const fn force_lock(&mut self) -> &mut T {
-        self.locked = true;
+        self.state = LockState::Locked;
         self.as_inner_unchecked_mut()
     }
 
@@ -165,30 +315,33 @@ impl<T> Lock<T> {
         Some(self.value)
     }
 
+    /// Returns `None` if the lock is not [locked](LockState::Locked).
+    /// Poisoned locks should be [cleared](Self::clear_poison) instead.
     #[must_use]
     #[inline]
     pub const fn unlock(&mut self) -> Option<&mut T> {
-        if !self.is_locked() {
+        if !matches!(self.state, LockState::Locked) {
             return None;
         }
-        self.locked = false;
+        self.state = LockState::Unlocked;
         Some(self.as_inner_unchecked_mut())
     }
 
+    /// Unlocks the lock regardless of its state, including poisoned
     #[inline]
     pub const fn force_unlock(&mut self) -> &mut T {
-        self.locked = false;
+        self.state = LockState::Unlocked;
         self.as_inner_unchecked_mut()
     }
 
     #[inline]
     pub const fn as_ref(&self) -> Lock<&T> {
-        Lock::new(self.is_locked(), self.as_inner_unchecked())
+        Lock::with_state(self.state, self.as_inner_unchecked())
     }
 
     #[inline]
     pub const fn as_mut(&mut self) -> Lock<&mut T> {
-        Lock::new(self.is_locked(), self.as_inner_unchecked_mut())
+        Lock::with_state(self.state, self.as_inner_unchecked_mut())
     }
 
     #[inline]
@@ -196,7 +349,7 @@ impl<T> Lock<T> {
     where
         F: FnOnce(T) -> U,
     {
-        Lock::new(self.is_locked(), f(self.into_inner_unchecked()))
+        Lock::with_state(self.state, f(self.into_inner_unchecked()))
     }
 }
 
@@ -217,7 +370,7 @@ where
         W: io::Write,
     {
         Lock {
-            locked: source.locked,
+            state: source.state,
             value: AsWrap::<&T, &As>::new(&source.value),
         }
         .serialize(writer)
@@ -234,28 +387,106 @@ where
         R: io::Read,
     {
         Lock::<AsWrap<T, As>>::deserialize_reader(reader).map(|v| Lock {
-            locked: v.locked,
+            state: v.state,
             value: v.value.into_inner(),
         })
     }
 }
 
 #[cfg(test)]
-#[test]
-fn test() {
-    let mut a = Lock::new(false, 0);
+mod tests {
+    use near_sdk::{borsh, serde_json};
+
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut a = Lock::new(false, 0);
+
+        assert!(!a.is_locked());
+        assert_eq!(a.unlock(), None);
+
+        assert_eq!(a.get().copied(), Some(0));
+        *a.get_mut().unwrap() += 1;
+        assert_eq!(*a.as_inner_unchecked(), 1);
+
+        assert_eq!(a.lock().copied(), Some(1));
+        assert!(a.is_locked());
 
-    assert!(!a.is_locked());
-    assert_eq!(a.unlock(), None);
+        assert_eq!(a.as_locked().copied(), Some(1));
+        *a.as_locked_mut().unwrap() += 1;
+        assert_eq!(*a.as_inner_unchecked(), 2);
+    }
+
+    #[test]
+    fn poison() {
+        let mut a = Lock::unlocked(0);
+
+        *a.begin_write().unwrap() += 1;
+        assert!(a.is_poisoned());
+        assert!(a.is_locked());
+        assert_eq!(a.read(), None);
+        assert_eq!(a.get(), None);
+        assert_eq!(a.write(), None);
+        assert_eq!(a.as_locked(), None);
+        assert_eq!(a.lock(), None);
+        assert_eq!(a.unlock(), None);
+        assert_eq!(a.begin_write(), None);
+
+        assert_eq!(a.clear_poison().copied(), Some(1));
+        assert_eq!(a.state(), LockState::Unlocked);
+        assert_eq!(a.clear_poison(), None);
+
+        *a.begin_write().unwrap() += 1;
+        assert_eq!(a.finish_write().copied(), Some(2));
+        assert_eq!(a.write().copied(), Some(2));
+    }
 
-    assert_eq!(a.get().copied(), Some(0));
-    *a.get_mut().unwrap() += 1;
-    assert_eq!(*a.as_inner_unchecked(), 1);
+    #[test]
+    fn read_write() {
+        let mut a = Lock::locked(0);
+        assert_eq!(a.read().copied(), Some(0));
+        assert_eq!(a.write(), None);
+        assert_eq!(a.begin_write(), None);
 
-    assert_eq!(a.lock().copied(), Some(1));
-    assert!(a.is_locked());
+        a.unlock().unwrap();
+        assert_eq!(a.read().copied(), Some(0));
+        assert_eq!(a.write().copied(), Some(0));
+    }
 
-    assert_eq!(a.as_locked().copied(), Some(1));
-    *a.as_locked_mut().unwrap() += 1;
-    assert_eq!(*a.as_inner_unchecked(), 2);
+    #[test]
+    fn borsh_compat() {
+        // `Unlocked` and `Locked` are serialized the same as `bool`
+        for locked in [false, true] {
+            assert_eq!(
+                borsh::to_vec(&Lock::new(locked, 1u8)).unwrap(),
+                borsh::to_vec(&(locked, 1u8)).unwrap(),
+            );
+        }
+        let poisoned = borsh::to_vec(&Lock::with_state(LockState::Poisoned, 1u8)).unwrap();
+        assert_eq!(poisoned, [2, 1]);
+        assert_eq!(
+            borsh::from_slice::<Lock<u8>>(&poisoned).unwrap().state(),
+            LockState::Poisoned
+        );
+    }
+
+    #[test]
+    fn json() {
+        #[near(serializers = [json])]
+        #[derive(Debug, PartialEq, Eq)]
+        struct Value {
+            a: u8,
+        }
+
+        for (state, json) in [
+            (LockState::Unlocked, r#"{"a":1}"#),
+            (LockState::Locked, r#"{"locked":true,"a":1}"#),
+            (LockState::Poisoned, r#"{"poisoned":true,"a":1}"#),
+        ] {
+            let lock = Lock::with_state(state, Value { a: 1 });
+            assert_eq!(serde_json::to_string(&lock).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Lock<Value>>(json).unwrap(), lock);
+        }
+    }
 }
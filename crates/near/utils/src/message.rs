@@ -0,0 +1,95 @@
+use near_sdk::{serde::de::DeserializeOwned, serde_json};
+
+/// Typed `msg`/`memo` protocol: the message either starts with one of
+/// known [`PREFIXES`](PrefixedMessage::PREFIXES), which are tried in
+/// order, or falls back to a structured JSON envelope, i.e.
+/// `{"action": "...", "args": {...}}`.
+///
+/// JSON envelope is deserialized with [`serde`], so implementors are
+/// expected to be enums with `#[serde(tag = "action", content = "args")]`.
+pub trait PrefixedMessage: DeserializeOwned {
+    /// Prefixes in order of precedence
+    const PREFIXES: &'static [&'static str];
+
+    type Error: From<serde_json::Error>;
+
+    /// Parse the `rest` of the message after given `prefix`
+    fn from_prefixed(prefix: &'static str, rest: &str) -> Result<Self, Self::Error>;
+
+    fn parse_message(msg: &str) -> Result<Self, Self::Error> {
+        if let Some((prefix, rest)) = Self::PREFIXES
+            .iter()
+            .find_map(|prefix| Some((*prefix, msg.strip_prefix(prefix)?)))
+        {
+            return Self::from_prefixed(prefix, rest);
+        }
+        serde_json::from_str(msg).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::{AccountId, account_id::ParseAccountError, near};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[near(serializers = [json])]
+    #[serde(tag = "action", content = "args", rename_all = "snake_case")]
+    #[derive(Debug, PartialEq, Eq)]
+    enum Message {
+        Withdraw { receiver_id: AccountId },
+        Deposit { receiver_id: AccountId },
+        Burn,
+    }
+
+    #[derive(Debug)]
+    enum Error {
+        #[allow(dead_code)]
+        Account(ParseAccountError),
+        #[allow(dead_code)]
+        Json(serde_json::Error),
+    }
+
+    impl From<serde_json::Error> for Error {
+        fn from(err: serde_json::Error) -> Self {
+            Self::Json(err)
+        }
+    }
+
+    impl PrefixedMessage for Message {
+        const PREFIXES: &'static [&'static str] = &["withdraw_to:", "withdraw:", "deposit:"];
+
+        type Error = Error;
+
+        fn from_prefixed(prefix: &'static str, rest: &str) -> Result<Self, Self::Error> {
+            let receiver_id = rest.parse().map_err(Error::Account)?;
+            Ok(match prefix {
+                "deposit:" => Self::Deposit { receiver_id },
+                _ => Self::Withdraw { receiver_id },
+            })
+        }
+    }
+
+    #[rstest]
+    #[case("withdraw:alice.near", Message::Withdraw { receiver_id: "alice.near".parse().unwrap() })]
+    #[case("withdraw_to:alice.near", Message::Withdraw { receiver_id: "alice.near".parse().unwrap() })]
+    #[case("deposit:bob.near", Message::Deposit { receiver_id: "bob.near".parse().unwrap() })]
+    #[case(
+        r#"{"action":"deposit","args":{"receiver_id":"bob.near"}}"#,
+        Message::Deposit { receiver_id: "bob.near".parse().unwrap() },
+    )]
+    #[case(r#"{"action":"burn"}"#, Message::Burn)]
+    fn parse(#[case] msg: &str, #[case] expected: Message) {
+        assert_eq!(Message::parse_message(msg).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("withdraw:")]
+    #[case("burn")]
+    #[case(r#"{"action":"unknown"}"#)]
+    #[case(r#"{"action":"deposit","args":{}}"#)]
+    fn parse_invalid(#[case] msg: &str) {
+        Message::parse_message(msg).unwrap_err();
+    }
+}
@@ -1,4 +1,8 @@
-use near_sdk::{Promise, env, json_types::U128, serde::de::DeserializeOwned, serde_json};
+use core::fmt;
+
+use near_sdk::{
+    Promise, PromiseError, env, json_types::U128, serde::de::DeserializeOwned, serde_json,
+};
 
 pub trait PromiseExt: Sized {
     fn and_maybe(self, p: Option<Promise>) -> Promise;
@@ -61,6 +65,74 @@ pub fn promise_result_checked_json_with_len<T: MaxJsonLength<Args = (usize, ())>
     Ok(serde_json::from_slice::<T>(&value))
 }
 
+/// Same as [`promise_result_checked_json`], but flattens promise and
+/// deserialization errors into [`PromiseResultError`].
+#[inline]
+pub fn promise_result_json<T: MaxJsonLength<Args = ()>>(result_idx: u64) -> TypedPromiseResult<T> {
+    promise_result_checked_json(result_idx)?.map_err(PromiseResultError::Deserialize)
+}
+
+/// Same as [`promise_result_checked_json_with_len`], but flattens promise
+/// and deserialization errors into [`PromiseResultError`].
+#[inline]
+pub fn promise_result_json_with_len<T: MaxJsonLength<Args = (usize, ())>>(
+    result_idx: u64,
+    length: usize,
+) -> TypedPromiseResult<T> {
+    promise_result_checked_json_with_len(result_idx, length)?
+        .map_err(PromiseResultError::Deserialize)
+}
+
+pub type TypedPromiseResult<T> = Result<T, PromiseResultError>;
+
+#[derive(Debug)]
+pub enum PromiseResultError {
+    /// Promise failed
+    Failed,
+    /// Promise succeeded, but its result exceeded max length
+    TooLong(usize),
+    /// Promise succeeded, but its result couldn't be deserialized
+    Deserialize(serde_json::Error),
+}
+
+impl PromiseResultError {
+    /// Whether the promise itself failed or its result couldn't be read
+    #[inline]
+    pub const fn is_promise_error(&self) -> bool {
+        matches!(self, Self::Failed | Self::TooLong(_))
+    }
+}
+
+impl From<PromiseError> for PromiseResultError {
+    #[inline]
+    fn from(err: PromiseError) -> Self {
+        match err {
+            PromiseError::TooLong(len) => Self::TooLong(len),
+            // `PromiseError` is non-exhaustive
+            _ => Self::Failed,
+        }
+    }
+}
+
+impl fmt::Display for PromiseResultError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed => f.write_str("promise failed"),
+            Self::TooLong(len) => write!(f, "promise result is too long: {len} bytes"),
+            Self::Deserialize(err) => write!(f, "promise result deserialization: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PromiseResultError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Deserialize(err) => Some(err),
+            Self::Failed | Self::TooLong(_) => None,
+        }
+    }
+}
+
 /// Returns `Ok(())` if the promise at `result_idx` succeeded with an empty result.
 /// This is the expected outcome for void-returning cross-contract calls
 /// (e.g. `ft_transfer`, `nft_transfer`, `mt_batch_transfer`).
@@ -125,11 +197,49 @@ where
 
 #[cfg(test)]
 mod tests {
-    use near_sdk::json_types::U128;
+    use near_sdk::{
+        PromiseResult, RuntimeFeesConfig, json_types::U128, test_utils::VMContextBuilder,
+        test_vm_config, testing_env,
+    };
     use rstest::rstest;
 
     use super::*;
 
+    #[test]
+    fn typed_promise_results() {
+        testing_env!(
+            VMContextBuilder::new().build(),
+            test_vm_config(),
+            RuntimeFeesConfig::test(),
+            std::collections::HashMap::default(),
+            vec![
+                PromiseResult::Successful(br#""10""#.to_vec()),
+                PromiseResult::Successful(b"true".to_vec()),
+                PromiseResult::Failed,
+                PromiseResult::Successful(br#"["1","2"]"#.to_vec()),
+            ],
+        );
+
+        assert_eq!(promise_result_json::<U128>(0).unwrap(), U128(10));
+        assert!(matches!(
+            promise_result_json::<U128>(1),
+            Err(PromiseResultError::Deserialize(_))
+        ));
+        assert!(matches!(
+            promise_result_json::<bool>(2),
+            Err(PromiseResultError::Failed)
+        ));
+        assert_eq!(
+            promise_result_json_with_len::<Vec<U128>>(3, 2).unwrap(),
+            [U128(1), U128(2)]
+        );
+        assert!(
+            promise_result_json_with_len::<Vec<U128>>(3, 0)
+                .unwrap_err()
+                .is_promise_error()
+        );
+    }
+
     #[test]
     fn test_max_bool_json_len() {
         let max_len = bool::max_json_length_root(());
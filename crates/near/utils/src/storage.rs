@@ -0,0 +1,98 @@
+use near_sdk::{NearToken, StorageUsage, env};
+
+/// Tracks storage usage since its creation, so that exact storage
+/// costs can be charged or refunded afterwards.
+#[must_use = "use `.finish()` to get storage usage delta"]
+#[derive(Debug)]
+pub struct StorageTracker {
+    initial: StorageUsage,
+}
+
+impl StorageTracker {
+    #[inline]
+    pub fn start() -> Self {
+        Self {
+            initial: env::storage_usage(),
+        }
+    }
+
+    /// Returns storage usage delta so far
+    #[inline]
+    pub fn delta(&self) -> StorageDelta {
+        StorageDelta {
+            initial: self.initial,
+            current: env::storage_usage(),
+        }
+    }
+
+    #[inline]
+    pub fn finish(self) -> StorageDelta {
+        self.delta()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageDelta {
+    pub initial: StorageUsage,
+    pub current: StorageUsage,
+}
+
+impl StorageDelta {
+    /// Bytes added to storage, zero if storage usage decreased
+    #[inline]
+    pub const fn added(&self) -> StorageUsage {
+        self.current.saturating_sub(self.initial)
+    }
+
+    /// Bytes released from storage, zero if storage usage increased
+    #[inline]
+    pub const fn released(&self) -> StorageUsage {
+        self.initial.saturating_sub(self.current)
+    }
+
+    /// Cost of [added](Self::added) bytes
+    #[inline]
+    pub fn cost(&self) -> NearToken {
+        env::storage_byte_cost().saturating_mul(self.added().into())
+    }
+
+    /// Cost of [released](Self::released) bytes
+    #[inline]
+    pub fn refund(&self) -> NearToken {
+        env::storage_byte_cost().saturating_mul(self.released().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::{test_utils::VMContextBuilder, testing_env};
+
+    use super::*;
+
+    // see `num_extra_bytes_record` in runtime config
+    const RECORD_OVERHEAD: StorageUsage = 40;
+
+    #[test]
+    fn tracker() {
+        testing_env!(VMContextBuilder::new().build());
+
+        let tracker = StorageTracker::start();
+        env::storage_write(b"key", b"value");
+        let added = tracker.finish();
+        assert_eq!(added.added(), 3 + 5 + RECORD_OVERHEAD);
+        assert_eq!(added.released(), 0);
+        assert_eq!(
+            added.cost(),
+            env::storage_byte_cost().saturating_mul((3 + 5 + RECORD_OVERHEAD).into())
+        );
+        assert_eq!(added.refund(), NearToken::ZERO);
+
+        let tracker = StorageTracker::start();
+        env::storage_remove(b"key");
+        let released = tracker.finish();
+        assert_eq!(released.added(), 0);
+        assert_eq!(released.released(), 3 + 5 + RECORD_OVERHEAD);
+        assert_eq!(released.cost(), NearToken::ZERO);
+        assert_eq!(released.refund(), added.cost());
+    }
+}
@@ -6,6 +6,20 @@ pub trait CheckedSub<RHS = Self>: Sized {
     fn checked_sub(self, rhs: RHS) -> Option<Self>;
 }
 
+/// Adds signed delta to unsigned value, e.g. to apply a balance change.
+pub trait CheckedAddSigned: Sized {
+    type Signed;
+
+    fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self>;
+}
+
+/// Absolute difference between `self` and `rhs`, which never overflows.
+pub trait AbsDiff<RHS = Self> {
+    type Output;
+
+    fn abs_diff(self, rhs: RHS) -> Self::Output;
+}
+
 macro_rules! impl_checked_add {
     ($unsigned:ty, $signed:ty) => {
         impl CheckedAdd for $unsigned {
@@ -63,10 +77,42 @@ macro_rules! impl_checked_sub {
     };
 }
 
+macro_rules! impl_signed_ops {
+    ($unsigned:ty, $signed:ty) => {
+        impl CheckedAddSigned for $unsigned {
+            type Signed = $signed;
+
+            #[inline]
+            fn checked_add_signed(self, rhs: $signed) -> Option<Self> {
+                self.checked_add_signed(rhs)
+            }
+        }
+
+        impl AbsDiff for $unsigned {
+            type Output = Self;
+
+            #[inline]
+            fn abs_diff(self, rhs: Self) -> Self {
+                self.abs_diff(rhs)
+            }
+        }
+
+        impl AbsDiff for $signed {
+            type Output = $unsigned;
+
+            #[inline]
+            fn abs_diff(self, rhs: Self) -> $unsigned {
+                self.abs_diff(rhs)
+            }
+        }
+    };
+}
+
 macro_rules! impl_checked {
     ($unsigned:ty, $signed:ty) => {
         impl_checked_add!($unsigned, $signed);
         impl_checked_sub!($unsigned, $signed);
+        impl_signed_ops!($unsigned, $signed);
     };
 }
 impl_checked!(u8, i8);
@@ -74,3 +120,32 @@ impl_checked!(u16, i16);
 impl_checked!(u32, i32);
 impl_checked!(u64, i64);
 impl_checked!(u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_add_signed() {
+        assert_eq!(CheckedAddSigned::checked_add_signed(10u128, -3), Some(7));
+        assert_eq!(CheckedAddSigned::checked_add_signed(10u128, -11), None);
+        assert_eq!(
+            CheckedAddSigned::checked_add_signed(u128::MAX - 1, 1),
+            Some(u128::MAX)
+        );
+        assert_eq!(CheckedAddSigned::checked_add_signed(u128::MAX, 1), None);
+        assert_eq!(
+            CheckedAddSigned::checked_add_signed(u128::MAX, i128::MIN),
+            Some(u128::MAX / 2)
+        );
+    }
+
+    #[test]
+    fn abs_diff() {
+        assert_eq!(AbsDiff::abs_diff(3u128, 10), 7);
+        assert_eq!(AbsDiff::abs_diff(10u128, 3), 7);
+        assert_eq!(AbsDiff::abs_diff(0u128, u128::MAX), u128::MAX);
+        assert_eq!(AbsDiff::abs_diff(i128::MIN, i128::MAX), u128::MAX);
+        assert_eq!(AbsDiff::abs_diff(-5i128, 5), 10);
+    }
+}
@@ -1,9 +1,15 @@
+use crate::Rounding;
+
 pub trait CheckedDiv<RHS = Self>: Sized {
     fn checked_div(self, rhs: RHS) -> Option<Self>;
 
     fn checked_div_ceil(self, rhs: RHS) -> Option<Self>;
 }
 
+pub trait CheckedDivRound<RHS = Self>: Sized {
+    fn checked_div_round(self, rhs: RHS, rounding: Rounding) -> Option<Self>;
+}
+
 macro_rules! impl_checked_div {
     ($($t:ty),+) => {$(
         impl CheckedDiv for $t {
@@ -20,6 +26,21 @@ macro_rules! impl_checked_div {
                 Some(self.div_ceil(rhs))
             }
         }
+
+        impl CheckedDivRound for $t {
+            #[inline]
+            fn checked_div_round(self, rhs: Self, rounding: Rounding) -> Option<Self> {
+                match rounding {
+                    Rounding::Floor => CheckedDiv::checked_div(self, rhs),
+                    Rounding::Ceil => CheckedDiv::checked_div_ceil(self, rhs),
+                    Rounding::HalfUp => {
+                        let (q, r) = (self.checked_div(rhs)?, self % rhs);
+                        // r >= rhs / 2, without overflowing
+                        Some(if r >= rhs - r { q + 1 } else { q })
+                    }
+                }
+            }
+        }
     )+};
 }
 impl_checked_div!(u8, u16, u32, u64, u128);
@@ -0,0 +1,59 @@
+pub trait CheckedIlog10: Sized {
+    /// Returns the base 10 logarithm, rounded down.
+    /// Returns `None` if `self` is zero.
+    fn checked_ilog10(self) -> Option<u32>;
+
+    /// Returns the smallest power of ten greater than or equal to `self`.
+    /// Returns `None` on overflow.
+    fn checked_next_power_of_ten(self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_ilog10 {
+    ($($t:ty),+) => {$(
+        impl CheckedIlog10 for $t {
+            #[inline]
+            fn checked_ilog10(self) -> Option<u32> {
+                self.checked_ilog10()
+            }
+
+            #[inline]
+            fn checked_next_power_of_ten(self) -> Option<Self> {
+                let Some(exp) = self.checked_sub(1).and_then(<$t>::checked_ilog10) else {
+                    // self is 0 or 1
+                    return Some(1);
+                };
+                <$t>::checked_pow(10, exp + 1)
+            }
+        }
+    )+};
+}
+impl_checked_ilog10!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ilog10() {
+        assert_eq!(CheckedIlog10::checked_ilog10(0u128), None);
+        assert_eq!(CheckedIlog10::checked_ilog10(1u128), Some(0));
+        assert_eq!(CheckedIlog10::checked_ilog10(999u128), Some(2));
+        assert_eq!(CheckedIlog10::checked_ilog10(1000u128), Some(3));
+        assert_eq!(CheckedIlog10::checked_ilog10(u128::MAX), Some(38));
+    }
+
+    #[test]
+    fn next_power_of_ten() {
+        assert_eq!(0u128.checked_next_power_of_ten(), Some(1));
+        assert_eq!(1u128.checked_next_power_of_ten(), Some(1));
+        assert_eq!(2u128.checked_next_power_of_ten(), Some(10));
+        assert_eq!(10u128.checked_next_power_of_ten(), Some(10));
+        assert_eq!(11u128.checked_next_power_of_ten(), Some(100));
+        assert_eq!(
+            10u128.pow(38).checked_next_power_of_ten(),
+            Some(10u128.pow(38))
+        );
+        assert_eq!((10u128.pow(38) + 1).checked_next_power_of_ten(), None);
+        assert_eq!(101u8.checked_next_power_of_ten(), None);
+    }
+}
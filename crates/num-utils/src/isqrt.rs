@@ -0,0 +1,41 @@
+pub trait CheckedIsqrt: Sized {
+    /// Returns the integer square root, rounded down.
+    /// Returns `None` if `self` is negative.
+    fn checked_isqrt(self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_isqrt {
+    ($($unsigned:ty, $signed:ty);+) => {$(
+        impl CheckedIsqrt for $unsigned {
+            #[inline]
+            fn checked_isqrt(self) -> Option<Self> {
+                Some(self.isqrt())
+            }
+        }
+
+        impl CheckedIsqrt for $signed {
+            #[inline]
+            fn checked_isqrt(self) -> Option<Self> {
+                self.checked_isqrt()
+            }
+        }
+    )+};
+}
+impl_checked_isqrt!(u8, i8; u16, i16; u32, i32; u64, i64; u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isqrt() {
+        assert_eq!(CheckedIsqrt::checked_isqrt(0u128), Some(0));
+        assert_eq!(CheckedIsqrt::checked_isqrt(15u128), Some(3));
+        assert_eq!(CheckedIsqrt::checked_isqrt(16u128), Some(4));
+        assert_eq!(
+            CheckedIsqrt::checked_isqrt(u128::MAX),
+            Some(u128::from(u64::MAX))
+        );
+        assert_eq!(CheckedIsqrt::checked_isqrt(-1i128), None);
+    }
+}
@@ -2,7 +2,10 @@
 
 mod add_sub;
 mod div;
+mod ilog;
+mod isqrt;
 mod mul;
 mod mul_div;
+mod rounding;
 
-pub use self::{add_sub::*, div::*, mul::*, mul_div::*};
+pub use self::{add_sub::*, div::*, ilog::*, isqrt::*, mul::*, mul_div::*, rounding::*};
@@ -1,3 +1,7 @@
+use bnum::{BUint, cast::As};
+
+use crate::Rounding;
+
 pub trait CheckedMul<RHS = Self>: Sized {
     fn checked_mul(self, rhs: RHS) -> Option<Self>;
 
@@ -7,6 +11,10 @@ pub trait CheckedMul<RHS = Self>: Sized {
     }
 }
 
+pub trait CheckedMulRound<RHS = Self>: Sized {
+    fn checked_mul_round(self, rhs: RHS, rounding: Rounding) -> Option<Self>;
+}
+
 macro_rules! impl_checked_mul {
     ($($t:ty),+) => {$(
         impl CheckedMul for $t {
@@ -18,3 +26,48 @@ macro_rules! impl_checked_mul {
     )+};
 }
 impl_checked_mul!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+/// Computes full-width product of `self * rhs` without overflow,
+/// returning `(low, high)` halves of the result.
+pub trait WideningMul<RHS = Self>: Sized {
+    fn widening_mul(self, rhs: RHS) -> (Self, Self);
+}
+
+macro_rules! impl_widening_mul {
+    ($t:ty as $h:ty) => {
+        impl WideningMul for $t {
+            #[inline]
+            fn widening_mul(self, rhs: Self) -> (Self, Self) {
+                let prod = self.as_::<$h>() * rhs.as_::<$h>();
+                (prod.as_::<$t>(), (prod >> <$t>::BITS).as_::<$t>())
+            }
+        }
+    };
+}
+impl_widening_mul!(u8 as u16);
+impl_widening_mul!(u16 as u32);
+impl_widening_mul!(u32 as u64);
+impl_widening_mul!(u64 as u128);
+impl_widening_mul!(u128 as BUint<4>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widening_mul() {
+        assert_eq!(WideningMul::widening_mul(0u128, u128::MAX), (0, 0));
+        assert_eq!(WideningMul::widening_mul(u128::MAX, 1), (u128::MAX, 0));
+        assert_eq!(WideningMul::widening_mul(u128::MAX, 2), (u128::MAX - 1, 1));
+        assert_eq!(
+            WideningMul::widening_mul(u128::MAX, u128::MAX),
+            (1, u128::MAX - 1)
+        );
+        assert_eq!(WideningMul::widening_mul(1u128 << 64, 1 << 64), (0, 1));
+        assert_eq!(
+            WideningMul::widening_mul(u64::MAX, u64::MAX),
+            (1, u64::MAX - 1)
+        );
+        assert_eq!(WideningMul::widening_mul(200u8, 2), (144, 1));
+    }
+}
@@ -1,13 +1,22 @@
-use core::ops::Mul;
+use core::ops::{Add, Div, Mul};
 
 use bnum::{BInt, BUint, cast::As};
 
+use crate::Rounding;
+
+/// Computes `self * mul / div` using a double-width intermediate,
+/// so it only fails if `div` is zero or the final quotient doesn't fit
+/// into `Self`, even if `self * mul` alone would overflow.
 pub trait CheckedMulDiv<RHS = Self>: Sized {
     fn checked_mul_div(self, mul: RHS, div: RHS) -> Option<Self>;
     fn checked_mul_div_ceil(self, mul: RHS, div: RHS) -> Option<Self>;
     fn checked_mul_div_euclid(self, mul: RHS, div: RHS) -> Option<Self>;
 }
 
+pub trait CheckedMulDivRound<RHS = Self>: Sized {
+    fn checked_mul_div_round(self, mul: RHS, div: RHS, rounding: Rounding) -> Option<Self>;
+}
+
 macro_rules! impl_checked_mul_div {
     ($t:ty as $h:ty) => {
         impl CheckedMulDiv for $t {
@@ -52,6 +61,107 @@ impl_checked_mul_div!(u32 as u64);
 impl_checked_mul_div!(u64 as u128);
 impl_checked_mul_div!(u128 as BUint<4>);
 
+/// Same as [`CheckedMulDiv`], but clamps the result to `Self::MAX`
+/// on overflow.
+///
+/// # Panics
+/// If `div` is zero.
+pub trait SaturatingMulDiv<RHS = Self>: Sized {
+    #[must_use]
+    fn saturating_mul_div(self, mul: RHS, div: RHS) -> Self;
+    #[must_use]
+    fn saturating_mul_div_ceil(self, mul: RHS, div: RHS) -> Self;
+}
+
+/// Same as [`CheckedMulDiv`], but wraps around at the boundary of the
+/// type on overflow, i.e. returns the lowest bits of the quotient.
+///
+/// # Panics
+/// If `div` is zero.
+pub trait WrappingMulDiv<RHS = Self>: Sized {
+    #[must_use]
+    fn wrapping_mul_div(self, mul: RHS, div: RHS) -> Self;
+    #[must_use]
+    fn wrapping_mul_div_ceil(self, mul: RHS, div: RHS) -> Self;
+}
+
+macro_rules! impl_overflowing_mul_div {
+    ($t:ty as $h:ty) => {
+        impl SaturatingMulDiv for $t {
+            #[inline]
+            fn saturating_mul_div(self, mul: Self, div: Self) -> Self {
+                assert!(div != 0, "attempt to divide by zero");
+                self.checked_mul_div(mul, div).unwrap_or(Self::MAX)
+            }
+
+            #[inline]
+            fn saturating_mul_div_ceil(self, mul: Self, div: Self) -> Self {
+                assert!(div != 0, "attempt to divide by zero");
+                self.checked_mul_div_ceil(mul, div).unwrap_or(Self::MAX)
+            }
+        }
+
+        impl WrappingMulDiv for $t {
+            #[inline]
+            fn wrapping_mul_div(self, mul: Self, div: Self) -> Self {
+                self.as_::<$h>()
+                    .mul(mul.as_::<$h>())
+                    .div(div.as_::<$h>())
+                    .as_::<$t>()
+            }
+
+            #[inline]
+            fn wrapping_mul_div_ceil(self, mul: Self, div: Self) -> Self {
+                self.as_::<$h>()
+                    .mul(mul.as_::<$h>())
+                    .div_ceil(div.as_::<$h>())
+                    .as_::<$t>()
+            }
+        }
+    };
+}
+impl_overflowing_mul_div!(u8 as u16);
+impl_overflowing_mul_div!(u16 as u32);
+impl_overflowing_mul_div!(u32 as u64);
+impl_overflowing_mul_div!(u64 as u128);
+impl_overflowing_mul_div!(u128 as BUint<4>);
+
+macro_rules! impl_checked_mul_div_round {
+    ($t:ty as $h:ty) => {
+        impl CheckedMulDivRound for $t {
+            #[inline]
+            fn checked_mul_div_round(
+                self,
+                mul: Self,
+                div: Self,
+                rounding: Rounding,
+            ) -> Option<Self> {
+                match rounding {
+                    Rounding::Floor => self.checked_mul_div(mul, div),
+                    Rounding::Ceil => self.checked_mul_div_ceil(mul, div),
+                    Rounding::HalfUp => {
+                        if div == 0 {
+                            return None;
+                        }
+                        // (self * mul + div / 2) never overflows the double-width type
+                        self.as_::<$h>()
+                            .mul(mul.as_::<$h>())
+                            .add((div / 2).as_::<$h>())
+                            .div(div.as_::<$h>())
+                            .try_into()
+                            .ok()
+                    }
+                }
+            }
+        }
+    };
+}
+impl_checked_mul_div_round!(u8 as u16);
+impl_checked_mul_div_round!(u16 as u32);
+impl_checked_mul_div_round!(u32 as u64);
+impl_checked_mul_div_round!(u64 as u128);
+impl_checked_mul_div_round!(u128 as BUint<4>);
+
 // #![feature(int_roundings)]
 // const _: () = {
 //     impl_checked_mul_div!(i8 as i16);
@@ -60,3 +170,73 @@ impl_checked_mul_div!(u128 as BUint<4>);
 //     impl_checked_mul_div!(i64 as i128);
 // };
 impl_checked_mul_div!(i128 as BInt<4>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intermediate_overflow() {
+        assert_eq!(
+            u128::MAX.checked_mul_div(u128::MAX, u128::MAX),
+            Some(u128::MAX)
+        );
+        assert_eq!(
+            u128::MAX.checked_mul_div_ceil(u128::MAX - 1, u128::MAX),
+            Some(u128::MAX - 1)
+        );
+        assert_eq!(
+            (u128::MAX / 3).checked_mul_div(10u128.pow(30), 10u128.pow(30) + 1),
+            Some(113427455640312821154458202477142643029)
+        );
+        assert_eq!(u64::MAX.checked_mul_div(u64::MAX, 2), None);
+        assert_eq!(u128::MAX.checked_mul_div(2, 1), None);
+        assert_eq!(1u128.checked_mul_div(1, 0), None);
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(u128::MAX.saturating_mul_div(2, 1), u128::MAX);
+        assert_eq!(u128::MAX.saturating_mul_div_ceil(3, 2), u128::MAX);
+        assert_eq!(
+            u128::MAX.saturating_mul_div(u128::MAX, u128::MAX),
+            u128::MAX
+        );
+        assert_eq!(10u128.saturating_mul_div_ceil(1, 3), 4);
+        assert_eq!(200u8.saturating_mul_div(2, 1), u8::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn saturating_div_by_zero() {
+        let _ = 1u128.saturating_mul_div(1, 0);
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(u128::MAX.wrapping_mul_div(2, 1), u128::MAX - 1);
+        assert_eq!(u128::MAX.wrapping_mul_div(u128::MAX, u128::MAX), u128::MAX);
+        assert_eq!(200u8.wrapping_mul_div(2, 1), 144);
+        assert_eq!(200u8.wrapping_mul_div_ceil(3, 2), 44);
+        assert_eq!(10u64.wrapping_mul_div_ceil(1, 3), 4);
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(7u128.checked_mul_div(u128::MAX, u128::MAX - 1), Some(7));
+        assert_eq!(
+            7u128.checked_mul_div_ceil(u128::MAX, u128::MAX - 1),
+            Some(8)
+        );
+        assert_eq!(
+            7u128.checked_mul_div_round(u128::MAX, u128::MAX - 1, Rounding::HalfUp),
+            Some(7)
+        );
+        assert_eq!(
+            5u128.checked_mul_div_round(u128::MAX, 2 * (u128::MAX / 2), Rounding::HalfUp),
+            Some(5)
+        );
+        assert_eq!(1u8.checked_mul_div_round(1, 2, Rounding::HalfUp), Some(1));
+        assert_eq!(1u8.checked_mul_div_round(1, 3, Rounding::HalfUp), Some(0));
+    }
+}
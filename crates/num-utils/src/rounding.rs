@@ -0,0 +1,14 @@
+/// Rounding mode for operations producing inexact results.
+///
+/// Choosing the direction explicitly at the call site makes it
+/// obvious which party the rounding favors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// Round towards zero.
+    #[default]
+    Floor,
+    /// Round away from zero.
+    Ceil,
+    /// Round to the nearest, ties away from zero.
+    HalfUp,
+}
@@ -0,0 +1,104 @@
+use thiserror::Error as ThisError;
+
+use crate::UD128;
+
+impl UD128 {
+    /// Converts `f64` into [`UD128`] using the shortest decimal
+    /// representation that round-trips back to the same `f64`.
+    /// Values with more than 38 decimals are rounded to 38 decimals.
+    ///
+    /// Fails if the relative error introduced by the conversion
+    /// exceeds `max_relative_error`.
+    ///
+    /// NOTE: floats are not deterministic across platforms,
+    /// so this is intended for off-chain components only.
+    pub fn try_from_f64(value: f64, max_relative_error: f64) -> Result<Self, FromF64Error> {
+        if !value.is_finite() || value < 0.0 {
+            return Err(FromF64Error::InvalidValue);
+        }
+        // get rid of negative zero
+        let value = value.abs();
+
+        let d: Self = value
+            // `Display` for floats never uses scientific notation
+            .to_string()
+            .parse()
+            .or_else(|_| {
+                format!("{value:.decimals$}", decimals = Self::MAX_DECIMALS.into()).parse()
+            })
+            .map_err(|_| FromF64Error::Overflow)?;
+
+        if value > 0.0 && ((d.to_f64_lossy() - value) / value).abs() > max_relative_error {
+            return Err(FromF64Error::PrecisionLoss);
+        }
+
+        Ok(d)
+    }
+
+    /// Converts into the nearest `f64`.
+    ///
+    /// NOTE: floats are not deterministic across platforms,
+    /// so this is intended for off-chain components only.
+    pub fn to_f64_lossy(self) -> f64 {
+        self.to_string()
+            .parse()
+            .unwrap_or_else(|_| unreachable!("decimal is always a valid float"))
+    }
+}
+
+#[derive(Debug, ThisError, PartialEq, Eq)]
+pub enum FromF64Error {
+    #[error("not a finite non-negative number")]
+    InvalidValue,
+    #[error("overflow")]
+    Overflow,
+    #[error("precision loss")]
+    PrecisionLoss,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0.0, "0")]
+    #[case(-0.0, "0")]
+    #[case(1.0, "1")]
+    #[case(0.1, "0.1")]
+    #[case(1.5, "1.5")]
+    #[case(0.000001, "0.000001")]
+    #[case(123456.789, "123456.789")]
+    #[case(1e30, "1000000000000000000000000000000")]
+    #[case(1.23e-38, "0.00000000000000000000000000000000000001")]
+    fn from_f64(#[case] value: f64, #[case] expected: &str) {
+        assert_eq!(
+            UD128::try_from_f64(value, 1.0).unwrap(),
+            expected.parse().unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(f64::NAN, FromF64Error::InvalidValue)]
+    #[case(f64::INFINITY, FromF64Error::InvalidValue)]
+    #[case(-1.0, FromF64Error::InvalidValue)]
+    #[case(1e39, FromF64Error::Overflow)]
+    #[case(1.23e-38, FromF64Error::PrecisionLoss)]
+    #[case(1e-40, FromF64Error::PrecisionLoss)]
+    fn from_f64_invalid(#[case] value: f64, #[case] err: FromF64Error) {
+        assert_eq!(UD128::try_from_f64(value, 1e-9).unwrap_err(), err);
+    }
+
+    #[rstest]
+    #[case("0", 0.0)]
+    #[case("1", 1.0)]
+    #[case("0.1", 0.1)]
+    #[case("123456.789", 123456.789)]
+    #[case("0.00000000000000000000000000000000000001", 1e-38)]
+    #[case("340282366920938463463374607431768211455", 3.402823669209385e38)]
+    fn to_f64_lossy(#[case] d: &str, #[case] expected: f64) {
+        let d: UD128 = d.parse().unwrap();
+        assert!((d.to_f64_lossy() - expected).abs() <= f64::EPSILON * expected);
+    }
+}
@@ -1,9 +1,14 @@
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
+mod float;
 mod ops;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod str;
 
-pub use self::str::*;
+pub use defuse_num_utils::Rounding;
+
+pub use self::{float::*, str::*};
 
 /// Floating point unsigned decimal price, i.e. dst per 1 src
 /// always reduced (i.e. normalized)
@@ -3,10 +3,173 @@ use core::{
     ops::{Div, Mul},
 };
 
-use defuse_num_utils::{CheckedDiv, CheckedMul, CheckedMulDiv};
+use bnum::BUint;
+use defuse_num_utils::{
+    CheckedDiv, CheckedDivRound, CheckedMul, CheckedMulDivRound, CheckedMulRound, Rounding,
+};
 
 use crate::UD128;
 
+type U256 = BUint<4>;
+
+impl UD128 {
+    /// Returns `1 / self`, i.e. converts "dst per 1 src" price into
+    /// "src per 1 dst" and vice versa.
+    ///
+    /// The result is computed with the maximum precision representable
+    /// by [`UD128`] and rounded according to `rounding`. Returns `None`
+    /// if `self` is zero or if the inverse rounds to zero, which can
+    /// only happen for `self > 10^38` unless [`Rounding::Ceil`] is used:
+    /// in that case such values are inverted to [`UD128::MIN`].
+    ///
+    /// Inversion never overflows, since the inverse of [`UD128::MIN`]
+    /// is `10^38 < UD128::MAX`.
+    #[must_use]
+    #[inline]
+    pub fn checked_invert(self, rounding: Rounding) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        // 10^(len - 1) <= digits < 10^len, so
+        // 10^(self.decimals + decimals) / digits < 10^(self.decimals + decimals - len + 1),
+        // which always fits into u128 for decimals <= MAX_DECIMALS + len - 1 - self.decimals
+        #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+        let len = (self.digits().ilog10() + 1) as u8;
+        let decimals = (Self::MAX_DECIMALS + len - 1 - self.decimals()).min(Self::MAX_DECIMALS);
+
+        let invert = |decimals: u8| {
+            self.denominator()
+                .checked_mul_div_round(10u128.pow(decimals.into()), self.digits(), rounding)
+                .and_then(|digits| Self::new(decimals, digits))
+        };
+
+        // try to squeeze one more digit of precision first
+        Some(decimals)
+            .filter(|d| *d < Self::MAX_DECIMALS)
+            .and_then(|d| invert(d + 1))
+            .or_else(|| invert(decimals))
+            .filter(|inverted| !inverted.is_zero())
+    }
+
+    /// Returns the square root of `self` rounded according to `rounding`.
+    ///
+    /// The result is computed with 38 significant digits, but no more
+    /// than 38 decimals, i.e. its absolute error is less than
+    /// [`UD128::MIN`] and its relative error is less than `10^-37`.
+    /// Square root never overflows, since `sqrt(UD128::MAX) < 2^64`.
+    #[must_use]
+    pub fn sqrt(self, rounding: Rounding) -> Self {
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+
+        // radicand = digits * 10^(2 * decimals - self.decimals) < 10^(len + 2 * decimals - self.decimals),
+        // so for decimals <= (76 - len + self.decimals) / 2 the radicand is less than 10^76,
+        // which fits into U256, while the root is less than 10^38, which fits into u128
+        let len = self.digits().ilog10() + 1;
+        let decimals = u32::midpoint(
+            2 * u32::from(Self::MAX_DECIMALS) - len,
+            u32::from(self.decimals()),
+        )
+        .min(Self::MAX_DECIMALS.into());
+
+        let radicand =
+            U256::from(self.digits()) * U256::TEN.pow(2 * decimals - u32::from(self.decimals()));
+        let root = isqrt(radicand);
+        let root = match rounding {
+            Rounding::Ceil if root * root < radicand => root + U256::ONE,
+            // (root + 0.5)^2 = root^2 + root + 0.25
+            Rounding::HalfUp if radicand - root * root > root => root + U256::ONE,
+            Rounding::Floor | Rounding::Ceil | Rounding::HalfUp => root,
+        };
+
+        decimals
+            .try_into()
+            .ok()
+            .zip(root.try_into().ok())
+            .and_then(|(decimals, digits)| Self::new(decimals, digits))
+            .unwrap_or_else(|| unreachable!())
+    }
+
+    /// Raises `self` to the power of `exp` using exponentiation by squaring.
+    ///
+    /// Each intermediate multiplication keeps at least 38 significant
+    /// digits (but no more than 38 decimals) and is rounded according to
+    /// `rounding`, so [`Rounding::Floor`] never exceeds the exact value,
+    /// while [`Rounding::Ceil`] is never less than that. The relative
+    /// error is less than `2 * log2(exp) * 10^-37`.
+    ///
+    /// Returns `None` on overflow.
+    #[must_use]
+    pub fn checked_pow(self, mut exp: u32, rounding: Rounding) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul_ud128(base, rounding)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul_ud128(base, rounding)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns `self * rhs` with at least 38 significant digits, but
+    /// no more than 38 decimals, rounded according to `rounding`.
+    fn checked_mul_ud128(self, rhs: Self, rounding: Rounding) -> Option<Self> {
+        const MAX_LEN: u32 = u128::MAX.ilog10() + 1;
+
+        let product = U256::from(self.digits()) * U256::from(rhs.digits());
+        if product.is_zero() {
+            return Some(Self::ZERO);
+        }
+        let decimals = u32::from(self.decimals()) + u32::from(rhs.decimals());
+
+        // number of least significant digits to drop
+        let mut drop = decimals
+            .saturating_sub(Self::MAX_DECIMALS.into())
+            .max((product.ilog10() + 1).saturating_sub(MAX_LEN));
+        loop {
+            // integer part doesn't fit into u128
+            let decimals: u8 = decimals.checked_sub(drop)?.try_into().ok()?;
+
+            if let Ok(digits) = div_round(product, U256::TEN.pow(drop), rounding).try_into() {
+                return Self::new(decimals, digits);
+            }
+            drop += 1;
+        }
+    }
+}
+
+/// Integer square root using Newton's method
+fn isqrt(n: U256) -> U256 {
+    if n < U256::TWO {
+        return n;
+    }
+    // 2^ceil(bits / 2) >= sqrt(n)
+    let mut x = U256::power_of_two(n.bits().div_ceil(2));
+    loop {
+        let y = (x + n / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+fn div_round(n: U256, d: U256, rounding: Rounding) -> U256 {
+    let (q, r) = (n / d, n % d);
+    match rounding {
+        Rounding::Ceil if !r.is_zero() => q + U256::ONE,
+        // r >= d / 2, without overflowing
+        Rounding::HalfUp if r >= d - r => q + U256::ONE,
+        Rounding::Floor | Rounding::Ceil | Rounding::HalfUp => q,
+    }
+}
+
 impl Ord for UD128 {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -32,15 +195,22 @@ impl PartialOrd for UD128 {
     }
 }
 
+impl CheckedMulRound<UD128> for u128 {
+    #[inline]
+    fn checked_mul_round(self, rhs: UD128, rounding: Rounding) -> Option<Self> {
+        self.checked_mul_div_round(rhs.digits(), rhs.denominator(), rounding)
+    }
+}
+
 impl CheckedMul<UD128> for u128 {
     #[inline]
     fn checked_mul(self, rhs: UD128) -> Option<Self> {
-        self.checked_mul_div(rhs.digits(), rhs.denominator())
+        self.checked_mul_round(rhs, Rounding::Floor)
     }
 
     #[inline]
     fn checked_mul_ceil(self, rhs: UD128) -> Option<Self> {
-        self.checked_mul_div_ceil(rhs.digits(), rhs.denominator())
+        self.checked_mul_round(rhs, Rounding::Ceil)
     }
 }
 
@@ -53,15 +223,22 @@ impl Mul<UD128> for u128 {
     }
 }
 
+impl CheckedDivRound<UD128> for u128 {
+    #[inline]
+    fn checked_div_round(self, rhs: UD128, rounding: Rounding) -> Option<Self> {
+        self.checked_mul_div_round(rhs.denominator(), rhs.digits(), rounding)
+    }
+}
+
 impl CheckedDiv<UD128> for u128 {
     #[inline]
     fn checked_div(self, rhs: UD128) -> Option<Self> {
-        self.checked_mul_div(rhs.denominator(), rhs.digits())
+        self.checked_div_round(rhs, Rounding::Floor)
     }
 
     #[inline]
     fn checked_div_ceil(self, rhs: UD128) -> Option<Self> {
-        self.checked_mul_div_ceil(rhs.denominator(), rhs.digits())
+        self.checked_div_round(rhs, Rounding::Ceil)
     }
 }
 
@@ -105,4 +282,183 @@ mod tests {
         assert_eq!(a.cmp(&b), ord);
         assert_eq!(b.cmp(&a), ord.reverse(), "reverse ordering mismatch");
     }
+
+    #[rstest]
+    #[case("1", "1", "1", "1")]
+    #[case("2", "0.5", "0.5", "0.5")]
+    #[case("0.5", "2", "2", "2")]
+    #[case("0.25", "4", "4", "4")]
+    #[case(
+        "3",
+        "0.33333333333333333333333333333333333333",
+        "0.33333333333333333333333333333333333334",
+        "0.33333333333333333333333333333333333333"
+    )]
+    #[case(
+        "1.5",
+        "0.66666666666666666666666666666666666666",
+        "0.66666666666666666666666666666666666667",
+        "0.66666666666666666666666666666666666667"
+    )]
+    #[case(
+        "0.3",
+        "3.33333333333333333333333333333333333333",
+        "3.33333333333333333333333333333333333334",
+        "3.33333333333333333333333333333333333333"
+    )]
+    #[case(
+        "0.00000000000000000000000000000000000001",
+        "100000000000000000000000000000000000000",
+        "100000000000000000000000000000000000000",
+        "100000000000000000000000000000000000000"
+    )]
+    #[case(
+        "100000000000000000000000000000000000000",
+        "0.00000000000000000000000000000000000001",
+        "0.00000000000000000000000000000000000001",
+        "0.00000000000000000000000000000000000001"
+    )]
+    fn invert(#[case] price: &str, #[case] floor: &str, #[case] ceil: &str, #[case] half_up: &str) {
+        let price: UD128 = price.parse().unwrap();
+
+        for (rounding, expected) in [
+            (Rounding::Floor, floor),
+            (Rounding::Ceil, ceil),
+            (Rounding::HalfUp, half_up),
+        ] {
+            assert_eq!(
+                price.checked_invert(rounding).unwrap(),
+                expected.parse().unwrap(),
+                "{rounding:?}"
+            );
+        }
+    }
+
+    #[rstest]
+    fn invert_zero(
+        #[values(Rounding::Floor, Rounding::Ceil, Rounding::HalfUp)] rounding: Rounding,
+    ) {
+        assert_eq!(UD128::ZERO.checked_invert(rounding), None);
+    }
+
+    #[test]
+    fn invert_max() {
+        assert_eq!(UD128::MAX.checked_invert(Rounding::Floor), None);
+        assert_eq!(UD128::MAX.checked_invert(Rounding::HalfUp), None);
+        assert_eq!(UD128::MAX.checked_invert(Rounding::Ceil), Some(UD128::MIN));
+    }
+
+    #[rstest]
+    #[case("0", "0", "0", "0")]
+    #[case("1", "1", "1", "1")]
+    #[case("4", "2", "2", "2")]
+    #[case("0.04", "0.2", "0.2", "0.2")]
+    #[case(
+        "2",
+        "1.4142135623730950488016887242096980785",
+        "1.4142135623730950488016887242096980786",
+        "1.4142135623730950488016887242096980786"
+    )]
+    #[case(
+        "0.00000000000000000000000000000000000001",
+        "0.0000000000000000001",
+        "0.0000000000000000001",
+        "0.0000000000000000001"
+    )]
+    #[case(
+        "0.00000000000000000000000000000000000002",
+        "0.00000000000000000014142135623730950488",
+        "0.00000000000000000014142135623730950489",
+        "0.00000000000000000014142135623730950488"
+    )]
+    #[case(
+        "340282366920938463463374607431768211455",
+        "18446744073709551615.999999999999999999",
+        "18446744073709551616",
+        "18446744073709551616"
+    )]
+    fn sqrt(#[case] d: &str, #[case] floor: &str, #[case] ceil: &str, #[case] half_up: &str) {
+        let d: UD128 = d.parse().unwrap();
+
+        for (rounding, expected) in [
+            (Rounding::Floor, floor),
+            (Rounding::Ceil, ceil),
+            (Rounding::HalfUp, half_up),
+        ] {
+            assert_eq!(d.sqrt(rounding), expected.parse().unwrap(), "{rounding:?}");
+        }
+    }
+
+    #[rstest]
+    #[case("0", 0, Rounding::Floor, "1")]
+    #[case("0", 3, Rounding::Floor, "0")]
+    #[case("2", 10, Rounding::Floor, "1024")]
+    #[case("1.5", 2, Rounding::Floor, "2.25")]
+    #[case("0.1", 38, Rounding::Floor, "0.00000000000000000000000000000000000001")]
+    #[case("0.1", 39, Rounding::Floor, "0")]
+    #[case("0.1", 39, Rounding::Ceil, "0.00000000000000000000000000000000000001")]
+    #[case("1.1", 3, Rounding::Floor, "1.331")]
+    #[case("10", 38, Rounding::Floor, "100000000000000000000000000000000000000")]
+    #[case(
+        "1.0000000000000000000000000000000000001",
+        2,
+        Rounding::Floor,
+        "1.0000000000000000000000000000000000002"
+    )]
+    #[case(
+        "1.0000000000000000000000000000000000001",
+        2,
+        Rounding::Ceil,
+        "1.00000000000000000000000000000000000021"
+    )]
+    fn pow(#[case] d: &str, #[case] exp: u32, #[case] rounding: Rounding, #[case] expected: &str) {
+        let d: UD128 = d.parse().unwrap();
+        assert_eq!(
+            d.checked_pow(exp, rounding).unwrap(),
+            expected.parse().unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("10", 39)]
+    #[case("2", 128)]
+    #[case("340282366920938463463374607431768211455", 2)]
+    fn pow_overflow(#[case] d: &str, #[case] exp: u32) {
+        let d: UD128 = d.parse().unwrap();
+        assert_eq!(d.checked_pow(exp, Rounding::Floor), None);
+    }
+
+    #[rstest]
+    #[case(10, "0.25", Rounding::Floor, 2)]
+    #[case(10, "0.25", Rounding::Ceil, 3)]
+    #[case(10, "0.25", Rounding::HalfUp, 3)]
+    #[case(10, "0.24", Rounding::HalfUp, 2)]
+    #[case(10, "0.3", Rounding::Floor, 3)]
+    #[case(10, "0.3", Rounding::Ceil, 3)]
+    #[case(10, "0.3", Rounding::HalfUp, 3)]
+    fn mul_round(
+        #[case] amount: u128,
+        #[case] price: &str,
+        #[case] rounding: Rounding,
+        #[case] expected: u128,
+    ) {
+        let price: UD128 = price.parse().unwrap();
+        assert_eq!(amount.checked_mul_round(price, rounding), Some(expected));
+    }
+
+    #[rstest]
+    #[case(10, "3", Rounding::Floor, 3)]
+    #[case(10, "3", Rounding::Ceil, 4)]
+    #[case(10, "3", Rounding::HalfUp, 3)]
+    #[case(10, "4", Rounding::HalfUp, 3)]
+    #[case(10, "0", Rounding::Floor, None)]
+    fn div_round(
+        #[case] amount: u128,
+        #[case] price: &str,
+        #[case] rounding: Rounding,
+        #[case] expected: impl Into<Option<u128>>,
+    ) {
+        let price: UD128 = price.parse().unwrap();
+        assert_eq!(amount.checked_div_round(price, rounding), expected.into());
+    }
 }
@@ -0,0 +1,244 @@
+//! Opt-in alternative serde representations for [`UD128`] to be used
+//! with `#[serde_as(as = "...")]`. By default, [`UD128`] is serialized
+//! as a string.
+
+use core::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde_with::{DeserializeAs, SerializeAs};
+
+use crate::UD128;
+
+/// Serializes [`UD128`] as a number.
+///
+/// Integers are serialized exactly, while fractional values are
+/// converted to the nearest `f64`, so precision might be lost.
+/// Deserialization accepts both numbers and strings, but note that
+/// most deserializers (including `serde_json`) parse numbers that
+/// don't fit into `u64` as `f64`.
+pub struct AsNumber;
+
+impl SerializeAs<UD128> for AsNumber {
+    fn serialize_as<S>(source: &UD128, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if source.decimals() == 0 {
+            return serializer.serialize_u128(source.digits());
+        }
+        serializer.serialize_f64(source.to_f64_lossy())
+    }
+}
+
+impl<'de> DeserializeAs<'de, UD128> for AsNumber {
+    fn deserialize_as<D>(deserializer: D) -> Result<UD128, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = UD128;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("non-negative number or decimal string")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(u128::from(v).into())
+            }
+
+            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(v.into())
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                u128::try_from(v)
+                    .map(Into::into)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                UD128::try_from_f64(v, f64::EPSILON).map_err(E::custom)
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// Serializes [`UD128`] as `{"decimals": u8, "digits": u128}` object
+/// without loss of precision.
+pub struct AsObject;
+
+#[cfg_attr(feature = "abi", derive(::schemars::JsonSchema))]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "UD128Object", deny_unknown_fields)]
+struct Object {
+    decimals: u8,
+    digits: u128,
+}
+
+impl SerializeAs<UD128> for AsObject {
+    fn serialize_as<S>(source: &UD128, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Object {
+            decimals: source.decimals(),
+            digits: source.digits(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, UD128> for AsObject {
+    fn deserialize_as<D>(deserializer: D) -> Result<UD128, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Object { decimals, digits } = Object::deserialize(deserializer)?;
+        UD128::new(decimals, digits)
+            .ok_or_else(|| de::Error::custom(format!("decimals: {decimals} is out of range")))
+    }
+}
+
+#[cfg(feature = "abi")]
+const _: () = {
+    use schemars::{
+        JsonSchema, SchemaGenerator,
+        schema::{InstanceType, Schema, SchemaObject},
+    };
+    use serde_with::schemars_0_8::JsonSchemaAs;
+
+    impl JsonSchemaAs<UD128> for AsNumber {
+        fn schema_name() -> String {
+            "UD128Number".into()
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            SchemaObject {
+                instance_type: Some(InstanceType::Number.into()),
+                ..Default::default()
+            }
+            .into()
+        }
+    }
+
+    impl JsonSchemaAs<UD128> for AsObject {
+        fn schema_name() -> String {
+            Object::schema_name()
+        }
+
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            Object::json_schema(generator)
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_with::serde_as;
+
+    use super::*;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Number(#[serde_as(as = "AsNumber")] UD128);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Obj(#[serde_as(as = "AsObject")] UD128);
+
+    #[rstest]
+    #[case("0", "0")]
+    #[case("123", "123")]
+    #[case("18446744073709551615", "18446744073709551615")]
+    #[case("0.5", "0.5")]
+    #[case("1.25", "1.25")]
+    #[case("0.000001", "1e-6")]
+    fn as_number(#[case] d: &str, #[case] json: &str) {
+        let d = Number(d.parse().unwrap());
+        assert_eq!(serde_json::to_string(&d).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Number>(json).unwrap(), d);
+    }
+
+    #[test]
+    fn as_number_max() {
+        assert_eq!(
+            serde_json::to_string(&Number(UD128::MAX)).unwrap(),
+            "340282366920938463463374607431768211455"
+        );
+    }
+
+    #[rstest]
+    #[case(r#""1.5""#, "1.5")]
+    #[case("1.50", "1.5")]
+    #[case("42", "42")]
+    #[case(r#""1e-6""#, "0.000001")]
+    fn as_number_deserialize(#[case] json: &str, #[case] d: &str) {
+        assert_eq!(
+            serde_json::from_str::<Number>(json).unwrap(),
+            Number(d.parse().unwrap())
+        );
+    }
+
+    #[rstest]
+    #[case("-1")]
+    #[case("-0.5")]
+    #[case(r#""abc""#)]
+    #[case("null")]
+    fn as_number_invalid(#[case] json: &str) {
+        serde_json::from_str::<Number>(json).unwrap_err();
+    }
+
+    #[rstest]
+    #[case("0", r#"{"decimals":0,"digits":0}"#)]
+    #[case("1.25", r#"{"decimals":2,"digits":125}"#)]
+    #[case(
+        "3.40282366920938463463374607431768211455",
+        r#"{"decimals":38,"digits":340282366920938463463374607431768211455}"#
+    )]
+    fn as_object(#[case] d: &str, #[case] json: &str) {
+        let d = Obj(d.parse().unwrap());
+        assert_eq!(serde_json::to_string(&d).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Obj>(json).unwrap(), d);
+    }
+
+    #[test]
+    fn as_object_normalizes() {
+        assert_eq!(
+            serde_json::from_str::<Obj>(r#"{"decimals":3,"digits":1500}"#).unwrap(),
+            Obj("1.5".parse().unwrap())
+        );
+    }
+
+    #[rstest]
+    #[case(r#"{"decimals":39,"digits":1}"#)]
+    #[case(r#"{"decimals":1}"#)]
+    #[case(r#"{"decimals":1,"digits":1,"extra":1}"#)]
+    #[case(r#""1.5""#)]
+    fn as_object_invalid(#[case] json: &str) {
+        serde_json::from_str::<Obj>(json).unwrap_err();
+    }
+}
@@ -15,6 +15,8 @@ impl Debug for UD128 {
     }
 }
 
+/// Formats as plain decimal without exponent and trailing zeros,
+/// so that `s.parse::<UD128>()?.to_string()` is stable.
 impl Display for UD128 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -32,53 +34,82 @@ impl Display for UD128 {
     }
 }
 
+/// Accepts optional leading `+`, `_` separators between digits
+/// (e.g. `1_000.000_001`) and scientific notation (e.g. `1.5e-6`).
 impl FromStr for UD128 {
     type Err = ParseDecimalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (integer, fract) = s.split_once('.').map_or((s, None), |(i, f)| (i, Some(f)));
+        let s = s.strip_prefix('+').unwrap_or(s);
 
-        if integer.starts_with('+') {
+        let (mantissa, exp) = match s.split_once(['e', 'E']) {
+            Some((mantissa, exp)) => (mantissa, parse_exp(exp)?),
+            None => (s, 0),
+        };
+
+        let (integer, fract) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if integer.is_empty() && fract.is_empty() {
             return Err(ParseDecimalError::InvalidFormat);
         }
+        validate_digits(integer)?;
+        validate_digits(fract)?;
 
-        let mut digits = if integer.is_empty() {
-            if fract.is_none_or(str::is_empty) {
-                return Err(ParseDecimalError::InvalidFormat);
-            }
-            0u128
-        } else {
-            integer.parse::<u128>()?
-        };
+        let mut digits: String = integer
+            .chars()
+            .chain(fract.chars())
+            .filter(|c| *c != '_')
+            .collect();
+        let mut decimals = i64::try_from(fract.chars().filter(|c| *c != '_').count())
+            .ok()
+            .and_then(|d| d.checked_sub(exp))
+            .ok_or(ParseDecimalError::Overflow)?;
+
+        // normalize
+        while digits.ends_with('0') {
+            digits.pop();
+            decimals = decimals.saturating_sub(1);
+        }
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() {
+            return Ok(Self::ZERO);
+        }
 
-        let decimals = if let Some(fract) = fract
-            .map(|s| s.trim_end_matches('0'))
-            .filter(|s| !s.is_empty())
-        {
-            if fract.starts_with('+') {
-                return Err(ParseDecimalError::InvalidFormat);
-            }
-
-            let decimals: u8 = fract
-                .len()
-                .try_into()
+        let mut digits = digits.parse::<u128>()?;
+        if decimals < 0 {
+            digits = u32::try_from(decimals.unsigned_abs())
                 .ok()
-                .filter(|d| *d <= Self::MAX_DECIMALS)
+                .and_then(|shift| 10u128.checked_pow(shift))
+                .and_then(|factor| digits.checked_mul(factor))
                 .ok_or(ParseDecimalError::Overflow)?;
+            decimals = 0;
+        }
 
-            let fract = fract.parse()?;
-            digits = digits
-                .checked_mul(10u128.pow(decimals.into()))
-                .and_then(|d| d.checked_add(fract))
-                .ok_or(ParseDecimalError::Overflow)?;
+        u8::try_from(decimals)
+            .ok()
+            .and_then(|decimals| Self::new(decimals, digits))
+            .ok_or(ParseDecimalError::Overflow)
+    }
+}
 
-            decimals
-        } else {
-            0
-        };
+/// Parses exponent with optional sign, i.e. `[+-]?[0-9]+`
+fn parse_exp(s: &str) -> Result<i64, ParseDecimalError> {
+    let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+    if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseDecimalError::InvalidFormat);
+    }
+    s.parse().map_err(|_| ParseDecimalError::Overflow)
+}
 
-        Self::new(decimals, digits).ok_or(ParseDecimalError::Overflow)
+/// Validates that `s` consists of ASCII digits, optionally separated
+/// with single underscores
+fn validate_digits(s: &str) -> Result<(), ParseDecimalError> {
+    if s.starts_with('_') || s.ends_with('_') || s.contains("__") {
+        return Err(ParseDecimalError::InvalidFormat);
     }
+    if !s.bytes().all(|b| b.is_ascii_digit() || b == b'_') {
+        return Err(ParseDecimalError::InvalidFormat);
+    }
+    Ok(())
 }
 
 #[derive(Debug, ThisError)]
@@ -146,9 +177,41 @@ mod tests {
         "3.40282366920938463463374607431768211455",
         "3.40282366920938463463374607431768211455"
     )]
+    #[case("+0", "0")]
+    #[case("+0.", "0")]
+    #[case("+1.5", "1.5")]
+    #[case("+.5", "0.5")]
+    #[case("1_000", "1000")]
+    #[case("1_000_000.000_001", "1000000.000001")]
+    #[case("0_1", "1")]
+    #[case("1e6", "1000000")]
+    #[case("1E6", "1000000")]
+    #[case("1e+6", "1000000")]
+    #[case("1e-6", "0.000001")]
+    #[case("1.5e-6", "0.0000015")]
+    #[case("+1.5E-6", "0.0000015")]
+    #[case("150e-2", "1.5")]
+    #[case(".15e1", "1.5")]
+    #[case("1.e1", "10")]
+    #[case("0e100", "0")]
+    #[case("0e-100", "0")]
+    #[case("1_000e-3", "1")]
+    #[case("1000e-41", "0.00000000000000000000000000000000000001")]
+    #[case(
+        "3.40282366920938463463374607431768211455e38",
+        "340282366920938463463374607431768211455"
+    )]
+    #[case(
+        "340282366920938463463374607431768211455e-38",
+        "3.40282366920938463463374607431768211455"
+    )]
+    #[case("1e-38", "0.00000000000000000000000000000000000001")]
     fn roundtrip(#[case] input: &str, #[case] result: &str) {
         let p: UD128 = input.parse().unwrap();
         assert_eq!(p.to_string(), result);
+        // display is stable
+        assert_eq!(result.parse::<UD128>().unwrap(), p);
+        assert_eq!(result.parse::<UD128>().unwrap().to_string(), result);
     }
 
     #[rstest]
@@ -156,8 +219,8 @@ mod tests {
     #[case("+")]
     #[case("-")]
     #[case::only_dot(".")]
-    #[case("+0")]
-    #[case("+0.")]
+    #[case("++0")]
+    #[case("+-0")]
     #[case("-0")]
     #[case("-0.0")]
     #[case("0+")]
@@ -173,11 +236,38 @@ mod tests {
     #[case("0.000.0")]
     #[case("0.+0")]
     #[case("0.-0")]
+    #[case("_")]
+    #[case("_1")]
+    #[case("1_")]
+    #[case("1__0")]
+    #[case("1_.0")]
+    #[case("1._0")]
+    #[case("+_1")]
+    #[case(" 1")]
+    #[case("1 ")]
+    #[case("1,5")]
+    #[case("e")]
+    #[case("e1")]
+    #[case(".e1")]
+    #[case("1e")]
+    #[case("1e+")]
+    #[case("1e-")]
+    #[case("1e1.5")]
+    #[case("1e1_0")]
+    #[case("1e+-1")]
+    #[case("1e1e1")]
+    #[case("1e 1")]
     #[case::integer_overflow("340282366920938463463374607431768211456")]
     #[case::integer_overflow("340282366920938463463374607431768211456.0")]
     #[case::integer_overflow("34028236692093846346337460743176821145.6")]
     #[case::integer_overflow("3.40282366920938463463374607431768211456")]
     #[case::decimals_overflow(".000000000000000000000000000000000000001")]
+    #[case::exp_overflow("1e39")]
+    #[case::exp_overflow("4e38")]
+    #[case::exp_overflow("1e-39")]
+    #[case::exp_overflow("1e99999999999999999999")]
+    #[case::exp_overflow("1e-9223372036854775808")]
+    #[case::exp_overflow("100e9223372036854775807")]
     fn invalid(#[case] s: &str) {
         s.parse::<UD128>().unwrap_err();
     }
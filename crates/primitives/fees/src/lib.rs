@@ -1,11 +1,13 @@
-use core::{
-    fmt::{self, Display},
-    ops::{Add, Div, Mul, Not, Sub},
-};
+use core::ops::{Add, Div, Mul, Not, Sub};
 
-use defuse_num_utils::{CheckedAdd, CheckedMulDiv, CheckedSub};
+use defuse_num_utils::{CheckedAdd, CheckedMulDivRound, CheckedSub, Rounding};
 use thiserror::Error as ThisError;
 
+mod schedule;
+mod str;
+
+pub use self::{schedule::*, str::*};
+
 /// 1 pip == 1/100th of bip == 0.0001%
 #[cfg_attr(
     feature = "borsh",
@@ -36,6 +38,16 @@ impl Pips {
         Some(Self(pips))
     }
 
+    /// Same as [`.from_pips()`](Self::from_pips), but additionally
+    /// validates that the result doesn't exceed given `max` fee.
+    #[inline]
+    pub const fn from_pips_with_max(pips: u32, max: Self) -> Option<Self> {
+        let Some(pips) = Self::from_pips(pips) else {
+            return None;
+        };
+        pips.at_most(max)
+    }
+
     #[inline]
     pub const fn from_bips(bips: u32) -> Option<Self> {
         Self::ONE_BIP.checked_mul(bips)
@@ -101,24 +113,61 @@ impl Pips {
         Some(Self(pips))
     }
 
+    /// Returns `self` if it doesn't exceed given `max` fee.
+    #[inline]
+    pub const fn at_most(self, max: Self) -> Option<Self> {
+        if self.as_pips() > max.as_pips() {
+            return None;
+        }
+        Some(self)
+    }
+
+    /// Returns the share of the amount left after taking the fee,
+    /// i.e. `100% - self`.
     #[must_use]
     #[inline]
-    pub const fn invert(self) -> Self {
+    pub const fn complement(self) -> Self {
         Self(Self::MAX.as_pips() - self.as_pips())
     }
 
+    /// Same as [`.complement()`](Self::complement).
+    #[must_use]
     #[inline]
-    pub fn fee(self, amount: u128) -> u128 {
+    pub const fn invert(self) -> Self {
+        self.complement()
+    }
+
+    /// Returns the total fee of applying `self` and then `other` to the
+    /// remaining amount, i.e. `1 - (1 - self) * (1 - other)`.
+    /// The result is rounded up, so it's never less than the exact one.
+    #[must_use]
+    #[inline]
+    pub fn compose(self, other: Self) -> Self {
+        // (1 - self) * (1 - other) <= MAX^2 fits into u64
+        let remaining = u64::from(self.complement().as_pips())
+            * u64::from(other.complement().as_pips())
+            / u64::from(Self::MAX.as_pips());
+        Self(remaining.try_into().unwrap_or_else(|_| unreachable!())).complement()
+    }
+
+    /// Returns the fee to be taken from given `amount` rounded
+    /// according to `rounding`.
+    #[inline]
+    pub fn apply_to(self, amount: u128, rounding: Rounding) -> u128 {
         amount
-            .checked_mul_div(self.as_pips().into(), Self::MAX.as_pips().into())
+            .checked_mul_div_round(self.as_pips().into(), Self::MAX.as_pips().into(), rounding)
+            // fee never exceeds the amount
             .unwrap_or_else(|| unreachable!())
     }
 
+    #[inline]
+    pub fn fee(self, amount: u128) -> u128 {
+        self.apply_to(amount, Rounding::Floor)
+    }
+
     #[inline]
     pub fn fee_ceil(self, amount: u128) -> u128 {
-        amount
-            .checked_mul_div_ceil(self.as_pips().into(), Self::MAX.as_pips().into())
-            .unwrap_or_else(|| unreachable!())
+        self.apply_to(amount, Rounding::Ceil)
     }
 }
 
@@ -176,14 +225,7 @@ impl Not for Pips {
     type Output = Self;
 
     fn not(self) -> Self::Output {
-        self.invert()
-    }
-}
-
-impl Display for Pips {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:.4}%", self.as_f64() * 100f64)
+        self.complement()
     }
 }
 
@@ -196,7 +238,7 @@ impl TryFrom<u32> for Pips {
     }
 }
 
-#[derive(Debug, ThisError)]
+#[derive(Debug, ThisError, PartialEq, Eq)]
 #[error("out of range: 0..={}", Pips::MAX.as_pips())]
 pub struct PipsOutOfRange;
 
@@ -212,3 +254,59 @@ impl ::borsh::BorshDeserialize for Pips {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complement() {
+        assert_eq!(Pips::ZERO.complement(), Pips::MAX);
+        assert_eq!(Pips::MAX.complement(), Pips::ZERO);
+        assert_eq!(
+            Pips::from_percent(30).unwrap().complement(),
+            Pips::from_percent(70).unwrap()
+        );
+    }
+
+    #[test]
+    fn compose() {
+        let ten = Pips::from_percent(10).unwrap();
+        assert_eq!(ten.compose(Pips::ZERO), ten);
+        assert_eq!(Pips::ZERO.compose(ten), ten);
+        assert_eq!(ten.compose(Pips::MAX), Pips::MAX);
+        // 1 - 0.9 * 0.9 = 0.19
+        assert_eq!(ten.compose(ten), Pips::from_percent(19).unwrap());
+        // 1 - 0.999999 * 0.999999 = 0.000001999999 -> rounded up
+        assert_eq!(
+            Pips::ONE_PIP.compose(Pips::ONE_PIP),
+            Pips::from_pips(2).unwrap()
+        );
+        assert_eq!(
+            Pips::from_pips(3)
+                .unwrap()
+                .compose(Pips::from_pips(500_000).unwrap()),
+            Pips::from_pips(500_002).unwrap()
+        );
+    }
+
+    #[test]
+    fn apply_to() {
+        let fee = Pips::from_bips(30).unwrap();
+        assert_eq!(fee.apply_to(1_000, Rounding::Floor), 3);
+        assert_eq!(fee.apply_to(1_001, Rounding::Floor), 3);
+        assert_eq!(fee.apply_to(1_001, Rounding::Ceil), 4);
+        assert_eq!(fee.apply_to(1_150, Rounding::HalfUp), 3);
+        assert_eq!(fee.apply_to(1_167, Rounding::HalfUp), 4);
+        assert_eq!(Pips::MAX.apply_to(u128::MAX, Rounding::Ceil), u128::MAX);
+    }
+
+    #[test]
+    fn with_max() {
+        let max = Pips::from_percent(25).unwrap();
+        assert_eq!(Pips::from_pips_with_max(250_000, max), Some(max));
+        assert_eq!(Pips::from_pips_with_max(250_001, max), None);
+        assert_eq!(Pips::from_pips_with_max(u32::MAX, Pips::MAX), None);
+        assert_eq!(Pips::MAX.at_most(max), None);
+    }
+}
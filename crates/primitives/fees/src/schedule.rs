@@ -0,0 +1,231 @@
+use thiserror::Error as ThisError;
+
+use crate::Pips;
+
+/// Fee applied to amounts starting from `min_amount` (inclusive)
+/// up to `min_amount` of the next tier (exclusive).
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeTier {
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::DisplayFromStr"))]
+    #[cfg_attr(all(feature = "serde", feature = "abi"), schemars(with = "String"))]
+    pub min_amount: u128,
+    pub fee: Pips,
+}
+
+/// Tiered fee schedule: maps amount brackets to [`Pips`].
+///
+/// Tiers are sorted by `min_amount` in strictly ascending order and
+/// the first tier always starts from zero, so that every amount
+/// falls into exactly one tier.
+#[cfg_attr(
+    feature = "borsh",
+    derive(::borsh::BorshSerialize),
+    cfg_attr(feature = "abi", derive(::borsh::BorshSchema))
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(try_from = "Vec<FeeTier>")
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeSchedule(Vec<FeeTier>);
+
+impl FeeSchedule {
+    pub fn new(tiers: Vec<FeeTier>) -> Result<Self, InvalidFeeSchedule> {
+        let first = tiers.first().ok_or(InvalidFeeSchedule::Empty)?;
+        if first.min_amount != 0 {
+            return Err(InvalidFeeSchedule::NonZeroStart);
+        }
+        if !tiers.is_sorted_by(|a, b| a.min_amount < b.min_amount) {
+            return Err(InvalidFeeSchedule::NotMonotonic);
+        }
+        Ok(Self(tiers))
+    }
+
+    /// Schedule with a single tier applying the same `fee` to any amount
+    #[inline]
+    pub fn flat(fee: Pips) -> Self {
+        Self(vec![FeeTier { min_amount: 0, fee }])
+    }
+
+    #[inline]
+    pub fn tiers(&self) -> &[FeeTier] {
+        &self.0
+    }
+
+    #[inline]
+    pub fn into_tiers(self) -> Vec<FeeTier> {
+        self.0
+    }
+
+    /// Returns the fee of the tier given `amount` falls into
+    #[inline]
+    pub fn fee_for(&self, amount: u128) -> Pips {
+        let idx = self.0.partition_point(|tier| tier.min_amount <= amount);
+        // first tier always starts from zero, so idx > 0
+        self.0[idx - 1].fee
+    }
+
+    /// Returns the highest fee across all tiers
+    #[inline]
+    pub fn max_fee(&self) -> Pips {
+        self.0
+            .iter()
+            .map(|tier| tier.fee)
+            .max()
+            .unwrap_or_else(|| unreachable!())
+    }
+
+    /// Returns `self` if none of the tiers exceeds given `max` fee.
+    #[inline]
+    pub fn at_most(self, max: Pips) -> Option<Self> {
+        self.max_fee().at_most(max)?;
+        Some(self)
+    }
+}
+
+impl Default for FeeSchedule {
+    #[inline]
+    fn default() -> Self {
+        Self::flat(Pips::ZERO)
+    }
+}
+
+impl From<Pips> for FeeSchedule {
+    #[inline]
+    fn from(fee: Pips) -> Self {
+        Self::flat(fee)
+    }
+}
+
+impl TryFrom<Vec<FeeTier>> for FeeSchedule {
+    type Error = InvalidFeeSchedule;
+
+    #[inline]
+    fn try_from(tiers: Vec<FeeTier>) -> Result<Self, Self::Error> {
+        Self::new(tiers)
+    }
+}
+
+impl From<FeeSchedule> for Vec<FeeTier> {
+    #[inline]
+    fn from(schedule: FeeSchedule) -> Self {
+        schedule.0
+    }
+}
+
+#[derive(Debug, ThisError, PartialEq, Eq)]
+pub enum InvalidFeeSchedule {
+    #[error("empty fee schedule")]
+    Empty,
+    #[error("first tier must start from zero amount")]
+    NonZeroStart,
+    #[error("tiers must be sorted by min_amount in strictly ascending order")]
+    NotMonotonic,
+}
+
+#[cfg(feature = "borsh")]
+impl ::borsh::BorshDeserialize for FeeSchedule {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        Vec::<FeeTier>::deserialize_reader(reader)?
+            .try_into()
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn tier(min_amount: u128, bips: u32) -> FeeTier {
+        FeeTier {
+            min_amount,
+            fee: Pips::from_bips(bips).unwrap(),
+        }
+    }
+
+    fn schedule() -> FeeSchedule {
+        FeeSchedule::new(vec![tier(0, 30), tier(1_000, 20), tier(1_000_000, 5)]).unwrap()
+    }
+
+    #[rstest]
+    #[case(0, 30)]
+    #[case(999, 30)]
+    #[case(1_000, 20)]
+    #[case(999_999, 20)]
+    #[case(1_000_000, 5)]
+    #[case(u128::MAX, 5)]
+    fn fee_for(#[case] amount: u128, #[case] bips: u32) {
+        assert_eq!(schedule().fee_for(amount), Pips::from_bips(bips).unwrap());
+    }
+
+    #[test]
+    fn flat() {
+        let fee = Pips::from_bips(30).unwrap();
+        let schedule = FeeSchedule::from(fee);
+        assert_eq!(schedule.fee_for(0), fee);
+        assert_eq!(schedule.fee_for(u128::MAX), fee);
+        assert_eq!(FeeSchedule::default().fee_for(1), Pips::ZERO);
+    }
+
+    #[rstest]
+    #[case(vec![], InvalidFeeSchedule::Empty)]
+    #[case(vec![tier(1, 30)], InvalidFeeSchedule::NonZeroStart)]
+    #[case(vec![tier(0, 30), tier(0, 20)], InvalidFeeSchedule::NotMonotonic)]
+    #[case(vec![tier(0, 30), tier(100, 20), tier(50, 10)], InvalidFeeSchedule::NotMonotonic)]
+    fn invalid(#[case] tiers: Vec<FeeTier>, #[case] err: InvalidFeeSchedule) {
+        assert_eq!(FeeSchedule::new(tiers).unwrap_err(), err);
+    }
+
+    #[test]
+    fn max_fee() {
+        let schedule = schedule();
+        assert_eq!(schedule.max_fee(), Pips::from_bips(30).unwrap());
+        assert!(
+            schedule
+                .clone()
+                .at_most(Pips::from_bips(30).unwrap())
+                .is_some()
+        );
+        assert!(schedule.at_most(Pips::from_bips(29).unwrap()).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let json = r#"[{"min_amount":"0","fee":3000},{"min_amount":"1000","fee":2000},{"min_amount":"1000000","fee":500}]"#;
+        assert_eq!(serde_json::to_string(&schedule()).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<FeeSchedule>(json).unwrap(),
+            schedule()
+        );
+        serde_json::from_str::<FeeSchedule>(r#"[{"min_amount":"1","fee":3000}]"#).unwrap_err();
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh() {
+        let bytes = ::borsh::to_vec(&schedule()).unwrap();
+        assert_eq!(
+            ::borsh::from_slice::<FeeSchedule>(&bytes).unwrap(),
+            schedule()
+        );
+        let invalid = ::borsh::to_vec(&vec![tier(0, 30), tier(0, 20)]).unwrap();
+        ::borsh::from_slice::<FeeSchedule>(&invalid).unwrap_err();
+    }
+}
@@ -0,0 +1,147 @@
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use thiserror::Error as ThisError;
+
+use crate::{Pips, PipsOutOfRange};
+
+/// Formats as exact percentage without trailing zeros, e.g. `0.3%`,
+/// which can be parsed back with [`FromStr`].
+impl Display for Pips {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = Self::ONE_PERCENT.as_pips();
+        write!(f, "{}", self.as_pips() / percent)?;
+
+        let fract = self.as_pips() % percent;
+        if fract != 0 {
+            write!(f, ".{}", format!("{fract:04}").trim_end_matches('0'))?;
+        }
+        f.write_str("%")
+    }
+}
+
+/// Parses human-readable formats with mandatory units:
+/// * `0.3%`: percent, up to 4 decimal places
+/// * `30bps`: basis points, up to 2 decimal places
+/// * `3000pips`: pips
+impl FromStr for Pips {
+    type Err = ParsePipsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, decimals) = if let Some(value) = s.strip_suffix('%') {
+            (value, 4)
+        } else if let Some(value) = s.strip_suffix("bps") {
+            (value, 2)
+        } else if let Some(value) = s.strip_suffix("pips") {
+            (value, 0)
+        } else {
+            return Err(ParsePipsError::InvalidFormat);
+        };
+
+        let pips = parse_scaled(value, decimals)?;
+        Ok(Self::try_from(pips)?)
+    }
+}
+
+/// Parses non-negative decimal `s` multiplied by `10^decimals`
+fn parse_scaled(s: &str, decimals: u32) -> Result<u32, ParsePipsError> {
+    let (integer, fract) = s.split_once('.').map_or((s, None), |(i, f)| (i, Some(f)));
+
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(integer) || fract.is_some_and(|f| !is_digits(f)) {
+        return Err(ParsePipsError::InvalidFormat);
+    }
+    let fract = fract.unwrap_or_default().trim_end_matches('0');
+
+    let scale = u32::try_from(fract.len())
+        .ok()
+        .and_then(|len| decimals.checked_sub(len))
+        .ok_or(ParsePipsError::Precision)?;
+
+    let integer: u32 = integer.parse().map_err(|_| PipsOutOfRange)?;
+    let fract: u32 = if fract.is_empty() {
+        0
+    } else {
+        fract.parse().map_err(|_| PipsOutOfRange)?
+    };
+
+    integer
+        .checked_mul(10u32.pow(decimals))
+        .and_then(|i| i.checked_add(fract.checked_mul(10u32.pow(scale))?))
+        .ok_or_else(|| PipsOutOfRange.into())
+}
+
+#[derive(Debug, ThisError, PartialEq, Eq)]
+pub enum ParsePipsError {
+    #[error("invalid format: expected '<decimal>%', '<decimal>bps' or '<integer>pips'")]
+    InvalidFormat,
+    #[error("precision is higher than 1 pip")]
+    Precision,
+    #[error(transparent)]
+    OutOfRange(#[from] PipsOutOfRange),
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("0%", 0)]
+    #[case("0.3%", 3000)]
+    #[case("0.30%", 3000)]
+    #[case("30bps", 3000)]
+    #[case("30.00bps", 3000)]
+    #[case("3000pips", 3000)]
+    #[case("0.0001%", 1)]
+    #[case("0.01bps", 1)]
+    #[case("1pips", 1)]
+    #[case("1%", 10_000)]
+    #[case("12.3456%", 123_456)]
+    #[case("100%", 1_000_000)]
+    #[case("10000bps", 1_000_000)]
+    #[case("1000000pips", 1_000_000)]
+    #[case("007%", 70_000)]
+    fn parse(#[case] s: &str, #[case] pips: u32) {
+        assert_eq!(s.parse::<Pips>().unwrap(), Pips::from_pips(pips).unwrap());
+    }
+
+    #[rstest]
+    #[case::no_unit("3000", ParsePipsError::InvalidFormat)]
+    #[case::unknown_unit("3000bips", ParsePipsError::InvalidFormat)]
+    #[case::empty("%", ParsePipsError::InvalidFormat)]
+    #[case::space("0.3 %", ParsePipsError::InvalidFormat)]
+    #[case::sign("+0.3%", ParsePipsError::InvalidFormat)]
+    #[case::sign("-0.3%", ParsePipsError::InvalidFormat)]
+    #[case::dot_only(".%", ParsePipsError::InvalidFormat)]
+    #[case::no_integer(".3%", ParsePipsError::InvalidFormat)]
+    #[case::no_fract("3.%", ParsePipsError::InvalidFormat)]
+    #[case::uppercase("30BPS", ParsePipsError::InvalidFormat)]
+    #[case::fract_pips("1.5pips", ParsePipsError::Precision)]
+    #[case::precision("0.00001%", ParsePipsError::Precision)]
+    #[case::precision("0.001bps", ParsePipsError::Precision)]
+    #[case::out_of_range("100.0001%", ParsePipsError::OutOfRange(PipsOutOfRange))]
+    #[case::out_of_range("10001bps", ParsePipsError::OutOfRange(PipsOutOfRange))]
+    #[case::out_of_range("1000001pips", ParsePipsError::OutOfRange(PipsOutOfRange))]
+    #[case::overflow("99999999999%", ParsePipsError::OutOfRange(PipsOutOfRange))]
+    fn parse_invalid(#[case] s: &str, #[case] err: ParsePipsError) {
+        assert_eq!(s.parse::<Pips>().unwrap_err(), err);
+    }
+
+    #[rstest]
+    #[case(0, "0%")]
+    #[case(1, "0.0001%")]
+    #[case(3000, "0.3%")]
+    #[case(10_000, "1%")]
+    #[case(123_450, "12.345%")]
+    #[case(1_000_000, "100%")]
+    fn display(#[case] pips: u32, #[case] s: &str) {
+        let pips = Pips::from_pips(pips).unwrap();
+        assert_eq!(pips.to_string(), s);
+        assert_eq!(s.parse::<Pips>().unwrap(), pips);
+    }
+}
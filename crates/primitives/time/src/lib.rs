@@ -6,7 +6,9 @@ pub mod borsh;
 pub mod serde;
 
 mod error;
-pub use self::error::*;
+mod recurring;
+
+pub use self::{error::*, recurring::*};
 
 use core::{
     ops::{Add, AddAssign, Sub, SubAssign},
@@ -0,0 +1,166 @@
+use core::time::Duration;
+
+use crate::Timestamp;
+
+/// Deadline recurring every `period` starting from `anchor` up to
+/// `end` (inclusive), i.e. `anchor + k * period` for `k >= 0`.
+///
+/// Zero `period` denotes a one-shot deadline at `anchor`.
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RecurringDeadline {
+    pub anchor: Timestamp,
+
+    /// Serialized as whole seconds
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::DurationSeconds<u64>"))]
+    pub period: Duration,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end: Option<Timestamp>,
+}
+
+impl RecurringDeadline {
+    #[must_use]
+    #[inline]
+    pub const fn new(anchor: Timestamp, period: Duration) -> Self {
+        Self {
+            anchor,
+            period,
+            end: None,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn until(mut self, end: Timestamp) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Returns the earliest occurrence at or after `now`, or `None` if
+    /// there are no more occurrences.
+    #[must_use]
+    pub fn next_occurrence(&self, now: Timestamp) -> Option<Timestamp> {
+        let next = match now.duration_since(self.anchor) {
+            // not started yet or exactly at anchor
+            Err(_) | Ok(Duration::ZERO) => self.anchor,
+            Ok(_) if self.period.is_zero() => return None,
+            Ok(elapsed) => {
+                let period = self.period.as_nanos();
+                let periods = elapsed.as_nanos().div_ceil(period);
+                let offset = periods.checked_mul(period)?;
+                self.anchor.checked_add_unsigned(Duration::new(
+                    (offset / 1_000_000_000).try_into().ok()?,
+                    (offset % 1_000_000_000).try_into().ok()?,
+                ))?
+            }
+        };
+        if self.end.is_some_and(|end| next > end) {
+            return None;
+        }
+        Some(next)
+    }
+
+    /// Returns whether there are no more occurrences at or after `now`
+    #[must_use]
+    #[inline]
+    pub fn has_ended(&self, now: Timestamp) -> bool {
+        self.next_occurrence(now).is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const HOUR: Duration = Duration::from_hours(1);
+
+    fn ts(secs: i64) -> Timestamp {
+        Timestamp::from_secs(secs).unwrap()
+    }
+
+    #[rstest]
+    #[case(0, Some(1000))]
+    #[case(999, Some(1000))]
+    #[case(1000, Some(1000))]
+    #[case(1001, Some(4600))]
+    #[case(4599, Some(4600))]
+    #[case(4600, Some(4600))]
+    #[case(4601, Some(8200))]
+    #[case(1000 + 10 * 3600, Some(1000 + 10 * 3600))]
+    #[case(1000 + 10 * 3600 + 1, None)]
+    fn next_occurrence(#[case] now: i64, #[case] expected: Option<i64>) {
+        let deadline = RecurringDeadline::new(ts(1000), HOUR).until(ts(1000 + 10 * 3600));
+        assert_eq!(deadline.next_occurrence(ts(now)), expected.map(ts));
+    }
+
+    #[rstest]
+    #[case(999, Some(1000))]
+    #[case(1000, Some(1000))]
+    #[case(1001, None)]
+    fn one_shot(#[case] now: i64, #[case] expected: Option<i64>) {
+        let deadline = RecurringDeadline::new(ts(1000), Duration::ZERO);
+        assert_eq!(deadline.next_occurrence(ts(now)), expected.map(ts));
+        assert_eq!(deadline.has_ended(ts(now)), expected.is_none());
+    }
+
+    #[test]
+    fn subsec_period() {
+        let deadline = RecurringDeadline::new(ts(0), Duration::from_millis(1500));
+        assert_eq!(
+            deadline.next_occurrence(ts(2)),
+            Timestamp::from_millis(3000)
+        );
+        assert_eq!(
+            deadline.next_occurrence(Timestamp::from_millis(3001).unwrap()),
+            Timestamp::from_millis(4500)
+        );
+    }
+
+    #[test]
+    fn end_before_anchor() {
+        let deadline = RecurringDeadline::new(ts(1000), HOUR).until(ts(999));
+        assert!(deadline.has_ended(ts(0)));
+    }
+
+    #[test]
+    fn overflow() {
+        let deadline = RecurringDeadline::new(Timestamp::MAX, HOUR);
+        assert_eq!(deadline.next_occurrence(ts(0)), Some(Timestamp::MAX));
+
+        let deadline = RecurringDeadline::new(ts(0), Duration::from_secs(u64::MAX));
+        assert_eq!(deadline.next_occurrence(ts(1)), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let deadline = RecurringDeadline::new(ts(1_782_395_622), HOUR).until(ts(1_782_482_022));
+        let json = serde_json::to_string(&deadline).unwrap();
+        assert_eq!(
+            json,
+            r#"{"anchor":"2026-06-25T13:53:42Z","period":3600,"end":"2026-06-26T13:53:42Z"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<RecurringDeadline>(&json).unwrap(),
+            deadline
+        );
+        assert_eq!(
+            serde_json::from_str::<RecurringDeadline>(
+                r#"{"anchor":"2026-06-25T13:53:42Z","period":3600}"#
+            )
+            .unwrap(),
+            RecurringDeadline::new(ts(1_782_395_622), HOUR)
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use core::marker::PhantomData;
+
+use derive_more::From;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde_with::{DeserializeAs, SerializeAs, serde_as};
+
+/// Serializes bytes as Base58 string (Bitcoin alphabet), optionally
+/// appending a [`Base58Check`] checksum.
+pub struct Base58<C: Checksum = NoChecksum>(PhantomData<C>);
+
+pub trait Checksum {
+    fn encode(bytes: &[u8]) -> String;
+    fn decode(s: &str) -> Result<Vec<u8>, bs58::decode::Error>;
+}
+
+/// Plain Base58 without checksum
+pub struct NoChecksum;
+
+impl Checksum for NoChecksum {
+    #[inline]
+    fn encode(bytes: &[u8]) -> String {
+        bs58::encode(bytes).into_string()
+    }
+
+    #[inline]
+    fn decode(s: &str) -> Result<Vec<u8>, bs58::decode::Error> {
+        bs58::decode(s).into_vec()
+    }
+}
+
+/// `Base58Check`: 4-byte double SHA-256 checksum is appended to the data
+pub struct Base58Check;
+
+impl Checksum for Base58Check {
+    #[inline]
+    fn encode(bytes: &[u8]) -> String {
+        bs58::encode(bytes).with_check().into_string()
+    }
+
+    #[inline]
+    fn decode(s: &str) -> Result<Vec<u8>, bs58::decode::Error> {
+        bs58::decode(s).with_check(None).into_vec()
+    }
+}
+
+impl<T, C> SerializeAs<T> for Base58<C>
+where
+    T: AsRef<[u8]>,
+    C: Checksum,
+{
+    #[inline]
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&C::encode(source.as_ref()))
+    }
+}
+
+impl<'de, T, C> DeserializeAs<'de, T> for Base58<C>
+where
+    T: TryFrom<Vec<u8>>,
+    C: Checksum,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = <&str>::deserialize(deserializer)?;
+        let bytes = C::decode(s).map_err(de::Error::custom)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| {
+            de::Error::invalid_length(len, &"number of bytes matching the target type")
+        })
+    }
+}
+
+#[cfg(feature = "schemars-v0_8")]
+const _: () = {
+    use schemars::{JsonSchema, SchemaGenerator, schema::Schema};
+    use serde_with::schemars_0_8::JsonSchemaAs;
+
+    impl<T, C> JsonSchemaAs<T> for Base58<C>
+    where
+        C: Checksum,
+    {
+        #[inline]
+        fn schema_name() -> String {
+            String::schema_name()
+        }
+
+        #[inline]
+        fn is_referenceable() -> bool {
+            false
+        }
+
+        #[inline]
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            String::json_schema(generator)
+        }
+    }
+};
+
+#[serde_as]
+#[cfg_attr(
+    feature = "schemars-v0_8",
+    derive(::schemars::JsonSchema),
+    schemars(transparent)
+)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, From)]
+#[serde(bound(serialize = "T: AsRef<[u8]>", deserialize = "T: TryFrom<Vec<u8>>"))]
+#[repr(transparent)]
+/// Helper type to implement `#[derive(Serialize, Deserialize)]`,
+/// as `#[near_bindgen]` doesn't support `#[serde(...)]` attributes on method arguments
+pub struct AsBase58<T>(#[serde_as(as = "Base58")] pub T);
+
+impl<T> AsBase58<T> {
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Checked(#[serde_as(as = "Base58<Base58Check>")] Vec<u8>);
+
+    #[test]
+    fn roundtrip() {
+        let val = AsBase58(vec![0x00, 0x01, 0x02, 0xff]);
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#""1LiA""#);
+        let recovered: AsBase58<Vec<u8>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(recovered.0, val.0);
+    }
+
+    #[test]
+    fn fixed_array() {
+        let val: AsBase58<[u8; 32]> =
+            serde_json::from_str(r#""11111111111111111111111111111111""#).unwrap();
+        assert_eq!(val.0, [0; 32]);
+
+        serde_json::from_str::<AsBase58<[u8; 31]>>(r#""11111111111111111111111111111111""#)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn invalid_alphabet() {
+        // '0', 'O', 'I' and 'l' are not in the alphabet
+        serde_json::from_str::<AsBase58<Vec<u8>>>(r#""0OIl""#).unwrap_err();
+    }
+
+    #[test]
+    fn checksum() {
+        let val = Checked(b"hello".to_vec());
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#""2L5B5yqsVG8Vt""#);
+        assert_eq!(serde_json::from_str::<Checked>(&json).unwrap(), val);
+
+        // plain base58 of the same data has no valid checksum
+        serde_json::from_str::<Checked>(r#""Cn8eVZg""#).unwrap_err();
+    }
+}
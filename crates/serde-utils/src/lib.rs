@@ -1,8 +1,14 @@
+#[cfg(feature = "base58")]
+pub mod base58;
+
 #[cfg(feature = "base64")]
 pub mod base64;
 
 #[cfg(feature = "hex")]
 pub mod hex;
 
+#[cfg(feature = "multibase")]
+pub mod multibase;
+
 #[cfg(feature = "tlb")]
 pub mod tlb;
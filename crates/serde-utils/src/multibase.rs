@@ -0,0 +1,235 @@
+//! [Multibase](https://github.com/multiformats/multibase) encoding:
+//! encoded data is prefixed with a single character denoting the base.
+
+use core::marker::PhantomData;
+
+use base64::{
+    Engine,
+    engine::general_purpose::{STANDARD_NO_PAD, URL_SAFE_NO_PAD},
+};
+use serde::{Deserialize, Deserializer, Serializer, de};
+use serde_with::{DeserializeAs, SerializeAs};
+
+/// Serializes bytes as multibase string encoded with `E`.
+/// Deserialization accepts any of the supported bases regardless of `E`.
+pub struct Multibase<E: Encoding = Base58Btc>(PhantomData<E>);
+
+pub trait Encoding {
+    const PREFIX: char;
+
+    fn encode(bytes: &[u8]) -> String;
+}
+
+/// `z`: Base58 with Bitcoin alphabet
+pub struct Base58Btc;
+
+impl Encoding for Base58Btc {
+    const PREFIX: char = 'z';
+
+    #[inline]
+    fn encode(bytes: &[u8]) -> String {
+        bs58::encode(bytes).into_string()
+    }
+}
+
+/// `f`: lowercase hex
+pub struct Base16Lower;
+
+impl Encoding for Base16Lower {
+    const PREFIX: char = 'f';
+
+    #[inline]
+    fn encode(bytes: &[u8]) -> String {
+        hex::encode(bytes)
+    }
+}
+
+/// `m`: standard Base64 without padding
+pub struct Base64;
+
+impl Encoding for Base64 {
+    const PREFIX: char = 'm';
+
+    #[inline]
+    fn encode(bytes: &[u8]) -> String {
+        STANDARD_NO_PAD.encode(bytes)
+    }
+}
+
+/// `u`: URL-safe Base64 without padding
+pub struct Base64Url;
+
+impl Encoding for Base64Url {
+    const PREFIX: char = 'u';
+
+    #[inline]
+    fn encode(bytes: &[u8]) -> String {
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+}
+
+/// Encodes `bytes` as multibase string with given encoding
+#[inline]
+pub fn encode<E: Encoding>(bytes: impl AsRef<[u8]>) -> String {
+    let mut s = String::from(E::PREFIX);
+    s.push_str(&E::encode(bytes.as_ref()));
+    s
+}
+
+/// Decodes multibase string in any of the supported bases
+pub fn decode(s: &str) -> Result<Vec<u8>, DecodeError> {
+    let mut chars = s.chars();
+    let prefix = chars.next().ok_or(DecodeError::Empty)?;
+    let data = chars.as_str();
+    match prefix {
+        Base58Btc::PREFIX => bs58::decode(data)
+            .into_vec()
+            .map_err(|_| DecodeError::InvalidData(prefix)),
+        // base16 is case-insensitive
+        Base16Lower::PREFIX | 'F' => {
+            hex::decode(data).map_err(|_| DecodeError::InvalidData(prefix))
+        }
+        Base64::PREFIX => STANDARD_NO_PAD
+            .decode(data)
+            .map_err(|_| DecodeError::InvalidData(prefix)),
+        Base64Url::PREFIX => URL_SAFE_NO_PAD
+            .decode(data)
+            .map_err(|_| DecodeError::InvalidData(prefix)),
+        _ => Err(DecodeError::UnsupportedBase(prefix)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    Empty,
+    UnsupportedBase(char),
+    InvalidData(char),
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty multibase string"),
+            Self::UnsupportedBase(prefix) => write!(f, "unsupported multibase prefix: '{prefix}'"),
+            Self::InvalidData(prefix) => write!(f, "invalid data for multibase prefix '{prefix}'"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl<T, E> SerializeAs<T> for Multibase<E>
+where
+    T: AsRef<[u8]>,
+    E: Encoding,
+{
+    #[inline]
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&encode::<E>(source))
+    }
+}
+
+impl<'de, T, E> DeserializeAs<'de, T> for Multibase<E>
+where
+    T: TryFrom<Vec<u8>>,
+    E: Encoding,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = <&str>::deserialize(deserializer)?;
+        let bytes = decode(s).map_err(de::Error::custom)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| {
+            de::Error::invalid_length(len, &"number of bytes matching the target type")
+        })
+    }
+}
+
+#[cfg(feature = "schemars-v0_8")]
+const _: () = {
+    use schemars::{JsonSchema, SchemaGenerator, schema::Schema};
+    use serde_with::schemars_0_8::JsonSchemaAs;
+
+    impl<T, E> JsonSchemaAs<T> for Multibase<E>
+    where
+        E: Encoding,
+    {
+        #[inline]
+        fn schema_name() -> String {
+            String::schema_name()
+        }
+
+        #[inline]
+        fn is_referenceable() -> bool {
+            false
+        }
+
+        #[inline]
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            String::json_schema(generator)
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+    use serde_with::serde_as;
+
+    use super::*;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Btc(#[serde_as(as = "Multibase")] Vec<u8>);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Hex(#[serde_as(as = "Multibase<Base16Lower>")] [u8; 4]);
+
+    const DATA: &[u8] = b"hello";
+
+    #[test]
+    fn encodings() {
+        assert_eq!(encode::<Base58Btc>(DATA), "zCn8eVZg");
+        assert_eq!(encode::<Base16Lower>(DATA), "f68656c6c6f");
+        assert_eq!(encode::<Base64>(DATA), "maGVsbG8");
+        assert_eq!(encode::<Base64Url>([0xfb, 0xff]), "u-_8");
+    }
+
+    #[test]
+    fn decode_any() {
+        for s in ["zCn8eVZg", "f68656c6c6f", "F68656C6C6F", "maGVsbG8"] {
+            assert_eq!(decode(s).unwrap(), DATA, "{s}");
+        }
+        assert_eq!(decode("u-_8").unwrap(), [0xfb, 0xff]);
+    }
+
+    #[test]
+    fn decode_invalid() {
+        assert_eq!(decode(""), Err(DecodeError::Empty));
+        assert_eq!(decode("Q1234"), Err(DecodeError::UnsupportedBase('Q')));
+        assert_eq!(decode("z0OIl"), Err(DecodeError::InvalidData('z')));
+        assert_eq!(decode("fxyz"), Err(DecodeError::InvalidData('f')));
+    }
+
+    #[test]
+    fn serde() {
+        let val = Btc(DATA.to_vec());
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#""zCn8eVZg""#);
+        assert_eq!(serde_json::from_str::<Btc>(&json).unwrap(), val);
+        // accepts other bases
+        assert_eq!(serde_json::from_str::<Btc>(r#""maGVsbG8""#).unwrap(), val);
+
+        let val = Hex([0xde, 0xad, 0xbe, 0xef]);
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#""fdeadbeef""#);
+        assert_eq!(serde_json::from_str::<Hex>(&json).unwrap(), val);
+        serde_json::from_str::<Hex>(r#""fdeadbeefaa""#).unwrap_err();
+    }
+}
@@ -0,0 +1,321 @@
+//! Aptos wallet standard `signMessage`, see
+//! [AIP-62](https://github.com/aptos-foundation/AIPs/blob/main/aips/aip-62.md)
+use defuse_crypto::{Curve, Ed25519};
+use defuse_digest::{Digest, sha2::Sha256, sha3::Sha3_256};
+use impl_tools::autoimpl;
+
+/// Account address
+pub type Address = [u8; 32];
+
+/// Parses account address in either long or short form, i.e. `0x1`
+pub fn parse_address(address: &str) -> Option<Address> {
+    let hex = address.strip_prefix("0x")?;
+    if hex.is_empty() || hex.len() > 64 {
+        return None;
+    }
+
+    let mut address = Address::default();
+    hex::decode_to_slice(format!("{hex:0>64}"), &mut address).ok()?;
+    Some(address)
+}
+
+/// Scheme used to derive authentication key (i.e. address) from
+/// the public key of the account.
+/// See [AIP-55](https://github.com/aptos-foundation/AIPs/blob/main/aips/aip-55.md)
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AptosAuthenticationScheme {
+    /// Legacy Ed25519 account
+    #[default]
+    Ed25519,
+    /// `SingleKey` account with Ed25519 key, as created by
+    /// multi-key aware wallets
+    SingleKey,
+}
+
+impl AptosAuthenticationScheme {
+    /// `sha3_256(public_key ‖ scheme)`
+    pub fn derive_address(self, public_key: &<Ed25519 as Curve>::PublicKey) -> Address {
+        match self {
+            Self::Ed25519 => Sha3_256::new()
+                .chain_update(public_key)
+                .chain_update([0x00]),
+            Self::SingleKey => Sha3_256::new()
+                // BCS: AnyPublicKey::Ed25519 variant, length of the key
+                .chain_update([0x00, 0x20])
+                .chain_update(public_key)
+                .chain_update([0x02]),
+        }
+        .finalize()
+        .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Debug, Clone)]
+pub struct AptosPayload {
+    /// Account address, if requested to be included
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub address: Option<String>,
+    /// dApp origin, if requested to be included
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub application: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub chain_id: Option<u8>,
+    pub message: String,
+    pub nonce: String,
+}
+
+impl AptosPayload {
+    pub const PREFIX: &str = "APTOS";
+
+    #[inline]
+    pub const fn new(message: String, nonce: String) -> Self {
+        Self {
+            address: None,
+            application: None,
+            chain_id: None,
+            message,
+            nonce,
+        }
+    }
+
+    /// Message which is actually signed by the wallet
+    pub fn full_message(&self) -> String {
+        let mut full_message = Self::PREFIX.to_string();
+        if let Some(address) = &self.address {
+            full_message.push_str("\naddress: ");
+            full_message.push_str(address);
+        }
+        if let Some(application) = &self.application {
+            full_message.push_str("\napplication: ");
+            full_message.push_str(application);
+        }
+        if let Some(chain_id) = self.chain_id {
+            full_message.push_str("\nchainId: ");
+            full_message.push_str(&chain_id.to_string());
+        }
+        full_message.push_str("\nmessage: ");
+        full_message.push_str(&self.message);
+        full_message.push_str("\nnonce: ");
+        full_message.push_str(&self.nonce);
+        full_message
+    }
+}
+
+impl defuse_crypto::Payload for AptosPayload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        Sha256::digest(self.full_message().as_bytes()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedAptosPayload {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub payload: AptosPayload,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scheme: AptosAuthenticationScheme,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Ed25519>")
+    )]
+    pub public_key: <Ed25519 as Curve>::PublicKey,
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Ed25519>")
+    )]
+    pub signature: <Ed25519 as Curve>::Signature,
+}
+
+impl defuse_crypto::Payload for SignedAptosPayload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedAptosPayload {
+    type PublicKey = <Ed25519 as Curve>::PublicKey;
+
+    /// Verifies the signature and ensures that `address`, if present,
+    /// is derived from the public key according to the `scheme`
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::VerifiableCurve;
+
+        if self
+            .payload
+            .address
+            .as_deref()
+            .map(parse_address)
+            .is_some_and(|address| address != Some(self.scheme.derive_address(&self.public_key)))
+        {
+            return None;
+        }
+
+        Ed25519::verify(
+            &self.signature,
+            self.payload.full_message().as_bytes(),
+            &self.public_key,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    // private key: 000102...1f
+    const REFERENCE_PUBKEY: [u8; 32] =
+        hex!("03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8");
+    const ED25519_ADDRESS: &str =
+        "0xdeb6bc1848ba9de44f8ebd4009dd666fe46ac921246500c18476dd1b96f1cb3a";
+    const SINGLE_KEY_ADDRESS: &str =
+        "0x6a029133f80479e949aa78c659a2b19fd3b1d5a2f1d317eb5bf510ca179e3f0c";
+
+    fn payload(address: Option<&str>) -> AptosPayload {
+        AptosPayload {
+            address: address.map(ToString::to_string),
+            application: Some("https://example.com".to_string()),
+            chain_id: Some(1),
+            ..AptosPayload::new(REFERENCE_MESSAGE.to_string(), "12345".to_string())
+        }
+    }
+
+    #[test]
+    fn full_message() {
+        assert_eq!(
+            payload(None).full_message(),
+            format!(
+                "APTOS\napplication: https://example.com\nchainId: 1\nmessage: {REFERENCE_MESSAGE}\nnonce: 12345"
+            )
+        );
+        assert_eq!(
+            payload(None).hash(),
+            hex!("1dea22b2457ecb3630bd4a6bbc470314dacdaea56557b3152831521bb633bbdc")
+        );
+    }
+
+    #[rstest]
+    #[case::ed25519(AptosAuthenticationScheme::Ed25519, ED25519_ADDRESS)]
+    #[case::single_key(AptosAuthenticationScheme::SingleKey, SINGLE_KEY_ADDRESS)]
+    fn derive_address(#[case] scheme: AptosAuthenticationScheme, #[case] address: &str) {
+        assert_eq!(
+            parse_address(address),
+            Some(scheme.derive_address(&REFERENCE_PUBKEY))
+        );
+    }
+
+    #[rstest]
+    #[case::no_address(
+        AptosAuthenticationScheme::Ed25519,
+        None,
+        hex!("f4a17b29a43955c8aa47595c11756b5ed35b1e7312e74f5dff772e86200d97bdba1a512266135a4e4234e5a1bd6e306a877c4432a8571275ecb6bdf110811806"),
+    )]
+    #[case::ed25519(
+        AptosAuthenticationScheme::Ed25519,
+        Some(ED25519_ADDRESS),
+        hex!("be85e2758aaa754ec4fc0c139f7e38b6c02e15d35bcd16e972d59a9512bad496f5f2c1f4752b1cf69c315e2de0e932d3605cf47ad46f99c0ce34fec2cd2c930e"),
+    )]
+    #[case::single_key(
+        AptosAuthenticationScheme::SingleKey,
+        Some(SINGLE_KEY_ADDRESS),
+        hex!("4a917f3b03f8eda38f7719a292b0e38489b9cb60bf710dedd84fd90334256464b8bbe5b35d5d6168b91b8a29d25225ea2721fcc84a182b4927d5845cd697db05"),
+    )]
+    fn verify(
+        #[case] scheme: AptosAuthenticationScheme,
+        #[case] address: Option<&str>,
+        #[case] signature: [u8; 64],
+    ) {
+        assert_eq!(
+            SignedAptosPayload {
+                payload: payload(address),
+                scheme,
+                public_key: REFERENCE_PUBKEY,
+                signature,
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[test]
+    fn address_scheme_mismatch() {
+        // signature is valid, but the address was derived with other scheme
+        assert_eq!(
+            SignedAptosPayload {
+                payload: payload(Some(SINGLE_KEY_ADDRESS)),
+                scheme: AptosAuthenticationScheme::Ed25519,
+                public_key: REFERENCE_PUBKEY,
+                signature: hex!(
+                    "4a917f3b03f8eda38f7719a292b0e38489b9cb60bf710dedd84fd90334256464b8bbe5b35d5d6168b91b8a29d25225ea2721fcc84a182b4927d5845cd697db05"
+                ),
+            }
+            .verify(),
+            None
+        );
+    }
+
+    #[test]
+    fn invalid_message() {
+        let mut payload = payload(None);
+        payload.nonce = "54321".to_string();
+        assert_eq!(
+            SignedAptosPayload {
+                payload,
+                scheme: AptosAuthenticationScheme::Ed25519,
+                public_key: REFERENCE_PUBKEY,
+                signature: hex!(
+                    "f4a17b29a43955c8aa47595c11756b5ed35b1e7312e74f5dff772e86200d97bdba1a512266135a4e4234e5a1bd6e306a877c4432a8571275ecb6bdf110811806"
+                ),
+            }
+            .verify(),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case("0x1", Some(hex!("0000000000000000000000000000000000000000000000000000000000000001")))]
+    #[case("1", None)]
+    #[case("0x", None)]
+    #[case("0xzz", None)]
+    fn parses_address(#[case] address: &str, #[case] expected: Option<Address>) {
+        assert_eq!(super::parse_address(address), expected);
+    }
+}
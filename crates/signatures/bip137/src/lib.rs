@@ -0,0 +1,228 @@
+//! Bitcoin legacy `signmessage` with recoverable signatures, see
+//! [BIP-137](https://github.com/bitcoin/bips/blob/master/bip-0137.mediawiki)
+use defuse_crypto::{CryptoHash, Curve, Secp256k1};
+use defuse_digest::{Digest, ripemd::Ripemd160, sha2::Sha256};
+use impl_tools::autoimpl;
+
+/// Message to be signed with "Bitcoin Signed Message" prefix
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(transparent)
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bip137Payload(pub String);
+
+impl Bip137Payload {
+    pub const MESSAGE_PREFIX: &[u8] = b"\x18Bitcoin Signed Message:\n";
+
+    #[inline]
+    pub const fn new(message: String) -> Self {
+        Self(message)
+    }
+
+    /// `"\x18Bitcoin Signed Message:\n" || varint(len) || message`
+    pub fn prefixed_message(&self) -> Vec<u8> {
+        let message = self.0.as_bytes();
+        let len = message.len();
+
+        let mut bytes = Vec::with_capacity(Self::MESSAGE_PREFIX.len() + 9 + len);
+        bytes.extend_from_slice(Self::MESSAGE_PREFIX);
+        // Bitcoin `CompactSize` unsigned integer
+        if let Ok(len @ ..0xfd) = u8::try_from(len) {
+            bytes.push(len);
+        } else if let Ok(len) = u16::try_from(len) {
+            bytes.push(0xfd);
+            bytes.extend_from_slice(&len.to_le_bytes());
+        } else if let Ok(len) = u32::try_from(len) {
+            bytes.push(0xfe);
+            bytes.extend_from_slice(&len.to_le_bytes());
+        } else {
+            let len = u64::try_from(len).unwrap_or_else(|_| unreachable!());
+            bytes.push(0xff);
+            bytes.extend_from_slice(&len.to_le_bytes());
+        }
+        bytes.extend_from_slice(message);
+        bytes
+    }
+}
+
+impl defuse_crypto::Payload for Bip137Payload {
+    /// `sha256(sha256(prefixed_message))`
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        Sha256::digest(Sha256::digest(self.prefixed_message())).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedBip137Payload {
+    pub payload: Bip137Payload,
+
+    /// Legacy P2PKH address, i.e. starting with `1`
+    pub address: String,
+
+    /// Base64-encoded `header || r || s`
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::base64::Base64"))]
+    #[cfg_attr(feature = "abi", schemars(with = "String"))]
+    pub signature: [u8; 65],
+}
+
+impl SignedBip137Payload {
+    /// Version byte of P2PKH addresses on mainnet
+    pub const P2PKH_VERSION: u8 = 0x00;
+
+    /// Splits the header byte into recovery id and whether the public
+    /// key is compressed. Only P2PKH headers are supported:
+    /// * `27..=30`: uncompressed public key
+    /// * `31..=34`: compressed public key
+    pub const fn parse_header(header: u8) -> Option<(u8, bool)> {
+        match header {
+            27..=30 => Some((header - 27, false)),
+            31..=34 => Some((header - 31, true)),
+            _ => None,
+        }
+    }
+
+    /// Decodes `hash160` of the public key from P2PKH address
+    pub fn public_key_hash(&self) -> Option<[u8; 20]> {
+        let decoded = bs58::decode(&self.address)
+            .with_check(Some(Self::P2PKH_VERSION))
+            .into_vec()
+            .ok()?;
+        let [Self::P2PKH_VERSION, public_key_hash @ ..] = <[u8; 21]>::try_from(decoded).ok()?
+        else {
+            return None;
+        };
+        Some(public_key_hash)
+    }
+}
+
+impl defuse_crypto::Payload for SignedBip137Payload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedBip137Payload {
+    type PublicKey = <Secp256k1 as Curve>::PublicKey;
+
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{Payload, VerifiableCurve};
+
+        let [header, r_s @ ..] = self.signature;
+        let (recovery_id, compressed) = Self::parse_header(header)?;
+
+        let mut signature = [recovery_id; 65];
+        signature[..64].copy_from_slice(&r_s);
+        let public_key = Secp256k1::verify(&signature, &self.payload.hash(), &())?;
+
+        (hash160(&public_key, compressed) == self.public_key_hash()?).then_some(public_key)
+    }
+}
+
+/// `ripemd160(sha256(public_key))` of SEC1-encoded public key
+fn hash160(public_key: &<Secp256k1 as Curve>::PublicKey, compressed: bool) -> [u8; 20] {
+    let sha256 = if compressed {
+        Sha256::new()
+            .chain_update([0x02 | (public_key[63] & 1)])
+            .chain_update(&public_key[..32])
+    } else {
+        Sha256::new().chain_update([0x04]).chain_update(public_key)
+    }
+    .finalize();
+
+    Ripemd160::digest(sha256).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+    use near_sdk::base64::{Engine, engine::general_purpose::STANDARD};
+    use rstest::rstest;
+
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    // private key: a4b319a82adfc43584e4537fec97a80516e16673db382cd91eba97abbab8ca56
+    const REFERENCE_PUBKEY: [u8; 64] = hex!(
+        "85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b801f41e9438155eb53a5e20f748640093bb42ae3aeca035f7b7fd7a1a21f22f68"
+    );
+
+    const COMPRESSED_ADDRESS: &str = "14gGTqsAGpH6bgVFn4V1M6ipFZVZB7AEVT";
+    const COMPRESSED_SIGNATURE: &str =
+        "HzUSZR+fwqZW4/xPbpTijxqpc5szLiEVvhgueVflR83tcomNY7bmEs5KE/fGtMuzLG8JtpJlTtb36gQ6HnkeOss=";
+    const UNCOMPRESSED_ADDRESS: &str = "12UviEYzRVkBeHb3JFqkm6X7Qpgq9EzoWC";
+    const UNCOMPRESSED_SIGNATURE: &str =
+        "GzUSZR+fwqZW4/xPbpTijxqpc5szLiEVvhgueVflR83tcomNY7bmEs5KE/fGtMuzLG8JtpJlTtb36gQ6HnkeOss=";
+
+    fn signed(message: &str, address: &str, signature: &str) -> SignedBip137Payload {
+        SignedBip137Payload {
+            payload: Bip137Payload::new(message.to_string()),
+            address: address.to_string(),
+            signature: STANDARD.decode(signature).unwrap().try_into().unwrap(),
+        }
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(
+            Bip137Payload::new(REFERENCE_MESSAGE.to_string()).hash(),
+            hex!("fb0f22d38a7df733b92004dd32509a2e7db74c04571badf133c594b647e11c2b")
+        );
+    }
+
+    #[rstest]
+    #[case::short(1, &[1])]
+    #[case::u16(0xfd, &[0xfd, 0xfd, 0x00])]
+    #[case::u16_max(0xffff, &[0xfd, 0xff, 0xff])]
+    #[case::u32(0x10000, &[0xfe, 0x00, 0x00, 0x01, 0x00])]
+    fn compact_size(#[case] len: usize, #[case] expected: &[u8]) {
+        let prefixed = Bip137Payload::new("a".repeat(len)).prefixed_message();
+        let prefix_len = Bip137Payload::MESSAGE_PREFIX.len();
+        assert_eq!(&prefixed[prefix_len..prefix_len + expected.len()], expected);
+    }
+
+    #[rstest]
+    #[case::compressed(COMPRESSED_ADDRESS, COMPRESSED_SIGNATURE)]
+    #[case::uncompressed(UNCOMPRESSED_ADDRESS, UNCOMPRESSED_SIGNATURE)]
+    fn verify(#[case] address: &str, #[case] signature: &str) {
+        assert_eq!(
+            signed(REFERENCE_MESSAGE, address, signature).verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[rstest]
+    #[case::invalid_message("Hello, Bitcoin!", COMPRESSED_ADDRESS, COMPRESSED_SIGNATURE)]
+    #[case::address_mismatch(REFERENCE_MESSAGE, UNCOMPRESSED_ADDRESS, COMPRESSED_SIGNATURE)]
+    #[case::invalid_address(
+        REFERENCE_MESSAGE,
+        "14gGTqsAGpH6bgVFn4V1M6ipFZVZB7AEVU",
+        COMPRESSED_SIGNATURE
+    )]
+    fn invalid(#[case] message: &str, #[case] address: &str, #[case] signature: &str) {
+        assert_eq!(signed(message, address, signature).verify(), None);
+    }
+
+    #[rstest]
+    #[case::segwit(39)]
+    #[case::unknown(0)]
+    fn unsupported_header(#[case] header: u8) {
+        let mut signed = signed(REFERENCE_MESSAGE, COMPRESSED_ADDRESS, COMPRESSED_SIGNATURE);
+        signed.signature[0] = header;
+        assert_eq!(signed.verify(), None);
+    }
+}
@@ -0,0 +1,247 @@
+use defuse_crypto::{CryptoHash, Curve, Secp256k1};
+use defuse_digest::{Digest, sha3::Keccak256};
+use impl_tools::autoimpl;
+
+/// `EIP712Domain` with fields supported for signing intents.
+/// Only fields which are present are included into the type
+/// in the order defined by the standard.
+///
+/// See [EIP-712](https://eips.ethereum.org/EIPS/eip-712#definition-of-domainseparator)
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub chain_id: Option<u64>,
+}
+
+impl Eip712Domain {
+    #[inline]
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            chain_id: None,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// `hashStruct(eip712Domain)`
+    pub fn separator(&self) -> CryptoHash {
+        let mut hasher = Keccak256::new_with_prefix(keccak256(if self.chain_id.is_some() {
+            b"EIP712Domain(string name,string version,uint256 chainId)".as_slice()
+        } else {
+            b"EIP712Domain(string name,string version)".as_slice()
+        }));
+        hasher.update(keccak256(self.name.as_bytes()));
+        hasher.update(keccak256(self.version.as_bytes()));
+        if let Some(chain_id) = self.chain_id {
+            hasher.update(encode_uint256(chain_id));
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// Typed data with `DefuseIntents` as a primary type:
+/// ```text
+/// DefuseIntents(string message)
+/// ```
+///
+/// See [EIP-712](https://eips.ethereum.org/EIPS/eip-712)
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Debug, Clone)]
+pub struct Eip712Payload {
+    pub domain: Eip712Domain,
+    pub message: String,
+}
+
+impl Eip712Payload {
+    pub const PRIMARY_TYPE: &str = "DefuseIntents(string message)";
+
+    #[inline]
+    pub const fn new(domain: Eip712Domain, message: String) -> Self {
+        Self { domain, message }
+    }
+
+    /// `hashStruct(message)`
+    pub fn struct_hash(&self) -> CryptoHash {
+        Keccak256::new()
+            .chain_update(keccak256(Self::PRIMARY_TYPE.as_bytes()))
+            .chain_update(keccak256(self.message.as_bytes()))
+            .finalize()
+            .into()
+    }
+}
+
+impl defuse_crypto::Payload for Eip712Payload {
+    /// `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        Keccak256::new_with_prefix(b"\x19\x01")
+            .chain_update(self.domain.separator())
+            .chain_update(self.struct_hash())
+            .finalize()
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedEip712Payload {
+    pub payload: Eip712Payload,
+
+    /// There is no public key member because the public key can be recovered
+    /// via `ecrecover()` knowing the data and the signature
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Secp256k1>")
+    )]
+    pub signature: <Secp256k1 as Curve>::Signature,
+}
+
+impl defuse_crypto::Payload for SignedEip712Payload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedEip712Payload {
+    type PublicKey = <Secp256k1 as Curve>::PublicKey;
+
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{Payload, VerifiableCurve};
+        Secp256k1::verify(&self.signature, &self.payload.hash(), &())
+    }
+}
+
+#[inline]
+fn keccak256(data: &[u8]) -> CryptoHash {
+    Keccak256::digest(data).into()
+}
+
+#[inline]
+fn encode_uint256(n: u64) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[24..].copy_from_slice(&n.to_be_bytes());
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    // Signed with private key: a4b319a82adfc43584e4537fec97a80516e16673db382cd91eba97abbab8ca56
+    const REFERENCE_PUBKEY: [u8; 64] = hex!(
+        "85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b801f41e9438155eb53a5e20f748640093bb42ae3aeca035f7b7fd7a1a21f22f68"
+    );
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    fn domain(chain_id: Option<u64>) -> Eip712Domain {
+        Eip712Domain {
+            chain_id,
+            ..Eip712Domain::new("NEAR Intents", "1")
+        }
+    }
+
+    #[rstest]
+    #[case(
+        None,
+        hex!("44e6ea595716358e2c7c0b512946a61eb4927fb4dd5390093524ceafa1b901a8"),
+        hex!("27c30dda3ff72662606251bd6b3971d79f50120274588c3ed65aac689602be54"),
+    )]
+    #[case(
+        Some(1),
+        hex!("651ae95550c0cf7b7552f0a77c4d1d785579ae7d8ada642dfa8411661469196d"),
+        hex!("1b56cd2fc31807aaddd870e534da77fdfcee2bf126e0ef586254ad224f34204f"),
+    )]
+    fn hash(
+        #[case] chain_id: Option<u64>,
+        #[case] separator: CryptoHash,
+        #[case] expected: CryptoHash,
+    ) {
+        let payload = Eip712Payload::new(domain(chain_id), REFERENCE_MESSAGE.to_string());
+        assert_eq!(payload.domain.separator(), separator);
+        assert_eq!(payload.hash(), expected);
+    }
+
+    #[rstest]
+    #[case(
+        None,
+        hex!("3b4318ac8198f4690f9bdc87d26f4c4a3bffe27bfd9548e41616430632a151bc3be965ed6b21a360cbe20efe72d5c34b833cba700e9a1c61d179cacc41b25d4f01"),
+    )]
+    #[case(
+        Some(1),
+        hex!("d54964caeeecac1269574dadfcd191c223746e0b8699ae94a2a736c513b662093b44f30c18f7bc61f1d6b3937da7bc1b2d737b55f84ffa95a80dfef0192838c800"),
+    )]
+    fn verify(#[case] chain_id: Option<u64>, #[case] signature: [u8; 65]) {
+        assert_eq!(
+            SignedEip712Payload {
+                payload: Eip712Payload::new(domain(chain_id), REFERENCE_MESSAGE.to_string()),
+                signature,
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[test]
+    fn invalid_domain() {
+        assert_ne!(
+            SignedEip712Payload {
+                payload: Eip712Payload::new(domain(Some(2)), REFERENCE_MESSAGE.to_string()),
+                signature: hex!(
+                    "d54964caeeecac1269574dadfcd191c223746e0b8699ae94a2a736c513b662093b44f30c18f7bc61f1d6b3937da7bc1b2d737b55f84ffa95a80dfef0192838c800"
+                ),
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[test]
+    fn invalid_message() {
+        assert_ne!(
+            SignedEip712Payload {
+                payload: Eip712Payload::new(domain(None), "Hello, NEAR!".to_string()),
+                signature: hex!(
+                    "3b4318ac8198f4690f9bdc87d26f4c4a3bffe27bfd9548e41616430632a151bc3be965ed6b21a360cbe20efe72d5c34b833cba700e9a1c61d179cacc41b25d4f01"
+                ),
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+}
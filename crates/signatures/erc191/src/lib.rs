@@ -10,19 +10,34 @@ use impl_tools::autoimpl;
 #[derive(Debug, Clone)]
 pub struct Erc191Payload(pub String);
 
-impl defuse_crypto::Payload for Erc191Payload {
+impl Erc191Payload {
+    /// `keccak256("\x19Ethereum Signed Message:\n" ‖ len(message) ‖ message)`
     #[inline]
-    fn hash(&self) -> defuse_crypto::CryptoHash {
+    pub fn prehash(message: &[u8]) -> defuse_crypto::CryptoHash {
         use defuse_digest::{Digest, sha3::Keccak256};
 
         Keccak256::new_with_prefix(b"\x19Ethereum Signed Message:\n")
-            .chain_update(self.0.len().to_string())
-            .chain_update(self.0.as_bytes())
+            .chain_update(message.len().to_string())
+            .chain_update(message)
             .finalize()
             .into()
     }
 }
 
+impl defuse_crypto::Payload for Erc191Payload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        Self::prehash(self.0.as_bytes())
+    }
+}
+
+impl defuse_crypto::DomainSeparatedPayload for Erc191Payload {
+    #[inline]
+    fn hash_with_context(&self, context: &[u8]) -> defuse_crypto::CryptoHash {
+        Self::prehash(&[context, self.0.as_bytes()].concat())
+    }
+}
+
 #[cfg_attr(
     feature = "serde",
     ::cfg_eval::cfg_eval,
@@ -51,6 +66,74 @@ impl defuse_crypto::Payload for SignedErc191Payload {
     }
 }
 
+/// ERC-191 version `0x00`: data with intended validator.
+/// Used by contracts which accept pre-signed approvals on behalf of
+/// EOAs, so that signatures can't be replayed on other validators.
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Debug, Clone)]
+pub struct Erc191ValidatorPayload {
+    /// Address of the intended validator, hex-encoded 20 bytes
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::hex::Hex"))]
+    pub validator: [u8; 20],
+    pub data: String,
+}
+
+impl Erc191ValidatorPayload {
+    pub const VERSION: u8 = 0x00;
+
+    /// `keccak256(0x19 ‖ 0x00 ‖ validator ‖ data)`
+    #[inline]
+    pub fn prehash(validator: &[u8; 20], data: &[u8]) -> defuse_crypto::CryptoHash {
+        use defuse_digest::{Digest, sha3::Keccak256};
+
+        Keccak256::new_with_prefix([0x19, Self::VERSION])
+            .chain_update(validator)
+            .chain_update(data)
+            .finalize()
+            .into()
+    }
+}
+
+impl defuse_crypto::Payload for Erc191ValidatorPayload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        Self::prehash(&self.validator, self.data.as_bytes())
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedErc191ValidatorPayload {
+    pub payload: Erc191ValidatorPayload,
+
+    /// Public key is recovered via `ecrecover()` as well
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Secp256k1>")
+    )]
+    pub signature: <Secp256k1 as Curve>::Signature,
+}
+
+impl defuse_crypto::Payload for SignedErc191ValidatorPayload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        self.payload.hash()
+    }
+}
+
 #[cfg(any(test, feature = "near-contract"))]
 const _: () = {
     use defuse_crypto::{Payload, SignedPayload, VerifiableCurve};
@@ -62,6 +145,15 @@ const _: () = {
             Secp256k1::verify(&self.signature, &self.payload.hash(), &())
         }
     }
+
+    impl SignedPayload for SignedErc191ValidatorPayload {
+        type PublicKey = <Secp256k1 as Curve>::PublicKey;
+
+        #[inline]
+        fn verify(&self) -> Option<Self::PublicKey> {
+            Secp256k1::verify(&self.signature, &self.payload.hash(), &())
+        }
+    }
 };
 
 #[cfg(test)]
@@ -137,4 +229,78 @@ mod tests {
             Some(REFERENCE_PUBKEY)
         );
     }
+
+    const REFERENCE_VALIDATOR: [u8; 20] = hex!("5fbdb2315678afecb367f032d93f642f64180aa3");
+    const REFERENCE_VALIDATOR_SIGNATURE: [u8; 65] = hex!(
+        "04b1d12ff3a1afdf4dcd1946919d2472e914b7dd1611abeef04c8ff4c13a18572c444123706e22dc789b0f6306040aa2b727330f772d4b261853a91924ae24d801"
+    );
+
+    #[test]
+    fn test_validator_prehash() {
+        assert_eq!(
+            Erc191ValidatorPayload::prehash(&REFERENCE_VALIDATOR, REFERENCE_MESSAGE.as_bytes()),
+            hex!("b8f0d81a88d80a17485825c84d517971b94da7f631f3dfcce0e203235988e0f1")
+        );
+    }
+
+    #[test]
+    fn test_validator_signature_verification_works() {
+        assert_eq!(
+            SignedErc191ValidatorPayload {
+                payload: Erc191ValidatorPayload {
+                    validator: REFERENCE_VALIDATOR,
+                    data: REFERENCE_MESSAGE.to_string(),
+                },
+                signature: REFERENCE_VALIDATOR_SIGNATURE,
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[test]
+    fn test_validator_signature_other_validator_fails() {
+        let mut validator = REFERENCE_VALIDATOR;
+        validator[0] ^= 1;
+
+        assert_ne!(
+            SignedErc191ValidatorPayload {
+                payload: Erc191ValidatorPayload {
+                    validator,
+                    data: REFERENCE_MESSAGE.to_string(),
+                },
+                signature: REFERENCE_VALIDATOR_SIGNATURE,
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[test]
+    fn test_personal_sign_signature_for_validator_fails() {
+        assert_ne!(
+            SignedErc191ValidatorPayload {
+                payload: Erc191ValidatorPayload {
+                    validator: REFERENCE_VALIDATOR,
+                    data: REFERENCE_MESSAGE.to_string(),
+                },
+                signature: fix_v_in_signature(REFERENCE_SIGNATURE),
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[test]
+    fn test_hash_with_context() {
+        use defuse_crypto::{DomainSeparatedPayload, Payload};
+
+        let payload = Erc191Payload(REFERENCE_MESSAGE.to_string());
+        assert_eq!(payload.hash_with_context(b""), payload.hash());
+        assert_eq!(
+            payload.hash_with_context(b"NEAR Intents\n"),
+            Erc191Payload(format!("NEAR Intents\n{REFERENCE_MESSAGE}")).hash()
+        );
+        assert_ne!(payload.hash_with_context(b"NEAR Intents\n"), payload.hash());
+    }
 }
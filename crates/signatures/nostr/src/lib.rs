@@ -0,0 +1,226 @@
+//! Nostr events signed by NIP-07 browser extensions with
+//! `window.nostr.signEvent()`, see
+//! [NIP-01](https://github.com/nostr-protocol/nips/blob/master/01.md) and
+//! [NIP-07](https://github.com/nostr-protocol/nips/blob/master/07.md)
+use core::fmt::Write;
+
+use defuse_crypto::{CryptoHash, Curve, SchnorrSecp256k1};
+use impl_tools::autoimpl;
+
+/// Unsigned Nostr event, where `content` holds the message
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Debug, Clone)]
+pub struct NostrEvent {
+    /// x-only public key of the signer, hex-encoded 32 bytes
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::hex::Hex"))]
+    pub pubkey: <SchnorrSecp256k1 as Curve>::PublicKey,
+    /// Unix timestamp in seconds
+    pub created_at: u64,
+    pub kind: u16,
+    pub tags: Vec<Vec<String>>,
+    pub content: String,
+}
+
+impl NostrEvent {
+    /// Canonical serialization of the event:
+    /// `[0,<pubkey>,<created_at>,<kind>,<tags>,<content>]`
+    ///
+    /// Strings are escaped the same way as by `JSON.stringify()`,
+    /// which is used by NIP-07 extensions.
+    pub fn serialize(&self) -> String {
+        let mut s = format!(
+            "[0,\"{}\",{},{},[",
+            hex::encode(self.pubkey),
+            self.created_at,
+            self.kind,
+        );
+        for (i, tag) in self.tags.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+            s.push('[');
+            for (j, value) in tag.iter().enumerate() {
+                if j > 0 {
+                    s.push(',');
+                }
+                push_json_string(&mut s, value);
+            }
+            s.push(']');
+        }
+        s.push_str("],");
+        push_json_string(&mut s, &self.content);
+        s.push(']');
+        s
+    }
+}
+
+impl defuse_crypto::Payload for NostrEvent {
+    /// Event id, i.e. `sha256(serialize())`
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        use defuse_digest::{Digest, sha2::Sha256};
+
+        Sha256::digest(self.serialize()).into()
+    }
+}
+
+/// Signed Nostr event as returned by `window.nostr.signEvent()`.
+/// Event `id` is not needed, since it's calculated from the event itself.
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedNostrPayload {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub payload: NostrEvent,
+
+    /// BIP-340 Schnorr signature over event id, hex-encoded 64 bytes
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::hex::Hex"))]
+    pub sig: <SchnorrSecp256k1 as Curve>::Signature,
+}
+
+impl defuse_crypto::Payload for SignedNostrPayload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedNostrPayload {
+    type PublicKey = <SchnorrSecp256k1 as Curve>::PublicKey;
+
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{Payload, VerifiableCurve};
+
+        SchnorrSecp256k1::verify(&self.sig, &self.hash(), &self.payload.pubkey)
+    }
+}
+
+fn push_json_string(s: &mut String, value: &str) {
+    s.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            '\u{8}' => s.push_str("\\b"),
+            '\u{c}' => s.push_str("\\f"),
+            c if c < ' ' => {
+                write!(s, "\\u{:04x}", u32::from(c)).unwrap_or_else(|_| unreachable!());
+            }
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+
+    use super::*;
+
+    const REFERENCE_CONTENT: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    // private key: a4b319a82adfc43584e4537fec97a80516e16673db382cd91eba97abbab8ca56
+    const REFERENCE_PUBKEY: [u8; 32] =
+        hex!("85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b8");
+
+    fn signed(content: &str) -> SignedNostrPayload {
+        SignedNostrPayload {
+            payload: NostrEvent {
+                pubkey: REFERENCE_PUBKEY,
+                created_at: 1_700_000_000,
+                kind: 1,
+                tags: vec![vec!["client".to_string(), "intents\tapp".to_string()]],
+                content: content.to_string(),
+            },
+            sig: hex!(
+                "5988a5cfac2279aed1947277b3ca860d4b2d074b9f7e5cf2e94a3605779f66f377a0c76b39613d6508cf2b440ec3479fc64a61093efac9d4baa1633d56f9b642"
+            ),
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            signed(REFERENCE_CONTENT).serialize(),
+            r#"[0,"85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b8",1700000000,1,[["client","intents\tapp"]],"{\"signer_id\":\"alice.near\",\"verifying_contract\":\"intents.near\",\"deadline\":\"2030-01-01T00:00:00Z\",\"nonce\":\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=\",\"intents\":[]}"]"#
+        );
+    }
+
+    #[test]
+    fn escape() {
+        let event = NostrEvent {
+            pubkey: REFERENCE_PUBKEY,
+            created_at: 1,
+            kind: 1,
+            tags: Vec::new(),
+            content: "a\"b\\c\nd\re\tf\u{8}g\u{c}h\u{1}é".to_string(),
+        };
+        assert_eq!(
+            event.serialize(),
+            r#"[0,"85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b8",1,1,[],"a\"b\\c\nd\re\tf\bg\fh\u0001é"]"#
+        );
+        assert_eq!(
+            event.hash(),
+            hex!("47787ef2ab2f4985328d73b1f2122f4999f70201d5306b1c16d0b74fa03305e2")
+        );
+    }
+
+    #[test]
+    fn id() {
+        assert_eq!(
+            signed(REFERENCE_CONTENT).hash(),
+            hex!("432a2c21ec8607009e44ac2a0d2f2dd01e65a9d64ec18dbda311ab0ed838bf08")
+        );
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(signed(REFERENCE_CONTENT).verify(), Some(REFERENCE_PUBKEY));
+    }
+
+    #[test]
+    fn tampered() {
+        assert_eq!(
+            signed(&REFERENCE_CONTENT.replace("alice.near", "bob.near")).verify(),
+            None
+        );
+
+        let mut signed = signed(REFERENCE_CONTENT);
+        signed.payload.created_at += 1;
+        assert_eq!(signed.verify(), None);
+    }
+
+    #[test]
+    fn deserialize_nip07() {
+        let signed: SignedNostrPayload = serde_json::from_str(r#"{
+  "id": "432a2c21ec8607009e44ac2a0d2f2dd01e65a9d64ec18dbda311ab0ed838bf08",
+  "pubkey": "85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b8",
+  "created_at": 1700000000,
+  "kind": 1,
+  "tags": [["client", "intents\tapp"]],
+  "content": "{\"signer_id\":\"alice.near\",\"verifying_contract\":\"intents.near\",\"deadline\":\"2030-01-01T00:00:00Z\",\"nonce\":\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=\",\"intents\":[]}",
+  "sig": "5988a5cfac2279aed1947277b3ca860d4b2d074b9f7e5cf2e94a3605779f66f377a0c76b39613d6508cf2b440ec3479fc64a61093efac9d4baa1633d56f9b642"
+}"#).unwrap();
+        assert_eq!(signed.content, REFERENCE_CONTENT);
+        assert_eq!(signed.verify(), Some(REFERENCE_PUBKEY));
+    }
+}
@@ -0,0 +1,217 @@
+//! Raw ES256 (ECDSA over P-256 with SHA-256) signatures made by keys
+//! stored in iOS Secure Enclave or Android Keystore, without `WebAuthn`
+//! envelope, e.g. with `SecKeyCreateSignature()` and
+//! `ecdsaSignatureMessageX962SHA256` algorithm.
+use defuse_crypto::{CryptoHash, Curve, P256};
+use impl_tools::autoimpl;
+
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Debug, Clone)]
+pub struct P256Payload(pub String);
+
+impl P256Payload {
+    /// `sha256(message)`
+    #[inline]
+    pub fn prehash(message: &[u8]) -> CryptoHash {
+        use defuse_digest::{Digest, sha2::Sha256};
+
+        Sha256::digest(message).into()
+    }
+}
+
+impl defuse_crypto::Payload for P256Payload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        Self::prehash(self.0.as_bytes())
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedP256Payload {
+    pub payload: P256Payload,
+
+    /// Compressed SEC1 encoded public key
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<P256>")
+    )]
+    pub public_key: <P256 as Curve>::PublicKey,
+
+    /// Base64-encoded signature, either ASN.1 DER encoded (as returned
+    /// by iOS and Android) or concatenated `r || s` coordinates.
+    /// Signatures of 64 bytes are always treated as the latter.
+    ///
+    /// NOTE: `s` must be normalized to the lower half of the curve order,
+    /// since malleable signatures are rejected.
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::base64::Base64"))]
+    #[cfg_attr(all(feature = "serde", feature = "abi"), schemars(with = "String"))]
+    pub signature: Vec<u8>,
+}
+
+impl SignedP256Payload {
+    /// Returns signature as concatenated `r || s` coordinates
+    pub fn raw_signature(&self) -> Option<<P256 as Curve>::Signature> {
+        self.signature
+            .as_slice()
+            .try_into()
+            .ok()
+            .or_else(|| der_to_raw(&self.signature))
+    }
+}
+
+impl defuse_crypto::Payload for SignedP256Payload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedP256Payload {
+    type PublicKey = <P256 as Curve>::PublicKey;
+
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{Payload, VerifiableCurve};
+
+        P256::verify(&self.raw_signature()?, &self.hash(), &self.public_key)
+    }
+}
+
+/// Converts ASN.1 DER encoded `SEQUENCE { r INTEGER, s INTEGER }` into
+/// concatenated `r || s` coordinates
+fn der_to_raw(der: &[u8]) -> Option<[u8; 64]> {
+    // both integers take at most 35 bytes, so length is always in short form
+    let [0x30, len, rest @ ..] = der else {
+        return None;
+    };
+    if usize::from(*len) != rest.len() {
+        return None;
+    }
+    let (r, rest) = der_uint256(rest)?;
+    let (s, rest) = der_uint256(rest)?;
+    if !rest.is_empty() {
+        return None;
+    }
+
+    let mut raw = [0; 64];
+    raw[32 - r.len()..32].copy_from_slice(r);
+    raw[64 - s.len()..].copy_from_slice(s);
+    Some(raw)
+}
+
+/// Parses minimally encoded positive DER `INTEGER` fitting into 32 bytes
+/// and returns its big-endian bytes along with the rest of the input
+fn der_uint256(input: &[u8]) -> Option<(&[u8], &[u8])> {
+    let [0x02, len, rest @ ..] = input else {
+        return None;
+    };
+    let (int, rest) = rest.split_at_checked(usize::from(*len))?;
+    let int = match int {
+        // leading zero is only allowed to keep the integer positive
+        [0x00, next, ..] if *next >= 0x80 => &int[1..],
+        // empty, zero or non-minimal
+        [] | [0x00, ..] => return None,
+        // negative
+        [first, ..] if *first >= 0x80 => return None,
+        _ => int,
+    };
+    (int.len() <= 32).then_some((int, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    use super::*;
+
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    // private key: a4b319a82adfc43584e4537fec97a80516e16673db382cd91eba97abbab8ca56
+    const REFERENCE_PUBLIC_KEY: [u8; 33] =
+        hex!("02d3ac5c59175e8dc300c7bd4bdb9412dad743b3046003390d83d7f53e3f37da4f");
+
+    const DER_SIGNATURE: [u8; 71] = hex!(
+        "3045022100c02ab2524753919265bb45f65c9792284a6a3b50d4d693a4979315af543c782c02206d98db74debe22d8db78ec474a8b4b7ba08e5f819135b5d8808c35b5af6f59ec"
+    );
+
+    const RAW_SIGNATURE: [u8; 64] = hex!(
+        "c02ab2524753919265bb45f65c9792284a6a3b50d4d693a4979315af543c782c6d98db74debe22d8db78ec474a8b4b7ba08e5f819135b5d8808c35b5af6f59ec"
+    );
+
+    // same signature with `s` from the upper half of the curve order
+    const HIGH_S_SIGNATURE: [u8; 72] = hex!(
+        "3046022100c02ab2524753919265bb45f65c9792284a6a3b50d4d693a4979315af543c782c0221009267248a2141dd28248713b8b574b4841c589b2c15e1e8ac732d950d4cf3cb65"
+    );
+
+    fn signed(message: &str, signature: &[u8]) -> SignedP256Payload {
+        SignedP256Payload {
+            payload: P256Payload(message.to_string()),
+            public_key: REFERENCE_PUBLIC_KEY,
+            signature: signature.to_vec(),
+        }
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(
+            signed(REFERENCE_MESSAGE, &RAW_SIGNATURE).hash(),
+            hex!("5ca83e4645fcfdb65feaceb0e8fb56e9dba2b02e6aaacc38f8615f888928c22c")
+        );
+    }
+
+    #[rstest]
+    #[case::der(&DER_SIGNATURE)]
+    #[case::raw(&RAW_SIGNATURE)]
+    fn verify(#[case] signature: &[u8]) {
+        let signed = signed(REFERENCE_MESSAGE, signature);
+        assert_eq!(signed.raw_signature(), Some(RAW_SIGNATURE));
+        assert_eq!(signed.verify(), Some(REFERENCE_PUBLIC_KEY));
+    }
+
+    #[rstest]
+    fn tampered_message(#[values(&DER_SIGNATURE[..], &RAW_SIGNATURE)] signature: &[u8]) {
+        assert_eq!(
+            signed(
+                &REFERENCE_MESSAGE.replace("alice.near", "bob.near"),
+                signature
+            )
+            .verify(),
+            None
+        );
+    }
+
+    #[test]
+    fn high_s() {
+        assert_eq!(signed(REFERENCE_MESSAGE, &HIGH_S_SIGNATURE).verify(), None);
+    }
+
+    #[rstest]
+    #[case::empty(&[])]
+    #[case::truncated(&DER_SIGNATURE[..70])]
+    #[case::trailing(&[DER_SIGNATURE.as_slice(), &[0x00]].concat())]
+    #[case::wrong_length(&[&[0x30, 0x46], &DER_SIGNATURE[2..]].concat())]
+    #[case::non_minimal(&hex!("3008020200010202007f"))]
+    #[case::negative(&hex!("30060201ff020101"))]
+    #[case::zero(&hex!("3006020100020101"))]
+    #[case::too_long(&hex!(
+        "30260221010000000000000000000000000000000000000000000000000000000000000000020101"
+    ))]
+    fn malformed_der(#[case] signature: &[u8]) {
+        assert_eq!(signed(REFERENCE_MESSAGE, signature).raw_signature(), None);
+    }
+}
@@ -1,5 +1,6 @@
 use defuse_crypto::{Curve, Ed25519};
 use impl_tools::autoimpl;
+use stellar_strkey::{Strkey, ed25519};
 
 /// See [SEP-53](https://github.com/stellar/stellar-protocol/blob/master/ecosystem/sep-0053.md)
 #[cfg_attr(
@@ -18,20 +19,33 @@ impl Sep53Payload {
     pub const fn new(payload: String) -> Self {
         Self { payload }
     }
-}
 
-impl defuse_crypto::Payload for Sep53Payload {
+    /// `sha256("Stellar Signed Message:\n" ‖ message)`
     #[inline]
-    fn hash(&self) -> defuse_crypto::CryptoHash {
+    pub fn prehash(message: &[u8]) -> defuse_crypto::CryptoHash {
         use defuse_digest::{Digest, sha2::Sha256};
 
         Sha256::new_with_prefix(b"Stellar Signed Message:\n")
-            .chain_update(self.payload.as_bytes())
+            .chain_update(message)
             .finalize()
             .into()
     }
 }
 
+impl defuse_crypto::Payload for Sep53Payload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        Self::prehash(self.payload.as_bytes())
+    }
+}
+
+impl defuse_crypto::DomainSeparatedPayload for Sep53Payload {
+    #[inline]
+    fn hash_with_context(&self, context: &[u8]) -> defuse_crypto::CryptoHash {
+        Self::prehash(&[context, self.payload.as_bytes()].concat())
+    }
+}
+
 #[cfg_attr(
     feature = "serde",
     ::cfg_eval::cfg_eval,
@@ -55,6 +69,29 @@ pub struct SignedSep53Payload {
         serde_as(as = "defuse_crypto::serde::AsCurve<Ed25519>")
     )]
     pub signature: <Ed25519 as Curve>::Signature,
+
+    /// Stellar account (`G...`) or muxed account (`M...`) address.
+    /// If present, it must embed the `public_key`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub address: Option<String>,
+}
+
+impl SignedSep53Payload {
+    /// Decodes Ed25519 public key embedded in the address.
+    /// Returns `None` if there is no address or it's not an account one.
+    pub fn address_public_key(&self) -> Option<<Ed25519 as Curve>::PublicKey> {
+        match Strkey::from_string(self.address.as_ref()?).ok()? {
+            Strkey::PublicKeyEd25519(ed25519::PublicKey(public_key))
+            | Strkey::MuxedAccountEd25519(ed25519::MuxedAccount {
+                ed25519: public_key,
+                ..
+            }) => Some(public_key),
+            _ => None,
+        }
+    }
 }
 
 impl defuse_crypto::Payload for SignedSep53Payload {
@@ -68,9 +105,12 @@ impl defuse_crypto::Payload for SignedSep53Payload {
 impl defuse_crypto::SignedPayload for SignedSep53Payload {
     type PublicKey = <Ed25519 as Curve>::PublicKey;
 
-    #[inline]
     fn verify(&self) -> Option<Self::PublicKey> {
         use defuse_crypto::{Payload, VerifiableCurve};
+
+        if self.address.is_some() && self.address_public_key()? != self.public_key {
+            return None;
+        }
         Ed25519::verify(&self.signature, &self.hash(), &self.public_key)
     }
 }
@@ -88,7 +128,10 @@ mod tests {
     use ed25519_dalek::{Signer, Verifier};
     use near_sdk::base64;
     use rstest::rstest;
-    use stellar_strkey::Strkey;
+    use stellar_strkey::{
+        Contract, Strkey,
+        ed25519::{MuxedAccount, PublicKey},
+    };
 
     #[test]
     fn reference_test_vectors() {
@@ -143,6 +186,7 @@ mod tests {
                 payload,
                 public_key: verifying_key.as_bytes().to_owned(),
                 signature: sig.to_bytes(),
+                address: None,
             };
 
             assert_eq!(
@@ -178,6 +222,7 @@ mod tests {
                 payload,
                 public_key: pk.to_bytes(),
                 signature: sig.to_bytes(),
+                address: None,
             };
             assert!(signed_good.verify().is_some());
         }
@@ -192,6 +237,7 @@ mod tests {
                 payload: bad_payload,
                 public_key: pk.to_bytes(),
                 signature: sig.to_bytes(),
+                address: None,
             };
             assert_eq!(signed_bad.verify(), None);
         }
@@ -214,6 +260,7 @@ mod tests {
                 payload: payload.clone(),
                 public_key: pk.to_bytes(),
                 signature: sig.into(),
+                address: None,
             };
             assert!(signed_good.verify().is_some());
         }
@@ -226,8 +273,83 @@ mod tests {
                 payload,
                 public_key: pk.to_bytes(),
                 signature: bad_bytes.try_into().unwrap(),
+                address: None,
             };
             assert!(signed_bad.verify().is_none());
         }
     }
+
+    #[rstest]
+    fn address_binding(mut rng: impl CryptoRng) {
+        let sk = make_ed25519_key(&mut rng);
+        let pk = sk.verifying_key().to_bytes();
+        let other_pk = make_ed25519_key(&mut rng).verifying_key().to_bytes();
+
+        let payload = Sep53Payload::new(gen_random_string(&mut rng, 100..1000));
+        let sig = sk.sign(payload.hash().as_ref());
+
+        let signed = |address: Strkey| SignedSep53Payload {
+            payload: payload.clone(),
+            public_key: pk,
+            signature: sig.to_bytes(),
+            address: Some(address.to_string()),
+        };
+
+        // account and muxed account addresses of the signer
+        assert_eq!(
+            signed(Strkey::PublicKeyEd25519(PublicKey(pk))).verify(),
+            Some(pk)
+        );
+        assert_eq!(
+            signed(Strkey::MuxedAccountEd25519(MuxedAccount {
+                ed25519: pk,
+                id: 42,
+            }))
+            .verify(),
+            Some(pk)
+        );
+
+        // addresses of other accounts
+        assert_eq!(
+            signed(Strkey::PublicKeyEd25519(PublicKey(other_pk))).verify(),
+            None
+        );
+        assert_eq!(
+            signed(Strkey::MuxedAccountEd25519(MuxedAccount {
+                ed25519: other_pk,
+                id: 42,
+            }))
+            .verify(),
+            None
+        );
+
+        // not an account address
+        assert_eq!(signed(Strkey::Contract(Contract(pk))).verify(), None);
+    }
+
+    #[test]
+    fn malformed_address() {
+        let signed = SignedSep53Payload {
+            payload: Sep53Payload::new("Hello, World!".to_string()),
+            public_key: [0; 32],
+            signature: [0; 64],
+            address: Some("MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJV".to_string()),
+        };
+        assert_eq!(signed.address_public_key(), None);
+        assert_eq!(signed.verify(), None);
+    }
+
+    #[rstest]
+    fn hash_with_context(mut rng: impl CryptoRng) {
+        use defuse_crypto::DomainSeparatedPayload;
+
+        let msg = gen_random_string(&mut rng, 100..1000);
+        let payload = Sep53Payload::new(msg.clone());
+        assert_eq!(payload.hash_with_context(b""), payload.hash());
+        assert_eq!(
+            payload.hash_with_context(b"NEAR Intents\n"),
+            Sep53Payload::new(format!("NEAR Intents\n{msg}")).hash()
+        );
+        assert_ne!(payload.hash_with_context(b"NEAR Intents\n"), payload.hash());
+    }
 }
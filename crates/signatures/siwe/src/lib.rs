@@ -0,0 +1,507 @@
+//! Sign-In with Ethereum, see [EIP-4361](https://eips.ethereum.org/EIPS/eip-4361)
+use core::{
+    fmt::{self, Display},
+    iter::Peekable,
+    str::FromStr,
+};
+
+use defuse_crypto::{Curve, Secp256k1};
+use defuse_digest::{Digest, sha3::Keccak256};
+use defuse_erc191::Erc191Payload;
+use defuse_time::Timestamp;
+use impl_tools::autoimpl;
+use thiserror::Error as ThisError;
+
+const HEADER_SUFFIX: &str = " wants you to sign in with your Ethereum account:";
+const RESOURCES: &str = "Resources:";
+const RESOURCE_PREFIX: &str = "- ";
+
+/// Ethereum address
+pub type Address = [u8; 20];
+
+#[derive(Debug, ThisError, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("invalid header")]
+    Header,
+    #[error("invalid address")]
+    Address,
+    #[error("address is not EIP-55 checksummed")]
+    Checksum,
+    #[error("missing `{0}`")]
+    Missing(&'static str),
+    #[error("invalid `{0}`")]
+    Invalid(&'static str),
+    #[error("unsupported version")]
+    Version,
+    #[error("unexpected trailing data")]
+    Trailing,
+}
+
+/// Parsed [EIP-4361](https://eips.ethereum.org/EIPS/eip-4361#message-format) message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiweMessage {
+    /// RFC 3986 authority requesting the signing, optionally prefixed with scheme
+    pub domain: String,
+    /// Address of the signer
+    pub address: Address,
+    /// Human-readable assertion, MUST NOT include `\n`
+    pub statement: Option<String>,
+    pub uri: String,
+    pub chain_id: u64,
+    /// At least 8 alphanumeric characters
+    pub nonce: String,
+    pub issued_at: Timestamp,
+    pub expiration_time: Option<Timestamp>,
+    pub not_before: Option<Timestamp>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+impl SiweMessage {
+    pub const VERSION: &str = "1";
+}
+
+impl FromStr for SiweMessage {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.split('\n').peekable();
+
+        let domain = lines
+            .next()
+            .and_then(|l| l.strip_suffix(HEADER_SUFFIX))
+            .filter(|domain| !domain.is_empty())
+            .ok_or(ParseError::Header)?
+            .to_string();
+        let address = parse_address(lines.next().ok_or(ParseError::Missing("address"))?)?;
+        if lines.next() != Some("") {
+            return Err(ParseError::Invalid("address"));
+        }
+
+        let statement = match lines.next_if(|l| !l.starts_with("URI: ")) {
+            Some("") | None => None,
+            Some(statement) => {
+                if lines.next() != Some("") {
+                    return Err(ParseError::Invalid("statement"));
+                }
+                Some(statement.to_string())
+            }
+        };
+
+        let uri = required(&mut lines, "URI")?.to_string();
+        if required(&mut lines, "Version")? != Self::VERSION {
+            return Err(ParseError::Version);
+        }
+        let chain_id = required(&mut lines, "Chain ID")?
+            .parse()
+            .map_err(|_| ParseError::Invalid("Chain ID"))?;
+        let nonce = required(&mut lines, "Nonce")?;
+        if nonce.len() < 8 || !nonce.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(ParseError::Invalid("Nonce"));
+        }
+        let issued_at = parse_timestamp(required(&mut lines, "Issued At")?, "Issued At")?;
+        let expiration_time = optional(&mut lines, "Expiration Time")
+            .map(|t| parse_timestamp(t, "Expiration Time"))
+            .transpose()?;
+        let not_before = optional(&mut lines, "Not Before")
+            .map(|t| parse_timestamp(t, "Not Before"))
+            .transpose()?;
+        let request_id = optional(&mut lines, "Request ID").map(ToString::to_string);
+
+        let mut resources = Vec::new();
+        if lines.next_if_eq(&RESOURCES).is_some() {
+            while let Some(resource) = lines
+                .peek()
+                .copied()
+                .and_then(|l| l.strip_prefix(RESOURCE_PREFIX))
+            {
+                resources.push(resource.to_string());
+                lines.next();
+            }
+        }
+
+        if lines.next().is_some() {
+            return Err(ParseError::Trailing);
+        }
+
+        Ok(Self {
+            domain,
+            address,
+            statement,
+            uri,
+            chain_id,
+            nonce: nonce.to_string(),
+            issued_at,
+            expiration_time,
+            not_before,
+            request_id,
+            resources,
+        })
+    }
+}
+
+impl Display for SiweMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}{HEADER_SUFFIX}", self.domain)?;
+        writeln!(f, "{}", to_checksum_address(&self.address))?;
+        writeln!(f)?;
+        if let Some(statement) = &self.statement {
+            writeln!(f, "{statement}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "URI: {}", self.uri)?;
+        writeln!(f, "Version: {}", Self::VERSION)?;
+        writeln!(f, "Chain ID: {}", self.chain_id)?;
+        writeln!(f, "Nonce: {}", self.nonce)?;
+        write!(f, "Issued At: {}", self.issued_at)?;
+        if let Some(expiration_time) = self.expiration_time {
+            write!(f, "\nExpiration Time: {expiration_time}")?;
+        }
+        if let Some(not_before) = self.not_before {
+            write!(f, "\nNot Before: {not_before}")?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, "\nRequest ID: {request_id}")?;
+        }
+        if !self.resources.is_empty() {
+            write!(f, "\n{RESOURCES}")?;
+            for resource in &self.resources {
+                write!(f, "\n{RESOURCE_PREFIX}{resource}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn required<'a>(
+    lines: &mut Peekable<impl Iterator<Item = &'a str>>,
+    tag: &'static str,
+) -> Result<&'a str, ParseError> {
+    optional(lines, tag).ok_or(ParseError::Missing(tag))
+}
+
+fn optional<'a>(
+    lines: &mut Peekable<impl Iterator<Item = &'a str>>,
+    tag: &'static str,
+) -> Option<&'a str> {
+    let value = lines
+        .peek()
+        .copied()?
+        .strip_prefix(tag)?
+        .strip_prefix(": ")?;
+    lines.next();
+    Some(value)
+}
+
+fn parse_timestamp(s: &str, tag: &'static str) -> Result<Timestamp, ParseError> {
+    s.parse().map_err(|_| ParseError::Invalid(tag))
+}
+
+fn parse_address(s: &str) -> Result<Address, ParseError> {
+    let mut address = Address::default();
+    hex::decode_to_slice(
+        s.strip_prefix("0x").ok_or(ParseError::Address)?,
+        &mut address,
+    )
+    .map_err(|_| ParseError::Address)?;
+
+    if to_checksum_address(&address) != s {
+        return Err(ParseError::Checksum);
+    }
+    Ok(address)
+}
+
+/// [EIP-55](https://eips.ethereum.org/EIPS/eip-55) mixed-case checksum encoding
+pub fn to_checksum_address(address: &Address) -> String {
+    let lower = hex::encode(address);
+    let hash = Keccak256::digest(lower.as_bytes());
+
+    let mut checksummed = String::with_capacity(2 + lower.len());
+    checksummed.push_str("0x");
+    checksummed.extend(lower.chars().enumerate().map(|(i, c)| {
+        let nibble = if i % 2 == 0 {
+            hash[i / 2] >> 4
+        } else {
+            hash[i / 2] & 0x0f
+        };
+        if nibble >= 8 {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        }
+    }));
+    checksummed
+}
+
+/// Address of the account controlled by given public key
+pub fn public_key_to_address(public_key: &<Secp256k1 as Curve>::PublicKey) -> Address {
+    let hash = Keccak256::digest(public_key);
+    let mut address = Address::default();
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// EIP-4361 message signed with `personal_sign()`, i.e. using
+/// [ERC-191](https://github.com/ethereum/ercs/blob/master/ERCS/erc-191.md) prehash
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Debug, Clone)]
+pub struct SiwePayload(pub String);
+
+impl SiwePayload {
+    #[inline]
+    pub fn parse(&self) -> Result<SiweMessage, ParseError> {
+        self.0.parse()
+    }
+}
+
+impl From<&SiweMessage> for SiwePayload {
+    #[inline]
+    fn from(message: &SiweMessage) -> Self {
+        Self(message.to_string())
+    }
+}
+
+impl defuse_crypto::Payload for SiwePayload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        Erc191Payload::prehash(self.0.as_bytes())
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedSiwePayload {
+    pub payload: SiwePayload,
+
+    /// There is no public key member because the public key can be recovered
+    /// via `ecrecover()` knowing the data and the signature
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Secp256k1>")
+    )]
+    pub signature: <Secp256k1 as Curve>::Signature,
+}
+
+impl defuse_crypto::Payload for SignedSiwePayload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedSiwePayload {
+    type PublicKey = <Secp256k1 as Curve>::PublicKey;
+
+    /// Recovers the signer and ensures that it matches the `address`
+    /// from the message
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{Payload, VerifiableCurve};
+
+        let message = self.payload.parse().ok()?;
+        let public_key = Secp256k1::verify(&self.signature, &self.payload.hash(), &())?;
+        (public_key_to_address(&public_key) == message.address).then_some(public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::SignedPayload;
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    // Signed with private key: a4b319a82adfc43584e4537fec97a80516e16673db382cd91eba97abbab8ca56
+    const REFERENCE_PUBKEY: [u8; 64] = hex!(
+        "85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b801f41e9438155eb53a5e20f748640093bb42ae3aeca035f7b7fd7a1a21f22f68"
+    );
+    const REFERENCE_ADDRESS: &str = "0x0551F7c9a91eE579C9e40444ffC490001C323108";
+    const OTHER_ADDRESS: &str = "0x89B795640A977077A3c25B246FE0448f42ce4ec0";
+
+    fn message(address: &str) -> String {
+        format!(
+            "example.com wants you to sign in with your Ethereum account:
+{address}
+
+{{\"signer_id\":\"alice.near\",\"verifying_contract\":\"intents.near\",\"intents\":[]}}
+
+URI: https://example.com/login
+Version: 1
+Chain ID: 1
+Nonce: 0000000000000000000000000000000000000000000000000000000000000000
+Issued At: 2025-01-01T00:00:00Z
+Expiration Time: 2030-01-01T00:00:00Z
+Resources:
+- https://example.com/terms"
+        )
+    }
+
+    #[test]
+    fn parse() {
+        let message = message(REFERENCE_ADDRESS);
+        let parsed: SiweMessage = message.parse().unwrap();
+        assert_eq!(
+            parsed,
+            SiweMessage {
+                domain: "example.com".to_string(),
+                address: hex!("0551F7c9a91eE579C9e40444ffC490001C323108"),
+                statement: Some(
+                    r#"{"signer_id":"alice.near","verifying_contract":"intents.near","intents":[]}"#
+                        .to_string()
+                ),
+                uri: "https://example.com/login".to_string(),
+                chain_id: 1,
+                nonce: "0".repeat(64),
+                issued_at: "2025-01-01T00:00:00Z".parse().unwrap(),
+                expiration_time: Some("2030-01-01T00:00:00Z".parse().unwrap()),
+                not_before: None,
+                request_id: None,
+                resources: vec!["https://example.com/terms".to_string()],
+            }
+        );
+        assert_eq!(parsed.to_string(), message);
+    }
+
+    #[rstest]
+    #[case::no_statement(
+        "https://example.com wants you to sign in with your Ethereum account:
+0x0551F7c9a91eE579C9e40444ffC490001C323108
+
+
+URI: https://example.com
+Version: 1
+Chain ID: 10
+Nonce: 32891756
+Issued At: 2025-01-01T00:00:00Z
+Not Before: 2025-01-01T00:00:00Z
+Request ID: 42"
+    )]
+    #[case::all_fields(
+        "example.com wants you to sign in with your Ethereum account:
+0x0551F7c9a91eE579C9e40444ffC490001C323108
+
+I accept the Terms of Service
+
+URI: https://example.com
+Version: 1
+Chain ID: 1
+Nonce: abcdefgh
+Issued At: 2025-01-01T00:00:00Z
+Expiration Time: 2025-01-02T00:00:00Z
+Not Before: 2025-01-01T00:00:00Z
+Request ID: 42
+Resources:
+- ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/
+- https://example.com/my-web2-claim.json"
+    )]
+    fn roundtrip(#[case] message: &str) {
+        assert_eq!(message.parse::<SiweMessage>().unwrap().to_string(), message);
+    }
+
+    #[rstest]
+    #[case::header("example.com wants you to sign in:", ParseError::Header)]
+    #[case::lowercase_address(
+        "example.com wants you to sign in with your Ethereum account:\n0x0551f7c9a91ee579c9e40444ffc490001c323108",
+        ParseError::Checksum
+    )]
+    #[case::version(
+        "example.com wants you to sign in with your Ethereum account:
+0x0551F7c9a91eE579C9e40444ffC490001C323108
+
+
+URI: https://example.com
+Version: 2",
+        ParseError::Version
+    )]
+    #[case::short_nonce(
+        "example.com wants you to sign in with your Ethereum account:
+0x0551F7c9a91eE579C9e40444ffC490001C323108
+
+
+URI: https://example.com
+Version: 1
+Chain ID: 1
+Nonce: 1234",
+        ParseError::Invalid("Nonce")
+    )]
+    #[case::missing_issued_at(
+        "example.com wants you to sign in with your Ethereum account:
+0x0551F7c9a91eE579C9e40444ffC490001C323108
+
+
+URI: https://example.com
+Version: 1
+Chain ID: 1
+Nonce: 12345678",
+        ParseError::Missing("Issued At")
+    )]
+    #[case::trailing(
+        "example.com wants you to sign in with your Ethereum account:
+0x0551F7c9a91eE579C9e40444ffC490001C323108
+
+
+URI: https://example.com
+Version: 1
+Chain ID: 1
+Nonce: 12345678
+Issued At: 2025-01-01T00:00:00Z
+Unknown: field",
+        ParseError::Trailing
+    )]
+    fn parse_invalid(#[case] message: &str, #[case] err: ParseError) {
+        assert_eq!(message.parse::<SiweMessage>().unwrap_err(), err);
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(
+            SignedSiwePayload {
+                payload: SiwePayload(message(REFERENCE_ADDRESS)),
+                signature: hex!(
+                    "9225ad66d9fbb7499454644b4e1da5e55b45213f4001da7b0c3c47925feacec4091f4a98a708814593df56e9473dbcdc5a496b8e6d4d4846490ca899629fb01f00"
+                ),
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[test]
+    fn address_mismatch() {
+        // valid signature, but produced by the key not matching the address
+        assert_eq!(
+            SignedSiwePayload {
+                payload: SiwePayload(message(OTHER_ADDRESS)),
+                signature: hex!(
+                    "62c09d2460bb44f3a79fbbcd3e7a34399039b0efc84a1ae12ffbbbf175da71a32d0097193bba962b15ee637e6fe9b6572bd5e354e8f2d99a8a91c6e9d8037dbc00"
+                ),
+            }
+            .verify(),
+            None
+        );
+    }
+
+    #[test]
+    fn checksum() {
+        assert_eq!(
+            to_checksum_address(&hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(
+            to_checksum_address(&public_key_to_address(&REFERENCE_PUBKEY)),
+            REFERENCE_ADDRESS
+        );
+    }
+}
@@ -0,0 +1,379 @@
+//! Starknet off-chain message signing, see
+//! [SNIP-12](https://github.com/starknet-io/SNIPs/blob/main/SNIPS/snip-12.md)
+use defuse_crypto::{CryptoHash, Curve, Stark};
+use defuse_digest::{Digest, sha3::Keccak256};
+use impl_tools::autoimpl;
+use lambdaworks_crypto::hash::poseidon::{Poseidon, starknet::PoseidonCairoStark252};
+use lambdaworks_math::{
+    field::{
+        element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+    },
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+use thiserror::Error as ThisError;
+
+/// Starknet field element
+pub type Felt = FieldElement<Stark252PrimeField>;
+
+/// ASCII string which fits into a single field element,
+/// i.e. at most 31 characters long
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(try_from = "String", into = "String")
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortString(String);
+
+#[derive(Debug, ThisError, PartialEq, Eq)]
+#[error("short string must consist of at most 31 ASCII characters")]
+pub struct InvalidShortString;
+
+impl ShortString {
+    pub const MAX_LEN: usize = 31;
+
+    #[inline]
+    pub fn to_felt(&self) -> Felt {
+        felt_from_bytes_be(self.0.as_bytes())
+    }
+}
+
+impl TryFrom<String> for ShortString {
+    type Error = InvalidShortString;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.len() > Self::MAX_LEN || !s.is_ascii() {
+            return Err(InvalidShortString);
+        }
+        Ok(Self(s))
+    }
+}
+
+impl From<ShortString> for String {
+    #[inline]
+    fn from(s: ShortString) -> Self {
+        s.0
+    }
+}
+
+/// `StarknetDomain` of revision 1
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StarknetDomain {
+    pub name: ShortString,
+    pub version: ShortString,
+    /// i.e. `SN_MAIN` or `SN_SEPOLIA`
+    pub chain_id: ShortString,
+}
+
+impl StarknetDomain {
+    pub const TYPE: &str = r#""StarknetDomain"("name":"shortstring","version":"shortstring","chainId":"shortstring","revision":"shortstring")"#;
+    pub const REVISION: u8 = 1;
+
+    /// `enc(domain)`
+    pub fn struct_hash(&self) -> Felt {
+        poseidon_hash_many(&[
+            starknet_keccak(Self::TYPE.as_bytes()),
+            self.name.to_felt(),
+            self.version.to_felt(),
+            self.chain_id.to_felt(),
+            u64::from(Self::REVISION).into(),
+        ])
+    }
+}
+
+/// Typed data of revision 1 with `DefuseIntents` as a primary type:
+/// ```text
+/// "DefuseIntents"("message":"string")
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Debug, Clone)]
+pub struct StarknetPayload {
+    pub domain: StarknetDomain,
+    /// Address of the account contract, hex-encoded 32 bytes
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::hex::Hex"))]
+    pub account_address: [u8; 32],
+    pub message: String,
+}
+
+impl StarknetPayload {
+    pub const PRIMARY_TYPE: &str = r#""DefuseIntents"("message":"string")"#;
+    pub const PREFIX: &str = "StarkNet Message";
+
+    /// `enc(message)`
+    pub fn struct_hash(&self) -> Felt {
+        poseidon_hash_many(&[
+            starknet_keccak(Self::PRIMARY_TYPE.as_bytes()),
+            poseidon_hash_many(&encode_byte_array(self.message.as_bytes())),
+        ])
+    }
+}
+
+impl defuse_crypto::Payload for StarknetPayload {
+    /// `poseidon("StarkNet Message", enc(domain), account, enc(message))`
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        poseidon_hash_many(&[
+            felt_from_bytes_be(Self::PREFIX.as_bytes()),
+            self.domain.struct_hash(),
+            felt_from_bytes_be(&self.account_address),
+            self.struct_hash(),
+        ])
+        .representative()
+        .to_bytes_be()
+        .try_into()
+        .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedStarknetPayload {
+    pub payload: StarknetPayload,
+
+    /// Signer key of the account contract
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Stark>")
+    )]
+    pub public_key: <Stark as Curve>::PublicKey,
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Stark>")
+    )]
+    pub signature: <Stark as Curve>::Signature,
+}
+
+impl defuse_crypto::Payload for SignedStarknetPayload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedStarknetPayload {
+    type PublicKey = <Stark as Curve>::PublicKey;
+
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{Payload, VerifiableCurve};
+
+        Stark::verify(&self.signature, &self.payload.hash(), &self.public_key)
+    }
+}
+
+/// `sn_keccak`: Keccak-256 truncated to 250 bits
+#[inline]
+fn starknet_keccak(data: &[u8]) -> Felt {
+    let mut hash: CryptoHash = Keccak256::digest(data).into();
+    hash[0] &= 0x03;
+    felt_from_bytes_be(&hash)
+}
+
+#[inline]
+fn poseidon_hash_many(inputs: &[Felt]) -> Felt {
+    PoseidonCairoStark252::hash_many(inputs)
+}
+
+/// Converts big-endian bytes (at most 32) into field element, reducing
+/// modulo the field prime
+fn felt_from_bytes_be(bytes: &[u8]) -> Felt {
+    let mut padded = [0; 32];
+    padded[32 - bytes.len()..].copy_from_slice(bytes);
+    Felt::new(U256::from_bytes_be(&padded).unwrap_or_else(|_| unreachable!()))
+}
+
+/// Serialized `ByteArray`:
+/// `data.len() || data (31-byte words) || pending_word || pending_word_len`
+fn encode_byte_array(bytes: &[u8]) -> Vec<Felt> {
+    let len = |len: usize| Felt::from(u64::try_from(len).unwrap_or_else(|_| unreachable!()));
+
+    let chunks = bytes.chunks_exact(31);
+    let pending = chunks.remainder();
+
+    let mut encoded = Vec::with_capacity(bytes.len() / 31 + 3);
+    encoded.push(len(chunks.len()));
+    encoded.extend(chunks.map(felt_from_bytes_be));
+    encoded.push(felt_from_bytes_be(pending));
+    encoded.push(len(pending.len()));
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+    use lambdaworks_math::{
+        cyclic_group::IsGroup,
+        elliptic_curve::{
+            short_weierstrass::curves::stark_curve::StarkCurve, traits::IsEllipticCurve,
+        },
+        field::fields::montgomery_backed_prime_fields::{IsModulus, U256PrimeField},
+    };
+    use rstest::rstest;
+
+    #[derive(Clone, Debug, Hash, Copy)]
+    struct StarkCurveOrder;
+
+    impl IsModulus<U256> for StarkCurveOrder {
+        const MODULUS: U256 = U256::from_hex_unchecked(
+            "800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f",
+        );
+    }
+
+    type StarkScalar = FieldElement<U256PrimeField<StarkCurveOrder>>;
+
+    fn to_bytes(value: U256) -> [u8; 32] {
+        value.to_bytes_be().try_into().unwrap()
+    }
+
+    fn get_public_key(private_key: U256) -> [u8; 32] {
+        to_bytes(
+            StarkCurve::generator()
+                .operate_with_self(private_key)
+                .to_affine()
+                .x()
+                .representative(),
+        )
+    }
+
+    /// ECDSA signature with given nonce `k`
+    fn sign(private_key: U256, hash: &CryptoHash, k: U256) -> [u8; 64] {
+        let r = StarkCurve::generator()
+            .operate_with_self(k)
+            .to_affine()
+            .x()
+            .representative();
+        let s = StarkScalar::new(k).inv().unwrap()
+            * (StarkScalar::new(U256::from_bytes_be(hash).unwrap())
+                + StarkScalar::new(r) * StarkScalar::new(private_key));
+
+        let mut sig = [0; 64];
+        sig[..32].copy_from_slice(&to_bytes(r));
+        sig[32..].copy_from_slice(&to_bytes(s.representative()));
+        sig
+    }
+
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    fn payload(message: &str) -> StarknetPayload {
+        StarknetPayload {
+            domain: StarknetDomain {
+                name: "NEAR Intents".to_string().try_into().unwrap(),
+                version: "1".to_string().try_into().unwrap(),
+                chain_id: "SN_MAIN".to_string().try_into().unwrap(),
+            },
+            account_address: hex!(
+                "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+            ),
+            message: message.to_string(),
+        }
+    }
+
+    fn signed(payload: StarknetPayload, private_key: U256) -> SignedStarknetPayload {
+        let signature = sign(
+            private_key,
+            &payload.hash(),
+            U256::from_hex_unchecked("0x2f4d4b7f5e1a2c3b"),
+        );
+
+        SignedStarknetPayload {
+            payload,
+            public_key: get_public_key(private_key),
+            signature,
+        }
+    }
+
+    fn word() -> Felt {
+        felt_from_bytes_be(&[1; 31])
+    }
+
+    #[rstest]
+    #[case::empty(b"", vec![Felt::zero(), Felt::zero(), Felt::zero()])]
+    #[case::short(b"abc", vec![Felt::zero(), Felt::from(0x0061_6263_u64), Felt::from(3_u64)])]
+    #[case::one_word(&[1; 31], vec![Felt::one(), word(), Felt::zero(), Felt::zero()])]
+    #[case::word_and_pending(&[1; 32], vec![Felt::one(), word(), Felt::one(), Felt::one()])]
+    fn byte_array(#[case] bytes: &[u8], #[case] expected: Vec<Felt>) {
+        assert_eq!(encode_byte_array(bytes), expected);
+    }
+
+    #[test]
+    fn starknet_keccak_truncated() {
+        assert!(
+            starknet_keccak(b"DefuseIntents")
+                .representative()
+                .to_bytes_be()[0]
+                <= 0x03
+        );
+    }
+
+    #[rstest]
+    #[case("shortstring", true)]
+    #[case("SN_MAIN", true)]
+    #[case("0123456789012345678901234567890", true)]
+    #[case("01234567890123456789012345678901", false)]
+    #[case("Ünicode", false)]
+    fn short_string(#[case] s: &str, #[case] ok: bool) {
+        assert_eq!(ShortString::try_from(s.to_string()).is_ok(), ok);
+    }
+
+    #[test]
+    fn short_string_encoding() {
+        assert_eq!(
+            ShortString::try_from("SN_MAIN".to_string())
+                .unwrap()
+                .to_felt(),
+            Felt::from_hex_unchecked("0x534e5f4d41494e")
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let private_key = U256::from_hex_unchecked("0x1234567890abcdef");
+        let signed = signed(payload(REFERENCE_MESSAGE), private_key);
+        assert_eq!(signed.verify(), Some(get_public_key(private_key)));
+    }
+
+    #[test]
+    fn invalid_message() {
+        let mut signed = signed(
+            payload(REFERENCE_MESSAGE),
+            U256::from_hex_unchecked("0x1234567890abcdef"),
+        );
+        signed.payload.message = "Hello, Starknet!".to_string();
+        assert_eq!(signed.verify(), None);
+    }
+
+    #[test]
+    fn invalid_domain() {
+        let mut signed = signed(
+            payload(REFERENCE_MESSAGE),
+            U256::from_hex_unchecked("0x1234567890abcdef"),
+        );
+        signed.payload.domain.chain_id = "SN_SEPOLIA".to_string().try_into().unwrap();
+        assert_eq!(signed.verify(), None);
+    }
+}
@@ -0,0 +1,259 @@
+//! Sui wallet standard `signPersonalMessage`, see
+//! [Sui Signatures](https://docs.sui.io/concepts/cryptography/transaction-auth/signatures)
+use blake2::{Blake2b, digest::consts::U32};
+use defuse_crypto::{CryptoHash, Curve, Ed25519, P256, Secp256k1};
+use defuse_digest::{Digest, sha2::Sha256};
+use impl_tools::autoimpl;
+
+type Blake2b256 = Blake2b<U32>;
+
+/// Message to be signed with `PersonalMessage` intent scope
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(transparent)
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuiPayload(pub String);
+
+impl SuiPayload {
+    /// `IntentMessage` prefix: `[IntentScope::PersonalMessage, IntentVersion::V0, AppId::Sui]`
+    pub const INTENT: [u8; 3] = [3, 0, 0];
+
+    #[inline]
+    pub const fn new(message: String) -> Self {
+        Self(message)
+    }
+
+    /// BCS-serialized `IntentMessage<PersonalMessage>`
+    pub fn intent_message(&self) -> Vec<u8> {
+        let message = self.0.as_bytes();
+        let mut bytes = Vec::with_capacity(Self::INTENT.len() + 5 + message.len());
+        bytes.extend_from_slice(&Self::INTENT);
+        // BCS: `Vec<u8>` is prefixed with ULEB128-encoded length
+        let mut len = message.len();
+        while len >= 0x80 {
+            bytes.push(0x80 | len.to_le_bytes()[0]);
+            len >>= 7;
+        }
+        bytes.push(len.to_le_bytes()[0]);
+        bytes.extend_from_slice(message);
+        bytes
+    }
+}
+
+impl defuse_crypto::Payload for SuiPayload {
+    /// `blake2b256(intent_message)`
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        use blake2::Digest;
+
+        Blake2b256::digest(self.intent_message()).into()
+    }
+}
+
+/// Signature scheme, encoded as a leading flag byte of serialized signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuiSignatureScheme {
+    Ed25519,
+    Secp256k1,
+    Secp256r1,
+}
+
+impl SuiSignatureScheme {
+    #[inline]
+    pub const fn from_flag(flag: u8) -> Option<Self> {
+        Some(match flag {
+            0x00 => Self::Ed25519,
+            0x01 => Self::Secp256k1,
+            0x02 => Self::Secp256r1,
+            _ => return None,
+        })
+    }
+
+    #[inline]
+    pub const fn flag(self) -> u8 {
+        match self {
+            Self::Ed25519 => 0x00,
+            Self::Secp256k1 => 0x01,
+            Self::Secp256r1 => 0x02,
+        }
+    }
+}
+
+/// Public key recovered after successful verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuiVerifiedKey {
+    Ed25519(<Ed25519 as Curve>::PublicKey),
+    /// Concatenated `x || y` coordinates with no leading SEC1 tag byte
+    Secp256k1(<Secp256k1 as Curve>::PublicKey),
+    /// Concatenated `x || y` coordinates with no leading SEC1 tag byte
+    Secp256r1([u8; 64]),
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedSuiPayload {
+    pub payload: SuiPayload,
+
+    /// Base64-encoded serialized signature: `flag || signature || public_key`
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::base64::Base64"))]
+    pub signature: Vec<u8>,
+}
+
+impl SignedSuiPayload {
+    /// Splits serialized signature into scheme, signature and public key
+    pub fn parse_signature(&self) -> Option<(SuiSignatureScheme, &[u8; 64], &[u8])> {
+        let [flag, rest @ ..] = self.signature.as_slice() else {
+            return None;
+        };
+        let (signature, public_key) = rest.split_first_chunk()?;
+        Some((SuiSignatureScheme::from_flag(*flag)?, signature, public_key))
+    }
+}
+
+impl defuse_crypto::Payload for SignedSuiPayload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedSuiPayload {
+    type PublicKey = SuiVerifiedKey;
+
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{P256CompressedPublicKey, Payload, VerifiableCurve};
+
+        let (scheme, signature, public_key) = self.parse_signature()?;
+        let digest = self.payload.hash();
+
+        match scheme {
+            SuiSignatureScheme::Ed25519 => {
+                Ed25519::verify(signature, &digest, public_key.try_into().ok()?)
+                    .map(SuiVerifiedKey::Ed25519)
+            }
+            // ECDSA schemes sign SHA-256 of the digest
+            SuiSignatureScheme::Secp256k1 => {
+                let compressed: &[u8; 33] = public_key.try_into().ok()?;
+                let hash: CryptoHash = Sha256::digest(digest).into();
+
+                // signature is not recoverable, so try both recovery ids
+                // and match the result against given public key
+                (0..=1)
+                    .filter_map(|v| {
+                        let mut signature_v = [v; 65];
+                        signature_v[..64].copy_from_slice(signature);
+                        Secp256k1::verify(&signature_v, &hash, &())
+                    })
+                    .find(|public_key| compress_secp256k1(public_key) == *compressed)
+                    .map(SuiVerifiedKey::Secp256k1)
+            }
+            SuiSignatureScheme::Secp256r1 => {
+                let compressed = P256CompressedPublicKey(public_key.try_into().ok()?);
+                P256::verify(signature, &Sha256::digest(digest).into(), &compressed.0)?;
+                defuse_crypto::decompress_public_key(&compressed)
+                    .map(|public_key| SuiVerifiedKey::Secp256r1(public_key.0))
+            }
+        }
+    }
+}
+
+#[inline]
+fn compress_secp256k1(public_key: &<Secp256k1 as Curve>::PublicKey) -> [u8; 33] {
+    let mut compressed = [0x02 | (public_key[63] & 1); 33];
+    compressed[1..].copy_from_slice(&public_key[..32]);
+    compressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+    use near_sdk::base64::{Engine, engine::general_purpose::STANDARD};
+    use rstest::rstest;
+
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    // ed25519 private key: 000102...1f
+    const ED25519_SIGNATURE: &str = "AK/d0HQjItkYbICXsfIadmYR2ZLA2wAx5d7M94gQqphiTxwQ2rkQFnCztzy8Z3jgbjSLilMAchscG6SOhsN3qQsDoQe/884Qvh1w3RjnS8CZZ+TWMJulDV8d3IZkElUxuA==";
+    // secp256k1 and secp256r1 private key: a4b319a82adfc43584e4537fec97a80516e16673db382cd91eba97abbab8ca56
+    const SECP256K1_SIGNATURE: &str = "AbEubPJVgoRREnVQOSy/4bBowIerV7runNyLlvxTsg+6Gp5ltUmXOLi2a/KRjlT/KwlCKPC9C9RnHzE0imphtcgChaZphCc/M4zk73uF5UMLAIMH6Fkbt8G5gIUs9kI3cLg=";
+    const SECP256R1_SIGNATURE: &str = "AvqFowEROZFTIL5HV7hg3f1xfKOwGEiMAsdncJ7mzHq5V7U7K6yQMSosT7j9uow00SgAsv4tLvb0J1wPRQbnbcYC06xcWRdejcMAx71L25QS2tdDswRgAzkNg9f1Pj832k8=";
+
+    fn signed(message: &str, signature: &str) -> SignedSuiPayload {
+        SignedSuiPayload {
+            payload: SuiPayload::new(message.to_string()),
+            signature: STANDARD.decode(signature).unwrap(),
+        }
+    }
+
+    #[test]
+    fn intent_message() {
+        let intent_message = SuiPayload::new(REFERENCE_MESSAGE.to_string()).intent_message();
+        // 164 bytes long message: ULEB128(164) = [0xa4, 0x01]
+        assert_eq!(intent_message[..5], [3, 0, 0, 0xa4, 0x01]);
+        assert_eq!(&intent_message[5..], REFERENCE_MESSAGE.as_bytes());
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(
+            SuiPayload::new(REFERENCE_MESSAGE.to_string()).hash(),
+            hex!("ad3d06f3d227cbb29cf594952e35d7581aa529a1c2879e403e5e64a087e057c5")
+        );
+    }
+
+    #[rstest]
+    #[case::ed25519(
+        ED25519_SIGNATURE,
+        SuiVerifiedKey::Ed25519(hex!(
+            "03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8"
+        )),
+    )]
+    #[case::secp256k1(
+        SECP256K1_SIGNATURE,
+        SuiVerifiedKey::Secp256k1(hex!(
+            "85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b801f41e9438155eb53a5e20f748640093bb42ae3aeca035f7b7fd7a1a21f22f68"
+        )),
+    )]
+    #[case::secp256r1(
+        SECP256R1_SIGNATURE,
+        SuiVerifiedKey::Secp256r1(hex!(
+            "d3ac5c59175e8dc300c7bd4bdb9412dad743b3046003390d83d7f53e3f37da4f823c798531b3f57e5165995c285d8fb524e0df79bad79ba8cf08fd88b9cd7756"
+        )),
+    )]
+    fn verify(#[case] signature: &str, #[case] public_key: SuiVerifiedKey) {
+        assert_eq!(
+            signed(REFERENCE_MESSAGE, signature).verify(),
+            Some(public_key)
+        );
+    }
+
+    #[rstest]
+    fn invalid_message(
+        #[values(ED25519_SIGNATURE, SECP256K1_SIGNATURE, SECP256R1_SIGNATURE)] signature: &str,
+    ) {
+        assert_eq!(signed("Hello, Sui!", signature).verify(), None);
+    }
+
+    #[rstest]
+    #[case::unknown_flag(0x03)]
+    #[case::other_scheme(0x01)]
+    fn invalid_flag(#[case] flag: u8) {
+        let mut signed = signed(REFERENCE_MESSAGE, ED25519_SIGNATURE);
+        signed.signature[0] = flag;
+        assert_eq!(signed.verify(), None);
+    }
+}
@@ -0,0 +1,165 @@
+//! Tezos wallets `signPayload` with `MICHELINE` signing type, see
+//! [Beacon documentation](https://docs.walletbeacon.io/guides/sign-payload)
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use defuse_crypto::{CryptoHash, Curve, Ed25519};
+use impl_tools::autoimpl;
+
+type Blake2b256 = Blake2b<U32>;
+
+/// String message to be signed as Micheline expression
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema)),
+    serde(transparent)
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TezosPayload(pub String);
+
+impl TezosPayload {
+    /// Prefix of the message recommended by Beacon for `MICHELINE` payloads:
+    /// `Tezos Signed Message: <dApp URL> <ISO 8601 timestamp> <message>`
+    pub const MESSAGE_PREFIX: &str = "Tezos Signed Message: ";
+
+    #[inline]
+    pub const fn new(message: String) -> Self {
+        Self(message)
+    }
+
+    /// Packed Micheline string:
+    /// `0x05 (packed) || 0x01 (string tag) || u32_be(len) || message`
+    pub fn micheline_bytes(&self) -> Vec<u8> {
+        let message = self.0.as_bytes();
+        let len = u32::try_from(message.len()).unwrap_or_else(|_| unreachable!());
+
+        let mut bytes = Vec::with_capacity(6 + message.len());
+        bytes.extend_from_slice(&[0x05, 0x01]);
+        bytes.extend_from_slice(&len.to_be_bytes());
+        bytes.extend_from_slice(message);
+        bytes
+    }
+
+    /// Message itself with Beacon prefix, dApp URL and timestamp stripped
+    pub fn message(&self) -> &str {
+        self.0
+            .strip_prefix(Self::MESSAGE_PREFIX)
+            .and_then(|s| s.split_once(' '))
+            .and_then(|(_dapp_url, s)| s.split_once(' '))
+            .map_or(&self.0, |(_timestamp, message)| message)
+    }
+}
+
+impl defuse_crypto::Payload for TezosPayload {
+    /// `blake2b256(micheline_bytes)`
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        Blake2b256::digest(self.micheline_bytes()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedTezosPayload {
+    pub payload: TezosPayload,
+
+    /// Public key of tz1 account
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Ed25519>")
+    )]
+    pub public_key: <Ed25519 as Curve>::PublicKey,
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Ed25519>")
+    )]
+    pub signature: <Ed25519 as Curve>::Signature,
+}
+
+impl defuse_crypto::Payload for SignedTezosPayload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedTezosPayload {
+    type PublicKey = <Ed25519 as Curve>::PublicKey;
+
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{Payload, VerifiableCurve};
+
+        Ed25519::verify(&self.signature, &self.payload.hash(), &self.public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    // private key: 000102...1f
+    const REFERENCE_PUBKEY: [u8; 32] =
+        hex!("03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8");
+
+    fn payload(message: &str) -> TezosPayload {
+        TezosPayload::new(format!(
+            "Tezos Signed Message: intents.near 2025-01-01T00:00:00Z {message}"
+        ))
+    }
+
+    #[test]
+    fn micheline_bytes() {
+        let bytes = payload(REFERENCE_MESSAGE).micheline_bytes();
+        assert_eq!(bytes[..6], hex!("0501000000dc"));
+        assert_eq!(bytes.len(), 6 + 220);
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(
+            payload(REFERENCE_MESSAGE).hash(),
+            hex!("575c87f24cc8505c02bd546b19dd775c5a659fd56021f6f6b7824afdeb5c3818")
+        );
+    }
+
+    #[rstest]
+    #[case::beacon(payload(REFERENCE_MESSAGE), REFERENCE_MESSAGE)]
+    #[case::raw(TezosPayload::new(REFERENCE_MESSAGE.to_string()), REFERENCE_MESSAGE)]
+    #[case::no_timestamp(
+        TezosPayload::new("Tezos Signed Message: intents.near".to_string()),
+        "Tezos Signed Message: intents.near",
+    )]
+    fn message(#[case] tezos_payload: TezosPayload, #[case] expected: &str) {
+        assert_eq!(tezos_payload.message(), expected);
+    }
+
+    #[rstest]
+    #[case(REFERENCE_MESSAGE, Some(REFERENCE_PUBKEY))]
+    #[case("Hello, Tezos!", None)]
+    fn verify(#[case] message: &str, #[case] expected: Option<[u8; 32]>) {
+        assert_eq!(
+            SignedTezosPayload {
+                payload: payload(message),
+                public_key: REFERENCE_PUBKEY,
+                signature: hex!(
+                    "85db8a508b093081d3cb56e29ad69680d443f4ef354df63bda20a0a522eb7bbab2947c62c327ed110a2ef5143cbc59f667c5957cc7700b58243969568f4eb400"
+                ),
+            }
+            .verify(),
+            expected
+        );
+    }
+}
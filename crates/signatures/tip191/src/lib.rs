@@ -10,20 +10,35 @@ use impl_tools::autoimpl;
 #[derive(Debug, Clone)]
 pub struct Tip191Payload(pub String);
 
-impl defuse_crypto::Payload for Tip191Payload {
+impl Tip191Payload {
+    /// `keccak256("\x19TRON Signed Message:\n" ‖ len(message) ‖ message)`
     #[inline]
-    fn hash(&self) -> defuse_crypto::CryptoHash {
+    pub fn prehash(message: &[u8]) -> defuse_crypto::CryptoHash {
         use defuse_digest::{Digest, sha3::Keccak256};
 
         // Prefix not specified in the standard. But from: https://tronweb.network/docu/docs/Sign%20and%20Verify%20Message/
         Keccak256::new_with_prefix(b"\x19TRON Signed Message:\n")
-            .chain_update(self.0.len().to_string())
-            .chain_update(self.0.as_bytes())
+            .chain_update(message.len().to_string())
+            .chain_update(message)
             .finalize()
             .into()
     }
 }
 
+impl defuse_crypto::Payload for Tip191Payload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        Self::prehash(self.0.as_bytes())
+    }
+}
+
+impl defuse_crypto::DomainSeparatedPayload for Tip191Payload {
+    #[inline]
+    fn hash_with_context(&self, context: &[u8]) -> defuse_crypto::CryptoHash {
+        Self::prehash(&[context, self.0.as_bytes()].concat())
+    }
+}
+
 #[cfg_attr(
     feature = "serde",
     ::cfg_eval::cfg_eval,
@@ -43,6 +58,32 @@ pub struct SignedTip191Payload {
         serde_as(as = "defuse_crypto::serde::AsCurve<Secp256k1>")
     )]
     pub signature: <Secp256k1 as Curve>::Signature,
+
+    /// Base58Check-encoded Tron address, i.e. starting with `T`.
+    /// If present, the recovered public key must correspond to it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub address: Option<String>,
+}
+
+impl SignedTip191Payload {
+    /// Version byte of Tron addresses
+    pub const ADDRESS_VERSION: u8 = 0x41;
+
+    /// Decodes last 20 bytes of `keccak256(public_key)` from Tron address.
+    /// Returns `None` if there is no address or it's malformed.
+    pub fn address_hash(&self) -> Option<[u8; 20]> {
+        let decoded = bs58::decode(self.address.as_ref()?)
+            .with_check(Some(Self::ADDRESS_VERSION))
+            .into_vec()
+            .ok()?;
+        let [Self::ADDRESS_VERSION, address_hash @ ..] = <[u8; 21]>::try_from(decoded).ok()? else {
+            return None;
+        };
+        Some(address_hash)
+    }
 }
 
 impl defuse_crypto::Payload for SignedTip191Payload {
@@ -56,18 +97,33 @@ impl defuse_crypto::Payload for SignedTip191Payload {
 impl defuse_crypto::SignedPayload for SignedTip191Payload {
     type PublicKey = <Secp256k1 as Curve>::PublicKey;
 
-    #[inline]
     fn verify(&self) -> Option<Self::PublicKey> {
         use defuse_crypto::{Payload, VerifiableCurve};
-        Secp256k1::verify(&self.signature, &self.payload.hash(), &())
+
+        let public_key = Secp256k1::verify(&self.signature, &self.payload.hash(), &())?;
+        if self.address.is_some() && self.address_hash()? != evm_address(&public_key) {
+            return None;
+        }
+        Some(public_key)
     }
 }
 
+/// Last 20 bytes of `keccak256(public_key)`, same as for Ethereum addresses
+#[cfg(any(test, feature = "near-contract"))]
+fn evm_address(public_key: &<Secp256k1 as Curve>::PublicKey) -> [u8; 20] {
+    use defuse_digest::{Digest, sha3::Keccak256};
+
+    Keccak256::digest(public_key)[12..]
+        .try_into()
+        .unwrap_or_else(|_| unreachable!())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use defuse_crypto::SignedPayload;
     use hex_literal::hex;
+    use rstest::rstest;
 
     const fn fix_v_in_signature(mut sig: [u8; 65]) -> [u8; 65] {
         if *sig.last().unwrap() >= 27 {
@@ -99,6 +155,7 @@ mod tests {
     const REFERENCE_PUBKEY: [u8; 64] = hex!(
         "85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b801f41e9438155eb53a5e20f748640093bb42ae3aeca035f7b7fd7a1a21f22f68"
     );
+    const REFERENCE_ADDRESS: &str = "TATLaFQM6PniXxK85r5BQNYTfKwnRzsgM5";
 
     #[test]
     fn test_reference_signature_verification_works() {
@@ -106,6 +163,7 @@ mod tests {
             SignedTip191Payload {
                 payload: Tip191Payload(REFERENCE_MESSAGE.to_string()),
                 signature: fix_v_in_signature(REFERENCE_SIGNATURE),
+                address: None,
             }
             .verify(),
             Some(REFERENCE_PUBKEY)
@@ -118,6 +176,7 @@ mod tests {
             SignedTip191Payload {
                 payload: Tip191Payload(INVALID_REFERENCE_MESSAGE.to_string()),
                 signature: fix_v_in_signature(REFERENCE_SIGNATURE),
+                address: None,
             }
             .verify(),
             Some(REFERENCE_PUBKEY)
@@ -130,9 +189,53 @@ mod tests {
             SignedTip191Payload {
                 payload: Tip191Payload(REFERENCE_MESSAGE.to_string()),
                 signature: fix_v_in_signature(INVALID_REFERENCE_SIGNATURE),
+                address: None,
             }
             .verify(),
             Some(REFERENCE_PUBKEY)
         );
     }
+
+    #[test]
+    fn test_reference_address_verification_works() {
+        assert_eq!(
+            SignedTip191Payload {
+                payload: Tip191Payload(REFERENCE_MESSAGE.to_string()),
+                signature: fix_v_in_signature(REFERENCE_SIGNATURE),
+                address: Some(REFERENCE_ADDRESS.to_string()),
+            }
+            .verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[rstest]
+    #[case::other_address("T9yD14Nj9j7xAB4dbGeiX9h8unkKHxuWwb")]
+    #[case::invalid_checksum("TATLaFQM6PniXxK85r5BQNYTfKwnRzsgM6")]
+    #[case::hex("410551f7c9a91ee579c9e40444ffc490001c323108")]
+    #[case::empty("")]
+    fn test_address_mismatch_verification_fails(#[case] address: &str) {
+        assert_eq!(
+            SignedTip191Payload {
+                payload: Tip191Payload(REFERENCE_MESSAGE.to_string()),
+                signature: fix_v_in_signature(REFERENCE_SIGNATURE),
+                address: Some(address.to_string()),
+            }
+            .verify(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hash_with_context() {
+        use defuse_crypto::{DomainSeparatedPayload, Payload};
+
+        let payload = Tip191Payload(REFERENCE_MESSAGE.to_string());
+        assert_eq!(payload.hash_with_context(b""), payload.hash());
+        assert_eq!(
+            payload.hash_with_context(b"NEAR Intents\n"),
+            Tip191Payload(format!("NEAR Intents\n{REFERENCE_MESSAGE}")).hash()
+        );
+        assert_ne!(payload.hash_with_context(b"NEAR Intents\n"), payload.hash());
+    }
 }
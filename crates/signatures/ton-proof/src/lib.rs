@@ -0,0 +1,298 @@
+//! TON Connect [ton_proof](https://docs.ton.org/v3/guidelines/ton-connect/verifying-signed-in-users)
+use defuse_crypto::{CryptoHash, Curve, Ed25519};
+use defuse_digest::{Digest, sha2::Sha256};
+use defuse_time::Timestamp;
+use impl_tools::autoimpl;
+use tlb_ton::{Cell, MsgAddress};
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TonProofPayload {
+    /// Wallet address in either [Raw](https://docs.ton.org/v3/documentation/smart-contracts/addresses/address-formats#raw-address) representation
+    /// or [user-friendly](https://docs.ton.org/v3/documentation/smart-contracts/addresses/address-formats#user-friendly-address) format
+    pub address: MsgAddress,
+    /// dApp domain
+    pub domain: String,
+    /// UNIX timestamp (in seconds or RFC3339) at the time of signing
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "::serde_with::PickFirst<(
+            _,
+            ::defuse_time::serde::TimestampSeconds<::serde_with::DisplayFromStr>,
+            ::defuse_time::serde::TimestampSeconds,
+        )>")
+    )]
+    pub timestamp: Timestamp,
+    pub payload: String,
+}
+
+impl TonProofPayload {
+    pub const MESSAGE_PREFIX: &[u8] = b"ton-proof-item-v2/";
+    pub const SIGNATURE_PREFIX: &[u8] = b"\xFF\xFFton-connect";
+
+    /// ```text
+    /// "ton-proof-item-v2/" || workchain_id (i32_be) || address ||
+    /// domain_len (u32_le) || domain || timestamp (u64_le) || payload
+    /// ```
+    pub fn try_message(&self) -> Option<Vec<u8>> {
+        let domain_len = u32::try_from(self.domain.len()).ok()?;
+        let timestamp = u64::try_from(self.timestamp.as_secs()).ok()?;
+
+        Some(
+            [
+                Self::MESSAGE_PREFIX,
+                &self.address.workchain_id.to_be_bytes(),
+                &self.address.address,
+                &domain_len.to_le_bytes(),
+                self.domain.as_bytes(),
+                &timestamp.to_le_bytes(),
+                self.payload.as_bytes(),
+            ]
+            .concat(),
+        )
+    }
+
+    /// `sha256(0xFFFF || "ton-connect" || sha256(message))`
+    pub fn try_hash(&self) -> Option<CryptoHash> {
+        let message = self.try_message()?;
+        Some(
+            Sha256::new_with_prefix(Self::SIGNATURE_PREFIX)
+                .chain_update(Sha256::digest(message))
+                .finalize()
+                .into(),
+        )
+    }
+
+    #[track_caller]
+    pub fn hash(&self) -> CryptoHash {
+        self.try_hash().expect("ton_proof hash")
+    }
+}
+
+impl defuse_crypto::Payload for TonProofPayload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        Self::hash(self)
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedTonProofPayload {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub payload: TonProofPayload,
+
+    /// `StateInit` of the wallet contract, which `address` is derived from
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_serde_utils::tlb::AsBoC<serde_with::base64::Base64>")
+    )]
+    pub state_init: Cell,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Ed25519>")
+    )]
+    pub public_key: <Ed25519 as Curve>::PublicKey,
+    #[cfg_attr(
+        feature = "serde",
+        serde_as(as = "defuse_crypto::serde::AsCurve<Ed25519>")
+    )]
+    pub signature: <Ed25519 as Curve>::Signature,
+}
+
+impl SignedTonProofPayload {
+    /// Offsets of `public_key:bits256` in persistent data of standard wallets:
+    /// * v3, v4: `seqno:uint32 subwallet_id:uint32 public_key:bits256 ...`
+    /// * v5: `is_signature_allowed:Bool seqno:uint32 wallet_id:int32 public_key:bits256 ...`
+    pub const PUBLIC_KEY_OFFSETS: [usize; 2] = [64, 65];
+
+    /// Checks that `address` is derived from `state_init` and its data
+    /// contains `public_key`
+    pub fn is_address_valid(&self) -> bool {
+        if self.state_init.hash_digest::<Sha256>() != self.address.address {
+            return false;
+        }
+
+        state_init_data(&self.state_init).is_some_and(|data| {
+            Self::PUBLIC_KEY_OFFSETS
+                .into_iter()
+                .filter_map(|offset| read_bits256(data, offset))
+                .any(|public_key| public_key == self.public_key)
+        })
+    }
+}
+
+impl defuse_crypto::Payload for SignedTonProofPayload {
+    #[inline]
+    fn hash(&self) -> CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedTonProofPayload {
+    type PublicKey = <Ed25519 as Curve>::PublicKey;
+
+    #[inline]
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::VerifiableCurve;
+
+        if !self.is_address_valid() {
+            return None;
+        }
+
+        Ed25519::verify(&self.signature, &self.payload.try_hash()?, &self.public_key)
+    }
+}
+
+/// Returns `data` cell of serialized `StateInit`:
+/// ```tlb
+/// _ split_depth:(Maybe (## 5)) special:(Maybe TickTock)
+///   code:(Maybe ^Cell) data:(Maybe ^Cell)
+///   library:(Maybe ^Cell) = StateInit;
+/// ```
+fn state_init_data(state_init: &Cell) -> Option<&Cell> {
+    let bits = &state_init.data;
+    let mut offset = 0;
+
+    // split_depth:(Maybe (## 5))
+    if *bits.get(offset)? {
+        offset += 5;
+    }
+    offset += 1;
+
+    // special:(Maybe TickTock)
+    if *bits.get(offset)? {
+        offset += 2;
+    }
+    offset += 1;
+
+    // code:(Maybe ^Cell)
+    let has_code = *bits.get(offset)?;
+    // data:(Maybe ^Cell)
+    if !*bits.get(offset + 1)? {
+        return None;
+    }
+
+    state_init
+        .references
+        .get(usize::from(has_code))
+        .map(AsRef::as_ref)
+}
+
+fn read_bits256(cell: &Cell, offset: usize) -> Option<[u8; 32]> {
+    let bits = cell.data.get(offset..offset + 256)?;
+
+    let mut bytes = [0; 32];
+    for (i, bit) in bits.iter().by_vals().enumerate() {
+        if bit {
+            bytes[i / 8] |= 0x80 >> (i % 8);
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::SignedPayload;
+    use hex_literal::hex;
+    use rstest::rstest;
+    use tlb_ton::BagOfCells;
+
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    // private key: 000102...1f
+    const REFERENCE_PUBKEY: [u8; 32] =
+        hex!("03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8");
+
+    // wallets with dummy code and persistent data of given version
+    const WALLET_V4_ADDRESS: &str =
+        "0:59c536b24468a4260440cb7a29e5f3c439bb404c2309141d5f5558ffdd5007de";
+    const WALLET_V4_STATE_INIT: &str =
+        "te6ccgEBAwEAMwACATQBAgAC/wBRAAAAACmpoxcDoQe/884Qvh1w3RjnS8CZZ+TWMJulDV8d3IZkElUxuEA=";
+    const WALLET_V4_SIGNATURE: [u8; 64] = hex!(
+        "bffee584ac57c356fab60627c8df8c54a5ca553c4d5d0c4d006c297b57129e867182ad7a7544b82f9797dc07c909817dc2a13164a5746c5dbc50e73361ceb309"
+    );
+
+    const WALLET_V5_ADDRESS: &str =
+        "0:70334188d99646e7e69c355cc6fb74b767509945f44e8e1af125abc5706a84b1";
+    const WALLET_V5_STATE_INIT: &str =
+        "te6ccgEBAwEAMwACATQBAgAC/wBRgAAAAD///4iB0IPf+ecIXw64boxzpeBMs/JrGE3Shq+O7kMyCSqY3CA=";
+    const WALLET_V5_SIGNATURE: [u8; 64] = hex!(
+        "b14de1b9ff97fa0d3bed7a2f632e16f312541fb9678b823ec2f66b1028cfb28c05ddc6fe22908c77903598ca2427571046d95c1fce49f5b9975cc76f51837308"
+    );
+
+    fn signed(address: &str, state_init: &str, signature: [u8; 64]) -> SignedTonProofPayload {
+        SignedTonProofPayload {
+            payload: TonProofPayload {
+                address: address.parse().unwrap(),
+                domain: "ton-connect.github.io".to_string(),
+                timestamp: Timestamp::from_secs(1747759882).unwrap(),
+                payload: REFERENCE_MESSAGE.to_string(),
+            },
+            state_init: BagOfCells::parse_base64(state_init)
+                .unwrap()
+                .into_single_root()
+                .unwrap()
+                .as_ref()
+                .clone(),
+            public_key: REFERENCE_PUBKEY,
+            signature,
+        }
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(
+            signed(WALLET_V4_ADDRESS, WALLET_V4_STATE_INIT, WALLET_V4_SIGNATURE).hash(),
+            hex!("e7805adea309dad3c02b9858fec0ee2c56f22973f555cfce33f777629c68f07e")
+        );
+    }
+
+    #[rstest]
+    #[case::v4(WALLET_V4_ADDRESS, WALLET_V4_STATE_INIT, WALLET_V4_SIGNATURE)]
+    #[case::v5(WALLET_V5_ADDRESS, WALLET_V5_STATE_INIT, WALLET_V5_SIGNATURE)]
+    fn verify(#[case] address: &str, #[case] state_init: &str, #[case] signature: [u8; 64]) {
+        assert_eq!(
+            signed(address, state_init, signature).verify(),
+            Some(REFERENCE_PUBKEY)
+        );
+    }
+
+    #[test]
+    fn invalid_payload() {
+        let mut signed = signed(WALLET_V4_ADDRESS, WALLET_V4_STATE_INIT, WALLET_V4_SIGNATURE);
+        signed.payload.payload = "Hello, TON!".to_string();
+        assert_eq!(signed.verify(), None);
+    }
+
+    #[test]
+    fn address_mismatch() {
+        // state_init of another wallet
+        let signed = signed(WALLET_V4_ADDRESS, WALLET_V5_STATE_INIT, WALLET_V4_SIGNATURE);
+        assert!(!signed.is_address_valid());
+        assert_eq!(signed.verify(), None);
+    }
+
+    #[test]
+    fn public_key_mismatch() {
+        let mut signed = signed(WALLET_V4_ADDRESS, WALLET_V4_STATE_INIT, WALLET_V4_SIGNATURE);
+        signed.public_key[0] ^= 1;
+        assert!(!signed.is_address_valid());
+    }
+}
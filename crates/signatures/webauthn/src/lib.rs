@@ -16,6 +16,11 @@ mod p256;
 #[cfg(feature = "p256")]
 pub use self::p256::*;
 
+#[cfg(feature = "rsa")]
+mod rsa;
+#[cfg(feature = "rsa")]
+pub use self::rsa::*;
+
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "abi", derive(::schemars::JsonSchema))]
@@ -46,17 +51,17 @@ impl<A: Algorithm + ?Sized> PayloadSignature<A> {
         &self,
         message: impl AsRef<[u8]>,
         public_key: &A::PublicKey,
-        user_verification: UserVerification,
+        options: VerifyOptions<'_>,
     ) -> bool {
         // verify authData flags
         if self.authenticator_data.len() < 37
-            || !Self::verify_flags(self.authenticator_data[32], user_verification)
+            || !Self::verify_flags(self.authenticator_data[32], options.user_verification)
         {
             return false;
         }
 
         // 10. Verify that the value of C.type is the string webauthn.get.
-        let Ok(c) = serde_json::from_str::<CollectedClientData>(&self.client_data_json) else {
+        let Some(c) = self.client_data() else {
             return false;
         };
         if c.typ != ClientDataType::Get {
@@ -69,6 +74,33 @@ impl<A: Algorithm + ?Sized> PayloadSignature<A> {
             return false;
         }
 
+        // 13. Verify that the value of C.origin is an origin expected by
+        // the Relying Party.
+        if options
+            .allowed_origins
+            .is_some_and(|allowed| !allowed.contains(&c.origin))
+        {
+            return false;
+        }
+
+        // 14. If C.crossOrigin is present and set to true, verify that the
+        // Relying Party expects this credential to be used within an iframe
+        // that is not same-origin with its ancestors.
+        if options.reject_cross_origin && c.cross_origin {
+            return false;
+        }
+
+        // 15. If C.topOrigin is present, verify that the Relying Party
+        // expects this credential to be used within an iframe that is not
+        // same-origin with its ancestors, and that it matches the origin of
+        // a page that the Relying Party expects to be sub-framed within.
+        if options
+            .top_origin
+            .is_some_and(|top_origin| c.top_origin() != top_origin)
+        {
+            return false;
+        }
+
         // 20. Let hash be the result of computing a hash over the cData using
         // SHA-256
         let hash = Sha256::digest(self.client_data_json.as_bytes());
@@ -82,6 +114,12 @@ impl<A: Algorithm + ?Sized> PayloadSignature<A> {
         )
     }
 
+    /// Parses [clientDataJSON](https://w3c.github.io/webauthn/#dom-authenticatorresponse-clientdatajson)
+    #[inline]
+    pub fn client_data(&self) -> Option<CollectedClientData> {
+        serde_json::from_str(&self.client_data_json).ok()
+    }
+
     #[allow(clippy::identity_op)]
     const AUTH_DATA_FLAGS_UP: u8 = 1 << 0;
     const AUTH_DATA_FLAGS_UV: u8 = 1 << 2;
@@ -116,8 +154,26 @@ impl<A: Algorithm + ?Sized> PayloadSignature<A> {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Relying Party expectations checked by [`PayloadSignature::verify`]
+/// in addition to the signature itself
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VerifyOptions<'a> {
+    pub user_verification: UserVerification,
+    /// If given, then the origin of the assertion must be one of them,
+    /// so that assertions created on phishing domains are rejected
+    pub allowed_origins: Option<&'a [String]>,
+    /// Reject assertions created within a cross-origin iframe, i.e.
+    /// initiated by a third-party page embedding the Relying Party
+    pub reject_cross_origin: bool,
+    /// If given, then the top-level origin of the assertion must be
+    /// equal to it. Top-level origin is `topOrigin` for assertions
+    /// created within cross-origin iframes, and `origin` otherwise.
+    pub top_origin: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
 pub enum UserVerification {
+    #[default]
     Ignore,
     Require,
 }
@@ -149,6 +205,25 @@ pub struct CollectedClientData {
     pub challenge: Vec<u8>,
 
     pub origin: String,
+
+    /// Whether the assertion was created within an iframe that is
+    /// not same-origin with its ancestors
+    #[serde(default, rename = "crossOrigin")]
+    pub cross_origin: bool,
+
+    /// Origin of the top-level browsing context, only present for
+    /// cross-origin assertions
+    #[serde(default, rename = "topOrigin", skip_serializing_if = "Option::is_none")]
+    pub top_origin: Option<String>,
+}
+
+impl CollectedClientData {
+    /// Origin of the top-level browsing context the assertion was
+    /// created in
+    #[inline]
+    pub fn top_origin(&self) -> &str {
+        self.top_origin.as_deref().unwrap_or(&self.origin)
+    }
 }
 
 #[serde_as]
@@ -0,0 +1,23 @@
+use defuse_crypto::VerifiableCurve;
+pub use defuse_crypto::{Rsa2048PublicKey, Rsa2048Signature};
+use defuse_digest::{Digest, sha2::Sha256};
+
+use crate::Algorithm;
+
+/// [COSE RS256 (-257) algorithm](https://www.iana.org/assignments/cose/cose.xhtml#algorithms):
+/// RSASSA-PKCS1-v1_5 over SHA-256. Only 2048-bit keys with public
+/// exponent `e = 65537` are supported.
+#[derive(Debug, Clone)]
+pub struct RS256;
+
+impl Algorithm for RS256 {
+    type PublicKey = Rsa2048PublicKey;
+    type Signature = Rsa2048Signature;
+
+    #[inline]
+    fn verify(msg: &[u8], public_key: &Self::PublicKey, signature: &Self::Signature) -> bool {
+        let prehashed = Sha256::digest(msg).into();
+
+        defuse_crypto::Rsa2048::verify(&signature.0, &prehashed, &public_key.0).is_some()
+    }
+}
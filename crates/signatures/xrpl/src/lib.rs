@@ -0,0 +1,313 @@
+//! XRP Ledger message signing as implemented in
+//! [ripple-keypairs](https://github.com/XRPLF/xrpl.js/tree/main/packages/ripple-keypairs)
+//! and used by XRPL wallets for `signMessage`:
+//! * Ed25519 keys sign the message as-is
+//! * Secp256k1 keys sign `SHA-512Half` of the message and produce
+//!   DER-encoded non-recoverable signatures
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use defuse_crypto::{Curve, Ed25519, Secp256k1};
+use impl_tools::autoimpl;
+use sha2::{Digest, Sha512};
+use thiserror::Error as ThisError;
+
+/// Prefix of Ed25519 public keys in XRPL serialization
+const ED25519_PREFIX: u8 = 0xED;
+
+#[derive(Debug, ThisError, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePublicKeyError {
+    #[error("invalid hex")]
+    Hex,
+    #[error("unknown key type")]
+    KeyType,
+}
+
+/// Public key as serialized in XRPL: 33 bytes, either Ed25519 key
+/// prefixed with `0xED` or compressed Secp256k1 key.
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_with::SerializeDisplay, ::serde_with::DeserializeFromStr)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XrplPublicKey {
+    Ed25519(<Ed25519 as Curve>::PublicKey),
+    Secp256k1([u8; 33]),
+}
+
+impl XrplPublicKey {
+    #[inline]
+    pub fn to_bytes(self) -> [u8; 33] {
+        match self {
+            Self::Ed25519(pk) => {
+                let mut bytes = [ED25519_PREFIX; 33];
+                bytes[1..].copy_from_slice(&pk);
+                bytes
+            }
+            Self::Secp256k1(pk) => pk,
+        }
+    }
+}
+
+impl Display for XrplPublicKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode_upper(self.to_bytes()))
+    }
+}
+
+impl FromStr for XrplPublicKey {
+    type Err = ParsePublicKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = [0u8; 33];
+        hex::decode_to_slice(s, &mut bytes).map_err(|_| ParsePublicKeyError::Hex)?;
+
+        match bytes {
+            [ED25519_PREFIX, pk @ ..] => Ok(Self::Ed25519(pk)),
+            [0x02 | 0x03, ..] => Ok(Self::Secp256k1(bytes)),
+            _ => Err(ParsePublicKeyError::KeyType),
+        }
+    }
+}
+
+/// Public key recovered from [`SignedXrplPayload`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XrplVerifiedKey {
+    Ed25519(<Ed25519 as Curve>::PublicKey),
+    Secp256k1(<Secp256k1 as Curve>::PublicKey),
+}
+
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[derive(Debug, Clone)]
+pub struct XrplPayload(pub String);
+
+impl defuse_crypto::Payload for XrplPayload {
+    /// `SHA-512Half(message)`
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        let mut hash = defuse_crypto::CryptoHash::default();
+        hash.copy_from_slice(&Sha512::digest(self.0.as_bytes())[..32]);
+        hash
+    }
+}
+
+#[cfg_attr(
+    feature = "serde",
+    ::cfg_eval::cfg_eval,
+    ::serde_with::serde_as,
+    derive(::serde::Serialize, ::serde::Deserialize),
+    cfg_attr(feature = "abi", derive(::schemars::JsonSchema))
+)]
+#[autoimpl(Deref using self.payload)]
+#[derive(Debug, Clone)]
+pub struct SignedXrplPayload {
+    pub payload: XrplPayload,
+
+    #[cfg_attr(feature = "abi", schemars(with = "String"))]
+    pub public_key: XrplPublicKey,
+
+    /// Raw Ed25519 signature or DER-encoded Secp256k1 signature
+    #[cfg_attr(feature = "serde", serde_as(as = "::serde_with::hex::Hex"))]
+    #[cfg_attr(feature = "abi", schemars(with = "String"))]
+    pub signature: Vec<u8>,
+}
+
+impl defuse_crypto::Payload for SignedXrplPayload {
+    #[inline]
+    fn hash(&self) -> defuse_crypto::CryptoHash {
+        self.payload.hash()
+    }
+}
+
+#[cfg(any(test, feature = "near-contract"))]
+impl defuse_crypto::SignedPayload for SignedXrplPayload {
+    type PublicKey = XrplVerifiedKey;
+
+    fn verify(&self) -> Option<Self::PublicKey> {
+        use defuse_crypto::{Payload, VerifiableCurve};
+
+        match self.public_key {
+            XrplPublicKey::Ed25519(public_key) => Ed25519::verify(
+                self.signature.as_slice().try_into().ok()?,
+                self.payload.0.as_bytes(),
+                &public_key,
+            )
+            .map(XrplVerifiedKey::Ed25519),
+            XrplPublicKey::Secp256k1(compressed) => {
+                let [r_s @ .., _] = parse_der_signature(&self.signature)?;
+                let hash = self.payload.hash();
+
+                // signature is not recoverable, so try both recovery ids
+                // and match the result against given public key
+                (0..=1)
+                    .filter_map(|v| {
+                        let mut signature = [v; 65];
+                        signature[..64].copy_from_slice(&r_s);
+                        Secp256k1::verify(&signature, &hash, &())
+                    })
+                    .find(|public_key| compress_secp256k1(public_key) == compressed)
+                    .map(XrplVerifiedKey::Secp256k1)
+            }
+        }
+    }
+}
+
+#[inline]
+fn compress_secp256k1(public_key: &<Secp256k1 as Curve>::PublicKey) -> [u8; 33] {
+    let mut compressed = [0x02 | (public_key[63] & 1); 33];
+    compressed[1..].copy_from_slice(&public_key[..32]);
+    compressed
+}
+
+/// Parses DER-encoded ECDSA signature into `r ‖ s ‖ 0`
+fn parse_der_signature(der: &[u8]) -> Option<[u8; 65]> {
+    let [0x30, len, rest @ ..] = der else {
+        return None;
+    };
+    if usize::from(*len) != rest.len() {
+        return None;
+    }
+    let (r, rest) = parse_der_integer(rest)?;
+    let (s, rest) = parse_der_integer(rest)?;
+    if !rest.is_empty() {
+        return None;
+    }
+
+    let mut signature = [0u8; 65];
+    signature[32 - r.len()..32].copy_from_slice(r);
+    signature[64 - s.len()..64].copy_from_slice(s);
+    Some(signature)
+}
+
+/// Returns big-endian unsigned integer of at most 32 bytes and the rest
+fn parse_der_integer(der: &[u8]) -> Option<(&[u8], &[u8])> {
+    let [0x02, len, rest @ ..] = der else {
+        return None;
+    };
+    let (int, rest) = rest.split_at_checked(usize::from(*len))?;
+    let int = match int {
+        // negative
+        [] | [0x80..=0xff, ..] => return None,
+        // leading zero for positive integers with high bit set
+        [0, tail @ ..] if tail.first().is_some_and(|b| *b >= 0x80) => tail,
+        _ => int,
+    };
+    (int.len() <= 32).then_some((int, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use defuse_crypto::{Payload, SignedPayload};
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    const REFERENCE_MESSAGE: &str = r#"{"signer_id":"alice.near","verifying_contract":"intents.near","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}"#;
+
+    // private key: 000102...1f
+    const ED25519_PUBKEY: &str =
+        "ED03A107BFF3CE10BE1D70DD18E74BC09967E4D6309BA50D5F1DDC8664125531B8";
+    const ED25519_SIGNATURE: [u8; 64] = hex!(
+        "FBDB40558CF8E351561C5E5ED8C660A3D5DD586F11F2E299612175D7C4A14EF45DB6CC3BB549714DE83C5F6E801B2E4D18B8DB89C5D6ECFA871EF6FD66FEBC0D"
+    );
+
+    // private key: a4b319a82adfc43584e4537fec97a80516e16673db382cd91eba97abbab8ca56
+    const SECP256K1_PUBKEY: &str =
+        "0285A66984273F338CE4EF7B85E5430B008307E8591BB7C1B980852CF6423770B8";
+    const SECP256K1_SIGNATURE: [u8; 71] = hex!(
+        "3045022100DC1B2846F218E6E03272468285745CE411BB1AB6B03D73D000D25467ABE76C45022038F64E213A9EFB00C18FBCE4E0D7F2967E174E1C33D3F63A70DFA0F387C09AE3"
+    );
+    const SECP256K1_UNCOMPRESSED: [u8; 64] = hex!(
+        "85a66984273f338ce4ef7b85e5430b008307e8591bb7c1b980852cf6423770b801f41e9438155eb53a5e20f748640093bb42ae3aeca035f7b7fd7a1a21f22f68"
+    );
+
+    fn signed(message: &str, public_key: &str, signature: &[u8]) -> SignedXrplPayload {
+        SignedXrplPayload {
+            payload: XrplPayload(message.to_string()),
+            public_key: public_key.parse().unwrap(),
+            signature: signature.to_vec(),
+        }
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(
+            XrplPayload(REFERENCE_MESSAGE.to_string()).hash(),
+            hex!("08079851a3572c98b6c66d5f082cd9b154467e956e4a364354c078a19d58785f")
+        );
+    }
+
+    #[test]
+    fn verify_ed25519() {
+        assert_eq!(
+            signed(REFERENCE_MESSAGE, ED25519_PUBKEY, &ED25519_SIGNATURE).verify(),
+            Some(XrplVerifiedKey::Ed25519(hex!(
+                "03A107BFF3CE10BE1D70DD18E74BC09967E4D6309BA50D5F1DDC8664125531B8"
+            )))
+        );
+    }
+
+    #[test]
+    fn verify_secp256k1() {
+        assert_eq!(
+            signed(REFERENCE_MESSAGE, SECP256K1_PUBKEY, &SECP256K1_SIGNATURE).verify(),
+            Some(XrplVerifiedKey::Secp256k1(SECP256K1_UNCOMPRESSED))
+        );
+    }
+
+    #[rstest]
+    #[case::ed25519(ED25519_PUBKEY, &ED25519_SIGNATURE)]
+    #[case::secp256k1(SECP256K1_PUBKEY, &SECP256K1_SIGNATURE)]
+    fn invalid_message(#[case] public_key: &str, #[case] signature: &[u8]) {
+        assert_eq!(signed("Hello, XRPL!", public_key, signature).verify(), None);
+    }
+
+    #[test]
+    fn secp256k1_other_public_key() {
+        // flip parity of the compressed public key
+        assert_eq!(
+            signed(
+                REFERENCE_MESSAGE,
+                &SECP256K1_PUBKEY.replacen("02", "03", 1),
+                &SECP256K1_SIGNATURE
+            )
+            .verify(),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case::ed25519(ED25519_PUBKEY)]
+    #[case::secp256k1(SECP256K1_PUBKEY)]
+    fn public_key_roundtrip(#[case] public_key: &str) {
+        assert_eq!(
+            public_key.parse::<XrplPublicKey>().unwrap().to_string(),
+            public_key
+        );
+    }
+
+    #[rstest]
+    #[case::unknown_prefix(
+        "0485A66984273F338CE4EF7B85E5430B008307E8591BB7C1B980852CF6423770B8",
+        ParsePublicKeyError::KeyType
+    )]
+    #[case::short("ED03A1", ParsePublicKeyError::Hex)]
+    fn public_key_invalid(#[case] public_key: &str, #[case] err: ParsePublicKeyError) {
+        assert_eq!(public_key.parse::<XrplPublicKey>().unwrap_err(), err);
+    }
+
+    #[rstest]
+    #[case::trailing(&[&SECP256K1_SIGNATURE[..], &[0]].concat())]
+    #[case::negative_r(&hex!("3006020180020101"))]
+    #[case::truncated(&SECP256K1_SIGNATURE[..70])]
+    fn der_invalid(#[case] der: &[u8]) {
+        assert_eq!(parse_der_signature(der), None);
+    }
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+use defuse_core::checkpoint::StateCheckpoint;
+use near_kit::{AccountId, Gas, Near, NearToken};
+use serde::Serialize;
+
+use crate::{extensions::FnCallTransaction, outcome::SuccessfulExecutionOutcome};
+
+#[derive(Serialize)]
+pub struct PublishStateCheckpointArgs {
+    pub checkpoint: StateCheckpoint,
+}
+
+#[derive(Serialize)]
+pub struct StateCheckpointArgs {
+    pub epoch: Option<u64>,
+}
+
+#[near_kit::contract]
+pub trait StateCheckpointsContract {
+    fn state_checkpoint(&self, args: StateCheckpointArgs) -> Option<StateCheckpoint>;
+    #[call]
+    fn publish_state_checkpoint(&mut self, args: PublishStateCheckpointArgs);
+}
+
+pub trait DefuseStateCheckpointsExt {
+    async fn defuse_publish_state_checkpoint(
+        &self,
+        defuse: impl Into<AccountId>,
+        checkpoint: StateCheckpoint,
+    ) -> Result<SuccessfulExecutionOutcome>;
+}
+
+impl DefuseStateCheckpointsExt for Near {
+    async fn defuse_publish_state_checkpoint(
+        &self,
+        defuse: impl Into<AccountId>,
+        checkpoint: StateCheckpoint,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            StateCheckpointsContract::publish_state_checkpoint(PublishStateCheckpointArgs {
+                checkpoint,
+            })
+            .deposit(NearToken::from_yoctonear(1))
+            .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+}
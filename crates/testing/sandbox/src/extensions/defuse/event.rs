@@ -1,11 +1,15 @@
 use defuse::core::{
+    PublicKey,
     accounts::{AccountEvent, NonceEvent, PublicKeyEvent},
     amounts::Amounts,
     crypto::Payload,
     events::DefuseEvent,
     intents::{
         DefuseIntents, Intent, MaybeIntentEvent,
-        account::{AddPublicKey, RemovePublicKey, SetAuthByPredecessorId},
+        account::{
+            AddBls12381PublicKey, AddPublicKey, FreezeAccount, InvalidateNonces, RemovePublicKey,
+            SetAuthByPredecessorId,
+        },
         token_diff::{TokenDiff, TokenDiffEvent},
         tokens::{FtWithdraw, MtWithdraw, NativeWithdraw, NftWithdraw, StorageDeposit, Transfer},
     },
@@ -14,7 +18,7 @@ use defuse::core::{
 };
 use near_kit::AccountId;
 use near_sdk_core::{events::AsNep297Event, types::CryptoHash};
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashSet};
 
 #[cfg(feature = "imt")]
 use defuse::core::{
@@ -53,6 +57,10 @@ where
     I: IntoIterator<Item = MultiPayload>,
 {
     fn to_defuse_events(self) -> Vec<DefuseEvent<'static>> {
+        // nonces committed so far in this batch: the contract only emits
+        // newly invalidated ones
+        let mut used = HashSet::new();
+
         let (nonce_events, intent_events): (Vec<_>, Vec<Vec<_>>) = self
             .into_iter()
             .map(|payload| {
@@ -67,6 +75,8 @@ where
                     .extract_defuse_payload()
                     .unwrap_or_else(|_| unreachable!("invalid payload in tests"));
 
+                used.insert((signer_id.clone(), nonce));
+
                 let nonce_event = MaybeIntentEvent::new_intent(
                     AccountEvent::new(signer_id.clone(), NonceEvent::new(nonce)),
                     hash,
@@ -75,6 +85,15 @@ where
                 let intent_events = message
                     .intents
                     .into_iter()
+                    .map(|intent| match intent {
+                        Intent::InvalidateNonces(mut intent) => {
+                            intent
+                                .nonces
+                                .retain(|n| used.insert((signer_id.clone(), *n)));
+                            Intent::InvalidateNonces(intent)
+                        }
+                        intent => intent,
+                    })
                     .flat_map(|i| i.into_defuse_events(signer_id.clone(), hash))
                     .collect();
 
@@ -123,6 +142,9 @@ impl<'a> IntoDefuseEvents<'a> for Intent {
             Self::StorageDeposit(intent) => intent.into_defuse_events(signer_id, intent_hash),
             Self::TokenDiff(intent) => intent.into_defuse_events(signer_id, intent_hash),
             Self::AuthCall(_) => vec![],
+            Self::InvalidateNonces(intent) => intent.into_defuse_events(signer_id, intent_hash),
+            Self::FreezeAccount(intent) => intent.into_defuse_events(signer_id, intent_hash),
+            Self::AddBls12381PublicKey(intent) => intent.into_defuse_events(signer_id, intent_hash),
             #[cfg(feature = "imt")]
             Self::ImtMint(intent) => intent.into_defuse_events(signer_id, intent_hash),
             #[cfg(feature = "imt")]
@@ -146,6 +168,37 @@ impl<'a> IntoDefuseEvents<'a> for SetAuthByPredecessorId {
     }
 }
 
+impl<'a> IntoDefuseEvents<'a> for InvalidateNonces {
+    fn into_defuse_events(
+        self,
+        signer_id: AccountId,
+        intent_hash: CryptoHash,
+    ) -> Vec<DefuseEvent<'a>> {
+        if self.nonces.is_empty() {
+            return vec![];
+        }
+        vec![DefuseEvent::NoncesInvalidated(
+            MaybeIntentEvent::new_intent(
+                AccountEvent::new(Cow::Owned(signer_id), Cow::Owned(self)),
+                intent_hash,
+            ),
+        )]
+    }
+}
+
+impl<'a> IntoDefuseEvents<'a> for FreezeAccount {
+    fn into_defuse_events(
+        self,
+        signer_id: AccountId,
+        _intent_hash: CryptoHash,
+    ) -> Vec<DefuseEvent<'a>> {
+        vec![DefuseEvent::AccountFrozen(AccountEvent::new(
+            Cow::Owned(signer_id),
+            (),
+        ))]
+    }
+}
+
 impl<'a> IntoDefuseEvents<'a> for RemovePublicKey {
     fn into_defuse_events(
         self,
@@ -182,6 +235,19 @@ impl<'a> IntoDefuseEvents<'a> for AddPublicKey {
     }
 }
 
+impl<'a> IntoDefuseEvents<'a> for AddBls12381PublicKey {
+    fn into_defuse_events(
+        self,
+        signer_id: AccountId,
+        intent_hash: CryptoHash,
+    ) -> Vec<DefuseEvent<'a>> {
+        AddPublicKey {
+            public_key: PublicKey::Bls12381(self.public_key.0),
+        }
+        .into_defuse_events(signer_id, intent_hash)
+    }
+}
+
 impl<'a> IntoDefuseEvents<'a> for Transfer {
     fn into_defuse_events(
         self,
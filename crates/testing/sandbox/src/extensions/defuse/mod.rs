@@ -1,37 +1,75 @@
+#[cfg(feature = "imt")]
+mod checkpoints;
 mod event;
 #[cfg(feature = "imt")]
 mod imt;
 mod nonce;
 mod signer;
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroU16,
+};
 
 use anyhow::Result;
-use defuse::{contract::config::DefuseConfig, simulation_output::SimulationOutput};
+use defuse::{
+    accounts::{AccountOverview, EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT},
+    contract::config::DefuseConfig,
+    simulation_output::{GasEstimate, SimulationOutput},
+    tokens::nep245::{MtCursor, MtTokensPage},
+};
 use defuse_core::{
-    Nonce, PublicKey, Salt, fees::Pips, intents::auth::AuthCall, payload::multi::MultiPayload,
+    Nonce, PublicKey, Salt, SaltRotationPolicy, Timestamp,
+    crypto::{Bls12381PublicKey, Bls12381Signature},
+    fees::{FeeExemption, Pips},
+    intents::auth::AuthCall,
+    limits::WithdrawalLimit,
+    payload::multi::MultiPayload,
+    token_id::TokenId,
 };
+use defuse_nep245::metadata::MTBaseTokenMetadata;
+use defuse_serde_utils::base64::AsBase64;
 use near_kit::{
     AccountId, AccountIdRef, Final, FinalExecutionOutcome, FunctionCallAction, Gas, Near, NearToken,
 };
-use near_sdk_core::json_types::U128;
+use near_sdk_core::{json_types::U128, types::CryptoHash};
 use serde::Serialize;
 use serde_json::json;
-use serde_with::{DisplayFromStr, base64::Base64, serde_as};
+use serde_with::{DisplayFromStr, base58::Base58, base64::Base64, serde_as};
 
 use crate::{account::Account, extensions::FnCallTransaction, outcome::SuccessfulExecutionOutcome};
 
+#[cfg(feature = "imt")]
+pub use checkpoints::*;
 pub use event::*;
 #[cfg(feature = "imt")]
 pub use imt::*;
 pub use nonce::*;
 pub use signer::*;
 
+pub use defuse::accounts;
+pub use defuse::allowances;
 pub use defuse::contract;
 pub use defuse::core;
+pub use defuse::simulation_output;
 pub use defuse::tokens;
 pub use defuse_nep245 as nep245;
 
+#[serde_as]
+#[derive(Serialize)]
+pub struct IntentHashArgs {
+    #[serde_as(as = "Base58")]
+    pub intent_hash: CryptoHash,
+}
+
+#[serde_as]
+#[derive(Serialize)]
+pub struct IsIntentCancelledArgs<'a> {
+    pub account_id: &'a AccountIdRef,
+    #[serde_as(as = "Base58")]
+    pub intent_hash: CryptoHash,
+}
+
 #[derive(Serialize)]
 pub struct HasPublicKeyArgs<'a> {
     pub account_id: &'a AccountIdRef,
@@ -46,11 +84,43 @@ pub struct IsNonceUsedArgs<'a> {
     pub nonce: &'a Nonce,
 }
 
+#[derive(Serialize)]
+pub struct NoncesOfArgs<'a> {
+    pub account_id: &'a AccountIdRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_index: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
 #[derive(Serialize)]
 pub struct PublicKeyArgs {
     pub public_key: PublicKey,
 }
 
+#[derive(Serialize)]
+pub struct AddPublicKeyArgs {
+    pub public_key: PublicKey,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
+
+#[derive(Serialize)]
+pub struct AddBls12381PublicKeyArgs {
+    pub public_key: Bls12381PublicKey,
+    pub proof_of_possession: Bls12381Signature,
+}
+
+#[derive(Serialize)]
+pub struct WebAuthnAllowedOriginsArgs<'a> {
+    pub allowed_origins: Option<&'a [String]>,
+}
+
+#[derive(Serialize)]
+pub struct MultisigThresholdArgs {
+    pub threshold: NonZeroU16,
+}
+
 #[derive(Serialize)]
 pub struct SaltArgs {
     pub salt: Salt,
@@ -61,6 +131,11 @@ pub struct InvalidateSaltArgs<'a> {
     pub salts: &'a [Salt],
 }
 
+#[derive(Serialize)]
+pub struct SaltRotationPolicyArgs {
+    pub policy: SaltRotationPolicy,
+}
+
 #[derive(Serialize)]
 pub struct FeeArgs {
     pub fee: Pips,
@@ -71,6 +146,81 @@ pub struct FeeCollectorArgs<'a> {
     pub fee_collector: &'a AccountIdRef,
 }
 
+#[derive(Serialize)]
+pub struct FeeExemptionsArgs<'a> {
+    pub exemptions: &'a [FeeExemption],
+}
+
+#[derive(Serialize)]
+pub struct FeeExemptionArgs<'a> {
+    pub exemption: &'a FeeExemption,
+}
+
+#[derive(Serialize)]
+pub struct TokenFeeArgs<'a> {
+    pub token_id: &'a TokenId,
+    pub fee: Option<Pips>,
+}
+
+#[derive(Serialize)]
+pub struct FeeShareArgs<'a> {
+    pub collector: &'a AccountIdRef,
+    pub share: Option<Pips>,
+}
+
+#[derive(Serialize)]
+pub struct WithdrawalLimitArgs<'a> {
+    pub token_id: &'a TokenId,
+    pub limit: Option<WithdrawalLimit>,
+}
+
+#[derive(Serialize)]
+pub struct TokenIdArgs<'a> {
+    pub token_id: &'a TokenId,
+}
+
+#[derive(Serialize)]
+pub struct WithdrawalLimitAvailableArgs<'a> {
+    pub token_id: &'a TokenId,
+    pub account_id: Option<&'a AccountIdRef>,
+}
+
+#[derive(Serialize)]
+pub struct DepositCapArgs<'a> {
+    pub token_id: &'a TokenId,
+    pub cap: Option<U128>,
+}
+
+#[derive(Serialize)]
+pub struct TokenDeniedArgs<'a> {
+    pub token_id: &'a TokenId,
+    pub denied: bool,
+}
+
+#[derive(Serialize)]
+pub struct Erc1271OracleArgs<'a> {
+    pub oracle_id: Option<&'a AccountIdRef>,
+}
+
+#[derive(Serialize)]
+pub struct Erc1271ChainAllowedArgs {
+    pub chain_id: u64,
+    pub allowed: bool,
+}
+
+#[derive(Serialize)]
+pub struct MtTokensPageArgs<'a> {
+    pub cursor: Option<&'a MtCursor>,
+    pub limit: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct MtTokensForOwnerPageArgs<'a> {
+    pub account_id: &'a AccountIdRef,
+    pub cursor: Option<&'a MtCursor>,
+    pub limit: Option<u32>,
+}
+
 #[derive(Serialize)]
 pub struct MultiPayloadArgs<'a> {
     pub signed: &'a [MultiPayload],
@@ -93,6 +243,63 @@ pub struct AccountArgs<'a> {
     pub account_id: &'a AccountIdRef,
 }
 
+#[derive(Serialize)]
+pub struct AccountStateArgs<'a> {
+    pub account_id: &'a AccountIdRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_index: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct NearDepositArgs<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver_id: Option<&'a AccountIdRef>,
+}
+
+#[derive(Serialize)]
+pub struct MtApproveArgs<'a> {
+    pub spender_id: &'a AccountIdRef,
+    pub token_id: &'a TokenId,
+    pub amount: U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
+
+#[derive(Serialize)]
+pub struct MtAllowanceArgs<'a> {
+    pub owner_id: &'a AccountIdRef,
+    pub spender_id: &'a AccountIdRef,
+    pub token_id: &'a TokenId,
+}
+
+#[derive(Serialize)]
+pub struct MtTransferFromArgs<'a> {
+    pub owner_id: &'a AccountIdRef,
+    pub receiver_id: &'a AccountIdRef,
+    pub token_id: &'a TokenId,
+    pub amount: U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+pub struct MtBatchBalanceOfManyArgs<'a> {
+    pub account_ids: &'a [AccountId],
+    pub token_ids: &'a [String],
+}
+
+#[derive(Serialize)]
+pub struct MtMetadataSyncArgs<'a> {
+    pub token_id: &'a str,
+}
+
+#[derive(Serialize)]
+pub struct MtTokenIdsArgs<'a> {
+    pub token_ids: &'a [String],
+}
+
 #[derive(Serialize)]
 pub struct MultipleAccountsArgs<'a> {
     pub account_ids: &'a [AccountId],
@@ -157,21 +364,84 @@ pub trait Defuse {
     fn public_keys_of(&self, args: AccountArgs) -> HashSet<PublicKey>;
 
     fn is_nonce_used(&self, args: IsNonceUsedArgs) -> bool;
+    fn nonces_of(&self, args: NoncesOfArgs) -> Vec<AsBase64<Nonce>>;
+    fn is_intent_cancelled(&self, args: IsIntentCancelledArgs) -> bool;
+    #[call]
+    fn cancel_intent(&mut self, args: IntentHashArgs);
     fn is_auth_by_predecessor_id_enabled(&self, args: AccountArgs) -> bool;
 
     #[call]
-    fn add_public_key(&mut self, args: PublicKeyArgs);
+    fn add_public_key(&mut self, args: AddPublicKeyArgs);
+    #[call]
+    fn add_bls12381_public_key(&mut self, args: AddBls12381PublicKeyArgs);
     #[call]
     fn remove_public_key(&mut self, args: PublicKeyArgs);
 
+    fn public_key_expires_at(&self, args: HasPublicKeyArgs) -> Option<Timestamp>;
+    #[call]
+    fn cleanup_expired_keys(&mut self, args: AccountArgs);
+
     #[call]
     fn disable_auth_by_predecessor_id(&mut self);
 
+    fn webauthn_allowed_origins(&self, args: AccountArgs) -> Option<Vec<String>>;
+    #[call]
+    fn set_webauthn_allowed_origins(&mut self, args: WebAuthnAllowedOriginsArgs);
+
+    fn multisig_threshold(&self, args: AccountArgs) -> NonZeroU16;
+    #[call]
+    fn set_multisig_threshold(&mut self, args: MultisigThresholdArgs);
+
+    fn is_account_frozen(&self, args: AccountArgs) -> bool;
+    #[call]
+    fn freeze_account(&mut self) -> bool;
+    #[call]
+    fn unfreeze_account(&mut self) -> bool;
+
+    fn account_state(&self, args: AccountStateArgs) -> AccountOverview;
+
     #[call]
     fn set_fee(&mut self, args: FeeArgs);
     #[call]
     fn set_fee_collector(&mut self, args: FeeCollectorArgs);
 
+    fn is_fee_exemption(&self, args: FeeExemptionArgs) -> bool;
+    #[call]
+    fn add_fee_exemptions(&mut self, args: FeeExemptionsArgs);
+    #[call]
+    fn remove_fee_exemptions(&mut self, args: FeeExemptionsArgs);
+
+    fn token_fee(&self, args: TokenIdArgs) -> Pips;
+    #[call]
+    fn set_token_fee(&mut self, args: TokenFeeArgs);
+
+    fn fee_shares(&self) -> Vec<(AccountId, Pips)>;
+    #[call]
+    fn set_fee_share(&mut self, args: FeeShareArgs);
+
+    fn withdrawal_limit(&self, args: TokenIdArgs) -> Option<WithdrawalLimit>;
+    fn withdrawal_limit_available(&self, args: WithdrawalLimitAvailableArgs) -> Option<U128>;
+    #[call]
+    fn set_withdrawal_limit(&mut self, args: WithdrawalLimitArgs);
+
+    fn deposit_cap(&self, args: TokenIdArgs) -> Option<U128>;
+
+    fn mt_tokens_page(&self, args: MtTokensPageArgs) -> MtTokensPage;
+    fn mt_tokens_for_owner_page(&self, args: MtTokensForOwnerPageArgs) -> MtTokensPage;
+    #[call]
+    fn set_deposit_cap(&mut self, args: DepositCapArgs);
+
+    fn is_token_denied(&self, args: TokenIdArgs) -> bool;
+    #[call]
+    fn set_token_denied(&mut self, args: TokenDeniedArgs);
+
+    fn erc1271_oracle(&self) -> Option<AccountId>;
+    #[call]
+    fn set_erc1271_oracle(&mut self, args: Erc1271OracleArgs);
+    fn erc1271_chains(&self) -> Vec<u64>;
+    #[call]
+    fn set_erc1271_chain_allowed(&mut self, args: Erc1271ChainAllowedArgs);
+
     fn current_salt(&self) -> Salt;
     fn is_valid_salt(&self, salt: SaltArgs) -> bool;
 
@@ -180,7 +450,12 @@ pub trait Defuse {
     #[call]
     fn invalidate_salts(&mut self, args: InvalidateSaltArgs) -> Salt;
 
+    fn salt_rotation_policy(&self) -> SaltRotationPolicy;
+    #[call]
+    fn set_salt_rotation_policy(&mut self, args: SaltRotationPolicyArgs);
+
     fn simulate_intents(&self, args: MultiPayloadArgs) -> SimulationOutput;
+    fn estimate_intents_gas(&self, args: MultiPayloadArgs) -> GasEstimate;
 
     #[call]
     fn execute_intents(&mut self, args: MultiPayloadArgs);
@@ -223,6 +498,24 @@ pub trait Defuse {
     // NOTE: private method for testing purposes, not part of the public API
     #[call]
     fn do_auth_call(&mut self, args: DoAuthCallArgs);
+
+    #[call]
+    fn near_deposit(&mut self, args: NearDepositArgs) -> U128;
+
+    fn mt_allowance(&self, args: MtAllowanceArgs) -> U128;
+    #[call]
+    fn mt_approve(&mut self, args: MtApproveArgs);
+    #[call]
+    fn mt_transfer_from(&mut self, args: MtTransferFromArgs);
+
+    fn mt_batch_balance_of_many(&self, args: MtBatchBalanceOfManyArgs) -> Vec<Vec<U128>>;
+
+    fn mt_metadata_base_by_token_id(
+        &self,
+        args: MtTokenIdsArgs,
+    ) -> Vec<Option<MTBaseTokenMetadata>>;
+    #[call]
+    fn mt_metadata_sync(&mut self, args: MtMetadataSyncArgs) -> bool;
 }
 
 pub trait DefuseExt {
@@ -232,6 +525,33 @@ pub trait DefuseExt {
         public_key: impl Into<PublicKey>,
     ) -> Result<SuccessfulExecutionOutcome>;
 
+    async fn defuse_add_expiring_public_key(
+        &self,
+        defuse: impl Into<AccountId>,
+        public_key: impl Into<PublicKey>,
+        expires_at: Timestamp,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_add_bls12381_public_key(
+        &self,
+        defuse: impl Into<AccountId>,
+        public_key: Bls12381PublicKey,
+        proof_of_possession: Bls12381Signature,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_cleanup_expired_keys(
+        &self,
+        defuse: impl Into<AccountId>,
+        account_id: &AccountIdRef,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_set_webauthn_allowed_origins(
+        &self,
+        defuse: impl Into<AccountId>,
+        allowed_origins: Option<&[String]>,
+        deposit: NearToken,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
     async fn defuse_remove_public_key(
         &self,
         defuse: impl Into<AccountId>,
@@ -243,6 +563,56 @@ pub trait DefuseExt {
         defuse: impl Into<AccountId>,
     ) -> Result<SuccessfulExecutionOutcome>;
 
+    async fn defuse_cancel_intent(
+        &self,
+        defuse: impl Into<AccountId>,
+        intent_hash: CryptoHash,
+        deposit: NearToken,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_freeze_account(
+        &self,
+        defuse: impl Into<AccountId>,
+    ) -> Result<(SuccessfulExecutionOutcome, bool)>;
+
+    async fn defuse_near_deposit(
+        &self,
+        defuse: impl Into<AccountId>,
+        receiver_id: Option<&AccountIdRef>,
+        amount: NearToken,
+    ) -> Result<(SuccessfulExecutionOutcome, u128)>;
+
+    async fn defuse_unfreeze_account(
+        &self,
+        defuse: impl Into<AccountId>,
+    ) -> Result<(SuccessfulExecutionOutcome, bool)>;
+
+    async fn defuse_mt_approve(
+        &self,
+        defuse: impl Into<AccountId>,
+        spender_id: &AccountIdRef,
+        token_id: &TokenId,
+        amount: u128,
+        expires_at: Option<Timestamp>,
+        deposit: NearToken,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_mt_transfer_from(
+        &self,
+        defuse: impl Into<AccountId>,
+        owner_id: &AccountIdRef,
+        receiver_id: &AccountIdRef,
+        token_id: &TokenId,
+        amount: u128,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_mt_metadata_sync(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        deposit: NearToken,
+    ) -> Result<(SuccessfulExecutionOutcome, bool)>;
+
     async fn defuse_set_fee(
         &self,
         defuse: impl Into<AccountId>,
@@ -255,6 +625,66 @@ pub trait DefuseExt {
         fee_collector: impl AsRef<AccountIdRef>,
     ) -> Result<SuccessfulExecutionOutcome>;
 
+    async fn defuse_add_fee_exemptions(
+        &self,
+        defuse: impl Into<AccountId>,
+        exemptions: &[FeeExemption],
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_remove_fee_exemptions(
+        &self,
+        defuse: impl Into<AccountId>,
+        exemptions: &[FeeExemption],
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_set_token_fee(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        fee: Option<Pips>,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_set_fee_share(
+        &self,
+        defuse: impl Into<AccountId>,
+        collector: &AccountIdRef,
+        share: Option<Pips>,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_set_withdrawal_limit(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        limit: Option<WithdrawalLimit>,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_set_deposit_cap(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        cap: Option<u128>,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_set_token_denied(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        denied: bool,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_set_erc1271_oracle(
+        &self,
+        defuse: impl Into<AccountId>,
+        oracle_id: Option<&AccountIdRef>,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
+    async fn defuse_set_erc1271_chain_allowed(
+        &self,
+        defuse: impl Into<AccountId>,
+        chain_id: u64,
+        allowed: bool,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
     async fn defuse_update_current_salt(
         &self,
         defuse: impl Into<AccountId>,
@@ -266,6 +696,12 @@ pub trait DefuseExt {
         salts: impl IntoIterator<Item = Salt>,
     ) -> Result<(SuccessfulExecutionOutcome, Salt)>;
 
+    async fn defuse_set_salt_rotation_policy(
+        &self,
+        defuse: impl Into<AccountId>,
+        policy: SaltRotationPolicy,
+    ) -> Result<SuccessfulExecutionOutcome>;
+
     async fn defuse_execute_intents(
         &self,
         defuse: impl Into<AccountId>,
@@ -424,8 +860,9 @@ impl DefuseExt for Near {
     ) -> Result<SuccessfulExecutionOutcome> {
         self.fn_call(
             defuse,
-            Defuse::add_public_key(PublicKeyArgs {
+            Defuse::add_public_key(AddPublicKeyArgs {
                 public_key: public_key.into(),
+                expires_at: None,
             })
             .deposit(NearToken::from_yoctonear(1))
             .gas(Gas::from_tgas(30)),
@@ -433,6 +870,69 @@ impl DefuseExt for Near {
         .await
     }
 
+    async fn defuse_add_expiring_public_key(
+        &self,
+        defuse: impl Into<AccountId>,
+        public_key: impl Into<PublicKey>,
+        expires_at: Timestamp,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::add_public_key(AddPublicKeyArgs {
+                public_key: public_key.into(),
+                expires_at: Some(expires_at),
+            })
+            .deposit(EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT)
+            .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_add_bls12381_public_key(
+        &self,
+        defuse: impl Into<AccountId>,
+        public_key: Bls12381PublicKey,
+        proof_of_possession: Bls12381Signature,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::add_bls12381_public_key(AddBls12381PublicKeyArgs {
+                public_key,
+                proof_of_possession,
+            })
+            .deposit(NearToken::from_yoctonear(1))
+            .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_cleanup_expired_keys(
+        &self,
+        defuse: impl Into<AccountId>,
+        account_id: &AccountIdRef,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::cleanup_expired_keys(AccountArgs { account_id }).gas(Gas::from_tgas(100)),
+        )
+        .await
+    }
+
+    async fn defuse_set_webauthn_allowed_origins(
+        &self,
+        defuse: impl Into<AccountId>,
+        allowed_origins: Option<&[String]>,
+        deposit: NearToken,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_webauthn_allowed_origins(WebAuthnAllowedOriginsArgs { allowed_origins })
+                .deposit(deposit)
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
     async fn defuse_remove_public_key(
         &self,
         defuse: impl Into<AccountId>,
@@ -462,6 +962,145 @@ impl DefuseExt for Near {
         .await
     }
 
+    async fn defuse_cancel_intent(
+        &self,
+        defuse: impl Into<AccountId>,
+        intent_hash: CryptoHash,
+        deposit: NearToken,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::cancel_intent(IntentHashArgs { intent_hash })
+                .deposit(deposit)
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_near_deposit(
+        &self,
+        defuse: impl Into<AccountId>,
+        receiver_id: Option<&AccountIdRef>,
+        amount: NearToken,
+    ) -> Result<(SuccessfulExecutionOutcome, u128)> {
+        let res = self
+            .transaction(defuse.into())
+            .add_action(
+                Defuse::near_deposit(NearDepositArgs { receiver_id })
+                    .deposit(amount)
+                    .gas(Gas::from_tgas(100)),
+            )
+            .wait_until(Final)
+            .await?;
+        let deposited = res.json::<U128>()?.0;
+
+        Ok((res.try_into()?, deposited))
+    }
+
+    async fn defuse_freeze_account(
+        &self,
+        defuse: impl Into<AccountId>,
+    ) -> Result<(SuccessfulExecutionOutcome, bool)> {
+        let res = self
+            .transaction(defuse.into())
+            .add_action(
+                Defuse::freeze_account()
+                    .deposit(NearToken::from_yoctonear(1))
+                    .gas(Gas::from_tgas(30)),
+            )
+            .wait_until(Final)
+            .await?;
+        let frozen = res.json::<bool>()?;
+
+        Ok((res.try_into()?, frozen))
+    }
+
+    async fn defuse_unfreeze_account(
+        &self,
+        defuse: impl Into<AccountId>,
+    ) -> Result<(SuccessfulExecutionOutcome, bool)> {
+        let res = self
+            .transaction(defuse.into())
+            .add_action(
+                Defuse::unfreeze_account()
+                    .deposit(NearToken::from_yoctonear(1))
+                    .gas(Gas::from_tgas(30)),
+            )
+            .wait_until(Final)
+            .await?;
+        let unfrozen = res.json::<bool>()?;
+
+        Ok((res.try_into()?, unfrozen))
+    }
+
+    async fn defuse_mt_approve(
+        &self,
+        defuse: impl Into<AccountId>,
+        spender_id: &AccountIdRef,
+        token_id: &TokenId,
+        amount: u128,
+        expires_at: Option<Timestamp>,
+        deposit: NearToken,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::mt_approve(MtApproveArgs {
+                spender_id,
+                token_id,
+                amount: U128(amount),
+                expires_at,
+            })
+            .deposit(deposit)
+            .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_mt_transfer_from(
+        &self,
+        defuse: impl Into<AccountId>,
+        owner_id: &AccountIdRef,
+        receiver_id: &AccountIdRef,
+        token_id: &TokenId,
+        amount: u128,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::mt_transfer_from(MtTransferFromArgs {
+                owner_id,
+                receiver_id,
+                token_id,
+                amount: U128(amount),
+                memo: None,
+            })
+            .deposit(NearToken::from_yoctonear(1))
+            .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_mt_metadata_sync(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        deposit: NearToken,
+    ) -> Result<(SuccessfulExecutionOutcome, bool)> {
+        let res = self
+            .transaction(defuse.into())
+            .add_action(
+                Defuse::mt_metadata_sync(MtMetadataSyncArgs {
+                    token_id: &token_id.to_string(),
+                })
+                .deposit(deposit)
+                .gas(Gas::from_tgas(50)),
+            )
+            .wait_until(Final)
+            .await?;
+        let cached = res.json::<bool>()?;
+
+        Ok((res.try_into()?, cached))
+    }
+
     async fn defuse_set_fee(
         &self,
         defuse: impl Into<AccountId>,
@@ -492,6 +1131,141 @@ impl DefuseExt for Near {
         .await
     }
 
+    async fn defuse_add_fee_exemptions(
+        &self,
+        defuse: impl Into<AccountId>,
+        exemptions: &[FeeExemption],
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::add_fee_exemptions(FeeExemptionsArgs { exemptions })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_remove_fee_exemptions(
+        &self,
+        defuse: impl Into<AccountId>,
+        exemptions: &[FeeExemption],
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::remove_fee_exemptions(FeeExemptionsArgs { exemptions })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_set_token_fee(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        fee: Option<Pips>,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_token_fee(TokenFeeArgs { token_id, fee })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_set_fee_share(
+        &self,
+        defuse: impl Into<AccountId>,
+        collector: &AccountIdRef,
+        share: Option<Pips>,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_fee_share(FeeShareArgs { collector, share })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_set_withdrawal_limit(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        limit: Option<WithdrawalLimit>,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_withdrawal_limit(WithdrawalLimitArgs { token_id, limit })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_set_deposit_cap(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        cap: Option<u128>,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_deposit_cap(DepositCapArgs {
+                token_id,
+                cap: cap.map(U128),
+            })
+            .deposit(NearToken::from_yoctonear(1))
+            .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_set_token_denied(
+        &self,
+        defuse: impl Into<AccountId>,
+        token_id: &TokenId,
+        denied: bool,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_token_denied(TokenDeniedArgs { token_id, denied })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_set_erc1271_oracle(
+        &self,
+        defuse: impl Into<AccountId>,
+        oracle_id: Option<&AccountIdRef>,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_erc1271_oracle(Erc1271OracleArgs { oracle_id })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
+    async fn defuse_set_erc1271_chain_allowed(
+        &self,
+        defuse: impl Into<AccountId>,
+        chain_id: u64,
+        allowed: bool,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_erc1271_chain_allowed(Erc1271ChainAllowedArgs { chain_id, allowed })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
     async fn defuse_update_current_salt(
         &self,
         defuse: impl Into<AccountId>,
@@ -529,6 +1303,20 @@ impl DefuseExt for Near {
         Ok((outcome.try_into()?, salt))
     }
 
+    async fn defuse_set_salt_rotation_policy(
+        &self,
+        defuse: impl Into<AccountId>,
+        policy: SaltRotationPolicy,
+    ) -> Result<SuccessfulExecutionOutcome> {
+        self.fn_call(
+            defuse,
+            Defuse::set_salt_rotation_policy(SaltRotationPolicyArgs { policy })
+                .deposit(NearToken::from_yoctonear(1))
+                .gas(Gas::from_tgas(30)),
+        )
+        .await
+    }
+
     async fn defuse_execute_intents(
         &self,
         defuse: impl Into<AccountId>,
@@ -36,6 +36,7 @@ pub trait DefuseSignerExt {
 
         let defuse_intents = DefuseIntents {
             intents: intents.into_iter().map(Into::into).collect(),
+            ..Default::default()
         };
         Ok(self
             .sign_defuse_message(
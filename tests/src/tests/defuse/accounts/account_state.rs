@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use defuse_sandbox::extensions::defuse::{
+    AccountStateArgs, DefuseExt, DefuseSignerExt,
+    core::{
+        Nonce, PublicKey, Timestamp,
+        intents::DefuseIntents,
+        token_id::{TokenId, nep141::Nep141TokenId},
+    },
+};
+use defuse_test_utils::fixtures::public_key;
+use rstest::rstest;
+
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::random::{Rng, RngExt, rng},
+};
+
+#[rstest]
+#[trace]
+#[tokio::test]
+async fn account_state(
+    #[notrace]
+    #[future(awt)]
+    env: Env,
+    public_key: PublicKey,
+    #[notrace] mut rng: impl Rng,
+) {
+    let (user, ft1, ft2) =
+        futures::join!(env.create_user(), env.create_token(), env.create_token());
+
+    env.initial_ft_storage_deposit([user.account_id()], [ft1.contract_id(), ft2.contract_id()])
+        .await;
+
+    for (ft, amount) in [(&ft1, 1000), (&ft2, 2000)] {
+        env.defuse_ft_deposit_to(ft.contract_id(), amount, user.account_id(), None)
+            .await
+            .unwrap();
+    }
+
+    let expires_at = Timestamp::now() + Duration::from_hours(1);
+    user.defuse_add_expiring_public_key(env.defuse.contract_id(), public_key, expires_at)
+        .await
+        .unwrap();
+
+    let state = env
+        .defuse
+        .account_state(AccountStateArgs {
+            account_id: user.account_id(),
+            from_index: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+
+    // user's own key is registered on creation and never expires
+    assert_eq!(state.public_keys.len(), 2);
+    assert_eq!(
+        state
+            .public_keys
+            .iter()
+            .find(|pk| pk.public_key == public_key)
+            .unwrap()
+            .expires_at,
+        Some(expires_at)
+    );
+    assert!(!state.is_locked);
+    assert!(!state.is_frozen);
+    assert!(state.is_auth_by_predecessor_id_enabled);
+    assert_eq!(state.nonce_bitmaps, 0);
+    assert_eq!(
+        state
+            .token_balances
+            .iter()
+            .map(|(token_id, amount)| (token_id.clone(), amount.0))
+            .collect::<Vec<_>>(),
+        [
+            (
+                TokenId::from(Nep141TokenId::new(ft1.contract_id().clone())),
+                1000
+            ),
+            (
+                TokenId::from(Nep141TokenId::new(ft2.contract_id().clone())),
+                2000
+            ),
+        ]
+    );
+
+    // token balances are paginated
+    let state = env
+        .defuse
+        .account_state(AccountStateArgs {
+            account_id: user.account_id(),
+            from_index: Some(1),
+            limit: Some(1),
+        })
+        .await
+        .unwrap();
+    assert_eq!(state.token_balances.len(), 1);
+    assert_eq!(state.public_keys.len(), 2);
+
+    // nonce stats reflect committed nonces
+    let nonce: Nonce = rng.random();
+    env.defuse_simulate_and_execute_intents(
+        env.defuse.contract_id(),
+        [user
+            .sign_defuse_message(
+                env.defuse.contract_id(),
+                nonce,
+                Timestamp::MAX,
+                DefuseIntents::default(),
+            )
+            .await],
+    )
+    .await
+    .unwrap();
+
+    let state = env
+        .defuse
+        .account_state(AccountStateArgs {
+            account_id: user.account_id(),
+            from_index: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(state.nonce_bitmaps, 1);
+}
@@ -0,0 +1,217 @@
+use crate::tests::defuse::env::{Env, env};
+use defuse_sandbox::{
+    extensions::{
+        defuse::{
+            AccountArgs, DefuseExt, DefuseSignerExt,
+            core::{
+                DefuseError,
+                accounts::AccountEvent,
+                amounts::Amounts,
+                events::DefuseEvent,
+                intents::{account::FreezeAccount, tokens::Transfer},
+                token_id::{TokenId, nep141::Nep141TokenId},
+            },
+        },
+        mt::{Mt, MtBalanceOfArgs},
+    },
+    kit::AccountId,
+};
+use defuse_test_utils::asserts::ResultAssertsExt;
+use near_sdk_core::events::AsNep297Event;
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test]
+async fn freeze_account(#[future(awt)] env: Env) {
+    let (user, ft) = futures::join!(env.create_user(), env.create_token());
+
+    env.initial_ft_storage_deposit([user.account_id()], [ft.contract_id()])
+        .await;
+
+    let receiver_id: AccountId = "receiver_id.near".parse().unwrap();
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    let ft_id: TokenId = Nep141TokenId::new(ft.contract_id().clone()).into();
+
+    // freeze
+    {
+        let (res, frozen) = user
+            .defuse_freeze_account(env.defuse.contract_id())
+            .await
+            .unwrap();
+        assert!(frozen);
+        assert_eq!(
+            res.logs(),
+            [
+                DefuseEvent::AccountFrozen(AccountEvent::new(user.account_id().clone(), ()))
+                    .to_nep297_event()
+                    .to_event_log()
+            ]
+        );
+
+        let (_, frozen) = user
+            .defuse_freeze_account(env.defuse.contract_id())
+            .await
+            .unwrap();
+        assert!(!frozen, "account is already frozen");
+
+        assert!(
+            env.defuse
+                .is_account_frozen(AccountArgs {
+                    account_id: user.account_id(),
+                })
+                .await
+                .unwrap()
+        );
+    }
+
+    let transfer_intent = Transfer {
+        receiver_id: receiver_id.clone(),
+        tokens: Amounts::new([(ft_id.clone(), 200)].into()),
+        memo: None,
+        notification: None,
+    };
+
+    // intents signed by frozen account should fail
+    {
+        let transfer_payload = user
+            .sign_defuse_payload_default(&env.defuse, [transfer_intent.clone()])
+            .await
+            .unwrap();
+
+        env.defuse_execute_intents(env.defuse.contract_id(), [transfer_payload])
+            .await
+            .assert_err_contains(DefuseError::AccountFrozen(user.account_id().clone()).to_string());
+    }
+
+    // withdrawals from frozen account should fail
+    {
+        user.defuse_ft_withdraw(
+            env.defuse.contract_id(),
+            ft.contract_id(),
+            &receiver_id,
+            100,
+            None,
+            None,
+        )
+        .await
+        .assert_err_contains(DefuseError::AccountFrozen(user.account_id().clone()).to_string());
+    }
+
+    assert_eq!(
+        env.contract::<Mt>(env.defuse.contract_id())
+            .mt_balance_of(MtBalanceOfArgs {
+                account_id: user.account_id(),
+                token_id: &ft_id.to_string(),
+            })
+            .await
+            .unwrap()
+            .0,
+        1000,
+        "nothing should be transferred/withdrawn from frozen account"
+    );
+
+    // unfreeze
+    {
+        let (res, unfrozen) = user
+            .defuse_unfreeze_account(env.defuse.contract_id())
+            .await
+            .unwrap();
+        assert!(unfrozen);
+        assert_eq!(
+            res.logs(),
+            [
+                DefuseEvent::AccountUnfrozen(AccountEvent::new(user.account_id().clone(), ()))
+                    .to_nep297_event()
+                    .to_event_log()
+            ]
+        );
+
+        assert!(
+            !env.defuse
+                .is_account_frozen(AccountArgs {
+                    account_id: user.account_id(),
+                })
+                .await
+                .unwrap()
+        );
+    }
+
+    // intents should succeed after unfreezing
+    {
+        let transfer_payload = user
+            .sign_defuse_payload_default(&env.defuse, [transfer_intent])
+            .await
+            .unwrap();
+
+        env.defuse_simulate_and_execute_intents(env.defuse.contract_id(), [transfer_payload])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            env.contract::<Mt>(env.defuse.contract_id())
+                .mt_balance_of(MtBalanceOfArgs {
+                    account_id: &receiver_id,
+                    token_id: &ft_id.to_string(),
+                })
+                .await
+                .unwrap()
+                .0,
+            200
+        );
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn freeze_account_via_intent(#[future(awt)] env: Env) {
+    let user = env.create_user().await;
+
+    let freeze_payload = user
+        .sign_defuse_payload_default(&env.defuse, [FreezeAccount {}])
+        .await
+        .unwrap();
+
+    env.defuse_simulate_and_execute_intents(env.defuse.contract_id(), [freeze_payload])
+        .await
+        .unwrap();
+
+    assert!(
+        env.defuse
+            .is_account_frozen(AccountArgs {
+                account_id: user.account_id(),
+            })
+            .await
+            .unwrap()
+    );
+
+    // otherwise, the account could never be unfrozen
+    user.defuse_disable_auth_by_predecessor_id(env.defuse.contract_id())
+        .await
+        .assert_err_contains(DefuseError::AccountFrozen(user.account_id().clone()).to_string());
+
+    let (_, unfrozen) = user
+        .defuse_unfreeze_account(env.defuse.contract_id())
+        .await
+        .unwrap();
+    assert!(unfrozen);
+
+    // can't be frozen via intent without a way to unfreeze
+    user.defuse_disable_auth_by_predecessor_id(env.defuse.contract_id())
+        .await
+        .unwrap();
+
+    let freeze_payload = user
+        .sign_defuse_payload_default(&env.defuse, [FreezeAccount {}])
+        .await
+        .unwrap();
+
+    env.defuse_execute_intents(env.defuse.contract_id(), [freeze_payload])
+        .await
+        .assert_err_contains(
+            DefuseError::AuthByPredecessorIdDisabled(user.account_id().clone()).to_string(),
+        );
+}
@@ -1,19 +1,37 @@
-use std::borrow::Cow;
-
-use defuse_sandbox::extensions::defuse::{
-    DefuseExt, HasPublicKeyArgs,
-    core::{
-        PublicKey,
-        accounts::{AccountEvent, PublicKeyEvent},
-        events::DefuseEvent,
-        intents::MaybeIntentEvent,
+use std::{borrow::Cow, time::Duration};
+
+use defuse_sandbox::{
+    extensions::{
+        acl::AccessControllableExt,
+        defuse::{
+            AccountArgs, DefuseExt, DefuseSignerExt, HasPublicKeyArgs,
+            accounts::{
+                EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT, MAX_WEBAUTHN_ALLOWED_ORIGINS,
+                MAX_WEBAUTHN_ORIGIN_LEN, WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT,
+            },
+            contract::Role,
+            core::{
+                Nonce, PublicKey, Timestamp,
+                accounts::{AccountEvent, PublicKeyEvent},
+                events::DefuseEvent,
+                intents::{DefuseIntents, MaybeIntentEvent},
+            },
+        },
     },
+    kit::NearToken,
 };
 use defuse_test_utils::fixtures::public_key;
 use near_sdk_core::events::AsNep297Event;
 use rstest::rstest;
+use tokio::time::sleep;
 
-use crate::tests::defuse::env::{Env, env};
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::{
+        asserts::ResultAssertsExt,
+        random::{Rng, RngExt, rng},
+    },
+};
 
 #[rstest]
 #[trace]
@@ -114,3 +132,253 @@ async fn test_add_and_remove_public_key(
             .unwrap()
     );
 }
+
+#[rstest]
+#[trace]
+#[tokio::test]
+async fn test_public_key_expiry(
+    #[notrace]
+    #[future(awt)]
+    env: Env,
+    #[notrace] mut rng: impl Rng,
+) {
+    const WAITING_TIME: Duration = Duration::from_secs(5);
+
+    let user = env.create_user().await;
+    let other_user = env.create_user().await;
+    let public_key: PublicKey = user.signer().unwrap().public_key().into();
+
+    // re-add the key used for signing with expiration
+    user.defuse_remove_public_key(env.defuse.contract_id(), public_key)
+        .await
+        .unwrap();
+
+    user.defuse_add_expiring_public_key(
+        env.defuse.contract_id(),
+        public_key,
+        Timestamp::now() - Duration::from_secs(1),
+    )
+    .await
+    .assert_err_contains("deadline has expired");
+
+    let expires_at = Timestamp::now() + WAITING_TIME;
+    user.defuse_add_expiring_public_key(env.defuse.contract_id(), public_key, expires_at)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        env.defuse
+            .public_key_expires_at(HasPublicKeyArgs {
+                account_id: user.account_id(),
+                public_key: &public_key,
+            })
+            .await
+            .unwrap(),
+        Some(expires_at),
+    );
+
+    // key is valid until it expires
+    env.defuse_execute_intents(
+        env.defuse.contract_id(),
+        [user
+            .sign_defuse_message(
+                env.defuse.contract_id(),
+                rng.random::<Nonce>(),
+                Timestamp::now() + Duration::from_hours(1),
+                DefuseIntents::default(),
+            )
+            .await],
+    )
+    .await
+    .unwrap();
+
+    // nothing to cleanup yet
+    other_user
+        .defuse_cleanup_expired_keys(env.defuse.contract_id(), user.account_id())
+        .await
+        .unwrap();
+    assert!(
+        env.defuse
+            .has_public_key(HasPublicKeyArgs {
+                account_id: user.account_id(),
+                public_key: &public_key,
+            })
+            .await
+            .unwrap()
+    );
+
+    sleep(WAITING_TIME).await;
+
+    env.defuse_execute_intents(
+        env.defuse.contract_id(),
+        [user
+            .sign_defuse_message(
+                env.defuse.contract_id(),
+                rng.random::<Nonce>(),
+                Timestamp::now() + Duration::from_hours(1),
+                DefuseIntents::default(),
+            )
+            .await],
+    )
+    .await
+    .assert_err_contains("has expired");
+
+    // keys of locked accounts are kept
+    env.acl_grant_role(
+        env.defuse.contract_id(),
+        Role::UnrestrictedAccountLocker,
+        other_user.account_id(),
+    )
+    .await
+    .unwrap();
+    other_user
+        .defuse_force_lock_account(env.defuse.contract_id(), user.account_id())
+        .await
+        .unwrap();
+    let result = other_user
+        .defuse_cleanup_expired_keys(env.defuse.contract_id(), user.account_id())
+        .await
+        .unwrap();
+    assert!(result.logs().is_empty());
+    assert!(
+        env.defuse
+            .has_public_key(HasPublicKeyArgs {
+                account_id: user.account_id(),
+                public_key: &public_key,
+            })
+            .await
+            .unwrap()
+    );
+    other_user
+        .defuse_force_unlock_account(env.defuse.contract_id(), user.account_id())
+        .await
+        .unwrap();
+
+    // anyone can remove expired keys, while storage deposit is
+    // refunded to the account
+    let balance = env.balance(user.account_id()).await.unwrap().total;
+    let result = other_user
+        .defuse_cleanup_expired_keys(env.defuse.contract_id(), user.account_id())
+        .await
+        .unwrap();
+    assert_eq!(
+        env.balance(user.account_id()).await.unwrap().total,
+        balance.saturating_add(EXPIRING_PUBLIC_KEY_STORAGE_DEPOSIT),
+    );
+
+    let event = DefuseEvent::PublicKeyRemoved(MaybeIntentEvent::new_fn_call(AccountEvent::new(
+        user.account_id(),
+        PublicKeyEvent {
+            public_key: Cow::Borrowed(&public_key),
+        },
+    )))
+    .to_nep297_event()
+    .to_event_log();
+
+    assert_eq!(result.logs(), [event]);
+
+    assert!(
+        !env.defuse
+            .has_public_key(HasPublicKeyArgs {
+                account_id: user.account_id(),
+                public_key: &public_key,
+            })
+            .await
+            .unwrap()
+    );
+    assert_eq!(
+        env.defuse
+            .public_key_expires_at(HasPublicKeyArgs {
+                account_id: user.account_id(),
+                public_key: &public_key,
+            })
+            .await
+            .unwrap(),
+        None,
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn webauthn_allowed_origins(#[future(awt)] env: Env) {
+    let user = env.create_user().await;
+    let origins = vec!["https://near-intents.org".to_string()];
+
+    // storage deposit is required to restrict origins
+    user.defuse_set_webauthn_allowed_origins(
+        env.defuse.contract_id(),
+        Some(&origins),
+        NearToken::from_yoctonear(1),
+    )
+    .await
+    .assert_err_contains("insufficient deposit");
+
+    // number and length of origins are bounded
+    user.defuse_set_webauthn_allowed_origins(
+        env.defuse.contract_id(),
+        Some(&vec![
+            "https://near-intents.org".to_string();
+            MAX_WEBAUTHN_ALLOWED_ORIGINS + 1
+        ]),
+        WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT,
+    )
+    .await
+    .assert_err_contains("too many WebAuthn origins");
+    user.defuse_set_webauthn_allowed_origins(
+        env.defuse.contract_id(),
+        Some(&["a".repeat(MAX_WEBAUTHN_ORIGIN_LEN + 1)]),
+        WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT,
+    )
+    .await
+    .assert_err_contains("WebAuthn origin is either empty or too long");
+
+    user.defuse_set_webauthn_allowed_origins(
+        env.defuse.contract_id(),
+        Some(&origins),
+        WEBAUTHN_ALLOWED_ORIGINS_STORAGE_DEPOSIT,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        env.defuse
+            .webauthn_allowed_origins(AccountArgs {
+                account_id: user.account_id(),
+            })
+            .await
+            .unwrap(),
+        Some(origins.clone()),
+    );
+
+    // replacing origins doesn't require another deposit
+    let origins = vec![
+        "https://near-intents.org".to_string(),
+        "https://app.near-intents.org".to_string(),
+    ];
+    user.defuse_set_webauthn_allowed_origins(
+        env.defuse.contract_id(),
+        Some(&origins),
+        NearToken::from_yoctonear(1),
+    )
+    .await
+    .unwrap();
+
+    // storage deposit is refunded once the restriction is removed
+    let balance = env.balance(user.account_id()).await.unwrap().total;
+    user.defuse_set_webauthn_allowed_origins(
+        env.defuse.contract_id(),
+        None,
+        NearToken::from_yoctonear(1),
+    )
+    .await
+    .unwrap();
+    assert!(env.balance(user.account_id()).await.unwrap().total > balance);
+    assert_eq!(
+        env.defuse
+            .webauthn_allowed_origins(AccountArgs {
+                account_id: user.account_id(),
+            })
+            .await
+            .unwrap(),
+        None,
+    );
+}
@@ -1,6 +1,8 @@
+mod account_state;
 #[cfg(feature = "imt")]
 mod account_sync;
 mod auth_by_predecessor_id;
 mod force;
+mod freeze;
 mod manage_public_keys;
 mod nonces;
@@ -3,15 +3,23 @@ use defuse_sandbox::{
     extensions::{
         acl::AccessControllableExt,
         defuse::{
-            DefuseExt, DefuseSignerExt, IsNonceUsedArgs,
+            DefuseExt, DefuseSignerExt, IsIntentCancelledArgs, IsNonceUsedArgs, NoncesOfArgs,
+            accounts::CANCELLED_INTENT_STORAGE_DEPOSIT,
             contract::Role,
-            core::{Nonce, Salt, Timestamp, intents::DefuseIntents},
+            core::{
+                DefuseError, Nonce, Salt, Timestamp,
+                accounts::{AccountEvent, IntentCancelledEvent},
+                crypto::Payload,
+                events::DefuseEvent,
+                intents::{DefuseIntents, account::InvalidateNonces},
+            },
             create_random_salted_nonce,
         },
     },
-    kit::AccountId,
+    kit::{AccountId, NearToken},
 };
 use futures::{FutureExt, future::join_all};
+use near_sdk_core::events::AsNep297Event;
 use rstest::rstest;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -55,7 +63,7 @@ async fn test_commit_nonces(
                     env.defuse.contract_id(),
                     legacy_nonce,
                     deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 )
                 .await],
         )
@@ -86,7 +94,7 @@ async fn test_commit_nonces(
                     env.defuse.contract_id(),
                     salted,
                     deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 )
                 .await],
         )
@@ -106,7 +114,7 @@ async fn test_commit_nonces(
                     env.defuse.contract_id(),
                     expired_nonce,
                     Timestamp::MAX,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 )
                 .await],
         )
@@ -126,7 +134,7 @@ async fn test_commit_nonces(
                     env.defuse.contract_id(),
                     expired_nonce,
                     deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 )
                 .await],
         )
@@ -146,7 +154,7 @@ async fn test_commit_nonces(
                     env.defuse.contract_id(),
                     expirable_nonce,
                     deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 )
                 .await],
         )
@@ -188,7 +196,7 @@ async fn test_commit_nonces(
                     env.defuse.contract_id(),
                     old_salt_nonce,
                     deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 )
                 .await],
         )
@@ -223,7 +231,7 @@ async fn test_commit_nonces(
                     env.defuse.contract_id(),
                     invalid_salt_nonce,
                     deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 )
                 .await],
         )
@@ -263,19 +271,19 @@ async fn test_cleanup_nonces(
                     env.defuse.contract_id(),
                     legacy_nonce,
                     deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 ),
                 user.sign_defuse_message(
                     env.defuse.contract_id(),
                     expirable_nonce,
                     deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 ),
                 user.sign_defuse_message(
                     env.defuse.contract_id(),
                     long_term_expirable_nonce,
                     long_term_deadline,
-                    DefuseIntents { intents: [].into() },
+                    DefuseIntents::default(),
                 ),
             ])
             .await,
@@ -442,7 +450,7 @@ async fn cleanup_multiple_nonces(
                 env.defuse.contract_id(),
                 expirable_nonce,
                 deadline,
-                DefuseIntents { intents: [].into() },
+                DefuseIntents::default(),
             )
         }))
         .await;
@@ -480,3 +488,193 @@ async fn cleanup_multiple_nonces(
             .await
     );
 }
+
+#[rstest]
+#[tokio::test]
+async fn invalidate_nonces(#[notrace] mut rng: impl Rng, #[future(awt)] env: Env) {
+    let user = env.create_user().await;
+    let deadline = Timestamp::now() + Duration::from_hours(1);
+
+    let used_nonce: Nonce = rng.random();
+    let outstanding_nonce: Nonce = rng.random();
+    let invalidating_nonce: Nonce = rng.random();
+
+    env.defuse_simulate_and_execute_intents(
+        env.defuse.contract_id(),
+        [user
+            .sign_defuse_message(
+                env.defuse.contract_id(),
+                used_nonce,
+                deadline,
+                DefuseIntents::default(),
+            )
+            .await],
+    )
+    .await
+    .unwrap();
+
+    // signed, but not executed yet
+    let outstanding = user
+        .sign_defuse_message(
+            env.defuse.contract_id(),
+            outstanding_nonce,
+            deadline,
+            DefuseIntents::default(),
+        )
+        .await;
+
+    // already used nonces are skipped
+    env.defuse_simulate_and_execute_intents(
+        env.defuse.contract_id(),
+        [user
+            .sign_defuse_message(
+                env.defuse.contract_id(),
+                invalidating_nonce,
+                deadline,
+                DefuseIntents {
+                    intents: [InvalidateNonces {
+                        nonces: vec![used_nonce, outstanding_nonce],
+                    }
+                    .into()]
+                    .into(),
+                    ..Default::default()
+                },
+            )
+            .await],
+    )
+    .await
+    .unwrap();
+
+    assert!(
+        env.defuse
+            .is_nonce_used(IsNonceUsedArgs {
+                account_id: user.account_id(),
+                nonce: &outstanding_nonce,
+            })
+            .await
+            .unwrap(),
+    );
+
+    // random nonces fall into separate bitmaps, which are listed
+    // in order of their creation and have no duplicates
+    assert_eq!(
+        env.defuse
+            .nonces_of(NoncesOfArgs {
+                account_id: user.account_id(),
+                from_index: None,
+                limit: None,
+            })
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|n| n.0)
+            .collect::<Vec<_>>(),
+        [used_nonce, invalidating_nonce, outstanding_nonce],
+    );
+
+    // pagination is done over bitmaps
+    assert_eq!(
+        env.defuse
+            .nonces_of(NoncesOfArgs {
+                account_id: user.account_id(),
+                from_index: Some(1),
+                limit: Some(1),
+            })
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|n| n.0)
+            .collect::<Vec<_>>(),
+        [invalidating_nonce],
+    );
+
+    env.defuse_simulate_and_execute_intents(env.defuse.contract_id(), [outstanding])
+        .await
+        .assert_err_contains("nonce was already used");
+}
+
+#[rstest]
+#[tokio::test]
+async fn cancel_intent(#[notrace] mut rng: impl Rng, #[future(awt)] env: Env) {
+    let (user, other_user) = futures::join!(env.create_user(), env.create_user());
+
+    let signed = user
+        .sign_defuse_message(
+            env.defuse.contract_id(),
+            rng.random(),
+            Timestamp::now() + Duration::from_hours(1),
+            DefuseIntents::default(),
+        )
+        .await;
+    let intent_hash = signed.hash();
+
+    // storage deposit is required
+    user.defuse_cancel_intent(
+        env.defuse.contract_id(),
+        intent_hash,
+        NearToken::from_yoctonear(1),
+    )
+    .await
+    .assert_err_contains("insufficient deposit");
+
+    // cancelling on behalf of other account has no effect
+    other_user
+        .defuse_cancel_intent(
+            env.defuse.contract_id(),
+            intent_hash,
+            CANCELLED_INTENT_STORAGE_DEPOSIT,
+        )
+        .await
+        .unwrap();
+    assert!(
+        !env.defuse
+            .is_intent_cancelled(IsIntentCancelledArgs {
+                account_id: user.account_id(),
+                intent_hash,
+            })
+            .await
+            .unwrap(),
+    );
+
+    let res = user
+        .defuse_cancel_intent(
+            env.defuse.contract_id(),
+            intent_hash,
+            CANCELLED_INTENT_STORAGE_DEPOSIT,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        res.logs(),
+        [DefuseEvent::IntentCancelled(AccountEvent::new(
+            user.account_id().clone(),
+            IntentCancelledEvent { intent_hash },
+        ))
+        .to_nep297_event()
+        .to_event_log()]
+    );
+    assert!(
+        env.defuse
+            .is_intent_cancelled(IsIntentCancelledArgs {
+                account_id: user.account_id(),
+                intent_hash,
+            })
+            .await
+            .unwrap(),
+    );
+
+    env.defuse_execute_intents(env.defuse.contract_id(), [signed])
+        .await
+        .assert_err_contains(DefuseError::IntentCancelled.to_string());
+
+    // repeated cancellation is a no-op
+    let res = user
+        .defuse_cancel_intent(
+            env.defuse.contract_id(),
+            intent_hash,
+            CANCELLED_INTENT_STORAGE_DEPOSIT,
+        )
+        .await
+        .unwrap();
+    assert!(res.logs().is_empty());
+}
@@ -0,0 +1,181 @@
+use std::time::Duration;
+
+use blstrs::{G1Projective, G2Projective, Scalar};
+use defuse_digest::{Digest, sha2::Sha256};
+use defuse_sandbox::extensions::defuse::{
+    DefuseExt, DefuseSignerExt, HasPublicKeyArgs, IsNonceUsedArgs,
+    core::{
+        Nonce, PublicKey, Timestamp,
+        crypto::{AggregatedSignedPayload, Bls12381, Bls12381PublicKey, Bls12381Signature},
+        intents::{
+            DefuseIntents,
+            account::{AddBls12381PublicKey, AddPublicKey},
+        },
+        payload::{DefusePayload, bls12381::Bls12381Payload, multi::MultiPayload},
+    },
+};
+use pairing::group::{Curve, Group};
+use rstest::rstest;
+
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::{
+        asserts::ResultAssertsExt,
+        random::{Rng, RngExt, rng},
+    },
+};
+
+fn keypair(rng: &mut impl Rng) -> (Scalar, Bls12381PublicKey) {
+    let sk = Scalar::from(rng.random::<u64>());
+    let pk = (G1Projective::generator() * sk).to_affine().to_compressed();
+    (sk, Bls12381PublicKey(pk))
+}
+
+fn sign(sk: Scalar, message: &[u8], dst: &[u8]) -> Bls12381Signature {
+    Bls12381Signature(
+        (G2Projective::hash_to_curve(message, dst, b"") * sk)
+            .to_affine()
+            .to_compressed(),
+    )
+}
+
+#[rstest]
+#[trace]
+#[tokio::test]
+async fn add_bls12381_public_key_requires_proof_of_possession(
+    #[notrace]
+    #[future(awt)]
+    env: Env,
+    #[notrace] mut rng: impl Rng,
+) {
+    let user = env.create_user().await;
+    let (sk, public_key) = keypair(&mut rng);
+
+    user.defuse_add_public_key(env.defuse.contract_id(), PublicKey::Bls12381(public_key.0))
+        .await
+        .assert_err_contains("missing or invalid proof of possession");
+
+    env.defuse_execute_intents(
+        env.defuse.contract_id(),
+        [user
+            .sign_defuse_payload_default(
+                &env.defuse,
+                [AddPublicKey {
+                    public_key: PublicKey::Bls12381(public_key.0),
+                }],
+            )
+            .await
+            .unwrap()],
+    )
+    .await
+    .assert_err_contains("missing or invalid proof of possession");
+
+    // signature with a wrong domain separation tag can't serve as a proof
+    env.defuse_execute_intents(
+        env.defuse.contract_id(),
+        [user
+            .sign_defuse_payload_default(
+                &env.defuse,
+                [AddBls12381PublicKey {
+                    public_key,
+                    proof_of_possession: sign(sk, &public_key.0, Bls12381::DST),
+                }],
+            )
+            .await
+            .unwrap()],
+    )
+    .await
+    .assert_err_contains("missing or invalid proof of possession");
+
+    env.defuse_simulate_and_execute_intents(
+        env.defuse.contract_id(),
+        [user
+            .sign_defuse_payload_default(
+                &env.defuse,
+                [AddBls12381PublicKey {
+                    public_key,
+                    proof_of_possession: sign(sk, &public_key.0, Bls12381::POP_DST),
+                }],
+            )
+            .await
+            .unwrap()],
+    )
+    .await
+    .unwrap();
+
+    assert!(
+        env.defuse
+            .has_public_key(HasPublicKeyArgs {
+                account_id: user.account_id(),
+                public_key: &PublicKey::Bls12381(public_key.0),
+            })
+            .await
+            .unwrap()
+    );
+}
+
+#[rstest]
+#[trace]
+#[tokio::test]
+async fn execute_bls12381_aggregated_intents(
+    #[notrace]
+    #[future(awt)]
+    env: Env,
+    #[notrace] mut rng: impl Rng,
+) {
+    let user = env.create_user().await;
+    let signers = [keypair(&mut rng), keypair(&mut rng)];
+
+    let nonce = rng.random::<Nonce>();
+    let payload = Bls12381Payload(
+        serde_json::to_string(&DefusePayload {
+            signer_id: user.account_id().clone(),
+            verifying_contract: env.defuse.contract_id().clone(),
+            deadline: Timestamp::now() + Duration::from_hours(1),
+            nonce,
+            message: DefuseIntents::default(),
+        })
+        .unwrap(),
+    );
+    let hash = Sha256::digest(payload.0.as_bytes());
+    let signed = MultiPayload::Bls12381Aggregated(AggregatedSignedPayload {
+        payload,
+        public_keys: signers.iter().map(|(_, pk)| pk.0).collect(),
+        signature: Bls12381::aggregate_signatures(
+            &signers
+                .iter()
+                .map(|(sk, _)| sign(*sk, &hash, Bls12381::DST).0)
+                .collect::<Vec<_>>(),
+        )
+        .unwrap(),
+    });
+
+    // keys are not registered yet
+    env.defuse_execute_intents(env.defuse.contract_id(), [signed.clone()])
+        .await
+        .assert_err_contains("doesn't exist for account");
+
+    for (sk, public_key) in signers {
+        user.defuse_add_bls12381_public_key(
+            env.defuse.contract_id(),
+            public_key,
+            sign(sk, &public_key.0, Bls12381::POP_DST),
+        )
+        .await
+        .unwrap();
+    }
+
+    env.defuse_simulate_and_execute_intents(env.defuse.contract_id(), [signed])
+        .await
+        .unwrap();
+
+    assert!(
+        env.defuse
+            .is_nonce_used(IsNonceUsedArgs {
+                account_id: user.account_id(),
+                nonce: &nonce,
+            })
+            .await
+            .unwrap()
+    );
+}
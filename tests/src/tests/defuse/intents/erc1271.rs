@@ -0,0 +1,129 @@
+use defuse_sandbox::extensions::{
+    acl::AccessControllableExt,
+    defuse::{
+        DefuseExt,
+        contract::Role,
+        core::{
+            erc191::Erc191Payload,
+            events::DefuseEvent,
+            payload::{
+                erc1271::{Erc1271ChainAllowedEvent, Erc1271OracleSetEvent, SignedErc1271Payload},
+                multi::MultiPayload,
+            },
+        },
+    },
+};
+use near_sdk_core::events::AsNep297Event;
+use rstest::rstest;
+use std::borrow::Cow;
+
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::asserts::ResultAssertsExt,
+};
+
+#[rstest]
+#[tokio::test]
+async fn erc1271_oracle(
+    #[with(Env::builder().deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let (manager, oracle) = futures::join!(env.create_user(), env.create_user());
+
+    let signed = MultiPayload::Erc1271(SignedErc1271Payload {
+        payload: Erc191Payload(format!(
+            r#"{{"signer_id":"1.0x4141414141414141414141414141414141414141","verifying_contract":"{}","deadline":"2030-01-01T00:00:00Z","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=","intents":[]}}"#,
+            env.defuse.contract_id(),
+        )),
+        chain_id: 1,
+        contract: [0x41; 20],
+        signature: vec![0xde, 0xad, 0xbe, 0xef],
+    });
+
+    // ERC-1271 signatures are disabled by default
+    env.defuse_execute_intents(env.defuse.contract_id(), [signed.clone()])
+        .await
+        .assert_err_contains("EVM signature oracle for ERC-1271 is not set");
+
+    // only DAO can set the oracle
+    manager
+        .defuse_set_erc1271_oracle(
+            env.defuse.contract_id().clone(),
+            Some(oracle.account_id().as_ref()),
+        )
+        .await
+        .assert_err_contains("Insufficient permissions for method");
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::DAO,
+        manager.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    let res = manager
+        .defuse_set_erc1271_oracle(
+            env.defuse.contract_id().clone(),
+            Some(oracle.account_id().as_ref()),
+        )
+        .await
+        .expect("unable to set oracle");
+
+    let event = DefuseEvent::Erc1271OracleSet(Erc1271OracleSetEvent {
+        oracle_id: Some(Cow::Borrowed(oracle.account_id().as_ref())),
+    })
+    .to_nep297_event()
+    .to_event_log();
+    assert!(res.logs().contains(&event));
+
+    assert_eq!(
+        env.defuse.erc1271_oracle().await.unwrap(),
+        Some(oracle.account_id().clone())
+    );
+
+    manager
+        .defuse_set_erc1271_oracle(
+            env.defuse.contract_id().clone(),
+            Some(oracle.account_id().as_ref()),
+        )
+        .await
+        .assert_err_contains("same");
+
+    // oracle is set, but the chain is not allowed yet
+    env.defuse_execute_intents(env.defuse.contract_id(), [signed])
+        .await
+        .assert_err_contains("ERC-1271 signatures on EVM chain 1 are not allowed");
+
+    let res = manager
+        .defuse_set_erc1271_chain_allowed(env.defuse.contract_id().clone(), 1, true)
+        .await
+        .expect("unable to allow chain");
+
+    let event = DefuseEvent::Erc1271ChainAllowed(Erc1271ChainAllowedEvent {
+        chain_id: 1,
+        allowed: true,
+    })
+    .to_nep297_event()
+    .to_event_log();
+    assert!(res.logs().contains(&event));
+    assert_eq!(env.defuse.erc1271_chains().await.unwrap(), vec![1]);
+
+    manager
+        .defuse_set_erc1271_chain_allowed(env.defuse.contract_id().clone(), 1, true)
+        .await
+        .assert_err_contains("same");
+
+    manager
+        .defuse_set_erc1271_chain_allowed(env.defuse.contract_id().clone(), 1, false)
+        .await
+        .expect("unable to disallow chain");
+    assert!(env.defuse.erc1271_chains().await.unwrap().is_empty());
+
+    manager
+        .defuse_set_erc1271_oracle(env.defuse.contract_id().clone(), None)
+        .await
+        .expect("unable to unset oracle");
+    assert_eq!(env.defuse.erc1271_oracle().await.unwrap(), None);
+}
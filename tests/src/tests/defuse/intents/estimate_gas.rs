@@ -0,0 +1,139 @@
+use defuse_sandbox::{
+    extensions::{
+        FnCallTransaction,
+        defuse::{
+            Defuse, DefuseSignerExt, MultiPayloadArgs,
+            core::{
+                amounts::Amounts,
+                crypto::AggregatedSignedPayload,
+                intents::{
+                    Intent,
+                    tokens::{FtWithdraw, Transfer},
+                },
+                payload::{bls12381::Bls12381Payload, multi::MultiPayload},
+                token_id::{TokenId, nep141::Nep141TokenId},
+            },
+            simulation_output::PayloadGasEstimate,
+        },
+        mt::{Mt, MtBalanceOfArgs},
+    },
+    kit::{Gas, NearToken},
+};
+use rstest::rstest;
+
+use crate::tests::defuse::env::{Env, env};
+
+#[rstest]
+#[tokio::test]
+async fn estimate_intents_gas(#[future(awt)] env: Env) {
+    let (user, receiver, ft) =
+        futures::join!(env.create_user(), env.create_user(), env.create_token());
+
+    env.initial_ft_storage_deposit(vec![user.account_id()], vec![ft.contract_id()])
+        .await;
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    let token_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    let signed = [user
+        .sign_defuse_payload_default(
+            &env.defuse,
+            [
+                Intent::Transfer(Transfer {
+                    receiver_id: receiver.account_id().clone(),
+                    tokens: Amounts::new([(token_id.clone(), 100)].into()),
+                    memo: None,
+                    notification: None,
+                }),
+                Intent::FtWithdraw(FtWithdraw {
+                    token: ft.contract_id().clone(),
+                    receiver_id: user.account_id().clone(),
+                    amount: 200.into(),
+                    memo: None,
+                    msg: None,
+                    storage_deposit: None,
+                    min_gas: None,
+                }),
+            ],
+        )
+        .await
+        .unwrap()];
+
+    let estimate = env
+        .defuse
+        .estimate_intents_gas(MultiPayloadArgs { signed: &signed })
+        .await
+        .unwrap();
+
+    let [PayloadGasEstimate::Ok { intents }] = estimate.intents.as_slice() else {
+        panic!("expected estimate for a single payload");
+    };
+    let [transfer, withdraw] = intents.as_slice() else {
+        panic!("expected estimates for both intents");
+    };
+    assert!(
+        withdraw > transfer,
+        "withdrawal schedules receipts, while transfer doesn't"
+    );
+    assert!(estimate.total >= transfer.saturating_add(*withdraw));
+    assert!(estimate.total.as_gas() < Gas::from_tgas(300).as_gas());
+
+    // estimated gas is enough for execution
+    env.fn_call(
+        env.defuse.contract_id(),
+        Defuse::execute_intents(MultiPayloadArgs { signed: &signed })
+            .deposit(NearToken::from_near(0))
+            .gas(Gas::from_gas(estimate.total.as_gas())),
+    )
+    .await
+    .expect("execution with estimated gas should succeed");
+
+    assert_eq!(
+        env.contract::<Mt>(env.defuse.contract_id())
+            .mt_balance_of(MtBalanceOfArgs {
+                account_id: user.account_id(),
+                token_id: &token_id.to_string(),
+            })
+            .await
+            .unwrap()
+            .0,
+        700
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn estimate_intents_gas_of_undecodable_payload(#[future(awt)] env: Env) {
+    let user = env.create_user().await;
+
+    let valid = user
+        .sign_defuse_payload_default(&env.defuse, Vec::<Intent>::new())
+        .await
+        .unwrap();
+    let undecodable = MultiPayload::Bls12381Aggregated(AggregatedSignedPayload {
+        payload: Bls12381Payload("not a payload".to_string()),
+        public_keys: Vec::new(),
+        signature: [0; 96],
+    });
+
+    let estimate = env
+        .defuse
+        .estimate_intents_gas(MultiPayloadArgs {
+            signed: &[valid, undecodable],
+        })
+        .await
+        .expect("estimation shouldn't fail on undecodable payloads");
+
+    let [
+        PayloadGasEstimate::Ok { intents },
+        PayloadGasEstimate::Err { error },
+    ] = estimate.intents.as_slice()
+    else {
+        panic!("expected estimates for both payloads");
+    };
+    assert!(intents.is_empty());
+    assert_eq!(error.code, "json");
+}
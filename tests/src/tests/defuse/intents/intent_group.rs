@@ -0,0 +1,152 @@
+use defuse_randomness::{Rng, RngExt};
+use defuse_sandbox::extensions::{
+    defuse::{
+        DefuseExt, DefuseSignerExt,
+        core::{
+            DefuseError, Timestamp,
+            amounts::Amounts,
+            intents::{DefuseIntents, IntentGroup, tokens::Transfer},
+            token_id::{TokenId, nep141::Nep141TokenId},
+        },
+    },
+    mt::{Mt, MtBalanceOfArgs},
+};
+use defuse_test_utils::{asserts::ResultAssertsExt, random::rng};
+use rstest::rstest;
+use std::time::Duration;
+
+use crate::tests::defuse::env::{Env, env};
+
+#[rstest]
+#[tokio::test]
+async fn intent_group_all_or_nothing(#[future(awt)] env: Env, #[notrace] mut rng: impl Rng) {
+    let (user1, user2, attacker, ft1, ft2) = futures::join!(
+        env.create_user(),
+        env.create_user(),
+        env.create_user(),
+        env.create_token(),
+        env.create_token()
+    );
+
+    let ft1_id = TokenId::from(Nep141TokenId::new(ft1.contract_id().clone()));
+    let ft2_id = TokenId::from(Nep141TokenId::new(ft2.contract_id().clone()));
+
+    env.initial_ft_storage_deposit(
+        vec![user1.account_id(), user2.account_id()],
+        vec![ft1.contract_id(), ft2.contract_id()],
+    )
+    .await;
+
+    env.defuse_ft_deposit_to(ft1.contract_id(), 100, user1.account_id(), None)
+        .await
+        .unwrap();
+    env.defuse_ft_deposit_to(ft2.contract_id(), 200, user2.account_id(), None)
+        .await
+        .unwrap();
+
+    let group = IntentGroup {
+        id: rng.random(),
+        signers: [user1.account_id().clone(), user2.account_id().clone()].into(),
+    };
+    let deadline = Timestamp::now() + Duration::from_mins(2);
+
+    let user1_payload = user1
+        .sign_defuse_message(
+            env.defuse.contract_id(),
+            rng.random(),
+            deadline,
+            DefuseIntents {
+                intents: vec![
+                    Transfer {
+                        receiver_id: user2.account_id().clone(),
+                        tokens: Amounts::new(std::iter::once((ft1_id.clone(), 100)).collect()),
+                        memo: None,
+                        notification: None,
+                    }
+                    .into(),
+                ],
+                group: Some(group.clone()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    let user2_payload = user2
+        .sign_defuse_message(
+            env.defuse.contract_id(),
+            rng.random(),
+            deadline,
+            DefuseIntents {
+                intents: vec![
+                    Transfer {
+                        receiver_id: user1.account_id().clone(),
+                        tokens: Amounts::new(std::iter::once((ft2_id.clone(), 200)).collect()),
+                        memo: None,
+                        notification: None,
+                    }
+                    .into(),
+                ],
+                group: Some(group.clone()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // nobody else can complete the group in place of a counterparty
+    let attacker_payload = attacker
+        .sign_defuse_message(
+            env.defuse.contract_id(),
+            rng.random(),
+            deadline,
+            DefuseIntents {
+                group: Some(group),
+                ..Default::default()
+            },
+        )
+        .await;
+    env.defuse_execute_intents(
+        env.defuse.contract_id(),
+        [user1_payload.clone(), attacker_payload],
+    )
+    .await
+    .assert_err_contains(DefuseError::IntentGroupSignersMismatch.to_string());
+
+    // only one side of the group
+    env.defuse_execute_intents(env.defuse.contract_id(), [user1_payload.clone()])
+        .await
+        .assert_err_contains(DefuseError::IntentGroupIncomplete.to_string());
+
+    // the same payload twice can't complete the group either
+    env.defuse_execute_intents(
+        env.defuse.contract_id(),
+        [user1_payload.clone(), user1_payload.clone()],
+    )
+    .await
+    .unwrap_err();
+
+    env.defuse_simulate_and_execute_intents(
+        env.defuse.contract_id(),
+        [user1_payload, user2_payload],
+    )
+    .await
+    .unwrap();
+
+    for (account_id, token_id, expected) in [
+        (user1.account_id(), &ft1_id, 0),
+        (user1.account_id(), &ft2_id, 200),
+        (user2.account_id(), &ft1_id, 100),
+        (user2.account_id(), &ft2_id, 0),
+    ] {
+        assert_eq!(
+            env.contract::<Mt>(env.defuse.contract_id())
+                .mt_balance_of(MtBalanceOfArgs {
+                    account_id,
+                    token_id: &token_id.to_string(),
+                })
+                .await
+                .unwrap()
+                .0,
+            expected,
+        );
+    }
+}
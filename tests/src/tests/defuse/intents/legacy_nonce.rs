@@ -77,6 +77,7 @@ async fn execute_intent_with_legacy_nonce(
             Timestamp::MAX,
             DefuseIntents {
                 intents: vec![transfer_intent.into()],
+                ..Default::default()
             },
         )
         .await;
@@ -13,6 +13,7 @@ use defuse_sandbox::{
                 crypto::Payload,
                 events::DefuseEvent,
                 intents::{DefuseIntents, tokens::Transfer},
+                payload::multi::{MultiPayload, UnsupportedPayloadEvent},
                 token_id::{TokenId, nep141::Nep141TokenId},
                 tokens::TransferEvent,
             },
@@ -51,14 +52,20 @@ impl AccountNonceIntentEvent {
     }
 }
 
+mod bls12381;
+mod erc1271;
+mod estimate_gas;
 mod ft_withdraw;
 #[cfg(feature = "imt")]
 mod imt_burn;
 #[cfg(feature = "imt")]
 mod imt_mint;
+mod intent_group;
 mod legacy_nonce;
 mod native_withdraw;
+mod not_before;
 mod public_key;
+mod relayer_fee;
 mod relayers;
 mod simulate;
 mod token_diff;
@@ -99,6 +106,7 @@ async fn simulate_is_view_method(#[future(awt)] env: Env, #[notrace] mut rng: im
             Timestamp::MAX,
             DefuseIntents {
                 intents: vec![transfer_intent.clone().into()],
+                ..Default::default()
             },
         )
         .await;
@@ -166,6 +174,81 @@ async fn simulate_is_view_method(#[future(awt)] env: Env, #[notrace] mut rng: im
     );
 }
 
+#[rstest]
+#[tokio::test]
+async fn unsupported_standard_is_rejected(#[future(awt)] env: Env, #[notrace] mut rng: impl Rng) {
+    let (user, other_user, ft) =
+        futures::join!(env.create_user(), env.create_user(), env.create_token());
+
+    let ft_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    env.initial_ft_storage_deposit(
+        vec![user.account_id(), other_user.account_id()],
+        vec![ft.contract_id()],
+    )
+    .await;
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    let transfer_intent_payload = user
+        .sign_defuse_message(
+            env.defuse.contract_id(),
+            rng.random(),
+            Timestamp::MAX,
+            DefuseIntents {
+                intents: vec![
+                    Transfer {
+                        receiver_id: other_user.account_id().clone(),
+                        tokens: Amounts::new(std::iter::once((ft_id.clone(), 1000)).collect()),
+                        memo: None,
+                        notification: None,
+                    }
+                    .into(),
+                ],
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // signed with a standard this verifier doesn't know about yet
+    let unsupported: MultiPayload = serde_json::from_str(
+        r#"{"standard":"unsupported_standard","payload":"{}","signature":"deadbeef"}"#,
+    )
+    .unwrap();
+
+    let res = env
+        .defuse_execute_intents(
+            env.defuse.contract_id(),
+            [unsupported, transfer_intent_payload],
+        )
+        .await
+        .unwrap();
+
+    // rejection is reported along with the standard
+    let event = DefuseEvent::UnsupportedPayloadRejected(UnsupportedPayloadEvent {
+        index: 0,
+        standard: Cow::Borrowed("unsupported_standard"),
+    })
+    .to_nep297_event()
+    .to_event_log();
+    assert!(res.logs().contains(&event));
+
+    // while the rest of the batch is executed
+    assert_eq!(
+        env.contract::<Mt>(env.defuse.contract_id())
+            .mt_balance_of(MtBalanceOfArgs {
+                account_id: other_user.account_id(),
+                token_id: &ft_id.to_string()
+            })
+            .await
+            .unwrap()
+            .0,
+        1000
+    );
+}
+
 #[rstest]
 #[tokio::test]
 async fn webauthn() {
@@ -0,0 +1,96 @@
+use defuse_randomness::{Rng, RngExt};
+use defuse_sandbox::extensions::{
+    defuse::{
+        DefuseExt, DefuseSignerExt,
+        core::{
+            DefuseError, Timestamp,
+            amounts::Amounts,
+            intents::{DefuseIntents, tokens::Transfer},
+            token_id::{TokenId, nep141::Nep141TokenId},
+        },
+    },
+    mt::{Mt, MtBalanceOfArgs},
+};
+use defuse_test_utils::{asserts::ResultAssertsExt, random::rng};
+use rstest::rstest;
+use std::time::Duration;
+
+use crate::tests::defuse::env::{Env, env};
+
+#[rstest]
+#[tokio::test]
+async fn not_before(#[future(awt)] env: Env, #[notrace] mut rng: impl Rng) {
+    let (user, other_user, ft) =
+        futures::join!(env.create_user(), env.create_user(), env.create_token());
+
+    let ft_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    env.initial_ft_storage_deposit(
+        vec![user.account_id(), other_user.account_id()],
+        vec![ft.contract_id()],
+    )
+    .await;
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    let intents = |not_before| DefuseIntents {
+        intents: vec![
+            Transfer {
+                receiver_id: other_user.account_id().clone(),
+                tokens: Amounts::new(std::iter::once((ft_id.clone(), 100)).collect()),
+                memo: None,
+                notification: None,
+            }
+            .into(),
+        ],
+        not_before: Some(not_before),
+        ..Default::default()
+    };
+
+    // scheduled for the future
+    {
+        let not_before = Timestamp::now() + Duration::from_hours(1);
+        let signed = user
+            .sign_defuse_message(
+                env.defuse.contract_id(),
+                rng.random(),
+                not_before + Duration::from_mins(1),
+                intents(not_before),
+            )
+            .await;
+
+        env.defuse_execute_intents(env.defuse.contract_id(), [signed])
+            .await
+            .assert_err_contains(DefuseError::NotBefore(not_before).to_string());
+    }
+
+    // already valid
+    {
+        let signed = user
+            .sign_defuse_message(
+                env.defuse.contract_id(),
+                rng.random(),
+                Timestamp::now() + Duration::from_mins(2),
+                intents(Timestamp::now() - Duration::from_mins(1)),
+            )
+            .await;
+
+        env.defuse_simulate_and_execute_intents(env.defuse.contract_id(), [signed])
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        env.contract::<Mt>(env.defuse.contract_id())
+            .mt_balance_of(MtBalanceOfArgs {
+                account_id: other_user.account_id(),
+                token_id: &ft_id.to_string(),
+            })
+            .await
+            .unwrap()
+            .0,
+        100,
+    );
+}
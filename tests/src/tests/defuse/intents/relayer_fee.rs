@@ -0,0 +1,93 @@
+use defuse_randomness::{Rng, RngExt};
+use defuse_sandbox::extensions::{
+    defuse::{
+        DefuseExt, DefuseSignerExt, MultiPayloadArgs,
+        core::{
+            Timestamp,
+            amounts::Amounts,
+            intents::{DefuseIntents, tokens::Transfer},
+            token_id::{TokenId, nep141::Nep141TokenId},
+        },
+    },
+    mt::{Mt, MtBalanceOfArgs},
+};
+use defuse_test_utils::{asserts::ResultAssertsExt, random::rng};
+use rstest::rstest;
+use std::time::Duration;
+
+use crate::tests::defuse::env::{Env, env};
+
+#[rstest]
+#[tokio::test]
+async fn relayer_fee_is_paid_to_caller(#[future(awt)] env: Env, #[notrace] mut rng: impl Rng) {
+    let (user, other_user, relayer, ft) = futures::join!(
+        env.create_user(),
+        env.create_user(),
+        env.create_user(),
+        env.create_token()
+    );
+
+    let ft_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    env.initial_ft_storage_deposit(
+        vec![user.account_id(), other_user.account_id()],
+        vec![ft.contract_id()],
+    )
+    .await;
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    let signed = user
+        .sign_defuse_message(
+            env.defuse.contract_id(),
+            rng.random(),
+            Timestamp::now() + Duration::from_mins(2),
+            DefuseIntents {
+                intents: vec![
+                    Transfer {
+                        receiver_id: other_user.account_id().clone(),
+                        tokens: Amounts::new(std::iter::once((ft_id.clone(), 900)).collect()),
+                        memo: None,
+                        notification: None,
+                    }
+                    .into(),
+                ],
+                relayer_fee: Amounts::new(std::iter::once((ft_id.clone(), 100)).collect()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // relayer is unknown without `relayer_id`
+    env.defuse
+        .simulate_intents(MultiPayloadArgs {
+            signed: &[signed.clone()],
+        })
+        .await
+        .assert_err_contains("relayer is unknown");
+
+    relayer
+        .defuse_execute_intents(env.defuse.contract_id(), [signed])
+        .await
+        .unwrap();
+
+    for (account_id, expected) in [
+        (user.account_id(), 0),
+        (other_user.account_id(), 900),
+        (relayer.account_id(), 100),
+    ] {
+        assert_eq!(
+            env.contract::<Mt>(env.defuse.contract_id())
+                .mt_balance_of(MtBalanceOfArgs {
+                    account_id,
+                    token_id: &ft_id.to_string(),
+                })
+                .await
+                .unwrap()
+                .0,
+            expected,
+        );
+    }
+}
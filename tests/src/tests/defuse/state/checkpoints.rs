@@ -0,0 +1,118 @@
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::asserts::ResultAssertsExt,
+};
+use defuse_sandbox::{
+    extensions::{
+        acl::AccessControllableExt,
+        defuse::{
+            DefuseStateCheckpointsExt, StateCheckpointArgs, StateCheckpointsContract,
+            contract::Role,
+            core::{
+                accounts::AccountEvent,
+                checkpoint::{CheckpointLeaf, CheckpointProof, MerkleTree, StateCheckpoint},
+                events::DefuseEvent,
+                token_id::{TokenId, nep141::Nep141TokenId},
+            },
+        },
+    },
+    kit::AccountId,
+};
+use near_sdk_core::events::AsNep297Event;
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test]
+async fn state_checkpoints(
+    #[with(Env::builder().deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let (publisher, user) = futures::join!(env.create_user(), env.create_user());
+
+    let leaves = [
+        CheckpointLeaf::Balance {
+            account_id: user.account_id().clone(),
+            token_id: TokenId::from(Nep141TokenId::new("ft.near".parse::<AccountId>().unwrap())),
+            amount: 1000,
+        },
+        CheckpointLeaf::Nonce {
+            account_id: user.account_id().clone(),
+            nonce: [1; 32],
+        },
+    ];
+    let tree = MerkleTree::new(&leaves);
+    let checkpoint = StateCheckpoint {
+        epoch: 0,
+        block_height: 1,
+        leaves: tree.leaves(),
+        root: tree.root().unwrap(),
+    };
+
+    // only DAO or checkpoint publisher can publish checkpoints
+    publisher
+        .defuse_publish_state_checkpoint(env.defuse.contract_id().clone(), checkpoint)
+        .await
+        .assert_err_contains("Insufficient permissions for method");
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::StateCheckpointPublisher,
+        publisher.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    {
+        let res = publisher
+            .defuse_publish_state_checkpoint(env.defuse.contract_id().clone(), checkpoint)
+            .await
+            .expect("unable to publish checkpoint");
+
+        let event =
+            DefuseEvent::StateCheckpoint(AccountEvent::new(publisher.account_id(), checkpoint))
+                .to_nep297_event()
+                .to_event_log();
+
+        assert!(res.logs().contains(&event));
+    }
+
+    // epochs must be sequential
+    publisher
+        .defuse_publish_state_checkpoint(env.defuse.contract_id().clone(), checkpoint)
+        .await
+        .assert_err_contains("invalid epoch");
+
+    // checkpoint can't be published for future blocks
+    publisher
+        .defuse_publish_state_checkpoint(
+            env.defuse.contract_id().clone(),
+            StateCheckpoint {
+                epoch: 1,
+                block_height: u64::MAX,
+                ..checkpoint
+            },
+        )
+        .await
+        .assert_err_contains("invalid block height");
+
+    let published = env
+        .contract::<StateCheckpointsContract>(env.defuse.contract_id())
+        .state_checkpoint(StateCheckpointArgs { epoch: None })
+        .await
+        .unwrap()
+        .expect("checkpoint should be published");
+    assert_eq!(published, checkpoint);
+
+    // balances and nonces can be verified against published checkpoint
+    for leaf in leaves {
+        assert!(
+            CheckpointProof {
+                checkpoint: published,
+                proof: tree.proof(&leaf).unwrap(),
+                leaf,
+            }
+            .verify()
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::asserts::ResultAssertsExt,
+};
+use defuse_sandbox::extensions::{
+    acl::AccessControllableExt,
+    defuse::{
+        DefuseExt, TokenIdArgs,
+        contract::Role,
+        core::{
+            events::DefuseEvent,
+            limits::DepositCapSetEvent,
+            token_id::{TokenId, nep141::Nep141TokenId},
+        },
+    },
+    mt::{Mt, MtBalanceOfArgs},
+};
+use near_sdk_core::{events::AsNep297Event, json_types::U128};
+use rstest::rstest;
+use std::borrow::Cow;
+
+#[rstest]
+#[tokio::test]
+async fn deposit_caps(
+    #[with(Env::builder().deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let (manager, user, ft) =
+        futures::join!(env.create_user(), env.create_user(), env.create_token());
+
+    env.initial_ft_storage_deposit(vec![user.account_id()], vec![ft.contract_id()])
+        .await;
+
+    let token_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    // only DAO or deposit caps manager can set caps
+    manager
+        .defuse_set_deposit_cap(env.defuse.contract_id().clone(), &token_id, Some(1000))
+        .await
+        .assert_err_contains("Insufficient permissions for method");
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::DepositCapsManager,
+        manager.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    {
+        let res = manager
+            .defuse_set_deposit_cap(env.defuse.contract_id().clone(), &token_id, Some(1000))
+            .await
+            .expect("unable to set deposit cap");
+
+        let event = DefuseEvent::DepositCapSet(DepositCapSetEvent {
+            token_id: Cow::Borrowed(&token_id),
+            cap: Some(U128(1000)),
+        })
+        .to_nep297_event()
+        .to_event_log();
+
+        assert!(res.logs().contains(&event));
+
+        assert_eq!(
+            env.defuse
+                .deposit_cap(TokenIdArgs {
+                    token_id: &token_id
+                })
+                .await
+                .unwrap(),
+            Some(U128(1000))
+        );
+
+        manager
+            .defuse_set_deposit_cap(env.defuse.contract_id().clone(), &token_id, Some(1000))
+            .await
+            .assert_err_contains("same");
+    }
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 600, user.account_id(), None)
+        .await
+        .expect("deposit within the cap should succeed");
+
+    // deposit exceeding the cap is refunded
+    env.defuse_ft_deposit_to(ft.contract_id(), 500, user.account_id(), None)
+        .await
+        .assert_err_contains("refunded");
+
+    let balance = || async {
+        env.contract::<Mt>(env.defuse.contract_id())
+            .mt_balance_of(MtBalanceOfArgs {
+                account_id: user.account_id(),
+                token_id: &token_id.to_string(),
+            })
+            .await
+            .unwrap()
+            .0
+    };
+
+    assert_eq!(balance().await, 600);
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 400, user.account_id(), None)
+        .await
+        .expect("deposit up to the cap should succeed");
+
+    assert_eq!(balance().await, 1000);
+
+    // remove the cap
+    manager
+        .defuse_set_deposit_cap(env.defuse.contract_id().clone(), &token_id, None)
+        .await
+        .expect("unable to remove deposit cap");
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 500, user.account_id(), None)
+        .await
+        .expect("deposit without cap should succeed");
+
+    assert_eq!(balance().await, 1500);
+}
@@ -3,22 +3,30 @@ use crate::{
     utils::asserts::ResultAssertsExt,
 };
 use defuse_sandbox::{
+    extensions::defuse::{FeeExemptionArgs, TokenIdArgs},
     extensions::{
         acl::AccessControllableExt,
         defuse::{
-            DefuseExt,
+            DefuseExt, DefuseSignerExt,
             contract::Role,
             core::{
                 events::DefuseEvent,
-                fees::{FeeChangedEvent, FeeCollectorChangedEvent, Pips},
+                fees::{
+                    FeeChangedEvent, FeeCollectorChangedEvent, FeeExemption, FeeExemptionsEvent,
+                    FeeShareSetEvent, Pips, TokenFeeSetEvent,
+                },
+                intents::token_diff::{TokenDeltas, TokenDiff},
+                token_id::{TokenId, nep141::Nep141TokenId},
             },
         },
+        mt::{Mt, MtBatchBalanceOfArgs},
     },
     kit::AccountId,
 };
 use futures::FutureExt;
-use near_sdk_core::events::AsNep297Event;
+use near_sdk_core::{events::AsNep297Event, json_types::U128};
 use rstest::rstest;
+use std::borrow::Cow;
 
 #[rstest]
 #[tokio::test]
@@ -123,3 +131,357 @@ async fn set_fee_collector(
         assert_eq!(current_collector, fee_collector);
     }
 }
+
+#[rstest]
+#[tokio::test]
+async fn fee_exemptions(
+    #[with(Env::builder().deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let (user1, user2) = futures::join!(env.create_user(), env.create_user());
+
+    let exemptions = [
+        FeeExemption::Account(user2.account_id().clone()),
+        FeeExemption::Token(TokenId::from(Nep141TokenId::new(
+            "ft.near".parse::<AccountId>().unwrap(),
+        ))),
+    ];
+
+    // only DAO or fee manager can manage fee exemptions
+    {
+        user2
+            .defuse_add_fee_exemptions(env.defuse.contract_id().clone(), &exemptions)
+            .await
+            .assert_err_contains("Insufficient permissions for method");
+    }
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::FeesManager,
+        user1.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    // add exemptions by fee manager
+    {
+        let res = user1
+            .defuse_add_fee_exemptions(env.defuse.contract_id().clone(), &exemptions)
+            .await
+            .expect("unable to add fee exemptions");
+
+        let event = DefuseEvent::FeeExemptionsAdded(FeeExemptionsEvent {
+            exemptions: exemptions.as_slice().into(),
+        })
+        .to_nep297_event()
+        .to_event_log();
+
+        assert!(res.logs().contains(&event));
+
+        for exemption in &exemptions {
+            assert!(
+                env.defuse
+                    .is_fee_exemption(FeeExemptionArgs { exemption })
+                    .await
+                    .unwrap()
+            );
+        }
+
+        user1
+            .defuse_add_fee_exemptions(env.defuse.contract_id().clone(), &exemptions)
+            .await
+            .assert_err_contains("same");
+    }
+
+    // remove exemptions by fee manager
+    {
+        let res = user1
+            .defuse_remove_fee_exemptions(env.defuse.contract_id().clone(), &exemptions[..1])
+            .await
+            .expect("unable to remove fee exemptions");
+
+        let event = DefuseEvent::FeeExemptionsRemoved(FeeExemptionsEvent {
+            exemptions: exemptions[..1].into(),
+        })
+        .to_nep297_event()
+        .to_event_log();
+
+        assert!(res.logs().contains(&event));
+
+        assert!(
+            !env.defuse
+                .is_fee_exemption(FeeExemptionArgs {
+                    exemption: &exemptions[0],
+                })
+                .await
+                .unwrap()
+        );
+        assert!(
+            env.defuse
+                .is_fee_exemption(FeeExemptionArgs {
+                    exemption: &exemptions[1],
+                })
+                .await
+                .unwrap()
+        );
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn token_fee_overrides(
+    #[with(Env::builder().deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let (user1, user2) = futures::join!(env.create_user(), env.create_user());
+
+    let token_id = TokenId::from(Nep141TokenId::new("ft.near".parse::<AccountId>().unwrap()));
+    let other_token_id = TokenId::from(Nep141TokenId::new(
+        "other-ft.near".parse::<AccountId>().unwrap(),
+    ));
+    let fee = Pips::from_pips(500).unwrap();
+    let global_fee = env.defuse.fee().await.unwrap();
+    assert_ne!(global_fee, fee);
+
+    // only DAO or fee manager can override token fees
+    {
+        user2
+            .defuse_set_token_fee(env.defuse.contract_id().clone(), &token_id, Some(fee))
+            .await
+            .assert_err_contains("Insufficient permissions for method");
+    }
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::FeesManager,
+        user1.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    // override fee by fee manager
+    {
+        let res = user1
+            .defuse_set_token_fee(env.defuse.contract_id().clone(), &token_id, Some(fee))
+            .await
+            .expect("unable to set token fee");
+
+        let event = DefuseEvent::TokenFeeSet(TokenFeeSetEvent {
+            token_id: Cow::Borrowed(&token_id),
+            fee: Some(fee),
+        })
+        .to_nep297_event()
+        .to_event_log();
+
+        assert!(res.logs().contains(&event));
+
+        assert_eq!(
+            env.defuse
+                .token_fee(TokenIdArgs {
+                    token_id: &token_id
+                })
+                .await
+                .unwrap(),
+            fee
+        );
+        assert_eq!(
+            env.defuse
+                .token_fee(TokenIdArgs {
+                    token_id: &other_token_id
+                })
+                .await
+                .unwrap(),
+            global_fee
+        );
+
+        user1
+            .defuse_set_token_fee(env.defuse.contract_id().clone(), &token_id, Some(fee))
+            .await
+            .assert_err_contains("same");
+    }
+
+    // reset to the global fee
+    {
+        let res = user1
+            .defuse_set_token_fee(env.defuse.contract_id().clone(), &token_id, None)
+            .await
+            .expect("unable to reset token fee");
+
+        let event = DefuseEvent::TokenFeeSet(TokenFeeSetEvent {
+            token_id: Cow::Borrowed(&token_id),
+            fee: None,
+        })
+        .to_nep297_event()
+        .to_event_log();
+
+        assert!(res.logs().contains(&event));
+
+        assert_eq!(
+            env.defuse
+                .token_fee(TokenIdArgs {
+                    token_id: &token_id
+                })
+                .await
+                .unwrap(),
+            global_fee
+        );
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn fee_shares(
+    #[with(Env::builder().fee(Pips::ONE_PERCENT).deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let (manager, collector, user1, user2, ft1, ft2) = futures::join!(
+        env.create_user(),
+        env.create_user(),
+        env.create_user(),
+        env.create_user(),
+        env.create_token(),
+        env.create_token()
+    );
+
+    let share = Pips::ONE_PERCENT * 20;
+
+    // only DAO or fee manager can set fee shares
+    manager
+        .defuse_set_fee_share(
+            env.defuse.contract_id().clone(),
+            collector.account_id(),
+            Some(share),
+        )
+        .await
+        .assert_err_contains("Insufficient permissions for method");
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::FeesManager,
+        manager.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    {
+        let res = manager
+            .defuse_set_fee_share(
+                env.defuse.contract_id().clone(),
+                collector.account_id(),
+                Some(share),
+            )
+            .await
+            .expect("unable to set fee share");
+
+        let event = DefuseEvent::FeeShareSet(FeeShareSetEvent {
+            collector: Cow::Borrowed(collector.account_id()),
+            share: Some(share),
+        })
+        .to_nep297_event()
+        .to_event_log();
+
+        assert!(res.logs().contains(&event));
+
+        assert_eq!(
+            env.defuse.fee_shares().await.unwrap(),
+            [(collector.account_id().clone(), share)]
+        );
+
+        manager
+            .defuse_set_fee_share(
+                env.defuse.contract_id().clone(),
+                collector.account_id(),
+                Some(share),
+            )
+            .await
+            .assert_err_contains("same");
+
+        // shares can't exceed collected fees
+        manager
+            .defuse_set_fee_share(
+                env.defuse.contract_id().clone(),
+                user1.account_id(),
+                Some(Pips::ONE_PERCENT * 81),
+            )
+            .await
+            .assert_err_contains("fee shares exceed 100%");
+    }
+
+    env.initial_ft_storage_deposit(
+        vec![user1.account_id(), user2.account_id()],
+        vec![ft1.contract_id(), ft2.contract_id()],
+    )
+    .await;
+
+    env.defuse_ft_deposit_to(ft1.contract_id(), 100_000, user1.account_id(), None)
+        .await
+        .unwrap();
+    env.defuse_ft_deposit_to(ft2.contract_id(), 100_000, user2.account_id(), None)
+        .await
+        .unwrap();
+
+    let ft1_token_id = TokenId::from(Nep141TokenId::new(ft1.contract_id().clone()));
+    let ft2_token_id = TokenId::from(Nep141TokenId::new(ft2.contract_id().clone()));
+
+    let signed = futures::try_join!(
+        user1.sign_defuse_payload_default(
+            &env.defuse,
+            [TokenDiff {
+                diff: TokenDeltas::default()
+                    .with_apply_deltas([
+                        (ft1_token_id.clone(), -100_000),
+                        (ft2_token_id.clone(), 99_000)
+                    ])
+                    .unwrap(),
+                memo: None,
+                referral: None,
+            }],
+        ),
+        user2.sign_defuse_payload_default(
+            &env.defuse,
+            [TokenDiff {
+                diff: TokenDeltas::default()
+                    .with_apply_deltas([
+                        (ft1_token_id.clone(), 99_000),
+                        (ft2_token_id.clone(), -100_000)
+                    ])
+                    .unwrap(),
+                memo: None,
+                referral: None,
+            }],
+        ),
+    )
+    .unwrap();
+
+    env.defuse_execute_intents(env.defuse.contract_id(), <[_; 2]>::from(signed))
+        .await
+        .expect("unable to execute token diffs");
+
+    // 1% fee of 100_000 for each token, 20% of which goes to the collector
+    for (account_id, expected) in [(collector.account_id(), 200), (env.account_id(), 800)] {
+        let balances = env
+            .contract::<Mt>(env.defuse.contract_id())
+            .mt_batch_balance_of(MtBatchBalanceOfArgs {
+                account_id,
+                token_ids: &[ft1_token_id.to_string(), ft2_token_id.to_string()],
+            })
+            .await
+            .unwrap();
+        assert_eq!(balances, [U128(expected), U128(expected)]);
+    }
+
+    // remove share, so that all fees go to the fee collector
+    manager
+        .defuse_set_fee_share(
+            env.defuse.contract_id().clone(),
+            collector.account_id(),
+            None,
+        )
+        .await
+        .expect("unable to remove fee share");
+
+    assert!(env.defuse.fee_shares().await.unwrap().is_empty());
+}
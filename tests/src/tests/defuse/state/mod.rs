@@ -1,3 +1,8 @@
+#[cfg(feature = "imt")]
+mod checkpoints;
+mod deposit_caps;
 mod fee;
 mod salt;
+mod token_denylist;
 mod upgrade;
+mod withdrawal_limits;
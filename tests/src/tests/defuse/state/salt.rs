@@ -9,7 +9,7 @@ use defuse_sandbox::extensions::{
     defuse::{
         DefuseExt, SaltArgs,
         contract::Role,
-        core::{accounts::SaltRotationEvent, events::DefuseEvent},
+        core::{SaltRotationPolicy, accounts::SaltRotationEvent, events::DefuseEvent},
     },
 };
 use futures::FutureExt;
@@ -162,3 +162,76 @@ async fn invalidate_salts(
         assert_ne!(prev_salt, current_salt);
     }
 }
+
+#[rstest]
+#[tokio::test]
+async fn salt_rotation_policy(
+    #[with(Env::builder().deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let user = env.create_user().await;
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::SaltManager,
+        user.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    assert_eq!(
+        env.defuse.salt_rotation_policy().await.unwrap(),
+        SaltRotationPolicy::default()
+    );
+
+    let set_policy = async |policy| {
+        let res = user
+            .defuse_set_salt_rotation_policy(env.defuse.contract_id().clone(), policy)
+            .await
+            .unwrap();
+        assert_eq!(
+            res.logs(),
+            [DefuseEvent::SaltRotationPolicySet(policy)
+                .to_nep297_event()
+                .to_event_log()]
+        );
+        assert_eq!(env.defuse.salt_rotation_policy().await.unwrap(), policy);
+    };
+
+    // previous salt remains valid during grace period
+    set_policy(SaltRotationPolicy {
+        min_interval_secs: 0,
+        grace_period_secs: Some(60 * 60),
+    })
+    .await;
+
+    let prev_salt = env.defuse.current_salt().await.unwrap();
+    user.defuse_update_current_salt(env.defuse.contract_id().clone())
+        .await
+        .unwrap();
+    assert!(
+        env.defuse
+            .is_valid_salt(SaltArgs { salt: prev_salt })
+            .await
+            .unwrap()
+    );
+
+    // shorter grace period applies to already rotated salts
+    set_policy(SaltRotationPolicy {
+        min_interval_secs: 60 * 60,
+        grace_period_secs: Some(0),
+    })
+    .await;
+    assert!(
+        !env.defuse
+            .is_valid_salt(SaltArgs { salt: prev_salt })
+            .await
+            .unwrap()
+    );
+
+    // can't rotate earlier than min interval
+    user.defuse_update_current_salt(env.defuse.contract_id().clone())
+        .await
+        .assert_err_contains("salt was rotated too recently");
+}
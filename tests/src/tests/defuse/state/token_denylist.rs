@@ -0,0 +1,187 @@
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::asserts::ResultAssertsExt,
+};
+use defuse_sandbox::{
+    extensions::{
+        acl::AccessControllableExt,
+        defuse::{
+            DefuseExt, DefuseSignerExt, TokenIdArgs,
+            contract::Role,
+            core::{
+                DefuseError,
+                amounts::Amounts,
+                events::DefuseEvent,
+                intents::tokens::Transfer,
+                token_id::{TokenId, nep141::Nep141TokenId},
+                tokens::TokenDenylistSetEvent,
+            },
+        },
+        mt::{Mt, MtBalanceOfArgs, MtExt},
+    },
+    kit::NearToken,
+};
+use near_sdk_core::events::AsNep297Event;
+use rstest::rstest;
+use std::borrow::Cow;
+
+#[rstest]
+#[tokio::test]
+async fn token_denylist(
+    #[with(Env::builder().deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let (manager, user, receiver, ft) = futures::join!(
+        env.create_user(),
+        env.create_user(),
+        env.create_user(),
+        env.create_token()
+    );
+
+    env.initial_ft_storage_deposit(vec![user.account_id()], vec![ft.contract_id()])
+        .await;
+
+    let token_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    // only DAO or token denylist manager can deny tokens
+    manager
+        .defuse_set_token_denied(env.defuse.contract_id().clone(), &token_id, true)
+        .await
+        .assert_err_contains("Insufficient permissions for method");
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::TokenDenylistManager,
+        manager.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    {
+        let res = manager
+            .defuse_set_token_denied(env.defuse.contract_id().clone(), &token_id, true)
+            .await
+            .expect("unable to deny token");
+
+        let event = DefuseEvent::TokenDenylistSet(TokenDenylistSetEvent {
+            token_id: Cow::Borrowed(&token_id),
+            denied: true,
+        })
+        .to_nep297_event()
+        .to_event_log();
+
+        assert!(res.logs().contains(&event));
+
+        assert!(
+            env.defuse
+                .is_token_denied(TokenIdArgs {
+                    token_id: &token_id
+                })
+                .await
+                .unwrap()
+        );
+
+        manager
+            .defuse_set_token_denied(env.defuse.contract_id().clone(), &token_id, true)
+            .await
+            .assert_err_contains("same");
+    }
+
+    // deposits of denied token are refunded
+    env.defuse_ft_deposit_to(ft.contract_id(), 100, user.account_id(), None)
+        .await
+        .assert_err_contains("refunded");
+
+    // native deposits of denied wNEAR are rejected before wrapping
+    {
+        let wnear_id = TokenId::from(Nep141TokenId::new(env.wnear.contract_id().clone()));
+
+        manager
+            .defuse_set_token_denied(env.defuse.contract_id().clone(), &wnear_id, true)
+            .await
+            .expect("unable to deny wNEAR");
+
+        user.defuse_near_deposit(env.defuse.contract_id(), None, NearToken::from_near(1))
+            .await
+            .assert_err_contains(DefuseError::TokenDenied(wnear_id.clone()).to_string());
+
+        manager
+            .defuse_set_token_denied(env.defuse.contract_id().clone(), &wnear_id, false)
+            .await
+            .expect("unable to allow wNEAR");
+    }
+
+    // transfers of denied token are rejected
+    user.mt_transfer(
+        env.defuse.contract_id(),
+        receiver.account_id(),
+        &token_id.to_string(),
+        100,
+        None,
+    )
+    .await
+    .assert_err_contains(DefuseError::TokenDenied(token_id.clone()).to_string());
+
+    {
+        let transfer_payload = user
+            .sign_defuse_payload_default(
+                &env.defuse,
+                [Transfer {
+                    receiver_id: receiver.account_id().clone(),
+                    tokens: Amounts::new([(token_id.clone(), 100)].into()),
+                    memo: None,
+                    notification: None,
+                }],
+            )
+            .await
+            .unwrap();
+
+        env.defuse_execute_intents(env.defuse.contract_id(), [transfer_payload])
+            .await
+            .assert_err_contains(DefuseError::TokenDenied(token_id.clone()).to_string());
+    }
+
+    let balance = || async {
+        env.contract::<Mt>(env.defuse.contract_id())
+            .mt_balance_of(MtBalanceOfArgs {
+                account_id: user.account_id(),
+                token_id: &token_id.to_string(),
+            })
+            .await
+            .unwrap()
+            .0
+    };
+
+    assert_eq!(balance().await, 1000);
+
+    // denied token can still be withdrawn
+    user.defuse_ft_withdraw(
+        env.defuse.contract_id(),
+        ft.contract_id(),
+        user.account_id(),
+        400,
+        None,
+        None,
+    )
+    .await
+    .expect("withdrawal of denied token should succeed");
+
+    assert_eq!(balance().await, 600);
+
+    // allow the token again
+    manager
+        .defuse_set_token_denied(env.defuse.contract_id().clone(), &token_id, false)
+        .await
+        .expect("unable to allow token");
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 100, user.account_id(), None)
+        .await
+        .expect("deposit of allowed token should succeed");
+
+    assert_eq!(balance().await, 700);
+}
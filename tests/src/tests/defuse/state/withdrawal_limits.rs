@@ -0,0 +1,254 @@
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::asserts::ResultAssertsExt,
+};
+use defuse_sandbox::extensions::{
+    acl::AccessControllableExt,
+    defuse::{
+        DefuseExt, TokenIdArgs, WithdrawalLimitAvailableArgs,
+        contract::Role,
+        core::{
+            events::DefuseEvent,
+            limits::{WithdrawalLimit, WithdrawalLimitSetEvent},
+            token_id::{TokenId, nep141::Nep141TokenId},
+        },
+    },
+};
+use near_sdk_core::{events::AsNep297Event, json_types::U128};
+use rstest::rstest;
+use std::borrow::Cow;
+
+#[rstest]
+#[tokio::test]
+async fn withdrawal_limits(
+    #[with(Env::builder().deployer_as_super_admin())]
+    #[future(awt)]
+    env: Env,
+) {
+    let (manager, user, unregistered, ft) = futures::join!(
+        env.create_user(),
+        env.create_user(),
+        env.create_user(),
+        env.create_token()
+    );
+
+    env.initial_ft_storage_deposit(
+        vec![user.account_id(), manager.account_id()],
+        vec![ft.contract_id()],
+    )
+    .await;
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    let token_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+    let limit = WithdrawalLimit {
+        // long enough window for the capacity not to be restored during the test
+        window_secs: 1_000_000,
+        total: Some(U128(500)),
+        per_account: Some(U128(300)),
+    };
+
+    // only DAO or withdrawal limits manager can set limits
+    manager
+        .defuse_set_withdrawal_limit(env.defuse.contract_id().clone(), &token_id, Some(limit))
+        .await
+        .assert_err_contains("Insufficient permissions for method");
+
+    env.acl_grant_role(
+        env.defuse.contract_id().clone(),
+        Role::WithdrawalLimitsManager,
+        manager.account_id().clone(),
+    )
+    .await
+    .expect("failed to grant role");
+
+    {
+        let res = manager
+            .defuse_set_withdrawal_limit(env.defuse.contract_id().clone(), &token_id, Some(limit))
+            .await
+            .expect("unable to set withdrawal limit");
+
+        let event = DefuseEvent::WithdrawalLimitSet(WithdrawalLimitSetEvent {
+            token_id: Cow::Borrowed(&token_id),
+            limit: Some(limit),
+        })
+        .to_nep297_event()
+        .to_event_log();
+
+        assert!(res.logs().contains(&event));
+
+        assert_eq!(
+            env.defuse
+                .withdrawal_limit(TokenIdArgs {
+                    token_id: &token_id
+                })
+                .await
+                .unwrap(),
+            Some(limit)
+        );
+
+        manager
+            .defuse_set_withdrawal_limit(env.defuse.contract_id().clone(), &token_id, Some(limit))
+            .await
+            .assert_err_contains("same");
+    }
+
+    // refunded withdrawals are released from the limits
+    {
+        let (_, withdrawn) = user
+            .defuse_ft_withdraw(
+                env.defuse.contract_id(),
+                ft.contract_id(),
+                unregistered.account_id(),
+                100,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(withdrawn, 0);
+
+        assert_eq!(
+            env.defuse
+                .withdrawal_limit_available(WithdrawalLimitAvailableArgs {
+                    token_id: &token_id,
+                    account_id: Some(user.account_id()),
+                })
+                .await
+                .unwrap(),
+            Some(U128(300))
+        );
+    }
+
+    // withdrawals are capped per account
+    {
+        user.defuse_ft_withdraw(
+            env.defuse.contract_id(),
+            ft.contract_id(),
+            user.account_id(),
+            300,
+            None,
+            None,
+        )
+        .await
+        .expect("withdrawal within the limit should succeed");
+
+        user.defuse_ft_withdraw(
+            env.defuse.contract_id(),
+            ft.contract_id(),
+            user.account_id(),
+            1,
+            None,
+            None,
+        )
+        .await
+        .assert_err_contains("withdrawal_limit_exceeded");
+
+        assert_eq!(
+            env.defuse
+                .withdrawal_limit_available(WithdrawalLimitAvailableArgs {
+                    token_id: &token_id,
+                    account_id: Some(user.account_id()),
+                })
+                .await
+                .unwrap(),
+            Some(U128(0))
+        );
+        assert_eq!(
+            env.defuse
+                .withdrawal_limit_available(WithdrawalLimitAvailableArgs {
+                    token_id: &token_id,
+                    account_id: None,
+                })
+                .await
+                .unwrap(),
+            Some(U128(200))
+        );
+    }
+
+    // exempt accounts bypass the limits
+    {
+        env.acl_grant_role(
+            env.defuse.contract_id().clone(),
+            Role::WithdrawalLimitsExempt,
+            user.account_id().clone(),
+        )
+        .await
+        .expect("failed to grant role");
+
+        user.defuse_ft_withdraw(
+            env.defuse.contract_id(),
+            ft.contract_id(),
+            user.account_id(),
+            100,
+            None,
+            None,
+        )
+        .await
+        .expect("exempt account should bypass the limits");
+
+        assert_eq!(
+            env.defuse
+                .withdrawal_limit_available(WithdrawalLimitAvailableArgs {
+                    token_id: &token_id,
+                    account_id: None,
+                })
+                .await
+                .unwrap(),
+            Some(U128(200))
+        );
+    }
+
+    // force withdrawals bypass the limits
+    {
+        env.acl_grant_role(
+            env.defuse.contract_id().clone(),
+            Role::UnrestrictedWithdrawer,
+            manager.account_id().clone(),
+        )
+        .await
+        .expect("failed to grant role");
+
+        assert_eq!(
+            manager
+                .defuse_ft_force_withdraw(
+                    env.defuse.contract_id(),
+                    user.account_id(),
+                    ft.contract_id(),
+                    manager.account_id(),
+                    600,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap(),
+            600
+        );
+    }
+
+    // remove the limit
+    {
+        manager
+            .defuse_set_withdrawal_limit(env.defuse.contract_id().clone(), &token_id, None)
+            .await
+            .expect("unable to remove withdrawal limit");
+
+        assert_eq!(
+            env.defuse
+                .withdrawal_limit_available(WithdrawalLimitAvailableArgs {
+                    token_id: &token_id,
+                    account_id: Some(user.account_id()),
+                })
+                .await
+                .unwrap(),
+            None
+        );
+
+        manager
+            .defuse_set_withdrawal_limit(env.defuse.contract_id().clone(), &token_id, None)
+            .await
+            .assert_err_contains("same");
+    }
+}
@@ -3,12 +3,16 @@ use defuse_sandbox::{
     extensions::{
         acl::AccessControllableExt,
         defuse::{
-            DefuseExt, DefuseSignerExt,
+            DefuseExt, DefuseSignerExt, MtTokensForOwnerPageArgs,
             contract::Role,
             core::{
+                DefuseError,
+                accounts::AccountEvent,
                 amounts::Amounts,
+                events::DefuseEvent,
                 intents::tokens::{FtWithdraw, NotifyOnTransfer, Transfer},
                 token_id::{TokenId, nep141::Nep141TokenId},
+                tokens::{DepositReferralEvent, MAX_WITHDRAW_MEMO_LEN, WithdrawMemo},
             },
             tokens::{DepositAction, DepositMessage, ExecuteIntents},
         },
@@ -16,13 +20,18 @@ use defuse_sandbox::{
         poa::PoAFactoryExt,
     },
     kit::{Final, Gas, NearToken},
+    outcome::SuccessfulExecutionOutcome,
 };
 use defuse_test_utils::wasms::MT_RECEIVER_STUB_WASM;
 use multi_token_receiver_stub::MTReceiverMode as StubAction;
-use near_sdk_core::json_types::U128;
+use near_sdk_core::{events::AsNep297Event, json_types::U128};
 use rstest::rstest;
+use std::borrow::Cow;
 
-use crate::tests::defuse::env::{Env, env};
+use crate::{
+    tests::defuse::env::{Env, env},
+    utils::asserts::ResultAssertsExt,
+};
 
 #[rstest]
 #[tokio::test]
@@ -85,6 +94,134 @@ async fn deposit_withdraw(#[future(awt)] env: Env) {
     );
 }
 
+#[rstest]
+#[tokio::test]
+async fn withdraw_memo(#[future(awt)] env: Env) {
+    let (user, ft) = futures::join!(env.create_user(), env.create_token());
+
+    env.initial_ft_storage_deposit(vec![user.account_id()], vec![ft.contract_id()])
+        .await;
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    let withdraw = |memo: String| {
+        user.defuse_ft_withdraw(
+            env.defuse.contract_id(),
+            ft.contract_id(),
+            user.account_id(),
+            100,
+            Some(memo),
+            None,
+        )
+    };
+
+    // structured memo
+    {
+        let memo = WithdrawMemo {
+            dest_chain: "eth:1".to_string(),
+            dest_address: "0x0000000000000000000000000000000000000001".to_string(),
+            bridge: None,
+        };
+        let memo = memo.to_string();
+        assert!(WithdrawMemo::parse(&memo).unwrap().is_some());
+
+        withdraw(memo)
+            .await
+            .expect("withdrawal with structured memo should succeed");
+    }
+
+    // arbitrary memos are passed as-is
+    for memo in [
+        "WITHDRAW_TO:0x0000000000000000000000000000000000000001".to_string(),
+        r#"{"dest_chain":"eth:1","unknown":true}"#.to_string(),
+        "a".repeat(MAX_WITHDRAW_MEMO_LEN + 1),
+    ] {
+        withdraw(memo)
+            .await
+            .expect("withdrawal with plain memo should succeed");
+    }
+
+    withdraw(format!(
+        r#"{}{{"dest_chain":"eth:1","dest_address":""}}"#,
+        WithdrawMemo::PREFIX
+    ))
+    .await
+    .assert_err_contains(DefuseError::InvalidWithdrawMemo.to_string());
+
+    withdraw(format!(
+        r#"{}{{"dest_chain":"eth:1"}}"#,
+        WithdrawMemo::PREFIX
+    ))
+    .await
+    .assert_err_contains("missing field `dest_address`");
+
+    withdraw(format!(
+        "{}{}",
+        WithdrawMemo::PREFIX,
+        "a".repeat(MAX_WITHDRAW_MEMO_LEN + 1 - WithdrawMemo::PREFIX.len())
+    ))
+    .await
+    .assert_err_contains(DefuseError::WithdrawMemoTooLong(MAX_WITHDRAW_MEMO_LEN + 1).to_string());
+
+    assert_eq!(
+        env.contract::<Mt>(env.defuse.contract_id())
+            .mt_balance_of(MtBalanceOfArgs {
+                account_id: user.account_id(),
+                token_id: &TokenId::from(Nep141TokenId::new(ft.contract_id().clone())).to_string(),
+            })
+            .await
+            .unwrap()
+            .0,
+        600
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn near_deposit(#[future(awt)] env: Env) {
+    let (user, other_user) = futures::join!(env.create_user(), env.create_user());
+
+    env.initial_ft_storage_deposit(vec![user.account_id(), other_user.account_id()], vec![])
+        .await;
+
+    let wnear_id = TokenId::from(Nep141TokenId::new(env.wnear.contract_id().clone())).to_string();
+
+    let (_, deposited) = user
+        .defuse_near_deposit(env.defuse.contract_id(), None, NearToken::from_near(1))
+        .await
+        .unwrap();
+    assert_eq!(deposited, NearToken::from_near(1).as_yoctonear());
+
+    let (_, deposited) = user
+        .defuse_near_deposit(
+            env.defuse.contract_id(),
+            Some(other_user.account_id()),
+            NearToken::from_near(2),
+        )
+        .await
+        .unwrap();
+    assert_eq!(deposited, NearToken::from_near(2).as_yoctonear());
+
+    for (account_id, expected) in [
+        (user.account_id(), NearToken::from_near(1)),
+        (other_user.account_id(), NearToken::from_near(2)),
+    ] {
+        assert_eq!(
+            env.contract::<Mt>(env.defuse.contract_id())
+                .mt_balance_of(MtBalanceOfArgs {
+                    account_id,
+                    token_id: &wnear_id,
+                })
+                .await
+                .unwrap()
+                .0,
+            expected.as_yoctonear(),
+        );
+    }
+}
+
 #[rstest]
 #[tokio::test]
 async fn poa_deposit(#[future(awt)] env: Env) {
@@ -178,6 +315,7 @@ async fn deposit_withdraw_intent(#[future(awt)] env: Env) {
                         // another promise will be created for `execute_intents()`
                         refund_if_fails: false,
                     })),
+                    referral: None,
                 }
                 .to_string()
             )
@@ -276,6 +414,7 @@ async fn deposit_withdraw_intent_refund(#[future(awt)] env: Env) {
                         execute_intents: [overflow_withdraw_payload].into(),
                         refund_if_fails: true,
                     })),
+                    referral: None,
                 }
                 .to_string()
             )
@@ -412,6 +551,89 @@ async fn ft_force_withdraw(
     );
 }
 
+#[rstest]
+#[tokio::test]
+async fn mt_tokens_for_owner_page(#[future(awt)] env: Env) {
+    let (user, ft1, ft2, ft3) = futures::join!(
+        env.create_user(),
+        env.create_token(),
+        env.create_token(),
+        env.create_token()
+    );
+
+    env.initial_ft_storage_deposit(
+        vec![user.account_id()],
+        vec![ft1.contract_id(), ft2.contract_id(), ft3.contract_id()],
+    )
+    .await;
+
+    let tokens: Vec<_> = [&ft1, &ft2, &ft3]
+        .into_iter()
+        .map(|ft| {
+            (
+                TokenId::from(Nep141TokenId::new(ft.contract_id().clone())).to_string(),
+                ft.contract_id().clone(),
+            )
+        })
+        .collect();
+
+    // tokens are stored in order of deposits
+    for (_, ft) in &tokens {
+        env.defuse_ft_deposit_to(ft, 1000, user.account_id(), None)
+            .await
+            .unwrap();
+    }
+
+    let page = env
+        .defuse
+        .mt_tokens_for_owner_page(MtTokensForOwnerPageArgs {
+            account_id: user.account_id(),
+            cursor: None,
+            limit: Some(2),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        page.tokens
+            .iter()
+            .map(|token| token.token_id.as_str())
+            .collect::<Vec<_>>(),
+        [tokens[1].0.as_str(), tokens[2].0.as_str()]
+    );
+    let cursor = page.next_cursor.expect("next page should exist");
+
+    // the returned token is removed from balances between pages,
+    // so that the last one takes its place
+    user.defuse_ft_withdraw(
+        env.defuse.contract_id(),
+        &tokens[1].1,
+        user.account_id(),
+        1000,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let page = env
+        .defuse
+        .mt_tokens_for_owner_page(MtTokensForOwnerPageArgs {
+            account_id: user.account_id(),
+            cursor: Some(&cursor),
+            limit: Some(2),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        page.tokens
+            .iter()
+            .map(|token| token.token_id.as_str())
+            .collect::<Vec<_>>(),
+        [tokens[0].0.as_str()]
+    );
+    assert!(page.next_cursor.is_none());
+}
+
 #[derive(Debug, Clone)]
 struct TransferCallExpectation {
     action: StubAction,
@@ -526,6 +748,7 @@ async fn ft_transfer_call_calls_mt_on_transfer_variants(
             action: Some(DepositAction::Notify(NotifyOnTransfer::new(
                 serde_json::to_string(&expectation.action).unwrap(),
             ))),
+            referral: None,
         }
     } else {
         DepositMessage {
@@ -534,6 +757,7 @@ async fn ft_transfer_call_calls_mt_on_transfer_variants(
                 execute_intents: intents,
                 refund_if_fails: expectation.refund_if_fails,
             })),
+            referral: None,
         }
     };
 
@@ -574,3 +798,65 @@ async fn ft_transfer_call_calls_mt_on_transfer_variants(
         },
     );
 }
+
+#[rstest]
+#[tokio::test]
+async fn deposit_referral(#[future(awt)] env: Env) {
+    let (user, referral, ft) =
+        futures::join!(env.create_user(), env.create_user(), env.create_token());
+
+    env.initial_ft_storage_deposit(vec![user.account_id()], vec![ft.contract_id()])
+        .await;
+
+    env.poa_factory_ft_deposit(
+        env.poa_factory.contract_id(),
+        &env.poa_factory.ft_name(ft.contract_id()),
+        user.account_id(),
+        1000,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let res: SuccessfulExecutionOutcome = user
+        .ft(ft.contract_id())
+        .unwrap()
+        .transfer_call(
+            env.defuse.contract_id(),
+            1000u128,
+            DepositMessage::new(user.account_id().clone())
+                .with_referral(referral.account_id().clone())
+                .to_string(),
+        )
+        .await
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    let token_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    let event = DefuseEvent::DepositReferral(AccountEvent::new(
+        user.account_id(),
+        DepositReferralEvent {
+            referral: Cow::Borrowed(referral.account_id()),
+            tokens: Amounts::new([(token_id.clone(), 1000)].into()),
+        },
+    ))
+    .to_nep297_event()
+    .to_event_log();
+
+    assert!(res.logs().contains(&event));
+
+    assert_eq!(
+        env.contract::<Mt>(env.defuse.contract_id())
+            .mt_balance_of(MtBalanceOfArgs {
+                account_id: user.account_id(),
+                token_id: &token_id.to_string(),
+            })
+            .await
+            .unwrap()
+            .0,
+        1000
+    );
+}
@@ -479,6 +479,7 @@ async fn nft_transfer_call_calls_mt_on_transfer_variants(
             action: Some(DepositAction::Notify(NotifyOnTransfer::new(
                 serde_json::to_string(&expectation.action).unwrap(),
             ))),
+            referral: None,
         }
     } else {
         DepositMessage {
@@ -487,6 +488,7 @@ async fn nft_transfer_call_calls_mt_on_transfer_variants(
                 execute_intents: intents,
                 refund_if_fails: expectation.refund_if_fails,
             })),
+            referral: None,
         }
     };
 
@@ -0,0 +1,205 @@
+use defuse_sandbox::{
+    extensions::{
+        defuse::{
+            DefuseExt, MtAllowanceArgs,
+            allowances::ALLOWANCE_STORAGE_DEPOSIT,
+            core::{
+                DefuseError,
+                accounts::AccountEvent,
+                allowances::AllowanceSetEvent,
+                events::DefuseEvent,
+                token_id::{TokenId, nep141::Nep141TokenId},
+            },
+        },
+        mt::{Mt, MtBalanceOfArgs},
+    },
+    kit::NearToken,
+};
+use defuse_test_utils::asserts::ResultAssertsExt;
+use near_sdk_core::{events::AsNep297Event, json_types::U128};
+use rstest::rstest;
+use std::borrow::Cow;
+
+use crate::tests::defuse::env::{Env, env};
+
+#[rstest]
+#[tokio::test]
+async fn mt_transfer_from(#[future(awt)] env: Env) {
+    let (owner, spender, receiver, ft) = futures::join!(
+        env.create_user(),
+        env.create_user(),
+        env.create_user(),
+        env.create_token()
+    );
+
+    env.initial_ft_storage_deposit([owner.account_id()], [ft.contract_id()])
+        .await;
+
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, owner.account_id(), None)
+        .await
+        .unwrap();
+
+    let ft_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    let allowance = || async {
+        env.defuse
+            .mt_allowance(MtAllowanceArgs {
+                owner_id: owner.account_id(),
+                spender_id: spender.account_id(),
+                token_id: &ft_id,
+            })
+            .await
+            .unwrap()
+            .0
+    };
+
+    // nothing can be spent without approval
+    spender
+        .defuse_mt_transfer_from(
+            env.defuse.contract_id(),
+            owner.account_id(),
+            receiver.account_id(),
+            &ft_id,
+            100,
+        )
+        .await
+        .assert_err_contains(DefuseError::AllowanceExceeded.to_string());
+
+    // storage deposit is required for a new allowance
+    owner
+        .defuse_mt_approve(
+            env.defuse.contract_id(),
+            spender.account_id(),
+            &ft_id,
+            300,
+            None,
+            NearToken::from_yoctonear(1),
+        )
+        .await
+        .assert_err_contains("insufficient deposit");
+
+    // approve
+    {
+        let res = owner
+            .defuse_mt_approve(
+                env.defuse.contract_id(),
+                spender.account_id(),
+                &ft_id,
+                300,
+                None,
+                ALLOWANCE_STORAGE_DEPOSIT,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            res.logs(),
+            [DefuseEvent::AllowanceSet(AccountEvent::new(
+                owner.account_id().clone(),
+                AllowanceSetEvent {
+                    spender_id: Cow::Borrowed(spender.account_id()),
+                    token_id: Cow::Borrowed(&ft_id),
+                    amount: U128(300),
+                    expires_at: None,
+                },
+            ))
+            .to_nep297_event()
+            .to_event_log()]
+        );
+        assert_eq!(allowance().await, 300);
+    }
+
+    // spend part of the allowance
+    spender
+        .defuse_mt_transfer_from(
+            env.defuse.contract_id(),
+            owner.account_id(),
+            receiver.account_id(),
+            &ft_id,
+            200,
+        )
+        .await
+        .unwrap();
+    assert_eq!(allowance().await, 100);
+
+    // can't spend more than allowed
+    spender
+        .defuse_mt_transfer_from(
+            env.defuse.contract_id(),
+            owner.account_id(),
+            receiver.account_id(),
+            &ft_id,
+            150,
+        )
+        .await
+        .assert_err_contains(DefuseError::AllowanceExceeded.to_string());
+
+    // revoke refunds storage deposit
+    let balance = env.balance(owner.account_id()).await.unwrap().total;
+    owner
+        .defuse_mt_approve(
+            env.defuse.contract_id(),
+            spender.account_id(),
+            &ft_id,
+            0,
+            None,
+            NearToken::from_yoctonear(1),
+        )
+        .await
+        .unwrap();
+    assert_eq!(allowance().await, 0);
+    assert!(env.balance(owner.account_id()).await.unwrap().total > balance);
+
+    spender
+        .defuse_mt_transfer_from(
+            env.defuse.contract_id(),
+            owner.account_id(),
+            receiver.account_id(),
+            &ft_id,
+            100,
+        )
+        .await
+        .assert_err_contains(DefuseError::AllowanceExceeded.to_string());
+
+    // spending the whole allowance refunds storage deposit to the owner
+    owner
+        .defuse_mt_approve(
+            env.defuse.contract_id(),
+            spender.account_id(),
+            &ft_id,
+            100,
+            None,
+            ALLOWANCE_STORAGE_DEPOSIT,
+        )
+        .await
+        .unwrap();
+    let balance = env.balance(owner.account_id()).await.unwrap().total;
+    spender
+        .defuse_mt_transfer_from(
+            env.defuse.contract_id(),
+            owner.account_id(),
+            receiver.account_id(),
+            &ft_id,
+            100,
+        )
+        .await
+        .unwrap();
+    assert_eq!(allowance().await, 0);
+    assert_eq!(
+        env.balance(owner.account_id()).await.unwrap().total,
+        balance.saturating_add(ALLOWANCE_STORAGE_DEPOSIT),
+    );
+
+    for (account_id, expected) in [(owner.account_id(), 700), (receiver.account_id(), 300)] {
+        assert_eq!(
+            env.contract::<Mt>(env.defuse.contract_id())
+                .mt_balance_of(MtBalanceOfArgs {
+                    account_id,
+                    token_id: &ft_id.to_string(),
+                })
+                .await
+                .unwrap()
+                .0,
+            expected,
+        );
+    }
+}
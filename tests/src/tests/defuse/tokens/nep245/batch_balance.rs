@@ -0,0 +1,61 @@
+use defuse_sandbox::extensions::defuse::{
+    MtBatchBalanceOfManyArgs,
+    core::token_id::{TokenId, nep141::Nep141TokenId},
+};
+use near_sdk_core::json_types::U128;
+use rstest::rstest;
+
+use crate::tests::defuse::env::{Env, env};
+
+#[rstest]
+#[tokio::test]
+async fn mt_batch_balance_of_many(#[future(awt)] env: Env) {
+    let (user1, user2, ft1, ft2) = futures::join!(
+        env.create_user(),
+        env.create_user(),
+        env.create_token(),
+        env.create_token()
+    );
+
+    env.initial_ft_storage_deposit(
+        [user1.account_id(), user2.account_id()],
+        [ft1.contract_id(), ft2.contract_id()],
+    )
+    .await;
+
+    for (ft, account_id, amount) in [
+        (&ft1, user1.account_id(), 100),
+        (&ft2, user1.account_id(), 200),
+        (&ft1, user2.account_id(), 300),
+    ] {
+        env.defuse_ft_deposit_to(ft.contract_id(), amount, account_id, None)
+            .await
+            .unwrap();
+    }
+
+    let token_ids = [ft1.contract_id(), ft2.contract_id()]
+        .map(|contract_id| TokenId::from(Nep141TokenId::new(contract_id.clone())).to_string());
+
+    assert_eq!(
+        env.defuse
+            .mt_batch_balance_of_many(MtBatchBalanceOfManyArgs {
+                account_ids: &[
+                    user1.account_id().clone(),
+                    user2.account_id().clone(),
+                    "unknown.near".parse().unwrap(),
+                ],
+                token_ids: &[
+                    token_ids[0].clone(),
+                    token_ids[1].clone(),
+                    "invalid".to_string(),
+                ],
+            })
+            .await
+            .unwrap(),
+        [
+            [U128(100), U128(200), U128(0)],
+            [U128(300), U128(0), U128(0)],
+            [U128(0), U128(0), U128(0)],
+        ]
+    );
+}
@@ -0,0 +1,78 @@
+use defuse_sandbox::{
+    extensions::defuse::{
+        DefuseExt, MtTokenIdsArgs,
+        core::token_id::{TokenId, nep141::Nep141TokenId},
+        nep245::metadata::MTBaseTokenMetadata,
+    },
+    kit::NearToken,
+};
+use defuse_test_utils::asserts::ResultAssertsExt;
+use rstest::rstest;
+
+use crate::tests::defuse::env::{Env, env};
+
+#[rstest]
+#[tokio::test]
+async fn mt_metadata_sync(#[future(awt)] env: Env) {
+    let (user, ft) = futures::join!(env.create_user(), env.create_token());
+
+    let ft_id = TokenId::from(Nep141TokenId::new(ft.contract_id().clone()));
+
+    let base_metadata = || async {
+        env.defuse
+            .mt_metadata_base_by_token_id(MtTokenIdsArgs {
+                token_ids: &[ft_id.to_string()],
+            })
+            .await
+            .unwrap()
+    };
+
+    // tokens without supply can't be synced
+    user.defuse_mt_metadata_sync(
+        env.defuse.contract_id(),
+        &ft_id,
+        NearToken::from_millinear(100),
+    )
+    .await
+    .assert_err_contains("token has no supply");
+
+    env.initial_ft_storage_deposit([user.account_id()], [ft.contract_id()])
+        .await;
+    env.defuse_ft_deposit_to(ft.contract_id(), 1000, user.account_id(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(base_metadata().await, [None]);
+
+    // storage is not covered
+    let (_, cached) = user
+        .defuse_mt_metadata_sync(
+            env.defuse.contract_id(),
+            &ft_id,
+            NearToken::from_yoctonear(1),
+        )
+        .await
+        .unwrap();
+    assert!(!cached);
+    assert_eq!(base_metadata().await, [None]);
+
+    let (_, cached) = user
+        .defuse_mt_metadata_sync(
+            env.defuse.contract_id(),
+            &ft_id,
+            NearToken::from_millinear(100),
+        )
+        .await
+        .unwrap();
+    assert!(cached);
+    assert_eq!(
+        base_metadata().await,
+        [Some(MTBaseTokenMetadata {
+            name: String::new(),
+            id: ft_id.to_string(),
+            symbol: Some(String::new()),
+            decimals: Some("0".to_string()),
+            ..Default::default()
+        })]
+    );
+}
@@ -1,4 +1,7 @@
+mod allowances;
+mod batch_balance;
 mod letter_gen;
+mod metadata;
 mod mt_deposit_resolve_gas;
 mod mt_transfer_resolve_gas;
 
@@ -1095,6 +1098,7 @@ async fn mt_transfer_call_calls_mt_on_transfer_single_token(
             action: Some(DepositAction::Notify(NotifyOnTransfer::new(
                 serde_json::to_string(&expectation.action).unwrap(),
             ))),
+            referral: None,
         }
     } else {
         DepositMessage {
@@ -1103,6 +1107,7 @@ async fn mt_transfer_call_calls_mt_on_transfer_single_token(
                 execute_intents: intents,
                 refund_if_fails: expectation.refund_if_fails,
             })),
+            referral: None,
         }
     };
 
@@ -1313,6 +1318,7 @@ async fn mt_transfer_call_calls_mt_on_transfer_multi_token(
             action: Some(DepositAction::Notify(NotifyOnTransfer::new(
                 serde_json::to_string(&expectation.action).unwrap(),
             ))),
+            referral: None,
         }
     } else {
         DepositMessage {
@@ -1321,6 +1327,7 @@ async fn mt_transfer_call_calls_mt_on_transfer_multi_token(
                 execute_intents: intents,
                 refund_if_fails: expectation.refund_if_fails,
             })),
+            referral: None,
         }
     };
 
@@ -1444,6 +1451,7 @@ async fn mt_transfer_call_circullar_callback(
         action: Some(DepositAction::Notify(NotifyOnTransfer::new(
             serde_json::to_string(&DepositMessage::new(user.account_id().clone())).unwrap(),
         ))),
+        referral: None,
     };
 
     // Get the nep245 token id for defuse1's wrapped token in defuse2
@@ -1560,6 +1568,7 @@ async fn mt_transfer_call_circullar_deposit(
                 action: Some(DepositAction::Notify(NotifyOnTransfer::new(
                     serde_json::to_string(&DepositMessage::new(user.account_id().clone())).unwrap(),
                 ))),
+                referral: None,
             })
             .unwrap(),
         )),
@@ -1718,6 +1727,7 @@ async fn mt_transfer_call_duplicate_tokens_with_stub_execute_and_refund(
         action: Some(DepositAction::Notify(NotifyOnTransfer::new(
             serde_json::to_string(&stub_action).unwrap(),
         ))),
+        referral: None,
     };
 
     let result = user
@@ -190,6 +190,7 @@ async fn run_deposit_resolve_gas_test(
             NotifyOnTransfer::new(serde_json::to_string(&MTReceiverMode::MaliciousRefund).unwrap())
                 .with_min_gas(Gas::from_tgas(5)),
         )),
+        referral: None,
     };
 
     let defuse_token_ids = make_defuse_token_ids(gen_mode, &author_account, &token_ids);
@@ -377,6 +378,7 @@ async fn mt_desposit_resolve_can_handle_large_blob_value_returned_from_notificat
             // NOTE: 300TGas - (10*2+4)
             .with_min_gas(Gas::from_tgas(250)),
         )),
+        referral: None,
     };
 
     let execution_result = author_account